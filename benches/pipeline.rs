@@ -0,0 +1,214 @@
+use std::hint::black_box;
+
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+use criterion::{
+    criterion_group,
+    criterion_main,
+    BenchmarkId,
+    Criterion,
+};
+
+#[derive(Component, Reflect, Clone, Default)]
+#[reflect(Component)]
+struct Position(Vec3);
+
+#[derive(Component, Reflect, Clone, Default)]
+#[reflect(Component)]
+struct Health(u32);
+
+#[derive(Component, Reflect, Clone, Default)]
+#[reflect(Component)]
+struct Inventory(Vec<u32>);
+
+/// Synthetic world configuration.
+#[derive(Clone, Copy)]
+struct Scenario {
+    name: &'static str,
+    entities: usize,
+    inventory: usize,
+}
+
+const SCENARIOS: &[Scenario] = &[
+    Scenario {
+        name: "small",
+        entities: 100,
+        inventory: 0,
+    },
+    Scenario {
+        name: "large",
+        entities: 10_000,
+        inventory: 0,
+    },
+    Scenario {
+        name: "vec_heavy",
+        entities: 1_000,
+        inventory: 64,
+    },
+];
+
+fn world(scenario: Scenario) -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Position>()
+        .register_type::<Health>()
+        .register_type::<Inventory>()
+        .register_type::<Vec<u32>>();
+
+    for i in 0..scenario.entities {
+        #[allow(clippy::cast_precision_loss)]
+        let mut entity = app
+            .world
+            .spawn((Position(Vec3::splat(i as f32)), Health(100)));
+
+        if scenario.inventory > 0 {
+            #[allow(clippy::cast_possible_truncation)]
+            entity.insert(Inventory((0..scenario.inventory as u32).collect()));
+        }
+    }
+
+    app
+}
+
+fn capture(world: &World) -> Snapshot {
+    Snapshot::builder(world).extract_all_entities().build()
+}
+
+fn serialize<F: Format>(world: &World, snapshot: &Snapshot) -> Vec<u8> {
+    let registry = world.resource::<AppTypeRegistry>();
+    let mut buf = Vec::new();
+
+    F::serialize(&mut buf, &SnapshotSerializer::new(snapshot, registry)).unwrap();
+
+    buf
+}
+
+fn deserialize<F: Format>(world: &World, data: &[u8]) -> Snapshot {
+    let registry = world.resource::<AppTypeRegistry>().read();
+
+    F::deserialize(data, SnapshotDeserializer::new(&registry)).unwrap()
+}
+
+fn bench_capture(c: &mut Criterion) {
+    let mut group = c.benchmark_group("capture");
+
+    for &scenario in SCENARIOS {
+        let app = world(scenario);
+
+        group.bench_function(scenario.name, |b| b.iter(|| capture(&app.world)));
+    }
+}
+
+fn bench_clone(c: &mut Criterion) {
+    let mut group = c.benchmark_group("clone");
+
+    for &scenario in SCENARIOS {
+        let mut app = world(scenario);
+
+        app.register_type_data::<Position, ReflectCloneValue>()
+            .register_type_data::<Health, ReflectCloneValue>()
+            .register_type_data::<Inventory, ReflectCloneValue>();
+
+        let world = &mut app.world;
+
+        let mut values: Vec<&dyn Reflect> = Vec::new();
+        let mut query = world.query::<(&Position, &Health, Option<&Inventory>)>();
+
+        for (position, health, inventory) in query.iter(world) {
+            values.push(position);
+            values.push(health);
+
+            if let Some(inventory) = inventory {
+                values.push(inventory);
+            }
+        }
+
+        let registry = world.resource::<AppTypeRegistry>().read();
+
+        group.bench_function(BenchmarkId::new("dynamic", scenario.name), |b| {
+            b.iter(|| values.iter().map(|v| v.clone_value()).collect::<Vec<_>>());
+        });
+
+        group.bench_function(BenchmarkId::new("fast", scenario.name), |b| {
+            b.iter(|| clone_reflect_values(values.iter().copied(), &registry));
+        });
+    }
+}
+
+fn bench_format<F: Format>(c: &mut Criterion, format: &str) {
+    let mut group = c.benchmark_group(format!("format/{format}"));
+
+    for &scenario in SCENARIOS {
+        let app = world(scenario);
+        let snapshot = capture(&app.world);
+        let data = serialize::<F>(&app.world, &snapshot);
+
+        group.bench_function(BenchmarkId::new("serialize", scenario.name), |b| {
+            b.iter(|| serialize::<F>(&app.world, &snapshot));
+        });
+
+        group.bench_function(BenchmarkId::new("deserialize", scenario.name), |b| {
+            b.iter(|| deserialize::<F>(&app.world, &data));
+        });
+    }
+}
+
+fn bench_formats(c: &mut Criterion) {
+    bench_format::<RMPFormat>(c, "rmp");
+    bench_format::<JSONFormat>(c, "json");
+}
+
+fn bench_io(c: &mut Criterion) {
+    let mut group = c.benchmark_group("io");
+    let dir = std::env::temp_dir().join("bevy_save_bench");
+
+    std::fs::create_dir_all(&dir).unwrap();
+
+    for &scenario in SCENARIOS {
+        let app = world(scenario);
+        let snapshot = capture(&app.world);
+        let data = serialize::<RMPFormat>(&app.world, &snapshot);
+        let path = dir.join(scenario.name);
+
+        group.bench_function(BenchmarkId::new("write", scenario.name), |b| {
+            b.iter(|| std::fs::write(&path, &data).unwrap());
+        });
+
+        group.bench_function(BenchmarkId::new("read", scenario.name), |b| {
+            b.iter(|| black_box(std::fs::read(&path).unwrap()));
+        });
+    }
+}
+
+fn bench_apply(c: &mut Criterion) {
+    let mut group = c.benchmark_group("apply");
+
+    for &scenario in SCENARIOS {
+        let app = world(scenario);
+        let snapshot = capture(&app.world);
+
+        group.bench_function(scenario.name, |b| {
+            b.iter_batched(
+                || {
+                    world(Scenario {
+                        entities: 0,
+                        ..scenario
+                    })
+                },
+                |mut app| snapshot.apply(&mut app.world).unwrap(),
+                criterion::BatchSize::LargeInput,
+            );
+        });
+    }
+}
+
+criterion_group!(
+    benches,
+    bench_capture,
+    bench_clone,
+    bench_formats,
+    bench_io,
+    bench_apply
+);
+criterion_main!(benches);
@@ -132,6 +132,13 @@ struct Paddle;
 #[reflect(Component)]
 struct Ball;
 
+// Created once, so loading does not leak a new mesh and material every time
+#[derive(Resource, Clone)]
+struct BallAssets {
+    mesh: Mesh2dHandle,
+    material: Handle<ColorMaterial>,
+}
+
 #[derive(Component, Deref, DerefMut, Reflect, Default)]
 #[reflect(Component)]
 struct Velocity(Vec2);
@@ -268,10 +275,16 @@ fn setup(
     ));
 
     // Ball
+    let ball_assets = BallAssets {
+        mesh: meshes.add(Circle::default()).into(),
+        material: materials.add(ColorMaterial::from(BALL_COLOR)),
+    };
+    commands.insert_resource(ball_assets.clone());
+
     commands.spawn((
         MaterialMesh2dBundle {
-            mesh: meshes.add(Circle::default()).into(),
-            material: materials.add(ColorMaterial::from(BALL_COLOR)),
+            mesh: ball_assets.mesh,
+            material: ball_assets.material,
             transform: Transform::from_translation(BALL_STARTING_POSITION).with_scale(BALL_SIZE),
             ..default()
         },
@@ -634,10 +647,7 @@ impl Pipeline for BreakoutPipeline {
     }
 
     fn apply(world: &mut World, snapshot: &Snapshot) -> Result<(), bevy_save::Error> {
-        let mut meshes = world.resource_mut::<Assets<Mesh>>();
-        let mesh = Mesh2dHandle(meshes.add(Circle::default()));
-        let mut materials = world.resource_mut::<Assets<ColorMaterial>>();
-        let material = materials.add(ColorMaterial::from(BALL_COLOR));
+        let BallAssets { mesh, material } = world.resource::<BallAssets>().clone();
 
         snapshot
             .applier(world)
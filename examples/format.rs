@@ -15,6 +15,10 @@ use serde::{
 struct RONFormat;
 
 impl Format for RONFormat {
+    fn name() -> &'static str {
+        "ron"
+    }
+
     fn extension() -> &'static str {
         ".ron"
     }
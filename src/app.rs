@@ -1,6 +1,15 @@
-use std::any::Any;
+use std::{
+    any::{
+        Any,
+        TypeId,
+    },
+    time::Duration,
+};
 
-use bevy::prelude::*;
+use bevy::{
+    prelude::*,
+    reflect::GetTypeRegistration,
+};
 
 use crate::prelude::*;
 
@@ -14,11 +23,113 @@ pub trait AppSaveableExt {
 
     /// Set a type to ignore rollback - it will be included in save/load but it won't change during rollback.
     fn deny_rollback<T: Any>(&mut self) -> &mut Self;
+
+    /// Set a type to allow rollback in the given [`SaveDomain`](crate::SaveDomain).
+    fn allow_rollback_in<T: Any>(&mut self, domain: &'static str) -> &mut Self;
+
+    /// Set a type to ignore rollback in the given [`SaveDomain`](crate::SaveDomain).
+    fn deny_rollback_in<T: Any>(&mut self, domain: &'static str) -> &mut Self;
+
+    /// Apply the resource `A` after the resource `B` when applying a snapshot.
+    ///
+    /// See [`ResourceOrder`].
+    fn apply_resource_after<A: Resource, B: Resource>(&mut self) -> &mut Self;
+
+    /// Insert the component `A` after the component `B` on each entity when applying a snapshot.
+    ///
+    /// See [`ComponentOrder`].
+    fn apply_component_after<A: Component, B: Component>(&mut self) -> &mut Self;
+
+    /// Apply saved values of the type `T` with the closure instead of inserting them.
+    ///
+    /// See [`ComponentRemaps`].
+    fn remap_component<T: FromReflect + TypePath + GetTypeRegistration>(
+        &mut self,
+        remap: impl Fn(T, &mut EntityWorldMut) + Send + Sync + 'static,
+    ) -> &mut Self;
+
+    /// Remap the [`Entity`] keys of maps in the resource `R` when applying a snapshot.
+    ///
+    /// See [`EntityKeyRemaps`].
+    fn remap_entity_keys<R: Resource + GetTypeRegistration>(&mut self) -> &mut Self;
+
+    /// Validate saved values of the type `T` with the closure before applying them, replacing any previous validator.
+    ///
+    /// Use this for types of other crates, which cannot implement [`Validate`]. See [`ReflectValidate`].
+    fn add_validator<T: FromReflect + TypePath + GetTypeRegistration>(
+        &mut self,
+        validate: impl Fn(&mut T) -> Result<(), String> + Send + Sync + 'static,
+    ) -> &mut Self;
+
+    /// Add a save interceptor to the end of the [`SaveInterceptors`] chain.
+    fn add_save_interceptor(
+        &mut self,
+        interceptor: impl Fn(&mut SaveContext) + Send + Sync + 'static,
+    ) -> &mut Self;
+
+    /// Save the non-send resource `T` as the reflectable proxy `R`, converting it on the main thread.
+    ///
+    /// See [`NonSendSaveables`].
+    fn register_nonsend_saveable<T, R>(
+        &mut self,
+        capture: impl Fn(&T) -> R + Send + Sync + 'static,
+        apply: impl Fn(&mut T, R) + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        T: 'static,
+        R: Reflect + FromReflect + TypePath + GetTypeRegistration;
+
+    /// Set the [`GameVersion`] stored alongside every save, and the range of versions that can be loaded.
+    fn set_game_version(
+        &mut self,
+        version: impl Into<String>,
+        compatibility: SaveCompatibility,
+    ) -> &mut Self;
+
+    /// Register a type added by the mod with the given name, recording it in the [`ModManifest`] stored in saves.
+    fn register_mod_type<T: GetTypeRegistration + TypePath>(&mut self, name: &str) -> &mut Self;
+
+    /// Capture a rollback checkpoint with the [`Pipeline`] at most every `interval` ticks.
+    ///
+    /// See [`CaptureThrottle`].
+    fn checkpoint_every<P: Pipeline + 'static>(&mut self, interval: u32) -> &mut Self;
+
+    /// Perform a final save with the [`Pipeline`] when the app exits.
+    ///
+    /// See [`ExitSaves`].
+    fn on_exit_save<P: Pipeline + Send + Sync + 'static>(&mut self, pipeline: P) -> &mut Self;
+
+    /// Coalesce writes to the backend `B` within the given window, flushing them when it elapses and on exit.
+    ///
+    /// Pipelines must use [`Batched<B>`] as their [`Pipeline::Backend`], and be initialized before calling this.
+    fn batch_writes<B: Backend<String> + Resource + Default>(
+        &mut self,
+        window: Duration,
+    ) -> &mut Self;
+
+    /// Enforce the [`SaveQuota`] on saves written through [`Quota<B>`](Quota), sending a [`SlotCleanup`] event for each
+    /// deleted slot.
+    fn save_quota<B: Backend<String> + SlotStorage + Resource + Default>(
+        &mut self,
+        quota: SaveQuota,
+    ) -> &mut Self;
+
+    /// In debug builds, warn at startup about registered types that cannot be saved or restored correctly.
+    ///
+    /// See [`validate_saveables`].
+    fn validate_saveables(&mut self) -> &mut Self;
 }
 
 impl AppSaveableExt for App {
     fn init_pipeline<P: Pipeline>(&mut self) -> &mut Self {
         P::build(self);
+
+        if let Some(domain) = P::domain() {
+            self.world
+                .get_resource_or_insert_with(SaveDomains::default)
+                .get_or_insert(domain);
+        }
+
         self
     }
 
@@ -33,4 +144,152 @@ impl AppSaveableExt for App {
         registry.deny::<T>();
         self
     }
+
+    fn allow_rollback_in<T: Any>(&mut self, domain: &'static str) -> &mut Self {
+        self.world
+            .get_resource_or_insert_with(SaveDomains::default)
+            .get_or_insert(domain)
+            .registry
+            .allow::<T>();
+        self
+    }
+
+    fn deny_rollback_in<T: Any>(&mut self, domain: &'static str) -> &mut Self {
+        self.world
+            .get_resource_or_insert_with(SaveDomains::default)
+            .get_or_insert(domain)
+            .registry
+            .deny::<T>();
+        self
+    }
+
+    fn apply_resource_after<A: Resource, B: Resource>(&mut self) -> &mut Self {
+        let mut order = self.world.resource_mut::<ResourceOrder>();
+        order.apply_after::<A, B>();
+        self
+    }
+
+    fn apply_component_after<A: Component, B: Component>(&mut self) -> &mut Self {
+        let mut order = self.world.resource_mut::<ComponentOrder>();
+        order.apply_after::<A, B>();
+        self
+    }
+
+    fn remap_component<T: FromReflect + TypePath + GetTypeRegistration>(
+        &mut self,
+        remap: impl Fn(T, &mut EntityWorldMut) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.register_type::<T>();
+        self.world.resource_mut::<ComponentRemaps>().remap(remap);
+        self
+    }
+
+    fn remap_entity_keys<R: Resource + GetTypeRegistration>(&mut self) -> &mut Self {
+        self.register_type::<R>();
+        self.world.resource_mut::<EntityKeyRemaps>().remap::<R>();
+        self
+    }
+
+    fn add_validator<T: FromReflect + TypePath + GetTypeRegistration>(
+        &mut self,
+        validate: impl Fn(&mut T) -> Result<(), String> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.register_type::<T>();
+        self.world
+            .resource::<AppTypeRegistry>()
+            .write()
+            .get_mut(TypeId::of::<T>())
+            .expect("type was registered")
+            .insert(ReflectValidate::from_fn(validate));
+        self
+    }
+
+    fn add_save_interceptor(
+        &mut self,
+        interceptor: impl Fn(&mut SaveContext) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.world
+            .get_resource_or_insert_with(SaveInterceptors::default)
+            .add(interceptor);
+        self
+    }
+
+    fn register_nonsend_saveable<T, R>(
+        &mut self,
+        capture: impl Fn(&T) -> R + Send + Sync + 'static,
+        apply: impl Fn(&mut T, R) + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        T: 'static,
+        R: Reflect + FromReflect + TypePath + GetTypeRegistration,
+    {
+        self.register_type::<R>();
+        self.world
+            .get_resource_or_insert_with(NonSendSaveables::default)
+            .register(capture, apply);
+        self
+    }
+
+    fn set_game_version(
+        &mut self,
+        version: impl Into<String>,
+        compatibility: SaveCompatibility,
+    ) -> &mut Self {
+        self.register_type::<GameVersion>()
+            .insert_resource(GameVersion(version.into()))
+            .insert_resource(compatibility)
+    }
+
+    fn register_mod_type<T: GetTypeRegistration + TypePath>(&mut self, name: &str) -> &mut Self {
+        self.register_type::<T>()
+            .register_type::<ModManifest>()
+            .register_type::<ModInfo>()
+            .register_type::<Vec<ModInfo>>()
+            .register_type::<Vec<String>>();
+
+        self.world
+            .get_resource_or_insert_with(ModManifest::default)
+            .add_type(name, T::type_path());
+
+        self
+    }
+
+    fn checkpoint_every<P: Pipeline + 'static>(&mut self, interval: u32) -> &mut Self {
+        self.insert_resource(CaptureThrottle::<P>::new(interval))
+            .add_systems(PostUpdate, CaptureThrottle::<P>::checkpoint.in_set(SaveSet))
+    }
+
+    fn on_exit_save<P: Pipeline + Send + Sync + 'static>(&mut self, pipeline: P) -> &mut Self {
+        self.world.resource_mut::<ExitSaves>().save(pipeline);
+        self
+    }
+
+    fn batch_writes<B: Backend<String> + Resource + Default>(
+        &mut self,
+        window: Duration,
+    ) -> &mut Self {
+        self.insert_resource(Batched::new(B::default(), window))
+            .add_systems(
+                Last,
+                (
+                    Batched::<B>::flush_expired_system,
+                    Batched::<B>::flush_on_exit.after(ExitSaves::apply),
+                ),
+            )
+    }
+
+    fn save_quota<B: Backend<String> + SlotStorage + Resource + Default>(
+        &mut self,
+        quota: SaveQuota,
+    ) -> &mut Self {
+        self.insert_resource(Quota::new(B::default(), quota))
+            .add_event::<SlotCleanup>()
+            .add_systems(Last, Quota::<B>::send_cleanup_events)
+    }
+
+    fn validate_saveables(&mut self) -> &mut Self {
+        #[cfg(debug_assertions)]
+        self.add_systems(PostStartup, log_saveable_issues);
+        self
+    }
 }
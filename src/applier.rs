@@ -5,7 +5,11 @@ use std::{
 
 use bevy::{
     ecs::{
-        entity::EntityHashMap,
+        component::ComponentId,
+        entity::{
+            EntityHashMap,
+            EntityHashSet,
+        },
         query::QueryFilter,
         reflect::ReflectMapEntities,
         system::{
@@ -15,13 +19,40 @@ use bevy::{
         world::EntityRef,
     },
     prelude::*,
-    scene::SceneSpawnError,
+    reflect::{
+        DynamicMap,
+        Map,
+        ReflectMut,
+        ReflectRef,
+        TypeRegistry,
+    },
+    scene::{
+        DynamicEntity,
+        SceneSpawnError,
+    },
     utils::HashMap,
 };
 
 use crate::{
+    profile::profile_span,
+    sparse::marker_value,
+    ComponentOrder,
+    ComponentRemaps,
+    DefaultComponents,
+    EntityFilter,
+    EntityKeyRemaps,
     Error,
+    MarkerTypes,
+    Markers,
+    NonSendSaveables,
+    ReflectValidate,
+    ResourceOrder,
+    RollbackConfig,
+    SaveId,
     Snapshot,
+    Tombstones,
+    UnknownComponents,
+    UnknownResources,
 };
 
 /// A [`Hook`] runs on each entity when applying a snapshot.
@@ -53,6 +84,381 @@ impl<T> Hook for T where T: for<'a> Fn(&'a EntityRef, &'a mut EntityCommands) +
 /// A boxed [`Hook`].
 pub type BoxedHook = Box<dyn Hook>;
 
+/// A [`SnapshotHook`] runs on each entity when applying a snapshot, with access to the captured data of the entity.
+///
+/// # Example
+/// This could be used to fix up components based on the captured data rather than the applied entity.
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # let world = &mut app.world;
+/// # let snapshot = Snapshot::from_world(world);
+/// snapshot
+///     .applier(world)
+///     .hook_with_snapshot(|source, entity, cmds| {
+///         if source.components.is_empty() {
+///             cmds.entity(entity.id()).despawn();
+///         }
+///     })
+///     .apply();
+/// ```
+pub trait SnapshotHook:
+    for<'a> Fn(&'a DynamicEntity, &'a EntityRef, &'a mut Commands) + Send + Sync
+{
+}
+
+impl<T> SnapshotHook for T where
+    T: for<'a> Fn(&'a DynamicEntity, &'a EntityRef, &'a mut Commands) + Send + Sync
+{
+}
+
+/// A boxed [`SnapshotHook`].
+pub type BoxedSnapshotHook = Box<dyn SnapshotHook>;
+
+type ResourceHookFn = Box<dyn Fn(Option<&dyn Reflect>, &World, &mut Commands) + Send + Sync>;
+
+type FilteredHook = (fn(&mut World) -> EntityHashSet, BoxedHook);
+
+type DespawnPredicate<'a> = Box<dyn Fn(&EntityRef) -> bool + 'a>;
+
+/// Releases an entity despawned by the applier, without recording a tombstone for it.
+fn release(world: &mut World, allocator: &mut dyn EntityAllocator, entity: Entity) {
+    if let Some(mut tombstones) = world.get_resource_mut::<Tombstones>() {
+        tombstones.forget(entity);
+    }
+
+    allocator.release(world, entity);
+}
+
+fn matching<F: QueryFilter>(world: &mut World) -> EntityHashSet {
+    world.query_filtered::<Entity, F>().iter(world).collect()
+}
+
+/// Determines how the [`SnapshotApplier`] handles entities whose [`Parent`] was not included in the snapshot.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MissingParentPolicy {
+    /// Spawn a new empty entity to act as the parent, shared between siblings.
+    #[default]
+    SpawnEmpty,
+
+    /// Attach the entity to the given parent instead.
+    ///
+    /// Applying fails with [`Error::NoSuchEntity`] if the parent does not exist.
+    ReparentTo(Entity),
+
+    /// Remove the [`Parent`], leaving the entity at the root of the hierarchy.
+    Strip,
+}
+
+/// Provides the entities the [`SnapshotApplier`] spawns and takes back the entities it despawns, allowing entities
+/// to be pooled.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component)]
+/// struct Bullet;
+///
+/// #[derive(Default)]
+/// struct Pool(Vec<Entity>);
+///
+/// impl EntityAllocator for Pool {
+///     fn allocate(&mut self, world: &mut World) -> Entity {
+///         self.0.pop().unwrap_or_else(|| world.spawn_empty().id())
+///     }
+///
+///     fn release(&mut self, world: &mut World, entity: Entity) {
+///         world.entity_mut(entity).retain::<()>();
+///         self.0.push(entity);
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # app.register_type::<Bullet>();
+/// # let world = &mut app.world;
+/// let bullet = world.spawn(Bullet).id();
+/// let snapshot = Snapshot::builder(world).extract_all_entities().build();
+///
+/// let mut pool = Pool::default();
+///
+/// snapshot
+///     .applier(world)
+///     .despawn::<With<Bullet>>()
+///     .allocator(&mut pool)
+///     .apply()
+///     .unwrap();
+///
+/// // The despawned bullet was reused
+/// assert!(world.get::<Bullet>(bullet).is_some());
+/// ```
+pub trait EntityAllocator {
+    /// Returns an entity without components to apply a snapshot entity to.
+    fn allocate(&mut self, world: &mut World) -> Entity;
+
+    /// Takes back an entity that would be despawned.
+    fn release(&mut self, world: &mut World, entity: Entity);
+}
+
+/// Spawns and despawns entities without pooling.
+struct Unpooled;
+
+impl EntityAllocator for Unpooled {
+    fn allocate(&mut self, world: &mut World) -> Entity {
+        world.spawn_empty().id()
+    }
+
+    fn release(&mut self, world: &mut World, entity: Entity) {
+        world.despawn(entity);
+    }
+}
+
+/// Determines how the [`SnapshotApplier`] handles entities referenced by components that were neither included in
+/// the snapshot nor in the entity map.
+///
+/// Only components reflecting `MapEntities` are checked. [`Parent`] is handled by [`MissingParentPolicy`].
+/// References to [`Entity::PLACEHOLDER`] are null, not dangling, and are left as-is.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DanglingEntityPolicy {
+    /// Spawn a new empty entity for each referenced entity, shared between references.
+    #[default]
+    SpawnEmpty,
+
+    /// Map all references to the given entity instead.
+    MapToPlaceholder(Entity),
+
+    /// Fail with [`Error::DanglingEntity`], reporting the component and the referenced entity.
+    ///
+    /// References are checked before anything is applied, so the [`World`] is left untouched.
+    Error,
+
+    /// Map all references to [`Entity::PLACEHOLDER`].
+    Null,
+}
+
+/// Collects all [`Entity`] values contained in the reflected value.
+fn referenced_entities(value: &dyn Reflect, out: &mut Vec<Entity>) {
+    if let Some(entity) = value.downcast_ref::<Entity>() {
+        out.push(*entity);
+        return;
+    }
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(s) => s.iter_fields().for_each(|f| referenced_entities(f, out)),
+        ReflectRef::TupleStruct(s) => s.iter_fields().for_each(|f| referenced_entities(f, out)),
+        ReflectRef::Tuple(t) => t.iter_fields().for_each(|f| referenced_entities(f, out)),
+        ReflectRef::List(l) => l.iter().for_each(|f| referenced_entities(f, out)),
+        ReflectRef::Array(a) => a.iter().for_each(|f| referenced_entities(f, out)),
+        ReflectRef::Map(m) => m.iter().for_each(|(k, v)| {
+            referenced_entities(k, out);
+            referenced_entities(v, out);
+        }),
+        ReflectRef::Enum(e) => e
+            .iter_fields()
+            .for_each(|f| referenced_entities(f.value(), out)),
+        ReflectRef::Value(_) => {}
+    }
+}
+
+/// Returns true if the snapshot entity has a [`SaveId`] that was tombstoned.
+fn is_tombstoned(entity: &DynamicEntity, tombstones: Option<&Tombstones>) -> bool {
+    let Some(tombstones) = tombstones else {
+        return false;
+    };
+
+    entity
+        .components
+        .iter()
+        .filter(|c| c.represents::<SaveId>())
+        .filter_map(|c| SaveId::from_reflect(&**c))
+        .any(|id| tombstones.contains(id))
+}
+
+/// Finds a reference to an entity that is neither included in the snapshot nor in the entity map.
+///
+/// Tombstoned entities are not applied, so they do not count as included. [`Entity::PLACEHOLDER`] is a null
+/// reference, not a dangling one.
+fn find_dangling(
+    snapshot: &Snapshot,
+    entity_map: &EntityHashMap<Entity>,
+    registry: &TypeRegistry,
+    tombstones: Option<&Tombstones>,
+) -> Option<Error> {
+    let live = snapshot
+        .entities
+        .iter()
+        .filter(|e| !is_tombstoned(e, tombstones))
+        .collect::<Vec<_>>();
+
+    let included = live.iter().map(|e| e.entity).collect::<EntityHashSet>();
+
+    for component in live.iter().flat_map(|e| &e.components) {
+        let Some(type_info) = component.get_represented_type_info() else {
+            continue;
+        };
+
+        // The hierarchy is handled by `MissingParentPolicy`
+        if type_info.type_id() == TypeId::of::<Parent>()
+            || type_info.type_id() == TypeId::of::<Children>()
+            || registry
+                .get_type_data::<ReflectMapEntities>(type_info.type_id())
+                .is_none()
+        {
+            continue;
+        }
+
+        let mut entities = Vec::new();
+        referenced_entities(&**component, &mut entities);
+
+        if let Some(entity) = entities.into_iter().find(|e| {
+            *e != Entity::PLACEHOLDER && !included.contains(e) && !entity_map.contains_key(e)
+        }) {
+            return Some(Error::DanglingEntity {
+                type_path: type_info.type_path().to_owned(),
+                entity,
+            });
+        }
+    }
+
+    None
+}
+
+/// Replaces the [`Entity`] keys of all maps in the dynamic value with the entities they are mapped to.
+///
+/// Entries of entities that are not mapped are dropped.
+fn remap_entity_keys(value: &mut dyn Reflect, entity_map: &EntityHashMap<Entity>) {
+    let remapped = match value.reflect_mut() {
+        ReflectMut::Struct(s) => {
+            for i in 0..s.field_len() {
+                if let Some(field) = s.field_at_mut(i) {
+                    remap_entity_keys(field, entity_map);
+                }
+            }
+            None
+        }
+        ReflectMut::TupleStruct(s) => {
+            for i in 0..s.field_len() {
+                if let Some(field) = s.field_mut(i) {
+                    remap_entity_keys(field, entity_map);
+                }
+            }
+            None
+        }
+        ReflectMut::Tuple(t) => {
+            for i in 0..t.field_len() {
+                if let Some(field) = t.field_mut(i) {
+                    remap_entity_keys(field, entity_map);
+                }
+            }
+            None
+        }
+        ReflectMut::List(l) => {
+            for i in 0..l.len() {
+                if let Some(item) = l.get_mut(i) {
+                    remap_entity_keys(item, entity_map);
+                }
+            }
+            None
+        }
+        ReflectMut::Array(a) => {
+            for i in 0..a.len() {
+                if let Some(item) = a.get_mut(i) {
+                    remap_entity_keys(item, entity_map);
+                }
+            }
+            None
+        }
+        ReflectMut::Enum(e) => {
+            for i in 0..e.field_len() {
+                if let Some(field) = e.field_at_mut(i) {
+                    remap_entity_keys(field, entity_map);
+                }
+            }
+            None
+        }
+        ReflectMut::Map(m) => {
+            for i in 0..m.len() {
+                if let Some((_, item)) = m.get_at_mut(i) {
+                    remap_entity_keys(item, entity_map);
+                }
+            }
+
+            // Removing entries from a `DynamicMap` invalidates its indices, so the map is rebuilt instead
+            m.iter().any(|(k, _)| k.represents::<Entity>()).then(|| {
+                let mut map = DynamicMap::default();
+                map.set_represented_type(m.get_represented_type_info());
+
+                for (key, item) in m.iter() {
+                    match Entity::from_reflect(key) {
+                        Some(entity) => {
+                            if let Some(target) = entity_map.get(&entity) {
+                                map.insert_boxed(Box::new(*target), item.clone_value());
+                            }
+                        }
+                        None => {
+                            map.insert_boxed(key.clone_value(), item.clone_value());
+                        }
+                    }
+                }
+
+                map
+            })
+        }
+        ReflectMut::Value(_) => None,
+    };
+
+    if let Some(map) = remapped {
+        if value.set(Box::new(map)).is_err() {
+            warn!(
+                "Failed to remap entity keys of `{}`",
+                value.reflect_type_path()
+            );
+        }
+    }
+}
+
+/// A saved value that was not applied because it was rejected by its [`ReflectValidate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RejectedValue {
+    /// The saved entity of the component, or `None` for resources.
+    pub entity: Option<Entity>,
+    /// The type path of the value.
+    pub type_path: String,
+    /// The reason the value was rejected.
+    pub reason: String,
+}
+
+/// Values rejected while applying the last snapshot to the [`World`].
+///
+/// Replaced each time a [`SnapshotApplier`] applies a snapshot, except in [`sandbox`](SnapshotApplier::sandbox) mode.
+/// See [`Validate`](crate::Validate).
+#[derive(Resource, Default, Debug, Clone, PartialEq, Eq)]
+pub struct ApplyReport {
+    /// The rejected values, in the order they were applied.
+    pub rejected: Vec<RejectedValue>,
+}
+
+impl ApplyReport {
+    /// Returns `true` if no value was rejected.
+    pub fn is_clean(&self) -> bool {
+        self.rejected.is_empty()
+    }
+}
+
+/// Marks the entities spawned by a [`SnapshotApplier`] in [`sandbox`](SnapshotApplier::sandbox) mode.
+///
+/// The value identifies the sandboxed snapshot, so several snapshots can be applied side by side, for example to
+/// compare ghost replays.
+#[derive(Component, Reflect, Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[reflect(Component)]
+pub struct FromSnapshot(pub u32);
+
 /// [`SnapshotApplier`] lets you configure how a snapshot will be applied to the [`World`].
 pub struct SnapshotApplier<'a, F = ()> {
     snapshot: &'a Snapshot,
@@ -60,7 +466,16 @@ pub struct SnapshotApplier<'a, F = ()> {
     entity_map: Option<&'a mut EntityHashMap<Entity>>,
     type_registry: Option<&'a AppTypeRegistry>,
     despawn: Option<PhantomData<F>>,
+    despawn_matching: Vec<DespawnPredicate<'a>>,
     hook: Option<BoxedHook>,
+    snapshot_hook: Option<BoxedSnapshotHook>,
+    filtered_hooks: Vec<FilteredHook>,
+    resource_hooks: Vec<(TypeId, ResourceHookFn)>,
+    missing_parent: MissingParentPolicy,
+    dangling_entity: DanglingEntityPolicy,
+    preserve_entities: bool,
+    sandbox: Option<FromSnapshot>,
+    allocator: Option<&'a mut dyn EntityAllocator>,
 }
 
 impl<'a> SnapshotApplier<'a> {
@@ -72,7 +487,16 @@ impl<'a> SnapshotApplier<'a> {
             entity_map: None,
             type_registry: None,
             despawn: None,
+            despawn_matching: Vec::new(),
             hook: None,
+            snapshot_hook: None,
+            filtered_hooks: Vec::new(),
+            resource_hooks: Vec::new(),
+            missing_parent: MissingParentPolicy::default(),
+            dangling_entity: DanglingEntityPolicy::default(),
+            preserve_entities: false,
+            sandbox: None,
+            allocator: None,
         }
     }
 }
@@ -100,15 +524,309 @@ impl<'a, A> SnapshotApplier<'a, A> {
             entity_map: self.entity_map,
             type_registry: self.type_registry,
             despawn: Some(PhantomData),
+            despawn_matching: self.despawn_matching,
             hook: self.hook,
+            snapshot_hook: self.snapshot_hook,
+            filtered_hooks: self.filtered_hooks,
+            resource_hooks: self.resource_hooks,
+            missing_parent: self.missing_parent,
+            dangling_entity: self.dangling_entity,
+            preserve_entities: self.preserve_entities,
+            sandbox: self.sandbox,
+            allocator: self.allocator,
         }
     }
 
+    /// Despawn existing entities matching the predicate while applying, for filters only known at runtime.
+    ///
+    /// May be called several times, and combined with [`despawn`](Self::despawn). Entities matching any of them are
+    /// despawned.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins((MinimalPlugins, SavePlugins));
+    /// # let world = &mut app.world;
+    /// world.spawn(Name::new("enemy"));
+    /// world.spawn(Name::new("player"));
+    ///
+    /// let snapshot = Snapshot::builder(world).build();
+    ///
+    /// snapshot
+    ///     .applier(world)
+    ///     .despawn_matching(|e| e.get::<Name>().is_some_and(|n| n.as_str() == "enemy"))
+    ///     .apply()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(world.query::<&Name>().single(world).as_str(), "player");
+    /// ```
+    pub fn despawn_matching(mut self, predicate: impl Fn(&EntityRef) -> bool + 'a) -> Self {
+        self.despawn_matching.push(Box::new(predicate));
+        self
+    }
+
+    /// Despawn existing entities matching the parsed [`EntityFilter`] while applying.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # #[derive(Component, Reflect, Default)]
+    /// # #[reflect(Component)]
+    /// # struct Bullet;
+    /// # let mut app = App::new();
+    /// # app.add_plugins((MinimalPlugins, SavePlugins));
+    /// # app.register_type::<Bullet>();
+    /// # let world = &mut app.world;
+    /// world.spawn(Bullet);
+    ///
+    /// let filter = EntityFilter::parse("With(Bullet)", &world.resource::<AppTypeRegistry>().read()).unwrap();
+    /// let snapshot = Snapshot::builder(world).build();
+    ///
+    /// snapshot.applier(world).despawn_filter(filter).apply().unwrap();
+    ///
+    /// assert_eq!(world.query::<&Bullet>().iter(world).count(), 0);
+    /// ```
+    pub fn despawn_filter(self, filter: EntityFilter) -> Self {
+        self.despawn_matching(move |e| filter.matches(e))
+    }
+
+    /// Despawn existing entities with all of the given components while applying.
+    pub fn despawn_with_components(
+        self,
+        components: impl IntoIterator<Item = ComponentId>,
+    ) -> Self {
+        let components = components.into_iter().collect::<Vec<_>>();
+
+        self.despawn_matching(move |e| components.iter().all(|&id| e.contains_id(id)))
+    }
+
+    /// Change how entities whose [`Parent`] was not included in the snapshot are handled.
+    ///
+    /// Defaults to [`MissingParentPolicy::SpawnEmpty`].
+    pub fn missing_parent(mut self, policy: MissingParentPolicy) -> Self {
+        self.missing_parent = policy;
+        self
+    }
+
+    /// Change how entities referenced by components but not included in the snapshot are handled.
+    ///
+    /// Defaults to [`DanglingEntityPolicy::SpawnEmpty`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy::ecs::{entity::{EntityMapper, MapEntities}, reflect::ReflectMapEntities};
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect)]
+    /// #[reflect(Component, MapEntities)]
+    /// struct Target(Entity);
+    ///
+    /// impl FromWorld for Target {
+    ///     fn from_world(_: &mut World) -> Self {
+    ///         Self(Entity::PLACEHOLDER)
+    ///     }
+    /// }
+    ///
+    /// impl MapEntities for Target {
+    ///     fn map_entities<M: EntityMapper>(&mut self, mapper: &mut M) {
+    ///         self.0 = mapper.map_entity(self.0);
+    ///     }
+    /// }
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # app.register_type::<Target>();
+    /// # let world = &mut app.world;
+    /// let enemy = world.spawn_empty().id();
+    /// let turret = world.spawn(Target(enemy)).id();
+    ///
+    /// // The enemy is not included in the snapshot
+    /// let snapshot = Snapshot::builder(world).extract_entity(turret).build();
+    ///
+    /// let err = snapshot
+    ///     .applier(world)
+    ///     .dangling_entity(DanglingEntityPolicy::Error)
+    ///     .apply()
+    ///     .unwrap_err();
+    ///
+    /// assert!(matches!(err, bevy_save::Error::DanglingEntity { entity, .. } if entity == enemy));
+    /// ```
+    pub fn dangling_entity(mut self, policy: DanglingEntityPolicy) -> Self {
+        self.dangling_entity = policy;
+        self
+    }
+
+    /// Update entities that still exist in the [`World`] in place, instead of spawning new entities.
+    ///
+    /// Entities are matched by the [`Entity`] recorded in the snapshot, including its generation, so cached handles stay
+    /// valid when applying checkpoints to the world they were captured from. Entities that were despawned are respawned.
+    ///
+    /// Components added since the snapshot was captured are not removed.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Health(u32);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # app.register_type::<Health>();
+    /// # let world = &mut app.world;
+    /// let player = world.spawn(Health(100)).id();
+    /// let snapshot = Snapshot::builder(world).extract_entity(player).build();
+    ///
+    /// world.entity_mut(player).insert(Health(10));
+    ///
+    /// snapshot.applier(world).preserve_entities().apply().unwrap();
+    ///
+    /// assert_eq!(world.get::<Health>(player).unwrap().0, 100);
+    /// assert_eq!(world.entities().len(), 1);
+    /// ```
+    pub fn preserve_entities(mut self) -> Self {
+        self.preserve_entities = true;
+        self
+    }
+
+    /// Spawn the entities of the snapshot alongside the existing entities, without changing anything in the [`World`].
+    ///
+    /// Every spawned entity is tagged with the given [`FromSnapshot`] marker. Resources, tombstones, and
+    /// [`despawn`](Self::despawn) are ignored, entities are never [preserved](Self::preserve_entities), and the
+    /// [`ApplyReport`] of the live world is left untouched. Orphans get an empty parent instead of being attached to a
+    /// live entity by [`MissingParentPolicy::ReparentTo`].
+    /// Useful for ghost replays and level preview overlays.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Position(f32);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # app.register_type::<Position>();
+    /// # let world = &mut app.world;
+    /// let player = world.spawn(Position(0.0)).id();
+    /// let snapshot = Snapshot::builder(world).extract_entity(player).build();
+    ///
+    /// world.entity_mut(player).insert(Position(10.0));
+    ///
+    /// snapshot.applier(world).sandbox(FromSnapshot(1)).apply().unwrap();
+    ///
+    /// let ghost = world
+    ///     .query_filtered::<&Position, With<FromSnapshot>>()
+    ///     .single(world);
+    ///
+    /// assert_eq!(ghost.0, 0.0);
+    /// assert_eq!(world.get::<Position>(player).unwrap().0, 10.0);
+    /// ```
+    pub fn sandbox(mut self, marker: FromSnapshot) -> Self {
+        self.sandbox = Some(marker);
+        self
+    }
+
+    /// Use the given [`EntityAllocator`] to spawn the entities of the snapshot and despawn entities, instead of
+    /// spawning and despawning them directly.
+    pub fn allocator(mut self, allocator: &'a mut dyn EntityAllocator) -> Self {
+        self.allocator = Some(allocator);
+        self
+    }
+
     /// Add a [`Hook`] that will run for each entity after applying.
     pub fn hook<F: Hook + 'static>(mut self, hook: F) -> Self {
         self.hook = Some(Box::new(hook));
         self
     }
+
+    /// Add a [`Hook`] that will run after applying, only for entities matching the [`QueryFilter`].
+    ///
+    /// The filter is evaluated after components are applied. Multiple filtered hooks can be added.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component)]
+    /// struct Player;
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// # let snapshot = Snapshot::from_world(world);
+    /// snapshot
+    ///     .applier(world)
+    ///     .hook_filtered::<With<Player>>(|_, cmds| {
+    ///         cmds.insert(Visibility::Visible);
+    ///     })
+    ///     .apply();
+    /// ```
+    pub fn hook_filtered<Q: QueryFilter + 'static>(mut self, hook: impl Hook + 'static) -> Self {
+        self.filtered_hooks.push((matching::<Q>, Box::new(hook)));
+        self
+    }
+
+    /// Add a hook that will run after applying if the snapshot contains the resource `T`.
+    ///
+    /// The hook receives the value of the resource before applying, if it existed, and the applied value.
+    /// Use the [`Commands`] to react to the change, or to restore the previous value to reject it.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Resource, Reflect, Clone, Default)]
+    /// #[reflect(Resource)]
+    /// struct Score(u32);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # app.register_type::<Score>();
+    /// # let world = &mut app.world;
+    /// # let snapshot = Snapshot::from_world(world);
+    /// snapshot
+    ///     .applier(world)
+    ///     .resource_hook(|old: Option<&Score>, new: &Score, cmds| {
+    ///         if new.0 > 1_000_000 {
+    ///             cmds.insert_resource(old.cloned().unwrap_or_default());
+    ///         }
+    ///     })
+    ///     .apply();
+    /// ```
+    pub fn resource_hook<T, H>(mut self, hook: H) -> Self
+    where
+        T: Resource + FromReflect,
+        H: Fn(Option<&T>, &T, &mut Commands) + Send + Sync + 'static,
+    {
+        self.resource_hooks.push((
+            TypeId::of::<T>(),
+            Box::new(move |old, world, commands| {
+                let old = old.and_then(T::from_reflect);
+
+                if let Some(new) = world.get_resource::<T>() {
+                    hook(old.as_ref(), new, commands);
+                }
+            }),
+        ));
+        self
+    }
+
+    /// Add a [`SnapshotHook`] that will run for each entity after applying, receiving the captured [`DynamicEntity`].
+    pub fn hook_with_snapshot<F: SnapshotHook + 'static>(mut self, hook: F) -> Self {
+        self.snapshot_hook = Some(Box::new(hook));
+        self
+    }
 }
 
 impl<'a, F: QueryFilter> SnapshotApplier<'a, F> {
@@ -118,8 +836,12 @@ impl<'a, F: QueryFilter> SnapshotApplier<'a, F> {
     /// If `type_registry` is not set or the [`AppTypeRegistry`] resource does not exist.
     ///
     /// # Errors
-    /// If a type included in the [`Snapshot`] has not been registered with the type registry.
+    /// - If a type included in the [`Snapshot`] has not been registered with the type registry.
+    /// - [`Error::NoSuchEntity`] if the parent of [`MissingParentPolicy::ReparentTo`] does not exist.
+    /// - [`Error::DanglingEntity`] if a reference is dangling under [`DanglingEntityPolicy::Error`].
     pub fn apply(self) -> Result<(), Error> {
+        profile_span!("apply");
+
         let default_type_registry = self.world.get_resource::<AppTypeRegistry>().cloned();
 
         let type_registry = self
@@ -132,7 +854,69 @@ impl<'a, F: QueryFilter> SnapshotApplier<'a, F> {
 
         let entity_map = self.entity_map.unwrap_or(&mut default_entity_map);
 
-        for resource in &self.snapshot.resources {
+        let mut unpooled = Unpooled;
+
+        let allocator = self.allocator.unwrap_or(&mut unpooled);
+
+        // Orphans are never attached to live entities in sandbox mode
+        let missing_parent = match self.missing_parent {
+            MissingParentPolicy::ReparentTo(_) if self.sandbox.is_some() => {
+                MissingParentPolicy::SpawnEmpty
+            }
+            policy => policy,
+        };
+
+        if let MissingParentPolicy::ReparentTo(parent) = missing_parent {
+            if self.world.get_entity(parent).is_none() {
+                return Err(Error::NoSuchEntity(parent));
+            }
+        }
+
+        // Only the tombstones carried by the snapshot are applied
+        let tombstones = if self.sandbox.is_some() {
+            None
+        } else {
+            self.snapshot.get_resource::<Tombstones>()
+        };
+
+        // Dangling references are found before anything is applied, leaving the world untouched
+        if self.dangling_entity == DanglingEntityPolicy::Error {
+            if let Some(err) = find_dangling(
+                self.snapshot,
+                entity_map,
+                &type_registry,
+                tombstones.as_ref(),
+            ) {
+                return Err(err);
+            }
+        }
+
+        // Resources are left untouched in sandbox mode
+        let order = if self.sandbox.is_some() {
+            Vec::new()
+        } else {
+            self.world.get_resource::<ResourceOrder>().map_or_else(
+                || (0..self.snapshot.resources.len()).collect(),
+                |o| o.sort(&self.snapshot.resources),
+            )
+        };
+
+        // Values rejected by their `ReflectValidate`
+        let mut report = ApplyReport::default();
+
+        // Values of hooked resources before applying
+        let mut previous: Vec<(TypeId, Option<Box<dyn Reflect>>)> = Vec::new();
+
+        let key_remaps = self
+            .world
+            .get_resource::<EntityKeyRemaps>()
+            .cloned()
+            .unwrap_or_default();
+
+        // Resources with entity keys, applied after the entities
+        let mut keyed: Vec<(ReflectResource, Box<dyn Reflect>)> = Vec::new();
+
+        for resource in order.into_iter().map(|i| &self.snapshot.resources[i]) {
             let type_info = resource.get_represented_type_info().ok_or_else(|| {
                 SceneSpawnError::NoRepresentedType {
                     type_path: resource.reflect_type_path().to_string(),
@@ -143,19 +927,117 @@ impl<'a, F: QueryFilter> SnapshotApplier<'a, F> {
                     type_path: type_info.type_path().to_string(),
                 }
             })?;
+
+            // Non-send resources are applied from their proxies on the main thread
+            if let Some(apply) = self
+                .world
+                .get_resource::<NonSendSaveables>()
+                .and_then(|n| n.get_by_proxy(type_info.type_id()))
+                .map(|s| s.apply.clone())
+            {
+                apply(self.world, &**resource);
+                continue;
+            }
+
             let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
                 SceneSpawnError::UnregisteredResource {
                     type_path: type_info.type_path().to_string(),
                 }
             })?;
 
+            // Tombstones are merged so despawns recorded by other saves are not lost
+            if type_info.type_id() == TypeId::of::<Tombstones>() {
+                if let Some(tombstones) = Tombstones::from_reflect(&**resource) {
+                    self.world
+                        .get_resource_or_insert_with(Tombstones::default)
+                        .merge(&tombstones);
+                }
+                continue;
+            }
+
+            // Marker types are only used to apply `Markers`, the rollback config is only diagnostic
+            if type_info.type_id() == TypeId::of::<MarkerTypes>()
+                || type_info.type_id() == TypeId::of::<RollbackConfig>()
+            {
+                continue;
+            }
+
+            let validated;
+            let resource = match registration.data::<ReflectValidate>() {
+                Some(validate) => match validate.validate(&**resource) {
+                    Ok(value) => {
+                        validated = value;
+                        &*validated
+                    }
+                    Err(reason) => {
+                        report.rejected.push(RejectedValue {
+                            entity: None,
+                            type_path: type_info.type_path().to_owned(),
+                            reason,
+                        });
+                        continue;
+                    }
+                },
+                None => &**resource,
+            };
+
+            if self
+                .resource_hooks
+                .iter()
+                .any(|(id, _)| *id == type_info.type_id())
+            {
+                let old = reflect_resource
+                    .reflect(self.world)
+                    .map(|r| r.clone_value());
+                previous.push((type_info.type_id(), old));
+            }
+
+            // Resources keyed by entity are applied once every entity has been mapped
+            if key_remaps.is_remapped_by_id(type_info.type_id()) {
+                keyed.push((reflect_resource.clone(), resource.clone_value()));
+                continue;
+            }
+
             // If the world already contains an instance of the given resource
             // just apply the (possibly) new value, otherwise insert the resource
-            reflect_resource.apply_or_insert(self.world, &**resource);
+            reflect_resource.apply_or_insert(self.world, resource);
+        }
+
+        // Resources of unregistered types are kept so they are written again
+        let unknown = self.snapshot.unknown().resources();
+
+        // Stale resources of an earlier load would otherwise be written into the next save
+        if self.sandbox.is_none() {
+            if unknown.is_empty() {
+                self.world.remove_resource::<UnknownResources>();
+            } else {
+                self.world
+                    .insert_resource(UnknownResources(unknown.to_vec()));
+            }
+        }
+
+        let marker_types = self
+            .snapshot
+            .get_resource::<MarkerTypes>()
+            .unwrap_or_default();
+
+        // Despawn tombstoned entities
+        if let Some(tombstones) = &tombstones {
+            let dead = self
+                .world
+                .query::<(Entity, &SaveId)>()
+                .iter(self.world)
+                .filter(|(_, id)| tombstones.contains(**id))
+                .map(|(e, _)| e)
+                .collect::<Vec<_>>();
+
+            for entity in dead {
+                release(self.world, allocator, entity);
+            }
         }
 
         // Despawn entities
-        if self.despawn.is_some() {
+        if self.despawn.is_some() && self.sandbox.is_none() {
             let invalid = self
                 .world
                 .query_filtered::<Entity, F>()
@@ -163,7 +1045,20 @@ impl<'a, F: QueryFilter> SnapshotApplier<'a, F> {
                 .collect::<Vec<_>>();
 
             for entity in invalid {
-                self.world.despawn(entity);
+                release(self.world, allocator, entity);
+            }
+        }
+
+        if !self.despawn_matching.is_empty() && self.sandbox.is_none() {
+            let invalid = self
+                .world
+                .iter_entities()
+                .filter(|e| self.despawn_matching.iter().any(|p| p(e)))
+                .map(|e| e.id())
+                .collect::<Vec<_>>();
+
+            for entity in invalid {
+                release(self.world, allocator, entity);
             }
         }
 
@@ -173,23 +1068,153 @@ impl<'a, F: QueryFilter> SnapshotApplier<'a, F> {
         // of the actual entities in the world.
         let mut scene_mappings: HashMap<TypeId, Vec<Entity>> = HashMap::default();
 
+        // Hierarchy edges are tracked separately and rebuilt once every entity has been spawned.
+        let mut hierarchy: Vec<(Entity, Entity)> = Vec::new();
+
+        // Entities referenced by mapped components, with the type path of the component
+        let mut references: Vec<(Entity, &str)> = Vec::new();
+
+        // Entities spawned for the snapshot, tagged in sandbox mode
+        let mut spawned: Vec<Entity> = Vec::new();
+
+        let remaps = self
+            .world
+            .get_resource::<ComponentRemaps>()
+            .cloned()
+            .unwrap_or_default();
+
         for scene_entity in &self.snapshot.entities {
+            if is_tombstoned(scene_entity, tombstones.as_ref()) {
+                continue;
+            }
+
             // Fetch the entity with the given entity id from the `entity_map`
             // or spawn a new entity with a transiently unique id if there is
             // no corresponding entry.
-            let entity = *entity_map
-                .entry(scene_entity.entity)
-                .or_insert_with(|| self.world.spawn_empty().id());
+            let entity = *entity_map.entry(scene_entity.entity).or_insert_with(|| {
+                if self.preserve_entities
+                    && self.sandbox.is_none()
+                    && self.world.get_entity(scene_entity.entity).is_some()
+                {
+                    scene_entity.entity
+                } else {
+                    allocator.allocate(self.world)
+                }
+            });
+
+            spawned.push(entity);
+
+            let component_order = self.world.get_resource::<ComponentOrder>().map_or_else(
+                || (0..scene_entity.components.len()).collect(),
+                |o| o.sort(&scene_entity.components),
+            );
 
             let entity_mut = &mut self.world.entity_mut(entity);
 
+            // Remapped components are applied once the other components exist
+            let mut remapped = Vec::new();
+
+            let unknown = self.snapshot.unknown().components(scene_entity.entity);
+
+            if !unknown.is_empty() {
+                entity_mut.insert(UnknownComponents(unknown.to_vec()));
+            }
+
             // Apply/ add each component to the given entity.
-            for component in &scene_entity.components {
+            for component in component_order
+                .into_iter()
+                .map(|i| &scene_entity.components[i])
+            {
                 let type_info = component.get_represented_type_info().ok_or_else(|| {
                     SceneSpawnError::NoRepresentedType {
                         type_path: component.reflect_type_path().to_string(),
                     }
                 })?;
+
+                // `Children` is rebuilt from the `Parent` of each child.
+                if type_info.type_id() == TypeId::of::<Children>() {
+                    continue;
+                }
+
+                if remaps.is_remapped_by_id(type_info.type_id()) {
+                    remapped.push(&**component);
+                    continue;
+                }
+
+                if type_info.type_id() == TypeId::of::<DefaultComponents>() {
+                    let Some(defaults) = DefaultComponents::from_reflect(&**component) else {
+                        continue;
+                    };
+
+                    for type_path in &defaults.types {
+                        let registration =
+                            type_registry.get_with_type_path(type_path).ok_or_else(|| {
+                                SceneSpawnError::UnregisteredButReflectedType {
+                                    type_path: type_path.clone(),
+                                }
+                            })?;
+
+                        let (Some(reflect_component), Some(reflect_default)) = (
+                            registration.data::<ReflectComponent>(),
+                            registration.data::<ReflectDefault>(),
+                        ) else {
+                            return Err(SceneSpawnError::UnregisteredComponent {
+                                type_path: type_path.clone(),
+                            }
+                            .into());
+                        };
+
+                        reflect_component.insert(
+                            entity_mut,
+                            &*reflect_default.default(),
+                            &type_registry,
+                        );
+                    }
+
+                    continue;
+                }
+
+                if type_info.type_id() == TypeId::of::<Markers>() {
+                    let Some(markers) = Markers::from_reflect(&**component) else {
+                        continue;
+                    };
+
+                    for index in markers.indices {
+                        let type_path =
+                            marker_types.types.get(index as usize).ok_or_else(|| {
+                                Error::custom(format!("invalid marker index {index}"))
+                            })?;
+
+                        let registration =
+                            type_registry.get_with_type_path(type_path).ok_or_else(|| {
+                                SceneSpawnError::UnregisteredButReflectedType {
+                                    type_path: type_path.clone(),
+                                }
+                            })?;
+
+                        let (Some(reflect_component), Some(value)) = (
+                            registration.data::<ReflectComponent>(),
+                            marker_value(registration.type_info()),
+                        ) else {
+                            return Err(SceneSpawnError::UnregisteredComponent {
+                                type_path: type_path.clone(),
+                            }
+                            .into());
+                        };
+
+                        reflect_component.insert(entity_mut, &*value, &type_registry);
+                    }
+
+                    continue;
+                }
+
+                if type_info.type_id() == TypeId::of::<Parent>() {
+                    if let Some(parent) = Parent::from_reflect(&**component) {
+                        hierarchy.push((entity, parent.get()));
+                    }
+                    continue;
+                }
+
                 let registration = type_registry.get(type_info.type_id()).ok_or_else(|| {
                     SceneSpawnError::UnregisteredButReflectedType {
                         type_path: type_info.type_path().to_string(),
@@ -202,6 +1227,25 @@ impl<'a, F: QueryFilter> SnapshotApplier<'a, F> {
                         }
                     })?;
 
+                let validated;
+                let component = match registration.data::<ReflectValidate>() {
+                    Some(validate) => match validate.validate(&**component) {
+                        Ok(value) => {
+                            validated = value;
+                            &*validated
+                        }
+                        Err(reason) => {
+                            report.rejected.push(RejectedValue {
+                                entity: Some(scene_entity.entity),
+                                type_path: type_info.type_path().to_owned(),
+                                reason,
+                            });
+                            continue;
+                        }
+                    },
+                    None => &**component,
+                };
+
                 // If this component references entities in the scene, track it
                 // so we can update it to the entity in the world.
                 if registration.data::<ReflectMapEntities>().is_some() {
@@ -209,15 +1253,51 @@ impl<'a, F: QueryFilter> SnapshotApplier<'a, F> {
                         .entry(registration.type_id())
                         .or_insert(Vec::new())
                         .push(entity);
+
+                    let mut entities = Vec::new();
+                    referenced_entities(component, &mut entities);
+                    references.extend(entities.into_iter().map(|e| (e, type_info.type_path())));
                 }
 
-                // If the entity already has the given component attached,
-                // just apply the (possibly) new value, otherwise add the
-                // component to the entity.
-                reflect_component.insert(entity_mut, &**component, &type_registry);
+                // Inserting replaces an existing value in place, so the entity
+                // only changes archetype when the component is new. Values are
+                // never patched with `apply`, which would keep stale list and
+                // map entries.
+                reflect_component.insert(entity_mut, component, &type_registry);
+            }
+
+            for component in remapped {
+                remaps.apply(component, entity_mut);
             }
         }
 
+        // Resolve references to entities that are not in the snapshot
+        for (source, type_path) in references {
+            if entity_map.contains_key(&source) {
+                continue;
+            }
+
+            let target = match self.dangling_entity {
+                // Null references stay null
+                _ if source == Entity::PLACEHOLDER => Entity::PLACEHOLDER,
+                DanglingEntityPolicy::SpawnEmpty => {
+                    let empty = allocator.allocate(self.world);
+                    spawned.push(empty);
+                    empty
+                }
+                DanglingEntityPolicy::MapToPlaceholder(target) => target,
+                DanglingEntityPolicy::Null => Entity::PLACEHOLDER,
+                DanglingEntityPolicy::Error => {
+                    return Err(Error::DanglingEntity {
+                        type_path: type_path.to_owned(),
+                        entity: source,
+                    });
+                }
+            };
+
+            entity_map.insert(source, target);
+        }
+
         // Updates references to entities in the scene to entities in the world
         for (type_id, entities) in scene_mappings {
             let registration = type_registry.get(type_id).expect(
@@ -228,12 +1308,78 @@ impl<'a, F: QueryFilter> SnapshotApplier<'a, F> {
             }
         }
 
+        // Rebuild the hierarchy
+        for (child, parent) in hierarchy {
+            let parent = match (entity_map.get(&parent), missing_parent) {
+                (Some(parent), _) => *parent,
+                (None, MissingParentPolicy::SpawnEmpty) => {
+                    let empty = allocator.allocate(self.world);
+                    entity_map.insert(parent, empty);
+                    spawned.push(empty);
+                    empty
+                }
+                (None, MissingParentPolicy::ReparentTo(parent)) => parent,
+                (None, MissingParentPolicy::Strip) => {
+                    self.world.entity_mut(child).remove_parent();
+                    continue;
+                }
+            };
+
+            self.world.entity_mut(parent).add_child(child);
+        }
+
+        for (reflect_resource, mut resource) in keyed {
+            remap_entity_keys(&mut *resource, entity_map);
+            reflect_resource.apply_or_insert(self.world, &*resource);
+        }
+
+        if self.sandbox.is_none() {
+            self.world.insert_resource(report);
+        }
+
+        if let Some(marker) = self.sandbox {
+            for entity in spawned {
+                self.world.entity_mut(entity).insert(marker);
+            }
+        }
+
         // Entity hook
         if let Some(hook) = &self.hook {
             let mut queue = CommandQueue::default();
             let mut commands = Commands::new(&mut queue, self.world);
 
-            for (_, entity) in entity_map {
+            for entity in entity_map.values() {
+                let entity_ref = self.world.entity(*entity);
+                let mut entity_mut = commands.entity(*entity);
+
+                hook(&entity_ref, &mut entity_mut);
+            }
+
+            queue.apply(self.world);
+        }
+
+        // Resource hooks
+        if !previous.is_empty() {
+            let mut queue = CommandQueue::default();
+            let mut commands = Commands::new(&mut queue, self.world);
+
+            for (type_id, old) in &previous {
+                for (_, hook) in self.resource_hooks.iter().filter(|(id, _)| id == type_id) {
+                    hook(old.as_deref(), self.world, &mut commands);
+                }
+            }
+
+            queue.apply(self.world);
+        }
+
+        // Filtered hooks
+        for (filter, hook) in &self.filtered_hooks {
+            let matches = filter(self.world);
+
+            let mut queue = CommandQueue::default();
+            let mut commands = Commands::new(&mut queue, self.world);
+
+            for entity in entity_map.values().filter(|e| matches.contains(*e)) {
                 let entity_ref = self.world.entity(*entity);
                 let mut entity_mut = commands.entity(*entity);
 
@@ -243,6 +1389,24 @@ impl<'a, F: QueryFilter> SnapshotApplier<'a, F> {
             queue.apply(self.world);
         }
 
+        // Snapshot hook
+        if let Some(hook) = &self.snapshot_hook {
+            let mut queue = CommandQueue::default();
+            let mut commands = Commands::new(&mut queue, self.world);
+
+            for source in &self.snapshot.entities {
+                let Some(entity) = entity_map.get(&source.entity) else {
+                    continue;
+                };
+
+                if let Some(entity_ref) = self.world.get_entity(*entity) {
+                    hook(source, &entity_ref, &mut commands);
+                }
+            }
+
+            queue.apply(self.world);
+        }
+
         Ok(())
     }
 }
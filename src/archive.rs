@@ -0,0 +1,304 @@
+use std::{
+    fmt::Display,
+    fs::File,
+    io::{
+        BufReader,
+        BufWriter,
+        Read,
+        Write,
+    },
+    path::Path,
+};
+
+use bevy::prelude::*;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::{
+    read_index,
+    read_sections,
+    version::parse_version,
+    write_sections,
+    Backend,
+    DeserializeLimits,
+    Error,
+    Format,
+    Pipeline,
+    RMPFormat,
+    SaveIndex,
+    SectionHashes,
+    Snapshot,
+    SnapshotDeserializer,
+    SnapshotSerializer,
+    Stamped,
+};
+
+/// Magic bytes at the start of every save archive.
+pub const ARCHIVE_MAGIC: &[u8; 8] = b"BEVYSAVE";
+
+/// The current version of the save archive layout.
+pub const ARCHIVE_VERSION: u32 = 1;
+
+/// Metadata stored in the header of a save archive.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct ArchiveMeta {
+    /// The version of the archive layout.
+    pub version: u32,
+    /// The version of `bevy_save` that created the archive.
+    pub crate_version: String,
+    /// Whether the save was stored as a multi-part save.
+    pub split: bool,
+    /// Whether the payload is compressed.
+    pub compressed: bool,
+}
+
+impl ArchiveMeta {
+    fn current(split: bool) -> Self {
+        Self {
+            version: ARCHIVE_VERSION,
+            crate_version: env!("CARGO_PKG_VERSION").to_owned(),
+            split,
+            compressed: cfg!(feature = "brotli"),
+        }
+    }
+
+    /// Checks that the archive can be read by this version of `bevy_save`.
+    ///
+    /// # Errors
+    /// If the archive was created by a newer archive layout or a newer version of `bevy_save`.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.version > ARCHIVE_VERSION {
+            return Err(Error::custom(format!(
+                "unsupported archive version: {} (supported: {ARCHIVE_VERSION})",
+                self.version
+            )));
+        }
+
+        if parse_version(&self.crate_version) > parse_version(env!("CARGO_PKG_VERSION")) {
+            return Err(Error::custom(format!(
+                "archive created by a newer version of bevy_save: {} (current: {})",
+                self.crate_version,
+                env!("CARGO_PKG_VERSION")
+            )));
+        }
+
+        if self.compressed && !cfg!(feature = "brotli") {
+            return Err(Error::custom(
+                "archive is compressed, enable the `brotli` feature to import it",
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes the payload, compressing it when the `brotli` feature is enabled.
+///
+/// The compressor is finished explicitly, so errors writing its final block are returned instead of being lost.
+#[cfg(feature = "brotli")]
+fn write_payload<W: Write>(writer: &mut W, payload: &[u8]) -> Result<(), Error> {
+    let params = brotli::enc::BrotliEncoderParams::default();
+    brotli::enc::BrotliCompress(&mut &payload[..], writer, &params)?;
+
+    Ok(())
+}
+
+#[cfg(not(feature = "brotli"))]
+fn write_payload<W: Write>(writer: &mut W, payload: &[u8]) -> Result<(), Error> {
+    writer.write_all(payload)?;
+
+    Ok(())
+}
+
+fn decompress<'r, R: Read + 'r>(reader: R, compressed: bool) -> Box<dyn Read + 'r> {
+    #[cfg(feature = "brotli")]
+    if compressed {
+        return Box::new(brotli::Decompressor::new(reader, 4096));
+    }
+
+    let _ = compressed;
+
+    Box::new(reader)
+}
+
+/// Extension trait that adds save archive methods to Bevy's [`World`].
+///
+/// Archives bundle a save and its metadata into a single shareable file.
+/// The payload is compressed when the `brotli` feature is enabled.
+pub trait WorldArchiveExt {
+    /// Bundles the save stored in the backend of the given [`Pipeline`] into an archive written to `writer`.
+    ///
+    /// Both regular and multi-part saves are supported.
+    ///
+    /// # Errors
+    /// - See [`Error`]
+    fn export_save_to<P, W>(&self, pipeline: P, writer: W) -> Result<ArchiveMeta, Error>
+    where
+        P: Pipeline,
+        P::Backend: Backend<String>,
+        for<'a> P::Key<'a>: Display,
+        W: Write;
+
+    /// Unpacks an archive read from `reader` into the backend of the given [`Pipeline`].
+    ///
+    /// The save is stored in the same layout (regular or multi-part) it was exported from.
+    ///
+    /// # Errors
+    /// - If the archive is invalid or was created by an incompatible version
+    /// - See [`Error`]
+    fn import_save_from<P, R>(&mut self, pipeline: P, reader: R) -> Result<ArchiveMeta, Error>
+    where
+        P: Pipeline,
+        P::Backend: Backend<String>,
+        for<'a> P::Key<'a>: Display,
+        R: Read;
+
+    /// Bundles the save stored in the backend of the given [`Pipeline`] into an archive file at `path`.
+    ///
+    /// # Errors
+    /// - See [`Error`]
+    fn export_save<P>(&self, pipeline: P, path: impl AsRef<Path>) -> Result<ArchiveMeta, Error>
+    where
+        P: Pipeline,
+        P::Backend: Backend<String>,
+        for<'a> P::Key<'a>: Display,
+    {
+        let file = File::create(path)?;
+        self.export_save_to(pipeline, BufWriter::new(file))
+    }
+
+    /// Unpacks the archive file at `path` into the backend of the given [`Pipeline`].
+    ///
+    /// # Errors
+    /// - If the archive is invalid or was created by an incompatible version
+    /// - See [`Error`]
+    fn import_save<P>(&mut self, pipeline: P, path: impl AsRef<Path>) -> Result<ArchiveMeta, Error>
+    where
+        P: Pipeline,
+        P::Backend: Backend<String>,
+        for<'a> P::Key<'a>: Display,
+    {
+        let file = File::open(path)?;
+        self.import_save_from(pipeline, BufReader::new(file))
+    }
+}
+
+impl WorldArchiveExt for World {
+    fn export_save_to<P, W>(&self, pipeline: P, mut writer: W) -> Result<ArchiveMeta, Error>
+    where
+        P: Pipeline,
+        P::Backend: Backend<String>,
+        for<'a> P::Key<'a>: Display,
+        W: Write,
+    {
+        let pipeline = pipeline.with_context(self);
+        let key = pipeline.key().to_string();
+
+        let registry = self.resource::<AppTypeRegistry>();
+        let reg = registry.read();
+        let backend = self.resource::<P::Backend>();
+
+        let (snapshot, split) = if read_index::<P::Format, _>(backend, &key).is_ok() {
+            let (snapshot, _) = read_sections::<P::Format, _>(
+                backend,
+                &key,
+                &SnapshotDeserializer::for_world(&reg, self).lenient(true),
+            )?;
+            (snapshot, true)
+        } else {
+            let de = SnapshotDeserializer::new(&reg)
+                .limits(DeserializeLimits::from_world(self))
+                .parallel(P::Format::self_describing())
+                .lenient(true);
+            (backend.load::<Stamped<P::Format>, _, _>(key, de)?, false)
+        };
+
+        drop(reg);
+
+        let meta = ArchiveMeta::current(split);
+
+        let mut payload = Vec::new();
+        RMPFormat::serialize(&mut payload, &SnapshotSerializer::new(&snapshot, registry))?;
+
+        writer.write_all(ARCHIVE_MAGIC)?;
+        RMPFormat::serialize(&mut writer, &meta)?;
+        write_payload(&mut writer, &payload)?;
+
+        writer.flush()?;
+
+        Ok(meta)
+    }
+
+    fn import_save_from<P, R>(&mut self, pipeline: P, mut reader: R) -> Result<ArchiveMeta, Error>
+    where
+        P: Pipeline,
+        P::Backend: Backend<String>,
+        for<'a> P::Key<'a>: Display,
+        R: Read,
+    {
+        let pipeline = pipeline.with_context(self);
+        let key = pipeline.key().to_string();
+
+        let mut magic = [0; ARCHIVE_MAGIC.len()];
+        reader.read_exact(&mut magic)?;
+
+        if &magic != ARCHIVE_MAGIC {
+            return Err(Error::custom("not a save archive"));
+        }
+
+        let meta: ArchiveMeta = RMPFormat::deserialize(&mut reader, std::marker::PhantomData)?;
+
+        meta.validate()?;
+
+        let registry = self.resource::<AppTypeRegistry>().clone();
+        let reg = registry.read();
+
+        let de = SnapshotDeserializer::for_world(&reg, self).parallel(RMPFormat::self_describing());
+
+        let mut snapshot: Snapshot =
+            RMPFormat::deserialize(decompress(reader, meta.compressed), de)?;
+
+        snapshot.verify_version(self)?;
+        snapshot.missing_mods(self)?;
+
+        // The stamps are stored with the save, so they are kept out of sanitizing
+        let version = snapshot.game_version();
+        let mods = snapshot.mod_manifest();
+
+        snapshot.sanitize_for(self, &reg)?;
+
+        if let Some(version) = version {
+            snapshot.insert_resource(version);
+        }
+
+        if let Some(mods) = mods {
+            snapshot.insert_resource(mods);
+        }
+
+        drop(reg);
+
+        let backend = self.resource::<P::Backend>();
+
+        if meta.split {
+            let index = write_sections::<P::Format, _>(
+                backend,
+                &key,
+                &snapshot,
+                &registry,
+                &SaveIndex::default(),
+            )?;
+
+            self.get_resource_or_insert_with(SectionHashes::default)
+                .insert(key, index);
+        } else {
+            backend.save::<Stamped<P::Format>, _>(
+                key,
+                &SnapshotSerializer::new(&snapshot, &registry),
+            )?;
+        }
+
+        Ok(meta)
+    }
+}
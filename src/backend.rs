@@ -29,35 +29,193 @@ pub trait Backend<K> {
         key: K,
         seed: S,
     ) -> Result<T, Error>;
+
+    /// Returns an opaque tag identifying the current contents stored under the key, if supported.
+    ///
+    /// Returns `None` if the key does not exist or the backend does not support tags.
+    ///
+    /// # Errors
+    /// - See [`Error`]
+    fn etag<F: Format>(&self, key: K) -> Result<Option<String>, Error> {
+        let _ = key;
+        Ok(None)
+    }
+
+    /// Attempts to serialize a value with the given [`Format`], only if the contents stored under the key
+    /// still match the given tag from [`Backend::etag`].
+    ///
+    /// Backends that do not support tags save unconditionally. The filesystem backends tag files by their size and
+    /// modification time, and cannot lock them against other processes, so the check is best-effort.
+    ///
+    /// # Errors
+    /// - [`Error::Conflict`] if the stored contents have changed
+    /// - See [`Backend::save`]
+    fn save_if_match<F: Format, T: Serialize>(
+        &self,
+        key: K,
+        value: &T,
+        etag: Option<&str>,
+    ) -> Result<(), Error> {
+        let _ = etag;
+        self.save::<F, T>(key, value)
+    }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
 mod desktop {
     use std::{
+        collections::BTreeMap,
         fs::File,
         io::{
             BufReader,
             BufWriter,
         },
+        path::{
+            Path,
+            PathBuf,
+        },
+        time::UNIX_EPOCH,
     };
 
     use bevy::prelude::*;
+    use serde::Deserialize;
 
     #[allow(clippy::wildcard_imports)]
     use super::*;
-    use crate::get_save_file;
+    use crate::{
+        get_save_file,
+        SlotInfo,
+        SlotStorage,
+        SAVE_DIR,
+    };
+
+    /// Tag derived from the size and modification time of the file.
+    fn file_etag(path: impl AsRef<Path>) -> Result<Option<String>, Error> {
+        let metadata = match std::fs::metadata(path) {
+            Ok(metadata) => metadata,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+
+        let modified = metadata
+            .modified()?
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos();
+
+        Ok(Some(format!("{}-{modified}", metadata.len())))
+    }
+
+    fn check_etag(path: impl AsRef<Path>, etag: Option<&str>) -> Result<(), Error> {
+        if file_etag(path)?.as_deref() == etag {
+            Ok(())
+        } else {
+            Err(Error::Conflict)
+        }
+    }
+
+    /// Writes the value to a temporary file, which replaces the file if its tag still matches afterwards.
+    fn write_if_match<F: Format, T: Serialize>(
+        path: &Path,
+        value: &T,
+        etag: Option<&str>,
+    ) -> Result<(), Error> {
+        check_etag(path, etag)?;
+
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+
+        let mut temp = path.as_os_str().to_owned();
+        temp.push(".tmp");
+        let temp = PathBuf::from(temp);
+
+        let result = File::create(&temp)
+            .map_err(Error::from)
+            .and_then(|file| F::serialize(BufWriter::new(file), value))
+            .and_then(|()| check_etag(path, etag))
+            .and_then(|()| std::fs::rename(&temp, path).map_err(Error::from));
+
+        if result.is_err() {
+            let _ = std::fs::remove_file(&temp);
+        }
+
+        result
+    }
 
     /// Simple filesystem backend.
     ///
-    /// Each name corresponds to an individual file on the disk.
+    /// Each name corresponds to an individual file on the disk, named after the key followed by the
+    /// [`Format::extension`], such as `player.ron` for [`RONFormat`](crate::RONFormat).
     ///
     /// Files are stored in `SAVE_DIR`.
-    #[derive(Default, Resource)]
-    pub struct FileIO;
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// App::new()
+    ///     .add_plugins(SavePlugins)
+    ///     // Keys already include an extension, such as `slot1.save`
+    ///     .insert_resource(FileIO::default().append_extension(false));
+    /// ```
+    #[derive(Resource)]
+    pub struct FileIO {
+        append_extension: bool,
+        #[cfg(feature = "mmap")]
+        memory_map: bool,
+    }
+
+    impl Default for FileIO {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    impl FileIO {
+        /// Create a [`FileIO`] backend appending the [`Format::extension`] to each key.
+        pub const fn new() -> Self {
+            Self {
+                append_extension: true,
+                #[cfg(feature = "mmap")]
+                memory_map: false,
+            }
+        }
+
+        /// Set whether the [`Format::extension`] is appended to each key.
+        ///
+        /// Enabled by default. Disable this if your keys already include an extension. The [`Format::key_suffix`] is
+        /// still appended, so summaries are not written over their saves.
+        pub fn append_extension(mut self, append_extension: bool) -> Self {
+            self.append_extension = append_extension;
+            self
+        }
+
+        /// Set whether saves are memory-mapped when loading, instead of being read through a buffer.
+        ///
+        /// The [`Format`] deserializes straight from the mapped file with [`Format::deserialize_slice`], so formats
+        /// and middleware that need the whole save in memory do not copy it. Disabled by default.
+        ///
+        /// The save file must not be modified by another process while it is being loaded.
+        #[cfg(feature = "mmap")]
+        pub fn memory_map(mut self, memory_map: bool) -> Self {
+            self.memory_map = memory_map;
+            self
+        }
+
+        /// Returns the path of the file storing the given key.
+        pub fn path<F: Format>(&self, key: impl std::fmt::Display) -> PathBuf {
+            if self.append_extension {
+                get_save_file(format!("{key}{}", F::extension()))
+            } else {
+                get_save_file(format!("{key}{}", F::key_suffix()))
+            }
+        }
+    }
 
     impl<K: std::fmt::Display> Backend<K> for FileIO {
         fn save<F: Format, T: Serialize>(&self, key: K, value: &T) -> Result<(), Error> {
-            let path = get_save_file(format!("{key}{}", F::extension()));
+            let path = self.path::<F>(key);
             let dir = path.parent().expect("Invalid save directory");
 
             std::fs::create_dir_all(dir)?;
@@ -73,12 +231,35 @@ mod desktop {
             key: K,
             seed: S,
         ) -> Result<T, Error> {
-            let path = get_save_file(format!("{key}{}", F::extension()));
-            let file = File::open(path)?;
+            let file = File::open(self.path::<F>(key))?;
+
+            #[cfg(feature = "mmap")]
+            if self.memory_map {
+                // SAFETY: The mapping is read-only and dropped before returning. Modifying the file while it is mapped
+                // is not allowed, as documented on `FileIO::memory_map`.
+                #[allow(unsafe_code)]
+                let map = unsafe { memmap2::Mmap::map(&file)? };
+
+                return F::deserialize_slice(&map, seed);
+            }
+
             let reader = BufReader::new(file);
 
             F::deserialize(reader, seed)
         }
+
+        fn etag<F: Format>(&self, key: K) -> Result<Option<String>, Error> {
+            file_etag(self.path::<F>(key))
+        }
+
+        fn save_if_match<F: Format, T: Serialize>(
+            &self,
+            key: K,
+            value: &T,
+            etag: Option<&str>,
+        ) -> Result<(), Error> {
+            write_if_match::<F, T>(&self.path::<F>(key), value, etag)
+        }
     }
 
     /// Debug filesystem backend.
@@ -91,7 +272,13 @@ mod desktop {
 
     impl<K: std::fmt::Display> Backend<K> for DebugFileIO {
         fn save<F: Format, T: Serialize>(&self, key: K, value: &T) -> Result<(), Error> {
-            let file = File::create(format!("{key}{}", F::extension()))?;
+            let path = format!("{key}{}", F::extension());
+
+            if let Some(dir) = std::path::Path::new(&path).parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+
+            let file = File::create(path)?;
             let writer = BufWriter::new(file);
 
             F::serialize(writer, value)
@@ -107,11 +294,223 @@ mod desktop {
 
             F::deserialize(reader, seed)
         }
+
+        fn etag<F: Format>(&self, key: K) -> Result<Option<String>, Error> {
+            file_etag(format!("{key}{}", F::extension()))
+        }
+
+        fn save_if_match<F: Format, T: Serialize>(
+            &self,
+            key: K,
+            value: &T,
+            etag: Option<&str>,
+        ) -> Result<(), Error> {
+            write_if_match::<F, T>(Path::new(&format!("{key}{}", F::extension())), value, etag)
+        }
+    }
+
+    /// An entry in a [`SaveManifest`].
+    #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+    pub struct ManifestEntry {
+        /// The name of the file within the save directory.
+        pub file: String,
+        /// The size of the file in bytes.
+        pub size: u64,
+    }
+
+    /// Lists the files contained in a save directory, stored as `manifest.ron`.
+    #[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+    pub struct SaveManifest {
+        /// The files of the save, by section name.
+        pub sections: BTreeMap<String, ManifestEntry>,
+    }
+
+    /// Directory-per-save filesystem backend.
+    ///
+    /// Each save is stored in its own directory, containing a `manifest.ron` and one file per section.
+    /// Keys of the form `KEY/SECTION` (as used by [`WorldSplitExt`](crate::WorldSplitExt)) are stored as `KEY/SECTION.ext`,
+    /// while plain keys are stored as `KEY/snapshot.ext`. Formats with a [`Format::key_suffix`], such as summaries, are
+    /// listed in the manifest as their own `SECTION.suffix` section.
+    ///
+    /// Files are stored in `SAVE_DIR` by default.
+    #[derive(Resource)]
+    pub struct DirBackend {
+        root: PathBuf,
+    }
+
+    impl Default for DirBackend {
+        fn default() -> Self {
+            Self::new(SAVE_DIR.clone())
+        }
+    }
+
+    impl DirBackend {
+        /// The name of the manifest file within each save directory.
+        pub const MANIFEST: &'static str = "manifest.ron";
+
+        /// The section name used for keys without a section.
+        pub const DEFAULT_SECTION: &'static str = "snapshot";
+
+        /// Create a new [`DirBackend`] storing saves in the given directory.
+        pub fn new(root: impl Into<PathBuf>) -> Self {
+            Self { root: root.into() }
+        }
+
+        /// Returns the directory containing all saves.
+        pub fn root(&self) -> &Path {
+            &self.root
+        }
+
+        fn locate(&self, key: &str) -> (PathBuf, String) {
+            let (dir, section) = key.rsplit_once('/').unwrap_or((key, Self::DEFAULT_SECTION));
+
+            (self.root.join(dir), section.to_owned())
+        }
+
+        /// Reads the [`SaveManifest`] of the save with the given key.
+        ///
+        /// # Errors
+        /// - [`Error::IO`] if the manifest does not exist
+        /// - [`Error::Loading`] if the manifest is invalid
+        pub fn manifest(&self, key: impl std::fmt::Display) -> Result<SaveManifest, Error> {
+            let path = self.root.join(key.to_string()).join(Self::MANIFEST);
+            let text = std::fs::read_to_string(path)?;
+
+            ron::from_str(&text).map_err(Error::loading)
+        }
+
+        fn update_manifest(dir: &Path, section: String, file: String) -> Result<(), Error> {
+            let path = dir.join(Self::MANIFEST);
+
+            let mut manifest: SaveManifest = match std::fs::read_to_string(&path) {
+                Ok(text) => ron::from_str(&text).map_err(Error::loading)?,
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => SaveManifest::default(),
+                Err(err) => return Err(err.into()),
+            };
+
+            let size = std::fs::metadata(dir.join(&file))?.len();
+
+            manifest
+                .sections
+                .insert(section, ManifestEntry { file, size });
+
+            let text = ron::ser::to_string_pretty(&manifest, ron::ser::PrettyConfig::default())
+                .map_err(Error::saving)?;
+
+            std::fs::write(path, text)?;
+
+            Ok(())
+        }
+    }
+
+    impl SlotStorage for DirBackend {
+        fn slot_of(&self, key: &str) -> String {
+            key.rsplit_once('/').map_or(key, |(dir, _)| dir).to_owned()
+        }
+
+        fn slots(&self) -> Result<Vec<SlotInfo>, Error> {
+            fn visit(root: &Path, dir: &Path, out: &mut Vec<SlotInfo>) -> Result<(), Error> {
+                let manifest = dir.join(DirBackend::MANIFEST);
+
+                if let Ok(metadata) = std::fs::metadata(&manifest) {
+                    let text = std::fs::read_to_string(&manifest)?;
+                    let manifest: SaveManifest = ron::from_str(&text).map_err(Error::loading)?;
+
+                    let name = dir
+                        .strip_prefix(root)
+                        .unwrap_or(dir)
+                        .components()
+                        .map(|c| c.as_os_str().to_string_lossy())
+                        .collect::<Vec<_>>()
+                        .join("/");
+
+                    out.push(SlotInfo {
+                        name,
+                        bytes: manifest.sections.values().map(|e| e.size).sum(),
+                        modified: metadata.modified()?,
+                    });
+                }
+
+                for entry in std::fs::read_dir(dir)? {
+                    let entry = entry?;
+
+                    if entry.file_type()?.is_dir() {
+                        visit(root, &entry.path(), out)?;
+                    }
+                }
+
+                Ok(())
+            }
+
+            let mut slots = Vec::new();
+
+            if self.root.is_dir() {
+                visit(&self.root, &self.root, &mut slots)?;
+            }
+
+            Ok(slots)
+        }
+
+        fn delete_slot(&self, name: &str) -> Result<(), Error> {
+            std::fs::remove_dir_all(self.root.join(name))?;
+            Ok(())
+        }
+    }
+
+    impl<K: std::fmt::Display> Backend<K> for DirBackend {
+        fn save<F: Format, T: Serialize>(&self, key: K, value: &T) -> Result<(), Error> {
+            let (dir, section) = self.locate(&key.to_string());
+            let file = format!("{section}{}", F::extension());
+
+            std::fs::create_dir_all(&dir)?;
+
+            {
+                let writer = BufWriter::new(File::create(dir.join(&file))?);
+                F::serialize(writer, value)?;
+            }
+
+            Self::update_manifest(&dir, format!("{section}{}", F::key_suffix()), file)
+        }
+
+        fn load<F: Format, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+            &self,
+            key: K,
+            seed: S,
+        ) -> Result<T, Error> {
+            let (dir, section) = self.locate(&key.to_string());
+            let file = File::open(dir.join(format!("{section}{}", F::extension())))?;
+
+            F::deserialize(BufReader::new(file), seed)
+        }
+
+        fn etag<F: Format>(&self, key: K) -> Result<Option<String>, Error> {
+            let (dir, section) = self.locate(&key.to_string());
+            file_etag(dir.join(format!("{section}{}", F::extension())))
+        }
+
+        fn save_if_match<F: Format, T: Serialize>(
+            &self,
+            key: K,
+            value: &T,
+            etag: Option<&str>,
+        ) -> Result<(), Error> {
+            let (dir, section) = self.locate(&key.to_string());
+            let file = format!("{section}{}", F::extension());
+
+            write_if_match::<F, T>(&dir.join(&file), value, etag)?;
+
+            Self::update_manifest(&dir, format!("{section}{}", F::key_suffix()), file)
+        }
     }
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-pub use desktop::FileIO;
+pub use desktop::{
+    DirBackend,
+    FileIO,
+    ManifestEntry,
+    SaveManifest,
+};
 #[cfg(not(target_arch = "wasm32"))]
 /// The [`Backend`] the default [`Pipeline`](crate::Pipeline) will use.
 pub type DefaultBackend = desktop::FileIO;
@@ -149,8 +548,8 @@ mod wasm {
         }
     }
 
-    impl<'a> Backend<&'a str> for WebStorage {
-        fn save<F: Format, T: Serialize>(&self, key: &str, value: &T) -> Result<(), Error> {
+    impl<K: std::fmt::Display> Backend<K> for WebStorage {
+        fn save<F: Format, T: Serialize>(&self, key: K, value: &T) -> Result<(), Error> {
             let mut buf: Vec<u8> = Vec::new();
 
             F::serialize(&mut buf, value)?;
@@ -158,7 +557,7 @@ mod wasm {
             self.storage
                 .get()
                 .set_item(
-                    &format!("{WORKSPACE}.{key}"),
+                    &format!("{WORKSPACE}.{key}{}", F::key_suffix()),
                     &serde_json::to_string(&buf).map_err(Error::saving)?,
                 )
                 .expect("Failed to save");
@@ -168,13 +567,13 @@ mod wasm {
 
         fn load<F: Format, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
             &self,
-            key: &str,
+            key: K,
             seed: S,
         ) -> Result<T, Error> {
             let value = self
                 .storage
                 .get()
-                .get_item(&format!("{WORKSPACE}.{key}"))
+                .get_item(&format!("{WORKSPACE}.{key}{}", F::key_suffix()))
                 .expect("Failed to load")
                 .ok_or(Error::custom("Invalid key"))?;
 
@@ -0,0 +1,457 @@
+use std::{
+    collections::{
+        hash_map::Entry,
+        HashMap,
+    },
+    fmt::Display,
+    marker::PhantomData,
+    sync::Mutex,
+    time::Duration,
+};
+
+use bevy::{
+    app::AppExit,
+    prelude::*,
+    utils::Instant,
+};
+use serde::{
+    de::{
+        value::BytesDeserializer,
+        DeserializeSeed,
+    },
+    ser::Impossible,
+    Serialize,
+    Serializer,
+};
+
+use crate::{
+    Backend,
+    Error,
+    FloatPrecision,
+    Format,
+};
+
+type WriteFn<B> = fn(&B, String, &[u8]) -> Result<(), Error>;
+
+struct PendingWrite<B> {
+    data: Vec<u8>,
+    since: Instant,
+    write: WriteFn<B>,
+}
+
+/// Backend middleware coalescing writes to the same key, reducing wear and stalls on slow storage.
+///
+/// Saves are serialized immediately but only written to the inner [`Backend`] once `window` has elapsed since the
+/// first pending write to the key, always writing the latest payload. Loads see pending writes.
+///
+/// Added by [`AppSaveableExt::batch_writes`](crate::AppSaveableExt::batch_writes), which also flushes pending writes
+/// when the window elapses and on [`AppExit`].
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// struct Autosave;
+///
+/// impl Pipeline for Autosave {
+///     type Backend = Batched<DefaultBackend>;
+///     type Format = DefaultFormat;
+///
+///     type Key<'a> = &'a str;
+///
+///     fn key(&self) -> Self::Key<'_> {
+///         "autosave"
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugins(SavePlugins)
+///     .init_pipeline::<Autosave>()
+///     .batch_writes::<DefaultBackend>(Duration::from_secs(5));
+/// ```
+#[derive(Resource)]
+pub struct Batched<B> {
+    inner: B,
+    window: Duration,
+    pending: Mutex<HashMap<String, PendingWrite<B>>>,
+}
+
+impl<B: Default> Default for Batched<B> {
+    fn default() -> Self {
+        Self::new(B::default(), Self::DEFAULT_WINDOW)
+    }
+}
+
+impl<B> Batched<B> {
+    /// The default window in which writes to the same key are coalesced.
+    pub const DEFAULT_WINDOW: Duration = Duration::from_secs(1);
+
+    /// Create a new [`Batched`] backend wrapping the given backend.
+    pub fn new(inner: B, window: Duration) -> Self {
+        Self {
+            inner,
+            window,
+            pending: Mutex::default(),
+        }
+    }
+
+    /// Returns the wrapped backend.
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Returns the window in which writes to the same key are coalesced.
+    pub fn window(&self) -> Duration {
+        self.window
+    }
+
+    /// Returns the number of pending writes.
+    ///
+    /// # Panics
+    /// If a thread panicked while writing.
+    pub fn pending(&self) -> usize {
+        self.pending.lock().expect("Batched lock poisoned").len()
+    }
+
+    /// Write all pending writes to the inner backend.
+    ///
+    /// Failed writes stay pending and are retried by the next flush.
+    ///
+    /// # Errors
+    /// - See [`Backend::save`]
+    pub fn flush(&self) -> Result<(), Error> {
+        self.flush_where(|_| true)
+    }
+
+    /// Write pending writes older than the window to the inner backend.
+    ///
+    /// # Errors
+    /// - See [`Backend::save`]
+    pub fn flush_expired(&self) -> Result<(), Error> {
+        self.flush_where(|p| p.since.elapsed() >= self.window)
+    }
+
+    fn flush_where(&self, predicate: impl Fn(&PendingWrite<B>) -> bool) -> Result<(), Error> {
+        let ready = {
+            let mut pending = self.pending.lock().expect("Batched lock poisoned");
+            let keys = pending
+                .iter()
+                .filter(|(_, p)| predicate(p))
+                .map(|(k, _)| k.clone())
+                .collect::<Vec<_>>();
+
+            keys.into_iter()
+                .filter_map(|k| pending.remove(&k).map(|p| (k, p)))
+                .collect::<Vec<_>>()
+        };
+
+        let mut result = Ok(());
+        let mut failed = Vec::new();
+
+        for (file, write) in ready {
+            let key = file.split_once('\0').map_or(file.as_str(), |(k, _)| k);
+
+            if let Err(err) = (write.write)(&self.inner, key.to_owned(), &write.data) {
+                result = Err(err);
+                failed.push((file, write));
+            }
+        }
+
+        if !failed.is_empty() {
+            let mut pending = self.pending.lock().expect("Batched lock poisoned");
+
+            // Failed writes are retried later, unless a newer payload was saved in the meantime
+            for (file, write) in failed {
+                pending.entry(file).or_insert(write);
+            }
+        }
+
+        result
+    }
+
+    fn take<F: Format>(&self, key: &str) -> Option<PendingWrite<B>> {
+        self.pending
+            .lock()
+            .expect("Batched lock poisoned")
+            .remove(&pending_key::<F>(key))
+    }
+}
+
+impl<B: Resource> Batched<B> {
+    /// System writing pending writes older than the window.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn flush_expired_system(batched: Res<Self>) {
+        if let Err(err) = batched.flush_expired() {
+            error!("Batched write failed: {err}");
+        }
+    }
+
+    /// System writing all pending writes when the app exits.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn flush_on_exit(batched: Res<Self>, mut exit: EventReader<AppExit>) {
+        if exit.read().last().is_some() {
+            if let Err(err) = batched.flush() {
+                error!("Batched write failed: {err}");
+            }
+        }
+    }
+}
+
+fn pending_key<F: Format>(key: &str) -> String {
+    format!("{key}\0{}", F::extension())
+}
+
+fn write_raw<B: Backend<String>, F: Format>(
+    backend: &B,
+    key: String,
+    data: &[u8],
+) -> Result<(), Error> {
+    backend.save::<Raw<F>, _>(key, &Payload(data))
+}
+
+impl<K: Display, B: Backend<String>> Backend<K> for Batched<B> {
+    fn save<F: Format, T: Serialize>(&self, key: K, value: &T) -> Result<(), Error> {
+        let mut data = Vec::new();
+        F::serialize(&mut data, value)?;
+
+        let mut pending = self.pending.lock().expect("Batched lock poisoned");
+
+        match pending.entry(pending_key::<F>(&key.to_string())) {
+            Entry::Occupied(mut entry) => entry.get_mut().data = data,
+            Entry::Vacant(entry) => {
+                entry.insert(PendingWrite {
+                    data,
+                    since: Instant::now(),
+                    write: write_raw::<B, F>,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn load<F: Format, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        &self,
+        key: K,
+        seed: S,
+    ) -> Result<T, Error> {
+        let key = key.to_string();
+
+        let pending = self.pending.lock().expect("Batched lock poisoned");
+
+        if let Some(write) = pending.get(&pending_key::<F>(&key)) {
+            return F::deserialize(write.data.as_slice(), seed);
+        }
+
+        drop(pending);
+
+        self.inner.load::<F, S, T>(key, seed)
+    }
+
+    fn etag<F: Format>(&self, key: K) -> Result<Option<String>, Error> {
+        let key = key.to_string();
+
+        if let Some(write) = self.take::<F>(&key) {
+            (write.write)(&self.inner, key.clone(), &write.data)?;
+        }
+
+        self.inner.etag::<F>(key)
+    }
+
+    fn save_if_match<F: Format, T: Serialize>(
+        &self,
+        key: K,
+        value: &T,
+        etag: Option<&str>,
+    ) -> Result<(), Error> {
+        let key = key.to_string();
+
+        // Conditional saves are written immediately, replacing any pending write
+        self.take::<F>(&key);
+        self.inner.save_if_match::<F, T>(key, value, etag)
+    }
+}
+
+/// Writes an already serialized [`Payload`] as-is and reads it back with [`PayloadSeed`], using the extension of `F`.
+pub(crate) struct Raw<F>(PhantomData<F>);
+
+impl<F: Format> Format for Raw<F> {
+    fn name() -> &'static str {
+        F::name()
+    }
+
+    fn extension() -> &'static str {
+        F::extension()
+    }
+
+    fn key_suffix() -> &'static str {
+        F::key_suffix()
+    }
+
+    fn mime_type() -> &'static str {
+        F::mime_type()
+    }
+
+    fn float_precision() -> FloatPrecision {
+        F::float_precision()
+    }
+
+    fn self_describing() -> bool {
+        F::self_describing()
+    }
+
+    fn serialize<W: std::io::Write, T: Serialize>(writer: W, value: &T) -> Result<(), Error> {
+        value
+            .serialize(RawSerializer(writer))
+            .map_err(Error::saving)
+    }
+
+    fn deserialize<R: std::io::Read, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        mut reader: R,
+        seed: S,
+    ) -> Result<T, Error> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+
+        seed.deserialize(BytesDeserializer::<serde::de::value::Error>::new(&data))
+            .map_err(Error::loading)
+    }
+}
+
+pub(crate) struct Payload<'a>(pub(crate) &'a [u8]);
+
+impl Serialize for Payload<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self.0)
+    }
+}
+
+/// Reads a payload written by [`Raw`] as bytes.
+#[cfg(feature = "dedup")]
+pub(crate) struct PayloadSeed;
+
+#[cfg(feature = "dedup")]
+impl<'de> DeserializeSeed<'de> for PayloadSeed {
+    type Value = Vec<u8>;
+
+    fn deserialize<D: serde::Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        struct PayloadVisitor;
+
+        impl serde::de::Visitor<'_> for PayloadVisitor {
+            type Value = Vec<u8>;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+                formatter.write_str("a raw payload")
+            }
+
+            fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                Ok(v.to_vec())
+            }
+        }
+
+        deserializer.deserialize_bytes(PayloadVisitor)
+    }
+}
+
+#[derive(Debug)]
+struct RawError(String);
+
+impl Display for RawError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for RawError {}
+
+impl serde::ser::Error for RawError {
+    fn custom<T: Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+/// Serializer writing byte payloads without any encoding.
+struct RawSerializer<W>(W);
+
+macro_rules! unsupported {
+    ($($name:ident($($arg:ty),*) -> $ret:ty;)*) => {
+        $(
+            fn $name(self, $(_: $arg),*) -> Result<$ret, Self::Error> {
+                Err(RawError("only byte payloads can be written raw".into()))
+            }
+        )*
+    };
+}
+
+impl<W: std::io::Write> Serializer for RawSerializer<W> {
+    type Ok = ();
+    type Error = RawError;
+
+    type SerializeSeq = Impossible<(), RawError>;
+    type SerializeTuple = Impossible<(), RawError>;
+    type SerializeTupleStruct = Impossible<(), RawError>;
+    type SerializeTupleVariant = Impossible<(), RawError>;
+    type SerializeMap = Impossible<(), RawError>;
+    type SerializeStruct = Impossible<(), RawError>;
+    type SerializeStructVariant = Impossible<(), RawError>;
+
+    fn serialize_bytes(mut self, v: &[u8]) -> Result<(), RawError> {
+        self.0
+            .write_all(v)
+            .and_then(|()| self.0.flush())
+            .map_err(|e| RawError(e.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<(), RawError> {
+        value.serialize(self)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> Result<(), RawError> {
+        Err(RawError("only byte payloads can be written raw".into()))
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<(), RawError> {
+        Err(RawError("only byte payloads can be written raw".into()))
+    }
+
+    unsupported! {
+        serialize_bool(bool) -> ();
+        serialize_i8(i8) -> ();
+        serialize_i16(i16) -> ();
+        serialize_i32(i32) -> ();
+        serialize_i64(i64) -> ();
+        serialize_u8(u8) -> ();
+        serialize_u16(u16) -> ();
+        serialize_u32(u32) -> ();
+        serialize_u64(u64) -> ();
+        serialize_f32(f32) -> ();
+        serialize_f64(f64) -> ();
+        serialize_char(char) -> ();
+        serialize_str(&str) -> ();
+        serialize_none() -> ();
+        serialize_unit() -> ();
+        serialize_unit_struct(&'static str) -> ();
+        serialize_unit_variant(&'static str, u32, &'static str) -> ();
+        serialize_seq(Option<usize>) -> Self::SerializeSeq;
+        serialize_tuple(usize) -> Self::SerializeTuple;
+        serialize_tuple_struct(&'static str, usize) -> Self::SerializeTupleStruct;
+        serialize_tuple_variant(&'static str, u32, &'static str, usize) -> Self::SerializeTupleVariant;
+        serialize_map(Option<usize>) -> Self::SerializeMap;
+        serialize_struct(&'static str, usize) -> Self::SerializeStruct;
+        serialize_struct_variant(&'static str, u32, &'static str, usize) -> Self::SerializeStructVariant;
+    }
+}
@@ -1,22 +1,50 @@
 use std::{
-    any::Any,
+    any::{
+        Any,
+        TypeId,
+    },
     collections::BTreeMap,
 };
 
 use bevy::{
-    ecs::component::ComponentId,
+    ecs::{
+        component::{
+            ComponentId,
+            Tick,
+        },
+        entity::EntityHashMap,
+    },
     prelude::*,
     scene::DynamicEntity,
 };
+use serde::Serialize;
 
 use crate::{
-    CloneReflect,
-    RollbackRegistry,
+    clone_reflect_values,
+    domain::{
+        rollback_registry,
+        rollbacks,
+    },
+    instance::diff_scene_instance,
+    profile::profile_span,
+    snapshot::resource_type_path,
+    sparse::{
+        compact_markers,
+        sparsify,
+    },
+    CapturePlan,
+    CheckpointPersistence,
+    Extras,
+    MarkerTypes,
+    NonSendSaveables,
+    RollbackConfig,
     Rollbacks,
     Snapshot,
+    UnknownData,
 };
 
 /// A snapshot builder that can extract entities, resources, and [`Rollbacks`] from a [`World`].
+#[allow(clippy::struct_excessive_bools)]
 pub struct SnapshotBuilder<'a> {
     world: &'a World,
     entities: BTreeMap<Entity, DynamicEntity>,
@@ -24,8 +52,18 @@ pub struct SnapshotBuilder<'a> {
     filter: SceneFilter,
     rollbacks: Option<Rollbacks>,
     is_rollback: bool,
+    is_sparse: bool,
+    is_compact: bool,
+    is_scene_diff: bool,
+    domain: Option<&'static str>,
+    persistence: CheckpointPersistence,
+    rollback_config: Option<RollbackConfig>,
+    extras: Extras,
+    exclusions: Vec<Exclusion<'a>>,
 }
 
+type Exclusion<'a> = Box<dyn Fn(&EntityRef) -> bool + 'a>;
+
 impl<'a> SnapshotBuilder<'a> {
     /// Create a new [`SnapshotBuilder`] from the [`World`].
     ///
@@ -57,6 +95,14 @@ impl<'a> SnapshotBuilder<'a> {
             filter: SceneFilter::default(),
             rollbacks: None,
             is_rollback: false,
+            is_sparse: false,
+            is_compact: false,
+            is_scene_diff: false,
+            domain: None,
+            persistence: CheckpointPersistence::All,
+            rollback_config: None,
+            extras: Extras::default(),
+            exclusions: Vec::new(),
         }
     }
 
@@ -92,6 +138,14 @@ impl<'a> SnapshotBuilder<'a> {
             filter: SceneFilter::default(),
             rollbacks: None,
             is_rollback: true,
+            is_sparse: false,
+            is_compact: false,
+            is_scene_diff: false,
+            domain: None,
+            persistence: CheckpointPersistence::All,
+            rollback_config: None,
+            extras: Extras::default(),
+            exclusions: Vec::new(),
         }
     }
 }
@@ -115,6 +169,22 @@ impl<'a> SnapshotBuilder<'a> {
         self
     }
 
+    /// Use the [`Rollbacks`] and [`RollbackRegistry`](crate::RollbackRegistry) of the given [`SaveDomain`](crate::SaveDomain).
+    ///
+    /// If `None`, the global resources are used.
+    pub fn domain(mut self, domain: Option<&'static str>) -> Self {
+        self.domain = domain;
+        self
+    }
+
+    /// Set how many checkpoints are kept by [`extract_rollbacks`](Self::extract_rollbacks).
+    ///
+    /// Defaults to [`CheckpointPersistence::All`].
+    pub fn checkpoint_persistence(mut self, persistence: CheckpointPersistence) -> Self {
+        self.persistence = persistence;
+        self
+    }
+
     /// Allows the given type, `T`, to be included in the generated snapshot.
     ///
     /// This method may be called multiple times for any number of types.
@@ -137,6 +207,73 @@ impl<'a> SnapshotBuilder<'a> {
         self
     }
 
+    /// Omit extracted components that are equal to their [`Default`] value, recording them in a [`DefaultComponents`](crate::DefaultComponents) marker instead.
+    ///
+    /// Only types registered with `ReflectDefault` can be omitted.
+    /// The [`SnapshotApplier`](crate::SnapshotApplier) inserts the default value when applying.
+    pub fn sparse(mut self) -> Self {
+        self.is_sparse = true;
+        self
+    }
+
+    /// Store the zero-sized components of each extracted entity, such as unit struct markers, as indices into a single
+    /// [`MarkerTypes`] table instead of a full type path per component.
+    ///
+    /// The [`SnapshotApplier`](crate::SnapshotApplier) inserts the markers when applying.
+    /// Markers are not returned by [`Snapshot::get_component`] and [`Snapshot::entities_with`].
+    pub fn compact_markers(mut self) -> Self {
+        self.is_compact = true;
+        self
+    }
+
+    /// Omit extracted components of scene instance entities that are equal to the components in the scene asset.
+    ///
+    /// Instance entities are tracked by the [`SceneInstancePlugin`](crate::SceneInstancePlugin) with a
+    /// [`SceneOrigin`](crate::SceneOrigin), which must be extracted. When loading, the scene is spawned again and the
+    /// remaining components are applied to the matching instance entities.
+    ///
+    /// Components removed from an instance entity are not recorded, and are restored from the scene when loading.
+    pub fn scene_overrides(mut self) -> Self {
+        self.is_scene_diff = true;
+        self
+    }
+
+    /// Skip entities with a component `T` matching the predicate when extracting.
+    ///
+    /// This may be called multiple times, skipping entities matching any of the predicates.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component)]
+    /// struct DespawnTimer(f32);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # let world = &mut app.world;
+    /// world.spawn(DespawnTimer(5.0));
+    /// world.spawn(DespawnTimer(0.05));
+    ///
+    /// let snapshot = Snapshot::builder(world)
+    ///     .exclude_if::<DespawnTimer>(|timer| timer.0 < 0.1)
+    ///     .extract_all_entities()
+    ///     .build();
+    ///
+    /// assert_eq!(snapshot.entities.len(), 1);
+    /// ```
+    pub fn exclude_if<T: Component>(mut self, predicate: impl Fn(&T) -> bool + 'a) -> Self {
+        self.exclusions.push(Box::new(move |entity| {
+            entity.get::<T>().is_some_and(&predicate)
+        }));
+        self
+    }
+
+    fn is_excluded(&self, entity: &EntityRef) -> bool {
+        self.exclusions.iter().any(|exclude| exclude(entity))
+    }
+
     /// Updates the filter to allow all types.
     ///
     /// This is useful for resetting the filter so that types may be selectively [denied].
@@ -165,23 +302,95 @@ impl<'a> SnapshotBuilder<'a> {
     }
 
     /// Extract the given entities from the builder’s [`World`].
-    pub fn extract_entities(mut self, entities: impl Iterator<Item = Entity>) -> Self {
+    pub fn extract_entities(self, entities: impl Iterator<Item = Entity>) -> Self {
+        self.extract_entities_with(entities, None)
+    }
+
+    /// Extract only the component types in the [`ComponentList`] `L`, from all entities with at least one of them.
+    ///
+    /// A concise alternative to [allowing](Self::allow) each type. The builder's filter still applies, and
+    /// components extracted by earlier calls are kept.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Health(u32);
+    ///
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Mana(u32);
+    ///
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Cooldown(f32);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # app.register_type::<Health>();
+    /// # app.register_type::<Mana>();
+    /// # app.register_type::<Cooldown>();
+    /// # let world = &mut app.world;
+    /// world.spawn((Health(10), Cooldown(0.5)));
+    /// world.spawn(Mana(5));
+    /// world.spawn(Cooldown(1.0));
+    ///
+    /// let snapshot = Snapshot::builder(world)
+    ///     .extract_components::<(Health, Mana)>()
+    ///     .build();
+    ///
+    /// assert_eq!(snapshot.entities.len(), 2);
+    /// assert!(snapshot.entities.iter().all(|e| e.components.len() == 1));
+    /// ```
+    pub fn extract_components<L: ComponentList>(self) -> Self {
+        let types = L::type_ids();
+
+        let ids = types
+            .iter()
+            .filter_map(|id| self.world.components().get_id(*id))
+            .collect::<Vec<_>>();
+
+        let entities = self
+            .world
+            .iter_entities()
+            .filter(|e| ids.iter().any(|id| e.contains_id(*id)))
+            .map(|e| e.id())
+            .collect::<Vec<_>>();
+
+        self.extract_entities_with(entities.into_iter(), Some(&types))
+    }
+
+    /// Extract the given entities, only extracting the given component types if any, merging them with components
+    /// extracted earlier.
+    fn extract_entities_with(
+        mut self,
+        entities: impl Iterator<Item = Entity>,
+        only: Option<&[TypeId]>,
+    ) -> Self {
+        profile_span!("extract_entities");
+
         let registry = self.world.resource::<AppTypeRegistry>().read();
-        let rollbacks = self.world.resource::<RollbackRegistry>();
+        let rollbacks = rollback_registry(self.world, self.domain);
 
         for entity in entities.filter_map(|e| self.world.get_entity(e)) {
+            if self.is_excluded(&entity) {
+                continue;
+            }
+
             let id = entity.id();
-            let mut entry = DynamicEntity {
-                entity: id,
-                components: Vec::new(),
-            };
 
-            for component in entity.archetype().components() {
-                let reflect = self
-                    .world
+            let components = entity.archetype().components().filter_map(|component| {
+                self.world
                     .components()
                     .get_info(component)
                     .and_then(|info| info.type_id())
+                    .filter(|id| match only {
+                        Some(only) => only.contains(id),
+                        None => true,
+                    })
                     .filter(|id| self.filter.is_allowed_by_id(*id))
                     .filter(|id| {
                         if self.is_rollback {
@@ -192,14 +401,33 @@ impl<'a> SnapshotBuilder<'a> {
                     })
                     .and_then(|id| registry.get(id))
                     .and_then(|reg| reg.data::<ReflectComponent>())
-                    .and_then(|reflect| reflect.reflect(entity));
+                    .and_then(|reflect| reflect.reflect(entity))
+            });
+
+            let mut entry = DynamicEntity {
+                entity: id,
+                components: clone_reflect_values(components, &registry),
+            };
 
-                if let Some(reflect) = reflect {
-                    entry.components.push(reflect.clone_value());
+            match self.entities.get_mut(&id) {
+                Some(existing) if only.is_some() => {
+                    let extracted = entry
+                        .components
+                        .iter()
+                        .filter_map(|c| c.get_represented_type_info())
+                        .map(|i| i.type_id())
+                        .collect::<Vec<_>>();
+
+                    existing.components.retain(|c| {
+                        !c.get_represented_type_info()
+                            .is_some_and(|i| extracted.contains(&i.type_id()))
+                    });
+                    existing.components.append(&mut entry.components);
+                }
+                _ => {
+                    self.entities.insert(id, entry);
                 }
             }
-
-            self.entities.insert(id, entry);
         }
 
         self
@@ -244,10 +472,27 @@ impl<'a> SnapshotBuilder<'a> {
         mut self,
         type_paths: impl Iterator<Item = T>,
     ) -> Self {
+        profile_span!("extract_resources");
+
         let registry = self.world.resource::<AppTypeRegistry>().read();
-        let rollbacks = self.world.resource::<RollbackRegistry>();
+        let rollbacks = rollback_registry(self.world, self.domain);
+        let nonsend = self.world.get_resource::<NonSendSaveables>();
+
+        let type_paths = type_paths.collect::<Vec<_>>();
 
+        // Non-send resources are captured as their proxies
         type_paths
+            .iter()
+            .filter_map(|p| nonsend?.get_by_path(p.as_ref()))
+            .filter(|s| self.filter.is_allowed_by_id(s.proxy))
+            .filter(|s| !self.is_rollback || rollbacks.is_allowed_by_id(s.proxy))
+            .filter_map(|s| (s.capture)(self.world))
+            .for_each(|(i, r)| {
+                self.resources.insert(i, r);
+            });
+
+        let (ids, resources) = type_paths
+            .into_iter()
             .filter_map(|p| registry.get_with_type_path(p.as_ref()))
             .filter(|r| self.filter.is_allowed_by_id((*r).type_id()))
             .filter(|r| {
@@ -260,14 +505,13 @@ impl<'a> SnapshotBuilder<'a> {
             .filter_map(|r| {
                 Some((
                     self.world.components().get_resource_id(r.type_id())?,
-                    r.data::<ReflectResource>()?
-                        .reflect(self.world)?
-                        .clone_value(),
+                    r.data::<ReflectResource>()?.reflect(self.world)?,
                 ))
             })
-            .for_each(|(i, r)| {
-                self.resources.insert(i, r);
-            });
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+
+        let values = clone_reflect_values(resources, &registry);
+        self.resources.extend(ids.into_iter().zip(values));
 
         self
     }
@@ -285,17 +529,180 @@ impl<'a> SnapshotBuilder<'a> {
             .filter_map(move |id| self.world.components().get_info(id))
             .filter_map(|info| info.type_id())
             .filter_map(|id| registry.get(id))
-            .map(|reg| reg.type_info().type_path());
+            .map(|reg| reg.type_info().type_path())
+            .chain(
+                self.world
+                    .get_resource::<NonSendSaveables>()
+                    .into_iter()
+                    .flat_map(|n| n.iter().map(|s| s.type_path)),
+            );
 
         self.extract_resources_by_path(resources)
     }
 
-    /// Extract [`Rollbacks`] from the builder's [`World`].
-    pub fn extract_rollbacks(mut self) -> Self {
-        self.rollbacks = self
+    /// Extract only the components and resources that have changed since the given [`Tick`].
+    ///
+    /// Entities without any changed components are skipped.
+    pub fn extract_changed_since(mut self, last_run: Tick) -> Self {
+        let registry = self.world.resource::<AppTypeRegistry>().read();
+        let rollbacks = rollback_registry(self.world, self.domain);
+        let this_run = self.world.read_change_tick();
+
+        let reflect = |id: ComponentId| {
+            self.world
+                .components()
+                .get_info(id)
+                .and_then(|info| info.type_id())
+                .filter(|id| self.filter.is_allowed_by_id(*id))
+                .filter(|id| !self.is_rollback || rollbacks.is_allowed_by_id(*id))
+                .and_then(|id| registry.get(id))
+        };
+
+        for entity in self.world.iter_entities() {
+            if self.is_excluded(&entity) {
+                continue;
+            }
+
+            let components = entity
+                .archetype()
+                .components()
+                .filter(|id| {
+                    entity
+                        .get_change_ticks_by_id(*id)
+                        .is_some_and(|ticks| ticks.is_changed(last_run, this_run))
+                })
+                .filter_map(reflect)
+                .filter_map(|reg| reg.data::<ReflectComponent>())
+                .filter_map(|reflect| reflect.reflect(entity));
+
+            let components = clone_reflect_values(components, &registry);
+
+            if !components.is_empty() {
+                self.entities.insert(entity.id(), DynamicEntity {
+                    entity: entity.id(),
+                    components,
+                });
+            }
+        }
+
+        let (ids, resources) = self
             .world
-            .get_resource::<Rollbacks>()
-            .map(|r| r.clone_value());
+            .storages()
+            .resources
+            .iter()
+            .map(|(id, _)| id)
+            .filter(|id| {
+                self.world
+                    .get_resource_change_ticks_by_id(*id)
+                    .is_some_and(|ticks| ticks.is_changed(last_run, this_run))
+            })
+            .filter_map(|id| {
+                let resource = reflect(id)?
+                    .data::<ReflectResource>()?
+                    .reflect(self.world)?;
+
+                Some((id, resource))
+            })
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+
+        let values = clone_reflect_values(resources, &registry);
+        self.resources.extend(ids.into_iter().zip(values));
+
+        self
+    }
+
+    /// Create a [`CapturePlan`] from the builder's filter, allowing repeated captures to skip filter and registry lookups.
+    pub fn plan(&self) -> CapturePlan {
+        CapturePlan::with_domain(
+            self.world,
+            self.filter.clone(),
+            self.is_rollback,
+            self.domain,
+        )
+    }
+
+    /// Extract all entities and resources from the builder's [`World`] using a precomputed [`CapturePlan`].
+    ///
+    /// The plan's filter is used instead of the builder's.
+    /// The plan is [refreshed](CapturePlan::refresh) first, so archetypes and resources added since it was built are
+    /// not skipped.
+    pub fn extract_with_plan(mut self, plan: &mut CapturePlan) -> Self {
+        plan.refresh(self.world);
+
+        let registry = self.world.resource::<AppTypeRegistry>().read();
+
+        for archetype in self.world.archetypes().iter() {
+            let Some(components) = plan.archetypes.get(archetype.id().index()) else {
+                continue;
+            };
+
+            profile_span!(
+                "extract_archetype",
+                archetype = archetype.id().index(),
+                entities = archetype.len()
+            );
+
+            for entity in archetype.entities() {
+                let entity = self.world.entity(entity.id());
+
+                if self.is_excluded(&entity) {
+                    continue;
+                }
+
+                self.entities.insert(entity.id(), DynamicEntity {
+                    entity: entity.id(),
+                    components: clone_reflect_values(
+                        components
+                            .iter()
+                            .filter_map(|reflect| reflect.reflect(entity)),
+                        &registry,
+                    ),
+                });
+            }
+        }
+
+        let (ids, resources) = plan
+            .resources
+            .iter()
+            .filter_map(|(id, reflect)| Some((*id, reflect.reflect(self.world)?)))
+            .unzip::<_, _, Vec<_>, Vec<_>>();
+
+        let values = clone_reflect_values(resources, &registry);
+        self.resources.extend(ids.into_iter().zip(values));
+
+        self
+    }
+
+    /// Extract [`Rollbacks`] of the builder's domain from the builder's [`World`].
+    ///
+    /// Only the checkpoints kept by the builder's [`CheckpointPersistence`] are extracted.
+    pub fn extract_rollbacks(mut self) -> Self {
+        self.rollbacks =
+            rollbacks(self.world, self.domain).and_then(|r| r.persisted(self.persistence));
+
+        self
+    }
+
+    /// Store the [`RollbackConfig`] of the builder's domain in the snapshot, for diagnostics.
+    pub fn extract_rollback_config(mut self) -> Self {
+        let registry = self.world.resource::<AppTypeRegistry>().read();
+        let config = rollback_registry(self.world, self.domain).config(&registry);
+
+        drop(registry);
+
+        self.rollback_config = Some(config);
+        self
+    }
+
+    /// Store an unstructured value with the given key in the snapshot's [`Extras`], replacing any existing value.
+    ///
+    /// Values that cannot be serialized are skipped with a warning.
+    pub fn extract_extra<T: Serialize>(mut self, key: impl Into<String>, value: T) -> Self {
+        let key = key.into();
+
+        if let Err(err) = self.extras.insert(key.clone(), &value) {
+            warn!("Failed to extract extra `{key}`: {err}");
+        }
 
         self
     }
@@ -344,11 +751,132 @@ impl<'a> SnapshotBuilder<'a> {
 
 impl<'a> SnapshotBuilder<'a> {
     /// Build the extracted entities and resources into a [`Snapshot`].
-    pub fn build(self) -> Snapshot {
-        Snapshot {
-            entities: self.entities.into_values().collect(),
+    ///
+    /// Hierarchy edges are only stored on the child as a [`Parent`] component.
+    /// Extracted [`Children`] components are dropped, and rebuilt by the [`SnapshotApplier`](crate::SnapshotApplier).
+    /// Siblings are stored in the order of their [`Children`], so they are rebuilt in the same order.
+    ///
+    /// Resources are sorted by type path, so snapshots of the same state are identical between runs.
+    pub fn build(mut self) -> Snapshot {
+        profile_span!("build");
+
+        for entity in self.entities.values_mut() {
+            entity.components.retain(|c| !c.represents::<Children>());
+        }
+
+        if self.is_scene_diff {
+            for entity in self.entities.values_mut() {
+                diff_scene_instance(entity, self.world);
+            }
+        }
+
+        let mut markers = MarkerTypes::default();
+
+        if self.is_compact {
+            for entity in self.entities.values_mut() {
+                compact_markers(entity, &mut markers);
+            }
+        }
+
+        if self.is_sparse {
+            let registry = self.world.resource::<AppTypeRegistry>().read();
+
+            for entity in self.entities.values_mut() {
+                sparsify(entity, &registry);
+            }
+        }
+
+        let mut snapshot = Snapshot {
+            entities: order_siblings(self.entities.into_values().collect(), self.world),
             resources: self.resources.into_values().collect(),
             rollbacks: self.rollbacks,
+            unknown: UnknownData::default(),
+        };
+
+        if !markers.types.is_empty() {
+            snapshot.insert_resource(markers);
+        }
+
+        if let Some(config) = self.rollback_config {
+            snapshot.insert_resource(config);
+        }
+
+        if !self.extras.is_empty() {
+            let mut extras = snapshot.extras().unwrap_or_default();
+            extras.merge(self.extras);
+            snapshot.insert_resource(extras);
+        }
+
+        // Resources are sorted so the snapshot does not depend on the order resources were added to the world
+        snapshot
+            .resources
+            .sort_by(|a, b| resource_type_path(&**a).cmp(resource_type_path(&**b)));
+
+        snapshot
+    }
+}
+
+/// Orders extracted siblings like the [`Children`] of their parent, leaving other entities in place.
+fn order_siblings(entities: Vec<DynamicEntity>, world: &World) -> Vec<DynamicEntity> {
+    let mut siblings: EntityHashMap<Vec<usize>> = EntityHashMap::default();
+
+    for (i, entity) in entities.iter().enumerate() {
+        if let Some(parent) = world.get::<Parent>(entity.entity) {
+            siblings.entry(parent.get()).or_default().push(i);
+        }
+    }
+
+    let mut order = (0..entities.len()).collect::<Vec<_>>();
+
+    for (parent, slots) in siblings {
+        let Some(children) = world.get::<Children>(parent).filter(|_| slots.len() > 1) else {
+            continue;
+        };
+
+        let index = children
+            .iter()
+            .enumerate()
+            .map(|(i, c)| (*c, i))
+            .collect::<EntityHashMap<_>>();
+
+        let mut sorted = slots.clone();
+        sorted.sort_by_key(|&i| index.get(&entities[i].entity).copied());
+
+        for (slot, i) in slots.into_iter().zip(sorted) {
+            order[slot] = i;
         }
     }
+
+    let mut entities = entities.into_iter().map(Some).collect::<Vec<_>>();
+
+    order
+        .into_iter()
+        .filter_map(|i| entities[i].take())
+        .collect()
+}
+
+/// A list of [`Component`] types extracted by [`SnapshotBuilder::extract_components`], implemented for tuples of up
+/// to 8 components.
+pub trait ComponentList {
+    /// Returns the [`TypeId`] of each component in the list.
+    fn type_ids() -> Vec<TypeId>;
+}
+
+macro_rules! impl_component_list {
+    ($($component:ident),*) => {
+        impl<$($component: Component),*> ComponentList for ($($component,)*) {
+            fn type_ids() -> Vec<TypeId> {
+                vec![$(TypeId::of::<$component>()),*]
+            }
+        }
+    };
 }
+
+impl_component_list!(A);
+impl_component_list!(A, B);
+impl_component_list!(A, B, C);
+impl_component_list!(A, B, C, D);
+impl_component_list!(A, B, C, D, E);
+impl_component_list!(A, B, C, D, E, F);
+impl_component_list!(A, B, C, D, E, F, G);
+impl_component_list!(A, B, C, D, E, F, G, H);
@@ -0,0 +1,366 @@
+use std::{
+    fmt::Formatter,
+    marker::PhantomData,
+};
+
+use serde::{
+    de::{
+        value::{
+            MapDeserializer,
+            SeqDeserializer,
+        },
+        DeserializeSeed,
+        EnumAccess,
+        Error,
+        IntoDeserializer,
+        MapAccess,
+        SeqAccess,
+        Unexpected,
+        VariantAccess,
+        Visitor,
+    },
+    forward_to_deserialize_any,
+    ser::{
+        SerializeMap,
+        SerializeSeq,
+    },
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+
+/// An owned, format-independent buffer of a deserialized value, which can be deserialized or serialized again later.
+///
+/// Only formats where `deserialize_any` preserves the structure of the value (such as JSON or `MessagePack`) can be
+/// buffered.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Content {
+    Bool(bool),
+    U64(u64),
+    I64(i64),
+    U128(u128),
+    I128(i128),
+    F64(f64),
+    Char(char),
+    String(String),
+    Bytes(Vec<u8>),
+    None,
+    Some(Box<Content>),
+    Unit,
+    Newtype(Box<Content>),
+    Seq(Vec<Content>),
+    Map(Vec<(Content, Content)>),
+}
+
+impl Content {
+    fn unexpected(&self) -> Unexpected<'_> {
+        match self {
+            Self::Bool(v) => Unexpected::Bool(*v),
+            Self::U64(v) => Unexpected::Unsigned(*v),
+            Self::I64(v) => Unexpected::Signed(*v),
+            Self::U128(_) | Self::I128(_) => Unexpected::Other("128-bit integer"),
+            Self::F64(v) => Unexpected::Float(*v),
+            Self::Char(v) => Unexpected::Char(*v),
+            Self::String(v) => Unexpected::Str(v),
+            Self::Bytes(v) => Unexpected::Bytes(v),
+            Self::None | Self::Some(_) => Unexpected::Option,
+            Self::Unit => Unexpected::Unit,
+            Self::Newtype(_) => Unexpected::NewtypeStruct,
+            Self::Seq(_) => Unexpected::Seq,
+            Self::Map(_) => Unexpected::Map,
+        }
+    }
+}
+
+impl Serialize for Content {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Bool(v) => serializer.serialize_bool(*v),
+            Self::U64(v) => serializer.serialize_u64(*v),
+            Self::I64(v) => serializer.serialize_i64(*v),
+            Self::U128(v) => serializer.serialize_u128(*v),
+            Self::I128(v) => serializer.serialize_i128(*v),
+            Self::F64(v) => serializer.serialize_f64(*v),
+            Self::Char(v) => serializer.serialize_char(*v),
+            Self::String(v) => serializer.serialize_str(v),
+            Self::Bytes(v) => serializer.serialize_bytes(v),
+            Self::None => serializer.serialize_none(),
+            Self::Some(v) => serializer.serialize_some(v),
+            Self::Unit => serializer.serialize_unit(),
+            Self::Newtype(v) => serializer.serialize_newtype_struct("Content", v),
+            Self::Seq(v) => {
+                let mut seq = serializer.serialize_seq(Some(v.len()))?;
+                for value in v {
+                    seq.serialize_element(value)?;
+                }
+                seq.end()
+            }
+            Self::Map(v) => {
+                let mut map = serializer.serialize_map(Some(v.len()))?;
+                for (key, value) in v {
+                    map.serialize_entry(key, value)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Content {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ContentVisitor)
+    }
+}
+
+struct ContentVisitor;
+
+impl<'de> Visitor<'de> for ContentVisitor {
+    type Value = Content;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("any self-describing value")
+    }
+
+    fn visit_bool<E: Error>(self, v: bool) -> Result<Content, E> {
+        Ok(Content::Bool(v))
+    }
+
+    fn visit_i64<E: Error>(self, v: i64) -> Result<Content, E> {
+        Ok(Content::I64(v))
+    }
+
+    fn visit_u64<E: Error>(self, v: u64) -> Result<Content, E> {
+        Ok(Content::U64(v))
+    }
+
+    fn visit_i128<E: Error>(self, v: i128) -> Result<Content, E> {
+        Ok(Content::I128(v))
+    }
+
+    fn visit_u128<E: Error>(self, v: u128) -> Result<Content, E> {
+        Ok(Content::U128(v))
+    }
+
+    fn visit_f64<E: Error>(self, v: f64) -> Result<Content, E> {
+        Ok(Content::F64(v))
+    }
+
+    fn visit_char<E: Error>(self, v: char) -> Result<Content, E> {
+        Ok(Content::Char(v))
+    }
+
+    fn visit_str<E: Error>(self, v: &str) -> Result<Content, E> {
+        Ok(Content::String(v.to_owned()))
+    }
+
+    fn visit_string<E: Error>(self, v: String) -> Result<Content, E> {
+        Ok(Content::String(v))
+    }
+
+    fn visit_bytes<E: Error>(self, v: &[u8]) -> Result<Content, E> {
+        Ok(Content::Bytes(v.to_owned()))
+    }
+
+    fn visit_byte_buf<E: Error>(self, v: Vec<u8>) -> Result<Content, E> {
+        Ok(Content::Bytes(v))
+    }
+
+    fn visit_none<E: Error>(self) -> Result<Content, E> {
+        Ok(Content::None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Content, D::Error> {
+        Content::deserialize(deserializer).map(|v| Content::Some(Box::new(v)))
+    }
+
+    fn visit_unit<E: Error>(self) -> Result<Content, E> {
+        Ok(Content::Unit)
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Content, D::Error> {
+        Content::deserialize(deserializer).map(|v| Content::Newtype(Box::new(v)))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Content, A::Error> {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+
+        Ok(Content::Seq(values))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Content, A::Error> {
+        let mut entries = Vec::with_capacity(map.size_hint().unwrap_or(0).min(4096));
+
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+
+        Ok(Content::Map(entries))
+    }
+}
+
+/// Deserializes a buffered [`Content`].
+pub(crate) struct ContentDeserializer<E> {
+    content: Content,
+    _marker: PhantomData<E>,
+}
+
+impl<E> ContentDeserializer<E> {
+    pub(crate) fn new(content: Content) -> Self {
+        Self {
+            content,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<E: Error> IntoDeserializer<'_, E> for Content {
+    type Deserializer = ContentDeserializer<E>;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        ContentDeserializer::new(self)
+    }
+}
+
+impl<'de, E: Error> Deserializer<'de> for ContentDeserializer<E> {
+    type Error = E;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        match self.content {
+            Content::Bool(v) => visitor.visit_bool(v),
+            Content::U64(v) => visitor.visit_u64(v),
+            Content::I64(v) => visitor.visit_i64(v),
+            Content::U128(v) => visitor.visit_u128(v),
+            Content::I128(v) => visitor.visit_i128(v),
+            Content::F64(v) => visitor.visit_f64(v),
+            Content::Char(v) => visitor.visit_char(v),
+            Content::String(v) => visitor.visit_string(v),
+            Content::Bytes(v) => visitor.visit_byte_buf(v),
+            Content::None => visitor.visit_none(),
+            Content::Some(v) => visitor.visit_some(Self::new(*v)),
+            Content::Unit => visitor.visit_unit(),
+            Content::Newtype(v) => visitor.visit_newtype_struct(Self::new(*v)),
+            Content::Seq(v) => visitor.visit_seq(SeqDeserializer::new(v.into_iter())),
+            Content::Map(v) => visitor.visit_map(MapDeserializer::new(v.into_iter())),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        match self.content {
+            Content::None | Content::Unit => visitor.visit_none(),
+            Content::Some(v) => visitor.visit_some(Self::new(*v)),
+            content => visitor.visit_some(Self::new(content)),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        match self.content {
+            Content::Newtype(v) => visitor.visit_newtype_struct(Self::new(*v)),
+            content => visitor.visit_newtype_struct(Self::new(content)),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        let (variant, value) = match self.content {
+            variant @ (Content::String(_) | Content::U64(_)) => (variant, None),
+            Content::Map(mut entries) if entries.len() == 1 => {
+                let (variant, value) = entries.remove(0);
+                (variant, Some(value))
+            }
+            content => {
+                return Err(E::invalid_type(content.unexpected(), &"enum"));
+            }
+        };
+
+        visitor.visit_enum(ContentEnum {
+            variant,
+            value,
+            _marker: PhantomData,
+        })
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct ContentEnum<E> {
+    variant: Content,
+    value: Option<Content>,
+    _marker: PhantomData<E>,
+}
+
+impl<'de, E: Error> EnumAccess<'de> for ContentEnum<E> {
+    type Error = E;
+    type Variant = ContentVariant<E>;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), E> {
+        let variant = seed.deserialize(ContentDeserializer::new(self.variant))?;
+
+        Ok((variant, ContentVariant {
+            value: self.value,
+            _marker: PhantomData,
+        }))
+    }
+}
+
+struct ContentVariant<E> {
+    value: Option<Content>,
+    _marker: PhantomData<E>,
+}
+
+impl<'de, E: Error> VariantAccess<'de> for ContentVariant<E> {
+    type Error = E;
+
+    fn unit_variant(self) -> Result<(), E> {
+        match self.value {
+            None | Some(Content::Unit) => Ok(()),
+            Some(content) => Err(E::invalid_type(content.unexpected(), &"unit variant")),
+        }
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, E> {
+        match self.value {
+            Some(content) => seed.deserialize(ContentDeserializer::new(content)),
+            None => Err(E::invalid_type(Unexpected::UnitVariant, &"newtype variant")),
+        }
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, E> {
+        match self.value {
+            Some(content) => ContentDeserializer::new(content).deserialize_any(visitor),
+            None => Err(E::invalid_type(Unexpected::UnitVariant, &"tuple variant")),
+        }
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        match self.value {
+            Some(content) => ContentDeserializer::new(content).deserialize_any(visitor),
+            None => Err(E::invalid_type(Unexpected::UnitVariant, &"struct variant")),
+        }
+    }
+}
@@ -0,0 +1,306 @@
+use std::fmt::{
+    Display,
+    Write,
+};
+
+use bevy::prelude::*;
+use fastcdc::v2020::{
+    FastCDC,
+    AVERAGE_MAX,
+    AVERAGE_MIN,
+    MAXIMUM_MAX,
+    MAXIMUM_MIN,
+    MINIMUM_MAX,
+    MINIMUM_MIN,
+};
+use serde::{
+    de::DeserializeSeed,
+    Deserialize,
+    Serialize,
+};
+use sha2::{
+    Digest,
+    Sha256,
+};
+
+use crate::{
+    batch::{
+        Payload,
+        PayloadSeed,
+        Raw,
+    },
+    Backend,
+    Error,
+    Format,
+};
+
+/// How a [`Deduplicated`] backend splits saves into chunks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Chunking {
+    /// Chunks of a fixed size in bytes.
+    ///
+    /// Cheap, but inserting or removing data shifts every following chunk, so only in-place changes deduplicate well.
+    Fixed(usize),
+
+    /// Content-defined chunks found with [FastCDC](https://www.usenix.org/conference/atc16/technical-sessions/presentation/xia),
+    /// with sizes in bytes.
+    ///
+    /// Chunk boundaries follow the contents, so data shifted by insertions still deduplicates.
+    ContentDefined {
+        /// The minimum size of a chunk.
+        min: u32,
+        /// The average size of a chunk.
+        avg: u32,
+        /// The maximum size of a chunk.
+        max: u32,
+    },
+}
+
+impl Default for Chunking {
+    fn default() -> Self {
+        Self::ContentDefined {
+            min: 4 * 1024,
+            avg: 16 * 1024,
+            max: 64 * 1024,
+        }
+    }
+}
+
+impl Chunking {
+    /// Splits the data into chunks.
+    fn split<'d>(&self, data: &'d [u8]) -> Vec<&'d [u8]> {
+        match *self {
+            Self::Fixed(size) => data.chunks(size.max(1)).collect(),
+            Self::ContentDefined { min, avg, max } => {
+                let min = min.clamp(MINIMUM_MIN, MINIMUM_MAX);
+                let avg = avg.clamp(AVERAGE_MIN, AVERAGE_MAX).max(min);
+                let max = max.clamp(MAXIMUM_MIN, MAXIMUM_MAX).max(avg);
+
+                FastCDC::new(data, min, avg, max)
+                    .map(|c| &data[c.offset..c.offset + c.length])
+                    .collect()
+            }
+        }
+    }
+}
+
+/// The contents of a save stored by a [`Deduplicated`] backend, listing its chunks in order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChunkManifest {
+    /// The total size of the save in bytes.
+    pub len: u64,
+    /// The hex-encoded SHA-256 hashes of the chunks.
+    pub chunks: Vec<String>,
+}
+
+/// Extension of chunk files.
+struct Chunk;
+
+impl Format for Chunk {
+    fn name() -> &'static str {
+        "chunk"
+    }
+
+    fn extension() -> &'static str {
+        ".chunk"
+    }
+
+    fn serialize<W: std::io::Write, T: Serialize>(_: W, _: &T) -> Result<(), Error> {
+        Err(Error::custom("chunks are only stored raw"))
+    }
+
+    fn deserialize<R: std::io::Read, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        _: R,
+        _: S,
+    ) -> Result<T, Error> {
+        Err(Error::custom("chunks are only stored raw"))
+    }
+}
+
+fn hash(chunk: &[u8]) -> String {
+    Sha256::digest(chunk)
+        .iter()
+        .fold(String::with_capacity(64), |mut hex, b| {
+            let _ = write!(hex, "{b:02x}");
+            hex
+        })
+}
+
+/// Backend middleware storing saves by content, deduplicating data shared by many slots, such as autosaves and
+/// quicksaves that are nearly identical.
+///
+/// Saves are serialized and split into chunks according to the [`Chunking`]. Each chunk is stored once in the inner
+/// [`Backend`] under the hash of its contents, and the save itself is replaced by a small [`ChunkManifest`] listing
+/// its chunks. Chunks are verified against their hash when loading.
+///
+/// Chunks are stored under keys starting with the [`chunk_prefix`](Self::chunk_prefix), and are not deleted when the
+/// saves referencing them are overwritten. Use [`manifest`](Self::manifest) to find the chunks still in use.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// struct Autosave;
+///
+/// impl Pipeline for Autosave {
+///     type Backend = Deduplicated<DirBackend>;
+///     type Format = DefaultFormat;
+///
+///     type Key<'a> = &'a str;
+///
+///     fn key(&self) -> Self::Key<'_> {
+///         "autosave/world"
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugins(SavePlugins)
+///     .init_pipeline::<Autosave>()
+///     .insert_resource(Deduplicated::new(
+///         DirBackend::new("saves"),
+///         Chunking::default(),
+///     ));
+/// ```
+#[derive(Resource)]
+pub struct Deduplicated<B> {
+    inner: B,
+    chunking: Chunking,
+    prefix: String,
+}
+
+impl<B: Default> Default for Deduplicated<B> {
+    fn default() -> Self {
+        Self::new(B::default(), Chunking::default())
+    }
+}
+
+impl<B> Deduplicated<B> {
+    /// The default prefix of the keys chunks are stored under.
+    pub const DEFAULT_CHUNK_PREFIX: &'static str = "chunks";
+
+    /// Create a new [`Deduplicated`] backend wrapping the given backend.
+    pub fn new(inner: B, chunking: Chunking) -> Self {
+        Self {
+            inner,
+            chunking,
+            prefix: Self::DEFAULT_CHUNK_PREFIX.to_owned(),
+        }
+    }
+
+    /// Store chunks under keys starting with the given prefix, followed by `/` and the hash of the chunk.
+    pub fn chunk_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Returns the wrapped backend.
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Returns how saves are split into chunks.
+    pub fn chunking(&self) -> Chunking {
+        self.chunking
+    }
+
+    /// Returns the key the chunk with the given hash is stored under.
+    pub fn chunk_key(&self, hash: &str) -> String {
+        format!("{}/{hash}", self.prefix)
+    }
+}
+
+impl<B: Backend<String>> Deduplicated<B> {
+    /// Reads the [`ChunkManifest`] of the save with the given key.
+    ///
+    /// # Errors
+    /// - [`Error::Loading`] if the manifest is invalid
+    /// - See [`Backend::load`]
+    pub fn manifest<F: Format>(&self, key: impl Display) -> Result<ChunkManifest, Error> {
+        let data = self
+            .inner
+            .load::<Raw<F>, _, _>(key.to_string(), PayloadSeed)?;
+        let text = std::str::from_utf8(&data).map_err(Error::loading)?;
+
+        ron::from_str(text).map_err(Error::loading)
+    }
+
+    /// Serializes the value, stores its chunks and returns the manifest to write.
+    fn store<F: Format, T: Serialize>(&self, value: &T) -> Result<Vec<u8>, Error> {
+        let mut data = Vec::new();
+        F::serialize(&mut data, value)?;
+
+        let mut manifest = ChunkManifest {
+            len: data.len() as u64,
+            chunks: Vec::new(),
+        };
+
+        for chunk in self.chunking.split(&data) {
+            let hash = hash(chunk);
+            let key = self.chunk_key(&hash);
+
+            // Backends without tags cannot tell whether the chunk exists, so it is written again
+            if self.inner.etag::<Chunk>(key.clone())?.is_none() {
+                self.inner.save::<Raw<Chunk>, _>(key, &Payload(chunk))?;
+            }
+
+            manifest.chunks.push(hash);
+        }
+
+        let text = ron::to_string(&manifest).map_err(Error::saving)?;
+
+        Ok(text.into_bytes())
+    }
+}
+
+impl<K: Display, B: Backend<String>> Backend<K> for Deduplicated<B> {
+    fn save<F: Format, T: Serialize>(&self, key: K, value: &T) -> Result<(), Error> {
+        let manifest = self.store::<F, T>(value)?;
+
+        self.inner
+            .save::<Raw<F>, _>(key.to_string(), &Payload(&manifest))
+    }
+
+    fn load<F: Format, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        &self,
+        key: K,
+        seed: S,
+    ) -> Result<T, Error> {
+        let manifest = self.manifest::<F>(key)?;
+
+        let mut data = Vec::with_capacity(usize::try_from(manifest.len).unwrap_or_default());
+
+        for hash in &manifest.chunks {
+            let chunk = self
+                .inner
+                .load::<Raw<Chunk>, _, _>(self.chunk_key(hash), PayloadSeed)?;
+
+            if self::hash(&chunk) != *hash {
+                return Err(Error::custom(format_args!("corrupted chunk: {hash}")));
+            }
+
+            data.extend_from_slice(&chunk);
+        }
+
+        if data.len() as u64 != manifest.len {
+            return Err(Error::custom("save does not match its chunk manifest"));
+        }
+
+        F::deserialize_slice(&data, seed)
+    }
+
+    fn etag<F: Format>(&self, key: K) -> Result<Option<String>, Error> {
+        self.inner.etag::<F>(key.to_string())
+    }
+
+    fn save_if_match<F: Format, T: Serialize>(
+        &self,
+        key: K,
+        value: &T,
+        etag: Option<&str>,
+    ) -> Result<(), Error> {
+        let manifest = self.store::<F, T>(value)?;
+
+        self.inner
+            .save_if_match::<Raw<F>, _>(key.to_string(), &Payload(&manifest), etag)
+    }
+}
@@ -0,0 +1,295 @@
+use std::any::TypeId;
+
+use bevy::{
+    ecs::{
+        archetype::ArchetypeId,
+        component::Tick,
+        entity::{
+            EntityHashMap,
+            EntityHashSet,
+        },
+    },
+    prelude::*,
+    scene::DynamicEntity,
+};
+
+use crate::{
+    CloneReflect,
+    Error,
+    Pipeline,
+    Snapshot,
+    SnapshotBuilder,
+};
+
+/// The changes made to the [`World`] between two checkpoints.
+pub struct SnapshotDelta {
+    /// Entities that were spawned or had components added or removed, stored in full.
+    pub entities: Vec<DynamicEntity>,
+    /// Entities with changed components, storing only the changed components.
+    pub changed: Vec<DynamicEntity>,
+    /// Entities that were despawned.
+    pub despawned: Vec<Entity>,
+    /// Resources that were changed.
+    pub resources: Vec<Box<dyn Reflect>>,
+}
+
+fn type_id(value: &dyn Reflect) -> Option<TypeId> {
+    value.get_represented_type_info().map(|i| i.type_id())
+}
+
+fn upsert(values: &mut Vec<Box<dyn Reflect>>, value: &dyn Reflect) {
+    let id = type_id(value);
+
+    match values.iter_mut().find(|v| type_id(&***v) == id) {
+        Some(existing) => *existing = value.clone_value(),
+        None => values.push(value.clone_value()),
+    }
+}
+
+impl SnapshotDelta {
+    /// Apply the changes to the [`Snapshot`] of the previous checkpoint, producing the [`Snapshot`] of this checkpoint.
+    pub fn apply_to(&self, snapshot: &mut Snapshot) {
+        let replaced = self
+            .entities
+            .iter()
+            .map(|e| e.entity)
+            .chain(self.despawned.iter().copied())
+            .collect::<EntityHashSet>();
+
+        snapshot.entities.retain(|e| !replaced.contains(&e.entity));
+        snapshot
+            .entities
+            .extend(self.entities.iter().map(|e| e.clone_value()));
+
+        let index = snapshot
+            .entities
+            .iter()
+            .enumerate()
+            .map(|(i, e)| (e.entity, i))
+            .collect::<EntityHashMap<_>>();
+
+        for changed in &self.changed {
+            let Some(&i) = index.get(&changed.entity) else {
+                continue;
+            };
+
+            for component in &changed.components {
+                upsert(&mut snapshot.entities[i].components, &**component);
+            }
+        }
+
+        for resource in &self.resources {
+            upsert(&mut snapshot.resources, &**resource);
+        }
+    }
+}
+
+enum Checkpoint {
+    Keyframe(Snapshot),
+    Delta(SnapshotDelta),
+}
+
+/// Copy-on-write checkpoints for rollback.
+///
+/// Instead of capturing a full [`Snapshot`] for every checkpoint, only the components and resources
+/// changed since the previous checkpoint are stored. A full keyframe is captured every `keyframe_interval` checkpoints,
+/// and the state of a checkpoint is reconstructed by applying deltas to the most recent keyframe.
+///
+/// Types are filtered by the [`RollbackRegistry`](crate::RollbackRegistry) and the provided [`SceneFilter`].
+/// Resource removal is not tracked.
+#[derive(Resource)]
+pub struct DeltaCheckpoints {
+    filter: SceneFilter,
+    keyframe_interval: usize,
+    checkpoints: Vec<Checkpoint>,
+    active: Option<usize>,
+    last_keyframe: usize,
+    last_tick: Option<Tick>,
+    archetypes: EntityHashMap<ArchetypeId>,
+}
+
+impl Default for DeltaCheckpoints {
+    fn default() -> Self {
+        Self::new(30)
+    }
+}
+
+impl DeltaCheckpoints {
+    /// Create a new [`DeltaCheckpoints`], capturing a full keyframe every `keyframe_interval` checkpoints.
+    pub fn new(keyframe_interval: usize) -> Self {
+        Self {
+            filter: SceneFilter::default(),
+            keyframe_interval: keyframe_interval.max(1),
+            checkpoints: Vec::new(),
+            active: None,
+            last_keyframe: 0,
+            last_tick: None,
+            archetypes: EntityHashMap::default(),
+        }
+    }
+
+    /// Only capture types allowed by the given [`SceneFilter`].
+    pub fn with_filter(mut self, filter: SceneFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Returns true if no checkpoints have been created.
+    pub fn is_empty(&self) -> bool {
+        self.checkpoints.is_empty()
+    }
+
+    /// Returns the number of stored checkpoints.
+    pub fn len(&self) -> usize {
+        self.checkpoints.len()
+    }
+
+    /// Returns true if the checkpoint at the given index is a full keyframe.
+    pub fn is_keyframe(&self, index: usize) -> bool {
+        matches!(self.checkpoints.get(index), Some(Checkpoint::Keyframe(_)))
+    }
+
+    /// Capture a new checkpoint from the [`World`] and set it as the active checkpoint.
+    ///
+    /// If you rollback and then create a checkpoint, it will erase all rollforward checkpoints.
+    pub fn checkpoint(&mut self, world: &World) {
+        let index = self.active.map_or(0, |a| a + 1);
+
+        self.checkpoints.truncate(index);
+
+        let builder = SnapshotBuilder::rollback(world).filter(self.filter.clone());
+
+        let archetypes = world
+            .iter_entities()
+            .map(|e| (e.id(), e.archetype().id()))
+            .collect::<EntityHashMap<_>>();
+
+        let checkpoint = match self.last_tick {
+            Some(last_tick) if index - self.last_keyframe < self.keyframe_interval => {
+                let full = archetypes
+                    .iter()
+                    .filter(|(e, a)| self.archetypes.get(*e) != Some(*a))
+                    .map(|(e, _)| *e)
+                    .collect::<EntityHashSet>();
+
+                let despawned = self
+                    .archetypes
+                    .keys()
+                    .filter(|e| !archetypes.contains_key(*e))
+                    .copied()
+                    .collect();
+
+                let entities = SnapshotBuilder::rollback(world)
+                    .filter(self.filter.clone())
+                    .extract_entities(full.iter().copied())
+                    .build()
+                    .entities;
+
+                let changed = builder.extract_changed_since(last_tick).build();
+
+                Checkpoint::Delta(SnapshotDelta {
+                    entities,
+                    changed: changed
+                        .entities
+                        .into_iter()
+                        .filter(|e| !full.contains(&e.entity))
+                        .collect(),
+                    despawned,
+                    resources: changed.resources,
+                })
+            }
+            _ => {
+                self.last_keyframe = index;
+                Checkpoint::Keyframe(
+                    builder
+                        .extract_all_entities()
+                        .extract_all_resources()
+                        .build(),
+                )
+            }
+        };
+
+        self.checkpoints.push(checkpoint);
+        self.active = Some(index);
+        self.archetypes = archetypes;
+        self.last_tick = Some(world.increment_change_tick());
+    }
+
+    /// Reconstruct the full [`Snapshot`] of the checkpoint at the given index.
+    pub fn snapshot_at(&self, index: usize) -> Option<Snapshot> {
+        if index >= self.checkpoints.len() {
+            return None;
+        }
+
+        let (start, mut snapshot) = self.checkpoints[..=index]
+            .iter()
+            .enumerate()
+            .rev()
+            .find_map(|(i, c)| match c {
+                Checkpoint::Keyframe(s) => Some((i, s.clone_value())),
+                Checkpoint::Delta(_) => None,
+            })?;
+
+        for checkpoint in &self.checkpoints[start + 1..=index] {
+            if let Checkpoint::Delta(delta) = checkpoint {
+                delta.apply_to(&mut snapshot);
+            }
+        }
+
+        Some(snapshot)
+    }
+
+    /// Rolls back the given number of checkpoints, returning the reconstructed [`Snapshot`].
+    ///
+    /// If checkpoints is negative, it rolls forward.
+    ///
+    /// This function will always clamp itself to valid checkpoints.
+    /// The next checkpoint created after rolling back is always a keyframe.
+    #[allow(clippy::cast_possible_wrap)]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn rollback(&mut self, checkpoints: isize) -> Option<Snapshot> {
+        let active = self.active?;
+        let raw = active as isize - checkpoints;
+        let new = raw.clamp(0, self.checkpoints.len() as isize - 1) as usize;
+
+        self.active = Some(new);
+        self.last_tick = None;
+
+        self.snapshot_at(new)
+    }
+}
+
+/// Extension trait that adds copy-on-write checkpoint methods to Bevy's [`World`].
+pub trait WorldDeltaExt {
+    /// Creates a copy-on-write checkpoint, storing only the changes since the previous checkpoint.
+    ///
+    /// Inserts a default [`DeltaCheckpoints`] resource if it does not exist.
+    fn checkpoint_delta(&mut self);
+
+    /// Rolls back / forward the [`World`] state using [`DeltaCheckpoints`] and the given [`Pipeline`].
+    ///
+    /// # Errors
+    /// - See [`Error`]
+    fn rollback_delta<P: Pipeline>(&mut self, checkpoints: isize) -> Result<(), Error>;
+}
+
+impl WorldDeltaExt for World {
+    fn checkpoint_delta(&mut self) {
+        self.init_resource::<DeltaCheckpoints>();
+        self.resource_scope(|world, mut checkpoints: Mut<DeltaCheckpoints>| {
+            checkpoints.checkpoint(world);
+        });
+    }
+
+    fn rollback_delta<P: Pipeline>(&mut self, checkpoints: isize) -> Result<(), Error> {
+        let snapshot = self
+            .get_resource_mut::<DeltaCheckpoints>()
+            .and_then(|mut c| c.rollback(checkpoints));
+
+        if let Some(snapshot) = snapshot {
+            P::apply(self, &snapshot)
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,315 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+};
+
+use bevy::{
+    prelude::*,
+    reflect::{
+        serde::TypedReflectSerializer,
+        TypeRegistry,
+    },
+};
+
+use crate::{
+    DeltaCheckpoints,
+    RegistryRef,
+    Rollbacks,
+    Snapshot,
+};
+
+/// How a value or entity differs between two snapshots.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// Only present in the newer snapshot.
+    Added,
+    /// Only present in the older snapshot.
+    Removed,
+    /// Present in both snapshots, with different values.
+    Changed,
+}
+
+/// A component or resource that differs between two snapshots, rendered as RON.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueDiff {
+    /// The type path of the value.
+    pub type_path: String,
+    /// The value in the older snapshot, or `None` if it was added.
+    pub before: Option<String>,
+    /// The value in the newer snapshot, or `None` if it was removed.
+    pub after: Option<String>,
+}
+
+impl ValueDiff {
+    /// Returns how the value differs.
+    pub fn change(&self) -> ChangeKind {
+        match (&self.before, &self.after) {
+            (None, _) => ChangeKind::Added,
+            (_, None) => ChangeKind::Removed,
+            _ => ChangeKind::Changed,
+        }
+    }
+}
+
+/// An entity that differs between two snapshots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntityDiff {
+    /// The entity.
+    pub entity: Entity,
+    /// Whether the entity was spawned, despawned, or had its components changed.
+    pub change: ChangeKind,
+    /// The components that differ, sorted by type path.
+    pub components: Vec<ValueDiff>,
+}
+
+/// The differences between two [`Snapshot`]s, for debugging rollback and desync issues.
+///
+/// Created by [`Snapshot::diff`], [`Rollbacks::diff`] and [`DeltaCheckpoints::diff`].
+/// Entities are matched by their id, so both snapshots should be captured from the same [`World`].
+///
+/// The [`Display`] implementation pretty-prints the differences.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SnapshotDiff {
+    /// The resources that differ, sorted by type path.
+    pub resources: Vec<ValueDiff>,
+    /// The entities that differ, sorted by entity.
+    pub entities: Vec<EntityDiff>,
+}
+
+impl SnapshotDiff {
+    /// Returns true if the snapshots are identical.
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty() && self.entities.is_empty()
+    }
+
+    /// Returns the differing component of the given type on the entity, if any.
+    pub fn component<T: TypePath>(&self, entity: Entity) -> Option<&ValueDiff> {
+        self.entities
+            .iter()
+            .find(|e| e.entity == entity)?
+            .components
+            .iter()
+            .find(|c| c.type_path == T::type_path())
+    }
+
+    /// Returns the differing resource of the given type, if any.
+    pub fn resource<T: TypePath>(&self) -> Option<&ValueDiff> {
+        self.resources
+            .iter()
+            .find(|r| r.type_path == T::type_path())
+    }
+}
+
+fn marker(change: ChangeKind) -> char {
+    match change {
+        ChangeKind::Added => '+',
+        ChangeKind::Removed => '-',
+        ChangeKind::Changed => '~',
+    }
+}
+
+fn write_value(
+    f: &mut std::fmt::Formatter<'_>,
+    indent: &str,
+    value: &ValueDiff,
+) -> std::fmt::Result {
+    let change = value.change();
+
+    write!(f, "{indent}{} {}: ", marker(change), value.type_path)?;
+
+    match (&value.before, &value.after) {
+        (Some(before), Some(after)) => writeln!(f, "{before} -> {after}"),
+        (Some(value), None) | (None, Some(value)) => writeln!(f, "{value}"),
+        (None, None) => writeln!(f),
+    }
+}
+
+impl Display for SnapshotDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No differences");
+        }
+
+        if !self.resources.is_empty() {
+            writeln!(f, "Resources:")?;
+            for resource in &self.resources {
+                write_value(f, "  ", resource)?;
+            }
+        }
+
+        if !self.entities.is_empty() {
+            writeln!(f, "Entities:")?;
+            for entity in &self.entities {
+                writeln!(f, "  {} {:?}", marker(entity.change), entity.entity)?;
+                for component in &entity.components {
+                    write_value(f, "    ", component)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// A value in the older and newer snapshot, if present.
+type BeforeAfter<T> = (Option<T>, Option<T>);
+
+fn type_path(value: &dyn Reflect) -> &str {
+    value
+        .get_represented_type_info()
+        .map_or_else(|| value.reflect_type_path(), |i| i.type_path())
+}
+
+/// Render the value as RON, falling back to its debug representation if it cannot be serialized.
+pub(crate) fn render(value: &dyn Reflect, registry: &TypeRegistry) -> String {
+    ron::to_string(&TypedReflectSerializer::new(value, registry))
+        .unwrap_or_else(|_| format!("{value:?}"))
+}
+
+fn diff_values(
+    before: &[Box<dyn Reflect>],
+    after: &[Box<dyn Reflect>],
+    registry: &TypeRegistry,
+) -> Vec<ValueDiff> {
+    let mut values: BTreeMap<&str, BeforeAfter<&dyn Reflect>> = BTreeMap::new();
+
+    for value in before {
+        values.entry(type_path(&**value)).or_default().0 = Some(&**value);
+    }
+
+    for value in after {
+        values.entry(type_path(&**value)).or_default().1 = Some(&**value);
+    }
+
+    values
+        .into_iter()
+        .filter_map(|(path, (before, after))| {
+            if let (Some(a), Some(b)) = (before, after) {
+                let equal = a
+                    .reflect_partial_eq(b)
+                    .unwrap_or_else(|| render(a, registry) == render(b, registry));
+
+                if equal {
+                    return None;
+                }
+            }
+
+            Some(ValueDiff {
+                type_path: path.to_owned(),
+                before: before.map(|v| render(v, registry)),
+                after: after.map(|v| render(v, registry)),
+            })
+        })
+        .collect()
+}
+
+impl Snapshot {
+    /// Returns the differences between this [`Snapshot`] and a newer one.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect, Default, PartialEq)]
+    /// #[reflect(Component, PartialEq)]
+    /// struct Health(u32);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # app.register_type::<Health>();
+    /// # let world = &mut app.world;
+    /// let player = world.spawn(Health(10)).id();
+    /// let before = Snapshot::builder(world).extract_all_entities().build();
+    ///
+    /// world.entity_mut(player).insert(Health(7));
+    /// let after = Snapshot::builder(world).extract_all_entities().build();
+    ///
+    /// let diff = before.diff(&after, world.resource::<AppTypeRegistry>());
+    /// let health = diff.component::<Health>(player).unwrap();
+    ///
+    /// assert_eq!(health.change(), ChangeKind::Changed);
+    /// assert_eq!(health.after.as_deref(), Some("(7)"));
+    ///
+    /// println!("{diff}");
+    /// ```
+    pub fn diff<'a>(&self, newer: &Snapshot, registry: impl Into<RegistryRef<'a>>) -> SnapshotDiff {
+        let registry = registry.into();
+        let registry = registry.read();
+
+        let resources = diff_values(&self.resources, &newer.resources, &registry);
+
+        let mut entities: BTreeMap<Entity, BeforeAfter<&[Box<dyn Reflect>]>> = BTreeMap::new();
+
+        for entity in &self.entities {
+            entities.entry(entity.entity).or_default().0 = Some(&entity.components);
+        }
+
+        for entity in &newer.entities {
+            entities.entry(entity.entity).or_default().1 = Some(&entity.components);
+        }
+
+        let entities = entities
+            .into_iter()
+            .filter_map(|(entity, (before, after))| {
+                let change = match (before, after) {
+                    (None, _) => ChangeKind::Added,
+                    (_, None) => ChangeKind::Removed,
+                    _ => ChangeKind::Changed,
+                };
+
+                let components =
+                    diff_values(before.unwrap_or(&[]), after.unwrap_or(&[]), &registry);
+
+                if change == ChangeKind::Changed && components.is_empty() {
+                    return None;
+                }
+
+                Some(EntityDiff {
+                    entity,
+                    change,
+                    components,
+                })
+            })
+            .collect();
+
+        SnapshotDiff {
+            resources,
+            entities,
+        }
+    }
+}
+
+impl Rollbacks {
+    /// Returns the differences from the checkpoint at index `a` to the checkpoint at index `b`.
+    ///
+    /// Indices cover the checkpoints of all branches. Returns `None` if either index is out of bounds.
+    pub fn diff<'a>(
+        &self,
+        a: usize,
+        b: usize,
+        registry: impl Into<RegistryRef<'a>>,
+    ) -> Option<SnapshotDiff> {
+        let a = self.checkpoints.get(a)?;
+        let b = self.checkpoints.get(b)?;
+
+        Some(a.diff(b, registry))
+    }
+}
+
+impl DeltaCheckpoints {
+    /// Returns the differences from the reconstructed checkpoint at index `a` to the one at index `b`.
+    ///
+    /// Returns `None` if either index is out of bounds.
+    pub fn diff<'a>(
+        &self,
+        a: usize,
+        b: usize,
+        registry: impl Into<RegistryRef<'a>>,
+    ) -> Option<SnapshotDiff> {
+        let a = self.snapshot_at(a)?;
+        let b = self.snapshot_at(b)?;
+
+        Some(a.diff(&b, registry))
+    }
+}
@@ -0,0 +1,138 @@
+use bevy::{
+    prelude::*,
+    utils::HashMap,
+};
+
+use crate::{
+    RollbackRegistry,
+    Rollbacks,
+};
+
+static EMPTY_REGISTRY: RollbackRegistry = RollbackRegistry::new();
+
+/// The rollback state of a single save domain.
+#[derive(Default)]
+pub struct SaveDomain {
+    /// The checkpoints of the domain.
+    pub rollbacks: Rollbacks,
+    /// The types allowed to roll back in the domain.
+    pub registry: RollbackRegistry,
+}
+
+/// Independent save domains, allowing several games to share an [`App`] without sharing checkpoints.
+///
+/// A [`Pipeline`](crate::Pipeline) selects its domain with [`Pipeline::domain`](crate::Pipeline::domain).
+/// Pipelines without a domain use the global [`Rollbacks`] and [`RollbackRegistry`] resources.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// #[derive(Resource, Reflect, Default)]
+/// #[reflect(Resource)]
+/// struct Score(u32);
+///
+/// struct Minigame;
+///
+/// impl Pipeline for Minigame {
+///     type Backend = DefaultBackend;
+///     type Format = DefaultFormat;
+///
+///     type Key<'a> = &'a str;
+///
+///     fn domain() -> Option<&'static str> {
+///         Some("minigame")
+///     }
+///
+///     fn key(&self) -> Self::Key<'_> {
+///         "minigame"
+///     }
+///
+///     fn capture(builder: SnapshotBuilder) -> Snapshot {
+///         builder.extract_all_resources().build()
+///     }
+/// }
+///
+/// let mut app = App::new();
+///
+/// app.add_plugins((MinimalPlugins, SavePlugins))
+///     .init_pipeline::<Minigame>()
+///     .register_type::<Score>()
+///     .allow_rollback_in::<Score>("minigame")
+///     .init_resource::<Score>();
+///
+/// let world = &mut app.world;
+///
+/// world.checkpoint::<Minigame>();
+///
+/// assert!(world.resource::<Rollbacks>().is_empty());
+/// assert_eq!(world.resource::<SaveDomains>().rollbacks("minigame").map(|r| r.len()), Some(1));
+/// ```
+#[derive(Resource, Default)]
+pub struct SaveDomains {
+    domains: HashMap<&'static str, SaveDomain>,
+}
+
+impl SaveDomains {
+    /// Returns the domain with the given name, if it exists.
+    pub fn get(&self, name: &str) -> Option<&SaveDomain> {
+        self.domains.get(name)
+    }
+
+    /// Returns the domain with the given name, if it exists.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut SaveDomain> {
+        self.domains.get_mut(name)
+    }
+
+    /// Returns the domain with the given name, creating it if needed.
+    pub fn get_or_insert(&mut self, name: &'static str) -> &mut SaveDomain {
+        self.domains.entry(name).or_default()
+    }
+
+    /// Returns the [`Rollbacks`] of the domain with the given name, if it exists.
+    pub fn rollbacks(&self, name: &str) -> Option<&Rollbacks> {
+        self.get(name).map(|d| &d.rollbacks)
+    }
+
+    /// Returns the names of all domains.
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.domains.keys().copied()
+    }
+}
+
+/// Returns the [`RollbackRegistry`] of the given domain, or the global one.
+///
+/// Unknown domains allow all types to roll back.
+pub(crate) fn rollback_registry<'w>(
+    world: &'w World,
+    domain: Option<&str>,
+) -> &'w RollbackRegistry {
+    match domain {
+        Some(name) => world
+            .get_resource::<SaveDomains>()
+            .and_then(|d| d.get(name))
+            .map_or(&EMPTY_REGISTRY, |d| &d.registry),
+        None => world.resource::<RollbackRegistry>(),
+    }
+}
+
+/// Returns the [`Rollbacks`] of the given domain, or the global ones.
+pub(crate) fn rollbacks<'w>(world: &'w World, domain: Option<&str>) -> Option<&'w Rollbacks> {
+    match domain {
+        Some(name) => world.get_resource::<SaveDomains>()?.rollbacks(name),
+        None => world.get_resource::<Rollbacks>(),
+    }
+}
+
+/// Returns the [`Rollbacks`] of the given domain, or the global ones, creating the domain if needed.
+pub(crate) fn rollbacks_mut<'w>(
+    world: &'w mut World,
+    domain: Option<&'static str>,
+) -> Mut<'w, Rollbacks> {
+    match domain {
+        Some(name) => world
+            .get_resource_or_insert_with(SaveDomains::default)
+            .map_unchanged(|d| &mut d.get_or_insert(name).rollbacks),
+        None => world.resource_mut::<Rollbacks>(),
+    }
+}
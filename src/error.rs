@@ -20,6 +20,50 @@ pub enum Error {
     #[error("io error: {0}")]
     IO(std::io::Error),
 
+    /// The save was created by a game version outside of the supported range.
+    #[error("incompatible save: version {save} (supported: {supported})")]
+    IncompatibleSave {
+        /// The version the save was created with.
+        save: String,
+        /// The range of versions that can be loaded.
+        supported: String,
+    },
+
+    /// The save signature is missing or does not match its contents.
+    #[error("invalid save signature")]
+    InvalidSignature,
+
+    /// The save was modified by someone else since it was last read.
+    #[error("save was modified concurrently")]
+    Conflict,
+
+    /// A component references an entity that was not included in the snapshot.
+    ///
+    /// See [`DanglingEntityPolicy::Error`](crate::DanglingEntityPolicy::Error).
+    #[error("`{type_path}` references entity {entity:?} which is not in the snapshot")]
+    DanglingEntity {
+        /// The type path of the component.
+        type_path: String,
+        /// The referenced entity.
+        entity: bevy::ecs::entity::Entity,
+    },
+
+    /// An entity the snapshot should be applied to does not exist in the [`World`].
+    ///
+    /// See [`MissingParentPolicy::ReparentTo`](crate::MissingParentPolicy::ReparentTo).
+    #[error("entity {0:?} does not exist")]
+    NoSuchEntity(bevy::ecs::entity::Entity),
+
+    /// The save was cancelled by a save interceptor.
+    ///
+    /// See [`SaveContext::veto`](crate::SaveContext::veto).
+    #[error("save vetoed: {0}")]
+    Vetoed(String),
+
+    /// The save would exceed the [`SaveQuota`](crate::SaveQuota) of its backend.
+    #[error("save quota exceeded")]
+    QuotaExceeded,
+
     /// Other error.
     #[error("other error: {0}")]
     Other(Box<dyn std::error::Error>),
@@ -29,7 +73,135 @@ pub enum Error {
     Custom(String),
 }
 
+/// A stable, machine-readable identifier for the kind of an [`Error`].
+///
+/// Discriminants never change between releases, so they can be stored or sent over the network.
+/// Use [`message_key`](Self::message_key) to look up a localized message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum ErrorCode {
+    /// See [`Error::Saving`].
+    Saving = 1,
+    /// See [`Error::Loading`], usually a corrupted save.
+    Loading = 2,
+    /// See [`Error::SceneSpawnError`].
+    SceneSpawn = 3,
+    /// See [`Error::IO`].
+    Io = 4,
+    /// An [`Error::IO`] caused by a save that does not exist.
+    NotFound = 5,
+    /// See [`Error::IncompatibleSave`].
+    IncompatibleSave = 6,
+    /// See [`Error::InvalidSignature`].
+    InvalidSignature = 7,
+    /// See [`Error::Conflict`].
+    Conflict = 8,
+    /// See [`Error::Other`].
+    Other = 9,
+    /// See [`Error::Custom`].
+    Custom = 10,
+    /// See [`Error::DanglingEntity`].
+    DanglingEntity = 11,
+    /// See [`Error::Vetoed`].
+    Vetoed = 12,
+    /// See [`Error::QuotaExceeded`].
+    QuotaExceeded = 13,
+    /// See [`Error::NoSuchEntity`].
+    NoSuchEntity = 14,
+}
+
+impl ErrorCode {
+    /// Returns a key identifying a user-presentable message for the error, such as `bevy_save.error.not_found`.
+    pub fn message_key(self) -> &'static str {
+        match self {
+            Self::Saving => "bevy_save.error.saving",
+            Self::Loading => "bevy_save.error.loading",
+            Self::SceneSpawn => "bevy_save.error.scene_spawn",
+            Self::Io => "bevy_save.error.io",
+            Self::NotFound => "bevy_save.error.not_found",
+            Self::IncompatibleSave => "bevy_save.error.incompatible_save",
+            Self::InvalidSignature => "bevy_save.error.invalid_signature",
+            Self::Conflict => "bevy_save.error.conflict",
+            Self::Other => "bevy_save.error.other",
+            Self::Custom => "bevy_save.error.custom",
+            Self::DanglingEntity => "bevy_save.error.dangling_entity",
+            Self::Vetoed => "bevy_save.error.vetoed",
+            Self::QuotaExceeded => "bevy_save.error.quota_exceeded",
+            Self::NoSuchEntity => "bevy_save.error.no_such_entity",
+        }
+    }
+}
+
 impl Error {
+    /// Returns the [`ErrorCode`] of the error.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_save::{Error, ErrorCode};
+    /// let err = Error::from(std::io::Error::from(std::io::ErrorKind::NotFound));
+    ///
+    /// assert_eq!(err.code(), ErrorCode::NotFound);
+    /// assert_eq!(err.code() as u16, 5);
+    /// assert_eq!(err.message_key(), "bevy_save.error.not_found");
+    /// ```
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            Self::Saving => ErrorCode::Saving,
+            Self::Loading => ErrorCode::Loading,
+            Self::SceneSpawnError(_) => ErrorCode::SceneSpawn,
+            Self::IO(err) if err.kind() == std::io::ErrorKind::NotFound => ErrorCode::NotFound,
+            Self::IO(_) => ErrorCode::Io,
+            Self::IncompatibleSave { .. } => ErrorCode::IncompatibleSave,
+            Self::InvalidSignature => ErrorCode::InvalidSignature,
+            Self::Conflict => ErrorCode::Conflict,
+            Self::Other(_) => ErrorCode::Other,
+            Self::Custom(_) => ErrorCode::Custom,
+            Self::DanglingEntity { .. } => ErrorCode::DanglingEntity,
+            Self::Vetoed(_) => ErrorCode::Vetoed,
+            Self::QuotaExceeded => ErrorCode::QuotaExceeded,
+            Self::NoSuchEntity(_) => ErrorCode::NoSuchEntity,
+        }
+    }
+
+    /// Returns a key identifying a user-presentable message for the error.
+    ///
+    /// See [`ErrorCode::message_key`].
+    pub fn message_key(&self) -> &'static str {
+        self.code().message_key()
+    }
+
+    /// Returns `true` if the operation may succeed when attempted again, such as after a timeout or dropped
+    /// connection.
+    ///
+    /// Used by [`RetryPolicy`](crate::RetryPolicy) by default. Backend errors wrapped in [`Error::Other`] are assumed
+    /// to be transient, while missing saves, invalid data and errors of the snapshot itself are not.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::io::ErrorKind;
+    /// # use bevy_save::Error;
+    /// assert!(Error::from(std::io::Error::from(ErrorKind::TimedOut)).is_transient());
+    /// assert!(!Error::from(std::io::Error::from(ErrorKind::NotFound)).is_transient());
+    /// assert!(!Error::Conflict.is_transient());
+    /// ```
+    pub fn is_transient(&self) -> bool {
+        use std::io::ErrorKind;
+
+        match self {
+            Self::IO(err) => !matches!(
+                err.kind(),
+                ErrorKind::NotFound
+                    | ErrorKind::PermissionDenied
+                    | ErrorKind::AlreadyExists
+                    | ErrorKind::InvalidInput
+                    | ErrorKind::InvalidData
+                    | ErrorKind::Unsupported
+            ),
+            Self::Other(_) => true,
+            _ => false,
+        }
+    }
+
     /// Saving or serialization error.
     pub fn saving(err: impl std::error::Error) -> Self {
         // TODO
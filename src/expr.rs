@@ -0,0 +1,218 @@
+use std::{
+    any::TypeId,
+    iter::Peekable,
+    str::CharIndices,
+};
+
+use bevy::{
+    prelude::*,
+    reflect::TypeRegistry,
+};
+
+use crate::Error;
+
+/// An entity predicate parsed from a filter expression, allowing the saved entities to be configured from data.
+///
+/// # Syntax
+/// - `With(Type)` matches entities with the component
+/// - `Without(Type)` matches entities without the component
+/// - `!a` matches entities not matching `a`
+/// - `a & b` matches entities matching both `a` and `b`
+/// - `a | b` matches entities matching either `a` or `b`
+/// - `(a)` groups expressions
+///
+/// `&` binds tighter than `|`. Types are resolved through the [`TypeRegistry`] by short or full type path.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// #[derive(Component, Reflect)]
+/// struct Player;
+///
+/// #[derive(Component, Reflect)]
+/// struct Enemy;
+///
+/// #[derive(Component, Reflect)]
+/// struct Boss;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # app.register_type::<Player>();
+/// # app.register_type::<Enemy>();
+/// # app.register_type::<Boss>();
+/// # let world = &mut app.world;
+/// let filter = EntityFilter::parse(
+///     "With(Player) | (With(Enemy) & Without(Boss))",
+///     &world.resource::<AppTypeRegistry>().read(),
+/// )
+/// .unwrap();
+///
+/// let snapshot = Snapshot::builder(world)
+///     .extract_entities_matching(|e| filter.matches(e))
+///     .build();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntityFilter {
+    /// Matches entities with the component.
+    With(TypeId),
+    /// Matches entities without the component.
+    Without(TypeId),
+    /// Matches entities not matching the inner filter.
+    Not(Box<EntityFilter>),
+    /// Matches entities matching both filters.
+    And(Box<EntityFilter>, Box<EntityFilter>),
+    /// Matches entities matching either filter.
+    Or(Box<EntityFilter>, Box<EntityFilter>),
+}
+
+impl EntityFilter {
+    /// Parse a filter expression, resolving component types with the [`TypeRegistry`].
+    ///
+    /// # Errors
+    /// If the expression is malformed or names a type that is not registered.
+    pub fn parse(expr: &str, registry: &TypeRegistry) -> Result<Self, Error> {
+        let mut parser = Parser {
+            expr,
+            chars: expr.char_indices().peekable(),
+            registry,
+        };
+
+        let filter = parser.or()?;
+
+        parser.skip_whitespace();
+
+        if let Some((i, _)) = parser.chars.peek() {
+            return Err(Error::custom(format!(
+                "unexpected input at {i} in filter expression: `{expr}`"
+            )));
+        }
+
+        Ok(filter)
+    }
+
+    /// Returns true if the entity matches the filter.
+    pub fn matches(&self, entity: &EntityRef) -> bool {
+        match self {
+            Self::With(id) => entity.contains_type_id(*id),
+            Self::Without(id) => !entity.contains_type_id(*id),
+            Self::Not(inner) => !inner.matches(entity),
+            Self::And(a, b) => a.matches(entity) && b.matches(entity),
+            Self::Or(a, b) => a.matches(entity) || b.matches(entity),
+        }
+    }
+}
+
+struct Parser<'a> {
+    expr: &'a str,
+    chars: Peekable<CharIndices<'a>>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, message: &str) -> Error {
+        Error::custom(format!("{message} in filter expression: `{}`", self.expr))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        self.skip_whitespace();
+        self.chars.next_if(|(_, c)| *c == expected).is_some()
+    }
+
+    fn or(&mut self) -> Result<EntityFilter, Error> {
+        let mut filter = self.and()?;
+
+        while self.eat('|') {
+            filter = EntityFilter::Or(Box::new(filter), Box::new(self.and()?));
+        }
+
+        Ok(filter)
+    }
+
+    fn and(&mut self) -> Result<EntityFilter, Error> {
+        let mut filter = self.unary()?;
+
+        while self.eat('&') {
+            filter = EntityFilter::And(Box::new(filter), Box::new(self.unary()?));
+        }
+
+        Ok(filter)
+    }
+
+    fn unary(&mut self) -> Result<EntityFilter, Error> {
+        if self.eat('!') {
+            return Ok(EntityFilter::Not(Box::new(self.unary()?)));
+        }
+
+        if self.eat('(') {
+            let filter = self.or()?;
+
+            if !self.eat(')') {
+                return Err(self.error("expected `)`"));
+            }
+
+            return Ok(filter);
+        }
+
+        let ident = self.ident();
+
+        if !self.eat('(') {
+            return Err(self.error("expected `(`"));
+        }
+
+        let type_id = self.type_path()?;
+
+        match ident {
+            "With" => Ok(EntityFilter::With(type_id)),
+            "Without" => Ok(EntityFilter::Without(type_id)),
+            _ => Err(self.error(&format!("unknown filter `{ident}`"))),
+        }
+    }
+
+    fn ident(&mut self) -> &'a str {
+        self.skip_whitespace();
+
+        let start = self.chars.peek().map_or(self.expr.len(), |(i, _)| *i);
+        let mut end = start;
+
+        while let Some((i, c)) = self
+            .chars
+            .next_if(|(_, c)| c.is_alphanumeric() || *c == '_')
+        {
+            end = i + c.len_utf8();
+        }
+
+        &self.expr[start..end]
+    }
+
+    /// Reads a type path up to the closing `)`, allowing nested generics such as `Foo<(A, B)>`.
+    fn type_path(&mut self) -> Result<TypeId, Error> {
+        self.skip_whitespace();
+
+        let start = self.chars.peek().map_or(self.expr.len(), |(i, _)| *i);
+        let mut depth = 0usize;
+
+        let end = loop {
+            match self.chars.next() {
+                Some((i, ')')) if depth == 0 => break i,
+                Some((_, '(' | '<')) => depth += 1,
+                Some((_, ')' | '>')) => depth = depth.saturating_sub(1),
+                Some(_) => {}
+                None => return Err(self.error("expected `)`")),
+            }
+        };
+
+        let path = self.expr[start..end].trim();
+
+        self.registry
+            .get_with_short_type_path(path)
+            .or_else(|| self.registry.get_with_type_path(path))
+            .map(|r| r.type_id())
+            .ok_or_else(|| self.error(&format!("unregistered type `{path}`")))
+    }
+}
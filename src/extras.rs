@@ -0,0 +1,105 @@
+use bevy::{
+    prelude::*,
+    utils::HashMap,
+};
+use serde::{
+    de::DeserializeOwned,
+    Serialize,
+};
+
+use crate::{
+    Error,
+    Snapshot,
+};
+
+/// Unstructured values stored in a save by key, such as play time or a death counter.
+///
+/// Values are stored as RON text, so any serde type can be stored without registering it. Extras are captured with
+/// [`SnapshotBuilder::extract_extra`](crate::SnapshotBuilder::extract_extra) and read back with [`Snapshot::extra`].
+/// When applied, they are inserted into the world as a resource, and captured again with the other resources.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # let world = &mut app.world;
+/// let snapshot = Snapshot::builder(world)
+///     .extract_extra("play_time", 3600.5_f64)
+///     .extract_extra("deaths", 12_u32)
+///     .build();
+///
+/// assert_eq!(snapshot.extra::<f64>("play_time"), Some(3600.5));
+/// assert_eq!(snapshot.extra::<u32>("deaths"), Some(12));
+///
+/// snapshot.apply(world).unwrap();
+///
+/// assert_eq!(world.resource::<Extras>().get::<u32>("deaths"), Some(12));
+/// ```
+#[derive(Resource, Reflect, Default, Debug, Clone, PartialEq, Eq)]
+#[reflect(Resource)]
+pub struct Extras {
+    /// The serialized value of each key.
+    pub values: HashMap<String, String>,
+}
+
+impl Extras {
+    /// Store the value with the given key, replacing any existing value.
+    ///
+    /// # Errors
+    /// If the value could not be serialized.
+    pub fn insert<T: Serialize>(&mut self, key: impl Into<String>, value: &T) -> Result<(), Error> {
+        let value = ron::to_string(value).map_err(Error::saving)?;
+        self.values.insert(key.into(), value);
+        Ok(())
+    }
+
+    /// Returns the value with the given key, or `None` if it is missing or is not a `T`.
+    pub fn get<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        ron::from_str(self.values.get(key)?).ok()
+    }
+
+    /// Remove the value with the given key, returning true if it was present.
+    pub fn remove(&mut self, key: &str) -> bool {
+        self.values.remove(key).is_some()
+    }
+
+    /// Returns `true` if a value with the given key is stored.
+    pub fn contains(&self, key: &str) -> bool {
+        self.values.contains_key(key)
+    }
+
+    /// Returns the stored keys, in arbitrary order.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.values.keys().map(String::as_str)
+    }
+
+    /// Returns the number of stored values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if no values are stored.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Store all values of `other`, replacing values with the same keys.
+    pub fn merge(&mut self, other: Self) {
+        self.values.extend(other.values);
+    }
+}
+
+impl Snapshot {
+    /// Returns the [`Extras`] stored in the snapshot, if any.
+    pub fn extras(&self) -> Option<Extras> {
+        self.get_resource::<Extras>()
+    }
+
+    /// Returns the extra value with the given key, or `None` if it is missing or is not a `T`.
+    pub fn extra<T: DeserializeOwned>(&self, key: &str) -> Option<T> {
+        self.extras()?.get(key)
+    }
+}
@@ -1,6 +1,12 @@
-use std::io::{
-    Read,
-    Write,
+use std::{
+    io::{
+        Read,
+        Write,
+    },
+    sync::{
+        Mutex,
+        PoisonError,
+    },
 };
 
 use serde::{
@@ -8,12 +14,21 @@ use serde::{
     Serialize,
 };
 
-use crate::Error;
+use crate::{
+    Error,
+    FloatPrecision,
+    SaveHeader,
+};
 
 // Trait |-------------------------------------------------------------------------------------------------------------
 
 /// Handles serialization and deserialization of save data.
 pub trait Format {
+    /// The name of the format, stored in the [`SaveHeader`](crate::SaveHeader) of saves.
+    ///
+    /// Must be stable across builds and unique among the formats a save may be read with.
+    fn name() -> &'static str;
+
     /// The file extension used by the format.
     ///
     /// Defaults to `.sav`.
@@ -21,6 +36,43 @@ pub trait Format {
         ".sav"
     }
 
+    /// A suffix appended to the key by backends that do not use the [`Format::extension`], such as
+    /// `WebStorage`, so values written with different formats never share a key.
+    ///
+    /// Defaults to an empty suffix.
+    fn key_suffix() -> &'static str {
+        ""
+    }
+
+    /// The MIME type of data written with the format, for backends storing saves over HTTP or in web storage.
+    ///
+    /// Defaults to `application/octet-stream`.
+    fn mime_type() -> &'static str {
+        "application/octet-stream"
+    }
+
+    /// The [`FloatPrecision`] applied to snapshots before they are serialized with the format.
+    ///
+    /// Defaults to [`FloatPrecision::Exact`].
+    fn float_precision() -> FloatPrecision {
+        FloatPrecision::Exact
+    }
+
+    /// Whether the format is self-describing, allowing parts of a save to be buffered and deserialized in parallel.
+    ///
+    /// Defaults to `false`.
+    fn self_describing() -> bool {
+        false
+    }
+
+    /// Whether the start of a save without a [`SaveHeader`] looks like it was written with the format.
+    ///
+    /// Used by [`DetectFormat`](crate::DetectFormat). Defaults to `false`.
+    fn matches(prefix: &[u8]) -> bool {
+        let _ = prefix;
+        false
+    }
+
     /// Serializes a value with the format.
     ///
     /// # Errors
@@ -35,6 +87,57 @@ pub trait Format {
         reader: R,
         seed: S,
     ) -> Result<T, Error>;
+
+    /// Deserializes a value from a save already in memory, such as a memory-mapped file.
+    ///
+    /// Defaults to [`Format::deserialize`] reading from the slice. Formats that need the whole save in memory should
+    /// override this to avoid copying it.
+    ///
+    /// # Errors
+    /// If deserialization fails.
+    fn deserialize_slice<S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        bytes: &[u8],
+        seed: S,
+    ) -> Result<T, Error> {
+        Self::deserialize(bytes, seed)
+    }
+
+    /// Deserializes a value from a save with the given [`SaveHeader`], if it had one.
+    ///
+    /// Called by [`Stamped`](crate::Stamped) after reading the header.
+    /// By default the header is checked with [`SaveHeader::check`].
+    ///
+    /// # Errors
+    /// If the header does not match the format or deserialization fails.
+    fn deserialize_with_header<R: Read, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        header: Option<&SaveHeader>,
+        reader: R,
+        seed: S,
+    ) -> Result<T, Error> {
+        if let Some(header) = header {
+            header.check::<Self>()?;
+        }
+
+        Self::deserialize(reader, seed)
+    }
+}
+
+/// Returns the name of a format wrapping another, e.g. `brotli(json)`.
+///
+/// Each combination is allocated once, so the name can be returned from [`Format::name`].
+pub(crate) fn wrapped_name(outer: &'static str, inner: &'static str) -> &'static str {
+    static NAMES: Mutex<Vec<(&str, &str, &str)>> = Mutex::new(Vec::new());
+
+    let mut names = NAMES.lock().unwrap_or_else(PoisonError::into_inner);
+
+    if let Some((.., name)) = names.iter().find(|(o, i, _)| *o == outer && *i == inner) {
+        return name;
+    }
+
+    let name: &'static str = Box::leak(format!("{outer}({inner})").into_boxed_str());
+    names.push((outer, inner, name));
+
+    name
 }
 
 // Implementations |---------------------------------------------------------------------------------------------------
@@ -43,10 +146,27 @@ pub trait Format {
 pub struct RMPFormat;
 
 impl Format for RMPFormat {
+    fn name() -> &'static str {
+        "msgpack"
+    }
+
     fn extension() -> &'static str {
         ".mp"
     }
 
+    fn mime_type() -> &'static str {
+        "application/msgpack"
+    }
+
+    fn self_describing() -> bool {
+        true
+    }
+
+    fn matches(prefix: &[u8]) -> bool {
+        // Snapshots are encoded as a map or array
+        matches!(prefix.first(), Some(0x80..=0x9f | 0xdc..=0xdf))
+    }
+
     fn serialize<W: Write, T: Serialize>(writer: W, value: &T) -> Result<(), Error> {
         let mut ser = rmp_serde::Serializer::new(writer);
         value.serialize(&mut ser).map_err(Error::saving)
@@ -65,10 +185,29 @@ impl Format for RMPFormat {
 pub struct JSONFormat;
 
 impl Format for JSONFormat {
+    fn name() -> &'static str {
+        "json"
+    }
+
     fn extension() -> &'static str {
         ".json"
     }
 
+    fn mime_type() -> &'static str {
+        "application/json"
+    }
+
+    fn self_describing() -> bool {
+        true
+    }
+
+    fn matches(prefix: &[u8]) -> bool {
+        matches!(
+            prefix.iter().find(|b| !b.is_ascii_whitespace()),
+            Some(b'{' | b'[')
+        )
+    }
+
     fn serialize<W: Write, T: Serialize>(writer: W, value: &T) -> Result<(), Error> {
         let mut ser = serde_json::Serializer::pretty(writer);
         value.serialize(&mut ser).map_err(Error::saving)
@@ -83,6 +222,132 @@ impl Format for JSONFormat {
     }
 }
 
+/// An implementation of [`Format`] that uses [`ron`].
+///
+/// `ron` does not support deserializing from a reader, so saves are read into memory first.
+pub struct RONFormat;
+
+impl Format for RONFormat {
+    fn name() -> &'static str {
+        "ron"
+    }
+
+    fn extension() -> &'static str {
+        ".ron"
+    }
+
+    fn mime_type() -> &'static str {
+        "application/ron"
+    }
+
+    fn serialize<W: Write, T: Serialize>(writer: W, value: &T) -> Result<(), Error> {
+        let mut ser = ron::Serializer::new(writer, Some(ron::ser::PrettyConfig::default()))
+            .map_err(Error::saving)?;
+        value.serialize(&mut ser).map_err(Error::saving)
+    }
+
+    fn deserialize<R: Read, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        mut reader: R,
+        seed: S,
+    ) -> Result<T, Error> {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data)?;
+
+        Self::deserialize_slice(&data, seed)
+    }
+
+    fn deserialize_slice<S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        bytes: &[u8],
+        seed: S,
+    ) -> Result<T, Error> {
+        let mut de = ron::Deserializer::from_bytes(bytes).map_err(Error::loading)?;
+        let value = seed.deserialize(&mut de).map_err(Error::loading)?;
+        de.end().map_err(Error::loading)?;
+
+        Ok(value)
+    }
+}
+
+/// A [`Format`] reading plain Bevy [`DynamicScene`](bevy::scene::DynamicScene) RON files, such as `.scn.ron` assets.
+///
+/// Scenes have the same structure as snapshots, so they are read as a [`Snapshot`](crate::Snapshot) directly. They are
+/// [matched](Format::matches) by their structure, allowing [`DetectFormat`](crate::DetectFormat) to load existing scene
+/// saves while migrating from scene-based saving. Snapshots are written as [`RONFormat`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// #[derive(Resource, Reflect, Default)]
+/// #[reflect(Resource)]
+/// struct Score(u32);
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # app.register_type::<Score>();
+/// # let world = &mut app.world;
+/// let scene = r#"(
+///   resources: {
+///     "rust_out::Score": (42),
+///   },
+///   entities: {},
+/// )"#;
+///
+/// type Migration = DetectFormat<DefaultFormat, (SceneFormat,)>;
+///
+/// let registry = world.resource::<AppTypeRegistry>().clone();
+/// let snapshot =
+///     Migration::deserialize(scene.as_bytes(), SnapshotDeserializer::new(&registry.read())).unwrap();
+///
+/// snapshot.apply(world).unwrap();
+///
+/// assert_eq!(world.resource::<Score>().0, 42);
+/// ```
+pub struct SceneFormat;
+
+impl Format for SceneFormat {
+    fn name() -> &'static str {
+        "bevy_scene"
+    }
+
+    fn extension() -> &'static str {
+        ".scn.ron"
+    }
+
+    fn mime_type() -> &'static str {
+        "application/ron"
+    }
+
+    fn matches(prefix: &[u8]) -> bool {
+        let compact = prefix
+            .iter()
+            .filter(|b| !b.is_ascii_whitespace())
+            .copied()
+            .collect::<Vec<_>>();
+
+        compact.starts_with(b"(resources:") || compact.starts_with(b"(entities:")
+    }
+
+    fn serialize<W: Write, T: Serialize>(writer: W, value: &T) -> Result<(), Error> {
+        RONFormat::serialize(writer, value)
+    }
+
+    fn deserialize<R: Read, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        reader: R,
+        seed: S,
+    ) -> Result<T, Error> {
+        RONFormat::deserialize(reader, seed)
+    }
+
+    fn deserialize_slice<S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        bytes: &[u8],
+        seed: S,
+    ) -> Result<T, Error> {
+        RONFormat::deserialize_slice(bytes, seed)
+    }
+}
+
 // Defaults |----------------------------------------------------------------------------------------------------------
 
 /// The [`Format`] the default [`Pipeline`](crate::Pipeline) will use.
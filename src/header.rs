@@ -0,0 +1,443 @@
+use std::{
+    io::{
+        Cursor,
+        Read,
+        Write,
+    },
+    marker::PhantomData,
+};
+
+use serde::{
+    de::DeserializeSeed,
+    Serialize,
+};
+
+use crate::{
+    Error,
+    FloatPrecision,
+    Format,
+};
+
+/// The version of the snapshot layout written by this version of the crate.
+///
+/// Incremented whenever the serialized structure of a [`Snapshot`](crate::Snapshot) changes.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+const HEADER_MAGIC: &[u8; 5] = b"BSAV ";
+const HEADER_MAX_LEN: usize = 256;
+
+/// A small self-describing header written at the start of every save by [`Stamped`].
+///
+/// The header is a single line of text, `BSAV <format> <snapshot version> <crate version>`, so it can be read
+/// regardless of the [`Format`] of the save.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveHeader {
+    /// The [`Format::name`] of the format the save was written with.
+    pub format: String,
+    /// The [`SNAPSHOT_VERSION`] the save was written with.
+    pub snapshot_version: u32,
+    /// The version of `bevy_save` the save was written with.
+    pub crate_version: String,
+}
+
+impl SaveHeader {
+    /// Create a header for a save written with the given [`Format`] by this version of the crate.
+    pub fn current<F: Format>() -> Self {
+        Self {
+            format: F::name().to_owned(),
+            snapshot_version: SNAPSHOT_VERSION,
+            crate_version: env!("CARGO_PKG_VERSION").to_owned(),
+        }
+    }
+
+    /// Write the header.
+    ///
+    /// # Errors
+    /// - [`Error::IO`] if writing fails
+    pub fn write<W: Write>(&self, mut writer: W) -> Result<(), Error> {
+        writer.write_all(HEADER_MAGIC)?;
+        writeln!(
+            writer,
+            "{} {} {}",
+            self.format, self.snapshot_version, self.crate_version
+        )?;
+        Ok(())
+    }
+
+    /// Read the header from the start of a save, if it has one.
+    ///
+    /// Returns the header and a reader positioned at the start of the save contents.
+    /// Saves written without a header are returned unchanged.
+    ///
+    /// # Errors
+    /// - [`Error::IO`] if reading fails
+    /// - [`Error::Custom`] if the header is invalid
+    pub fn read<R: Read>(mut reader: R) -> Result<(Option<Self>, impl Read), Error> {
+        let magic = peek(&mut reader, HEADER_MAGIC.len())?;
+
+        if magic != HEADER_MAGIC {
+            return Ok((None, Cursor::new(magic).chain(reader)));
+        }
+
+        let mut line = Vec::new();
+        let mut byte = [0];
+
+        while reader.read(&mut byte)? == 1 && byte[0] != b'\n' {
+            if line.len() >= HEADER_MAX_LEN {
+                return Err(Error::custom("invalid save header"));
+            }
+
+            line.push(byte[0]);
+        }
+
+        let line = String::from_utf8(line).map_err(|_| Error::custom("invalid save header"))?;
+        // Format names may contain spaces, so split from the end
+        let mut parts = line.rsplitn(3, ' ');
+
+        let (Some(crate_version), Some(snapshot_version), Some(format)) =
+            (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(Error::custom("invalid save header"));
+        };
+
+        let header = Self {
+            format: format.to_owned(),
+            snapshot_version: snapshot_version
+                .parse()
+                .map_err(|_| Error::custom("invalid save header"))?,
+            crate_version: crate_version.to_owned(),
+        };
+
+        Ok((Some(header), Cursor::new(Vec::new()).chain(reader)))
+    }
+
+    /// Check that a save with this header can be loaded with the [`Format`] `F`.
+    ///
+    /// # Errors
+    /// - [`Error::Custom`] if the save was written with another format or a newer snapshot layout
+    pub fn check<F: Format + ?Sized>(&self) -> Result<(), Error> {
+        if self.format != F::name() {
+            return Err(Error::custom(format_args!(
+                "save was written with format `{}`, expected `{}`",
+                self.format,
+                F::name()
+            )));
+        }
+
+        self.check_version()
+    }
+
+    /// Check that a save with this header was written with a supported snapshot layout.
+    ///
+    /// # Errors
+    /// - [`Error::Custom`] if the save was written with a newer snapshot layout
+    pub fn check_version(&self) -> Result<(), Error> {
+        if self.snapshot_version > SNAPSHOT_VERSION {
+            return Err(Error::custom(format_args!(
+                "save uses snapshot version {} (bevy_save {}), newer than the supported version {SNAPSHOT_VERSION}",
+                self.snapshot_version, self.crate_version
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+fn peek<R: Read>(reader: R, len: usize) -> Result<Vec<u8>, Error> {
+    let mut prefix = Vec::with_capacity(len);
+    reader.take(len as u64).read_to_end(&mut prefix)?;
+    Ok(prefix)
+}
+
+/// Format middleware writing a [`SaveHeader`] before the contents of the save.
+///
+/// Saves and loads performed with a [`Pipeline`](crate::Pipeline) are always stamped, so the format and version of
+/// a save file can be determined without external knowledge. Saves without a header are still loaded.
+///
+/// # Example
+/// ```
+/// # use std::marker::PhantomData;
+/// # use bevy_save::prelude::*;
+/// let mut data = Vec::new();
+/// Stamped::<JSONFormat>::serialize(&mut data, &42).unwrap();
+///
+/// let (header, _) = SaveHeader::read(&*data).unwrap();
+/// assert_eq!(header.unwrap().format, "json");
+///
+/// let value: u32 = Stamped::<JSONFormat>::deserialize(&*data, PhantomData).unwrap();
+/// assert_eq!(value, 42);
+/// ```
+pub struct Stamped<F>(PhantomData<F>);
+
+impl<F> Default for Stamped<F> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<F: Format> Format for Stamped<F> {
+    fn name() -> &'static str {
+        F::name()
+    }
+
+    fn extension() -> &'static str {
+        F::extension()
+    }
+
+    fn key_suffix() -> &'static str {
+        F::key_suffix()
+    }
+
+    fn mime_type() -> &'static str {
+        F::mime_type()
+    }
+
+    fn float_precision() -> FloatPrecision {
+        F::float_precision()
+    }
+
+    fn self_describing() -> bool {
+        F::self_describing()
+    }
+
+    fn matches(prefix: &[u8]) -> bool {
+        prefix.starts_with(HEADER_MAGIC) || F::matches(prefix)
+    }
+
+    fn serialize<W: Write, T: Serialize>(mut writer: W, value: &T) -> Result<(), Error> {
+        SaveHeader::current::<F>().write(&mut writer)?;
+        F::serialize(writer, value)
+    }
+
+    fn deserialize<R: Read, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        reader: R,
+        seed: S,
+    ) -> Result<T, Error> {
+        let (header, reader) = SaveHeader::read(reader)?;
+        F::deserialize_with_header(header.as_ref(), reader, seed)
+    }
+
+    fn deserialize_slice<S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        bytes: &[u8],
+        seed: S,
+    ) -> Result<T, Error> {
+        let mut contents = bytes;
+        let (header, _) = SaveHeader::read(&mut contents)?;
+
+        match header {
+            Some(header) => F::deserialize_with_header(Some(&header), contents, seed),
+            None => F::deserialize_slice(bytes, seed),
+        }
+    }
+}
+
+/// A list of [`Format`] types tried by [`DetectFormat`], implemented for tuples of up to 4 formats.
+pub trait FormatList {
+    /// The number of formats in the list.
+    fn len() -> usize;
+
+    /// Whether every format in the list is [self-describing](Format::self_describing).
+    fn self_describing() -> bool;
+
+    /// Returns the index of the format with the given [`Format::name`].
+    fn find(name: &str) -> Option<usize>;
+
+    /// Returns the index of the first format [matching](Format::matches) the start of a save.
+    fn detect(prefix: &[u8]) -> Option<usize>;
+
+    /// Deserializes a value with the format at `index`.
+    ///
+    /// # Errors
+    /// If deserialization fails or `index` is out of range.
+    fn deserialize_at<R: Read, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        index: usize,
+        reader: R,
+        seed: S,
+    ) -> Result<T, Error>;
+}
+
+impl FormatList for () {
+    fn len() -> usize {
+        0
+    }
+
+    fn self_describing() -> bool {
+        true
+    }
+
+    fn find(_: &str) -> Option<usize> {
+        None
+    }
+
+    fn detect(_: &[u8]) -> Option<usize> {
+        None
+    }
+
+    fn deserialize_at<R: Read, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        _: usize,
+        _: R,
+        _: S,
+    ) -> Result<T, Error> {
+        Err(Error::custom("no formats to deserialize with"))
+    }
+}
+
+macro_rules! impl_format_list {
+    ($len:literal: $($index:literal $format:ident),*) => {
+        impl<$($format: Format),*> FormatList for ($($format,)*) {
+            fn len() -> usize {
+                $len
+            }
+
+            fn self_describing() -> bool {
+                $($format::self_describing())&&*
+            }
+
+            fn find(name: &str) -> Option<usize> {
+                $(
+                    if $format::name() == name {
+                        return Some($index);
+                    }
+                )*
+                None
+            }
+
+            fn detect(prefix: &[u8]) -> Option<usize> {
+                $(
+                    if $format::matches(prefix) {
+                        return Some($index);
+                    }
+                )*
+                None
+            }
+
+            fn deserialize_at<R: Read, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+                index: usize,
+                reader: R,
+                seed: S,
+            ) -> Result<T, Error> {
+                match index {
+                    $($index => $format::deserialize(reader, seed),)*
+                    _ => Err(Error::custom("no formats to deserialize with")),
+                }
+            }
+        }
+    };
+}
+
+impl_format_list!(1: 0 A);
+impl_format_list!(2: 0 A, 1 B);
+impl_format_list!(3: 0 A, 1 B, 2 C);
+impl_format_list!(4: 0 A, 1 B, 2 C, 3 D);
+
+const DETECT_PREFIX_LEN: usize = 64;
+
+/// A [`Format`] writing with `F` while also loading saves written with any of the `Legacy` formats.
+///
+/// The format of a save is determined by its [`SaveHeader`] if it has one. Otherwise, the first format
+/// [matching](Format::matches) the start of the save is used, falling back to the last legacy format.
+///
+/// Saves are always written to and read from the [extension](Format::extension) of `F`, so legacy saves using another
+/// extension must be renamed first.
+///
+/// This is useful when transitioning a game to a new format, continuing to read saves written with the old one.
+///
+/// # Example
+/// ```
+/// # use std::marker::PhantomData;
+/// # use bevy_save::prelude::*;
+/// type Transition = DetectFormat<RMPFormat, (RONFormat,)>;
+///
+/// let mut legacy = Vec::new();
+/// RONFormat::serialize(&mut legacy, &(1, 2)).unwrap();
+///
+/// let value: (u32, u32) = Transition::deserialize(&*legacy, PhantomData).unwrap();
+/// assert_eq!(value, (1, 2));
+///
+/// let mut data = Vec::new();
+/// Transition::serialize(&mut data, &(1, 2)).unwrap();
+///
+/// let value: (u32, u32) = RMPFormat::deserialize(&*data, PhantomData).unwrap();
+/// assert_eq!(value, (1, 2));
+/// ```
+pub struct DetectFormat<F, Legacy = ()>(PhantomData<(F, Legacy)>);
+
+impl<F, L> Default for DetectFormat<F, L> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<F: Format, L: FormatList> Format for DetectFormat<F, L> {
+    fn name() -> &'static str {
+        F::name()
+    }
+
+    fn extension() -> &'static str {
+        F::extension()
+    }
+
+    fn mime_type() -> &'static str {
+        F::mime_type()
+    }
+
+    fn float_precision() -> FloatPrecision {
+        F::float_precision()
+    }
+
+    fn self_describing() -> bool {
+        F::self_describing() && L::self_describing()
+    }
+
+    fn matches(prefix: &[u8]) -> bool {
+        F::matches(prefix) || L::detect(prefix).is_some()
+    }
+
+    fn serialize<W: Write, T: Serialize>(writer: W, value: &T) -> Result<(), Error> {
+        F::serialize(writer, value)
+    }
+
+    fn deserialize<R: Read, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        reader: R,
+        seed: S,
+    ) -> Result<T, Error> {
+        let (header, reader) = SaveHeader::read(reader)?;
+        Self::deserialize_with_header(header.as_ref(), reader, seed)
+    }
+
+    fn deserialize_with_header<R: Read, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        header: Option<&SaveHeader>,
+        mut reader: R,
+        seed: S,
+    ) -> Result<T, Error> {
+        if let Some(header) = header {
+            header.check_version()?;
+
+            if header.format == F::name() {
+                return F::deserialize(reader, seed);
+            }
+
+            let Some(index) = L::find(&header.format) else {
+                return Err(Error::custom(format_args!(
+                    "save was written with unknown format `{}`",
+                    header.format
+                )));
+            };
+
+            return L::deserialize_at(index, reader, seed);
+        }
+
+        let prefix = peek(&mut reader, DETECT_PREFIX_LEN)?;
+        let reader = Cursor::new(prefix).chain(reader);
+        let prefix = reader.get_ref().0.get_ref();
+
+        if F::matches(prefix) {
+            return F::deserialize(reader, seed);
+        }
+
+        match L::detect(prefix).or(L::len().checked_sub(1)) {
+            Some(index) => L::deserialize_at(index, reader, seed),
+            None => F::deserialize(reader, seed),
+        }
+    }
+}
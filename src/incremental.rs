@@ -0,0 +1,255 @@
+use std::sync::{
+    atomic::{
+        AtomicBool,
+        AtomicUsize,
+        Ordering,
+    },
+    Arc,
+    Mutex,
+};
+
+use bevy::{
+    prelude::*,
+    scene::DynamicEntity,
+};
+
+use crate::Snapshot;
+
+/// Settings of a time-sliced capture, started with [`WorldIncrementalExt::capture_incremental`].
+#[derive(Clone)]
+pub struct IncrementalCapture {
+    filter: SceneFilter,
+    entities_per_frame: usize,
+    resources: bool,
+}
+
+impl IncrementalCapture {
+    /// Capture up to the given number of entities each frame, followed by all resources.
+    pub fn new(entities_per_frame: usize) -> Self {
+        Self {
+            filter: SceneFilter::default(),
+            entities_per_frame: entities_per_frame.max(1),
+            resources: true,
+        }
+    }
+
+    /// Only capture types allowed by the given [`SceneFilter`].
+    pub fn filter(mut self, filter: SceneFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Whether resources are captured once all entities have been captured. Defaults to `true`.
+    pub fn extract_resources(mut self, resources: bool) -> Self {
+        self.resources = resources;
+        self
+    }
+}
+
+#[derive(Default)]
+struct CaptureState {
+    cancelled: AtomicBool,
+    captured: AtomicUsize,
+    total: AtomicUsize,
+    finished: AtomicBool,
+    result: Mutex<Option<Snapshot>>,
+}
+
+/// Handle to a time-sliced capture, created by [`WorldIncrementalExt::capture_incremental`].
+///
+/// Captures are cancel-safe: cancelling or dropping every handle discards the partial capture on the next frame,
+/// and other captures can be started at any time.
+#[derive(Clone)]
+pub struct CaptureHandle(Arc<CaptureState>);
+
+impl CaptureHandle {
+    /// Cancel the capture, discarding everything captured so far.
+    ///
+    /// Cancelling a finished capture drops its [`Snapshot`] if it has not been taken.
+    ///
+    /// # Panics
+    /// If a thread panicked while taking the snapshot.
+    pub fn cancel(&self) {
+        self.0.cancelled.store(true, Ordering::Release);
+        self.0
+            .result
+            .lock()
+            .expect("CaptureHandle lock poisoned")
+            .take();
+    }
+
+    /// Returns `true` if the capture has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.cancelled.load(Ordering::Acquire)
+    }
+
+    /// Returns `true` if all entities and resources have been captured.
+    pub fn is_finished(&self) -> bool {
+        self.0.finished.load(Ordering::Acquire)
+    }
+
+    /// Returns the fraction of entities captured so far, from `0.0` to `1.0`.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn progress(&self) -> f32 {
+        if self.is_finished() {
+            return 1.0;
+        }
+
+        let total = self.0.total.load(Ordering::Acquire);
+
+        if total == 0 {
+            return 0.0;
+        }
+
+        self.0.captured.load(Ordering::Acquire) as f32 / total as f32
+    }
+
+    /// Takes the captured [`Snapshot`], or returns `None` if the capture is cancelled, unfinished, or already taken.
+    ///
+    /// # Panics
+    /// If a thread panicked while storing the snapshot.
+    pub fn take(&self) -> Option<Snapshot> {
+        if self.is_cancelled() {
+            return None;
+        }
+
+        self.0
+            .result
+            .lock()
+            .expect("CaptureHandle lock poisoned")
+            .take()
+    }
+}
+
+struct CaptureJob {
+    state: Arc<CaptureState>,
+    settings: IncrementalCapture,
+    remaining: Vec<Entity>,
+    entities: Vec<DynamicEntity>,
+}
+
+impl CaptureJob {
+    /// Returns `true` if the capture was cancelled, or nobody can take the result.
+    fn is_abandoned(&self) -> bool {
+        self.state.cancelled.load(Ordering::Acquire) || Arc::strong_count(&self.state) == 1
+    }
+
+    /// Captures the next batch of entities, returning the [`Snapshot`] once everything has been captured.
+    fn advance(&mut self, world: &World) -> Option<Snapshot> {
+        let at = self
+            .remaining
+            .len()
+            .saturating_sub(self.settings.entities_per_frame);
+        let batch = self.remaining.split_off(at);
+
+        // Entities despawned since the capture started are skipped
+        let snapshot = Snapshot::builder(world)
+            .filter(self.settings.filter.clone())
+            .extract_entities(batch.iter().rev().copied())
+            .build();
+
+        self.entities.extend(snapshot.entities);
+        self.state.captured.fetch_add(batch.len(), Ordering::AcqRel);
+
+        if !self.remaining.is_empty() {
+            return None;
+        }
+
+        let builder = Snapshot::builder(world).filter(self.settings.filter.clone());
+        let builder = if self.settings.resources {
+            builder.extract_all_resources()
+        } else {
+            builder
+        };
+
+        let mut snapshot = builder.build();
+        snapshot.entities = std::mem::take(&mut self.entities);
+
+        Some(snapshot)
+    }
+}
+
+/// Time-sliced captures in progress, advanced once per frame in the [`SaveSet`](crate::SaveSet).
+#[derive(Resource, Default)]
+pub(crate) struct IncrementalCaptures {
+    jobs: Vec<CaptureJob>,
+}
+
+impl IncrementalCaptures {
+    pub(crate) fn advance(world: &mut World) {
+        world.resource_scope(|world, mut captures: Mut<IncrementalCaptures>| {
+            // Partial state of cancelled captures is simply dropped
+            captures.jobs.retain_mut(|job| {
+                if job.is_abandoned() {
+                    return false;
+                }
+
+                let Some(snapshot) = job.advance(world) else {
+                    return true;
+                };
+
+                *job.state
+                    .result
+                    .lock()
+                    .expect("CaptureHandle lock poisoned") = Some(snapshot);
+                job.state.finished.store(true, Ordering::Release);
+
+                false
+            });
+        });
+    }
+}
+
+/// Extension trait that adds time-sliced captures to Bevy's [`World`].
+pub trait WorldIncrementalExt {
+    /// Starts capturing the [`World`] over several frames, to avoid a hitch when capturing large worlds.
+    ///
+    /// The entities are those alive when the capture starts, and are captured as they are on the frame they are
+    /// visited. Entities spawned later are not captured, and entities despawned in the meantime are skipped.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins((MinimalPlugins, SavePlugins));
+    /// app.world.spawn_batch((0..100).map(|_| Transform::default()));
+    ///
+    /// let handle = app.world.capture_incremental(IncrementalCapture::new(10));
+    ///
+    /// app.update();
+    ///
+    /// assert_eq!(handle.progress(), 0.1);
+    ///
+    /// // The player quit to the menu
+    /// handle.cancel();
+    ///
+    /// app.update();
+    ///
+    /// assert!(handle.take().is_none());
+    /// ```
+    fn capture_incremental(&mut self, capture: IncrementalCapture) -> CaptureHandle;
+}
+
+impl WorldIncrementalExt for World {
+    fn capture_incremental(&mut self, capture: IncrementalCapture) -> CaptureHandle {
+        let mut remaining = self.iter_entities().map(|e| e.id()).collect::<Vec<_>>();
+
+        // Batches are split off the end
+        remaining.reverse();
+
+        let state = Arc::new(CaptureState::default());
+        state.total.store(remaining.len(), Ordering::Release);
+
+        self.get_resource_or_insert_with(IncrementalCaptures::default)
+            .jobs
+            .push(CaptureJob {
+                state: state.clone(),
+                settings: capture,
+                remaining,
+                entities: Vec::new(),
+            });
+
+        CaptureHandle(state)
+    }
+}
@@ -0,0 +1,180 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{
+        Debug,
+        Formatter,
+    },
+};
+
+use bevy::{
+    prelude::*,
+    utils::get_short_name,
+};
+
+use crate::Snapshot;
+
+fn type_path(value: &dyn Reflect) -> &str {
+    value
+        .get_represented_type_info()
+        .map_or_else(|| value.reflect_type_path(), |i| i.type_path())
+}
+
+/// A group of entities in a [`Snapshot`] with the same set of components.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchetypeStats {
+    /// The type paths of the components of the entities, sorted.
+    pub components: Vec<String>,
+    /// The number of entities with exactly these components.
+    pub count: usize,
+}
+
+impl Debug for Snapshot {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.debug().fmt(f)
+    }
+}
+
+impl Snapshot {
+    /// Groups the entities by the set of their component types, most common first.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Brick;
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins((MinimalPlugins, SavePlugins));
+    /// # app.register_type::<Brick>();
+    /// # let world = &mut app.world;
+    /// world.spawn_batch((0..120).map(|_| Brick));
+    /// world.spawn_empty();
+    ///
+    /// let snapshot = Snapshot::builder(world).extract_all_entities().build();
+    /// let archetypes = snapshot.archetypes();
+    ///
+    /// assert_eq!(archetypes[0].count, 120);
+    /// assert_eq!(archetypes[1].count, 1);
+    /// ```
+    pub fn archetypes(&self) -> Vec<ArchetypeStats> {
+        let mut groups = BTreeMap::<Vec<&str>, usize>::new();
+
+        for entity in &self.entities {
+            let mut components = entity
+                .components
+                .iter()
+                .map(|c| type_path(c.as_ref()))
+                .collect::<Vec<_>>();
+
+            components.sort_unstable();
+
+            *groups.entry(components).or_default() += 1;
+        }
+
+        let mut archetypes = groups
+            .into_iter()
+            .map(|(components, count)| ArchetypeStats {
+                components: components.into_iter().map(str::to_owned).collect(),
+                count,
+            })
+            .collect::<Vec<_>>();
+
+        archetypes.sort_by_key(|a| std::cmp::Reverse(a.count));
+        archetypes
+    }
+
+    /// Returns a configurable [`Debug`] view of the [`Snapshot`].
+    ///
+    /// The [`Debug`] implementation of [`Snapshot`] uses the default settings, listing every entity.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// # let mut app = App::new();
+    /// # app.add_plugins((MinimalPlugins, SavePlugins));
+    /// # let world = &mut app.world;
+    /// # let snapshot = Snapshot::from_world(world);
+    /// // Prints "120 entities: [Brick, Collider, Transform]" instead of 120 entities
+    /// info!("{:#?}", snapshot.debug().group_archetypes(true));
+    /// ```
+    pub fn debug(&self) -> SnapshotDebug<'_> {
+        SnapshotDebug {
+            snapshot: self,
+            group_archetypes: false,
+        }
+    }
+}
+
+/// [`Debug`] view of a [`Snapshot`], created by [`Snapshot::debug`].
+pub struct SnapshotDebug<'a> {
+    snapshot: &'a Snapshot,
+    group_archetypes: bool,
+}
+
+impl SnapshotDebug<'_> {
+    /// Print the number of entities with each set of components instead of every entity.
+    ///
+    /// Makes logs of large snapshots readable.
+    pub fn group_archetypes(mut self, group_archetypes: bool) -> Self {
+        self.group_archetypes = group_archetypes;
+        self
+    }
+}
+
+/// Writes the string as-is inside [`Debug`] output.
+struct Raw(String);
+
+impl Debug for Raw {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+struct Entities<'a>(&'a Snapshot);
+
+impl Debug for Entities<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map()
+            .entries(self.0.entities.iter().map(|e| (e.entity, &e.components)))
+            .finish()
+    }
+}
+
+struct Archetypes(Vec<ArchetypeStats>);
+
+impl Debug for Archetypes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(|a| {
+                let noun = if a.count == 1 { "entity" } else { "entities" };
+                let components = a
+                    .components
+                    .iter()
+                    .map(|c| get_short_name(c))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                Raw(format!("{} {noun}: [{components}]", a.count))
+            }))
+            .finish()
+    }
+}
+
+impl Debug for SnapshotDebug<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut s = f.debug_struct("Snapshot");
+
+        s.field("resources", &self.snapshot.resources);
+
+        if self.group_archetypes {
+            s.field("entities", &Archetypes(self.snapshot.archetypes()));
+        } else {
+            s.field("entities", &Entities(self.snapshot));
+        }
+
+        s.finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,314 @@
+use std::any::TypeId;
+
+use bevy::{
+    ecs::{
+        entity::{
+            EntityHashMap,
+            EntityMapper,
+            MapEntities,
+        },
+        event::ManualEventReader,
+        reflect::ReflectMapEntities,
+        world::EntityRef,
+    },
+    prelude::*,
+    reflect::TypeRegistry,
+    scene::{
+        scene_spawner_system,
+        DynamicEntity,
+        SceneInstance,
+        SceneInstanceReady,
+    },
+};
+
+/// The asset path of the [`DynamicScene`] spawned on an entity.
+///
+/// Inserted by the [`SceneInstancePlugin`] once the scene is spawned. When loading, the scene is spawned again on
+/// entities with a [`SavedScene`] but without a scene handle.
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct SavedScene {
+    /// The asset path of the scene.
+    pub path: String,
+}
+
+/// The entity of a [`DynamicScene`] an instance entity was spawned from.
+///
+/// Inserted by the [`SceneInstancePlugin`] once the scene is spawned.
+/// Used by [`SnapshotBuilder::scene_overrides`](crate::SnapshotBuilder::scene_overrides) to only capture the
+/// components that differ from the scene asset.
+///
+/// Loaded entities with a [`SceneOrigin`] are merged into the matching instance entity once the scene is spawned again.
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component, MapEntities)]
+pub struct SceneOrigin {
+    /// The entity the scene was spawned on.
+    pub root: Entity,
+    /// The index of the entity within [`DynamicScene::entities`].
+    pub index: u32,
+}
+
+impl Default for SceneOrigin {
+    fn default() -> Self {
+        Self {
+            root: Entity::PLACEHOLDER,
+            index: 0,
+        }
+    }
+}
+
+impl MapEntities for SceneOrigin {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        self.root = entity_mapper.map_entity(self.root);
+    }
+}
+
+/// Tracks spawned [`DynamicScene`] instances, allowing them to be saved as the scene and its overrides.
+///
+/// Requires the `ScenePlugin` and `AssetPlugin`.
+///
+/// # Example
+/// ```no_run
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// App::new()
+///     .add_plugins((DefaultPlugins, SavePlugins, SceneInstancePlugin));
+///
+/// fn capture(builder: SnapshotBuilder) -> Snapshot {
+///     builder.extract_all_entities().scene_overrides().build()
+/// }
+/// ```
+pub struct SceneInstancePlugin;
+
+impl Plugin for SceneInstancePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<SavedScene>()
+            .register_type::<SceneOrigin>()
+            .add_systems(PreUpdate, SavedScene::respawn)
+            .add_systems(SpawnScene, SceneOrigin::track.after(scene_spawner_system));
+    }
+}
+
+impl SavedScene {
+    /// System spawning the scene of loaded entities with a [`SavedScene`] again.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn respawn(
+        mut commands: Commands,
+        server: Option<Res<AssetServer>>,
+        scenes: Query<(Entity, &SavedScene), Without<Handle<DynamicScene>>>,
+    ) {
+        let Some(server) = server else {
+            return;
+        };
+
+        for (entity, saved) in &scenes {
+            commands
+                .entity(entity)
+                .insert(server.load::<DynamicScene>(saved.path.clone()));
+        }
+    }
+}
+
+impl SceneOrigin {
+    /// System inserting a [`SceneOrigin`] on the entities of each spawned [`DynamicScene`] instance.
+    ///
+    /// Loaded entities with a matching [`SceneOrigin`] are merged into the instance entities and despawned.
+    pub fn track(world: &mut World, mut reader: Local<ManualEventReader<SceneInstanceReady>>) {
+        let Some(events) = world.get_resource::<Events<SceneInstanceReady>>() else {
+            return;
+        };
+
+        let roots = reader.read(events).map(|e| e.parent).collect::<Vec<_>>();
+
+        for root in roots {
+            track_instance(world, root);
+        }
+    }
+}
+
+fn track_instance(world: &mut World, root: Entity) {
+    let Some(handle) = world.get::<Handle<DynamicScene>>(root).cloned() else {
+        return;
+    };
+    let Some(instance) = world.get::<SceneInstance>(root).map(|i| **i) else {
+        return;
+    };
+
+    if let Some(path) = world
+        .get_resource::<AssetServer>()
+        .and_then(|s| s.get_path(&handle))
+    {
+        let path = path.to_string();
+        world.entity_mut(root).insert(SavedScene { path });
+    }
+
+    let entities = world
+        .resource::<SceneSpawner>()
+        .iter_instance_entities(instance)
+        .collect::<Vec<_>>();
+
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+
+    let origins = {
+        let Some(scene) = world.resource::<Assets<DynamicScene>>().get(&handle) else {
+            return;
+        };
+
+        match_origins(world, &scene.entities, &entities, &registry)
+    };
+
+    // Loaded entities waiting for the scene to be spawned again
+    let placeholders = world
+        .query::<(Entity, &SceneOrigin)>()
+        .iter(world)
+        .filter(|(e, o)| o.root == root && !entities.contains(e))
+        .map(|(e, o)| (e, o.index))
+        .collect::<Vec<_>>();
+
+    let mut merged = Vec::new();
+
+    for &(entity, index) in &origins {
+        world.entity_mut(entity).insert(SceneOrigin { root, index });
+
+        if let Some((placeholder, _)) = placeholders.iter().find(|(_, i)| *i == index) {
+            merged.push((*placeholder, entity));
+        }
+    }
+
+    merge_placeholders(world, &merged, &registry);
+}
+
+/// Matches each instance entity to the first unclaimed scene entity whose components it contains.
+///
+/// Components storing entities are ignored, as they are mapped when the scene is spawned.
+fn match_origins(
+    world: &World,
+    scene: &[DynamicEntity],
+    entities: &[Entity],
+    registry: &TypeRegistry,
+) -> Vec<(Entity, u32)> {
+    let mut claimed = vec![false; scene.len()];
+
+    entities
+        .iter()
+        .filter_map(|&entity| {
+            let entity_ref = world.get_entity(entity)?;
+            let index = scene
+                .iter()
+                .enumerate()
+                .position(|(i, e)| !claimed[i] && is_instance_of(entity_ref, e, registry))?;
+
+            claimed[index] = true;
+
+            Some((entity, u32::try_from(index).ok()?))
+        })
+        .collect()
+}
+
+fn is_instance_of(
+    entity: EntityRef,
+    scene_entity: &DynamicEntity,
+    registry: &TypeRegistry,
+) -> bool {
+    scene_entity.components.iter().all(|component| {
+        let Some(registration) = component
+            .get_represented_type_info()
+            .and_then(|i| registry.get(i.type_id()))
+        else {
+            return false;
+        };
+
+        if registration.data::<ReflectMapEntities>().is_some() {
+            return true;
+        }
+
+        registration
+            .data::<ReflectComponent>()
+            .and_then(|r| r.reflect(entity))
+            .and_then(|c| c.reflect_partial_eq(&**component))
+            .unwrap_or(false)
+    })
+}
+
+/// Moves the components of each placeholder onto its instance entity, then despawns the placeholder.
+fn merge_placeholders(world: &mut World, merged: &[(Entity, Entity)], registry: &TypeRegistry) {
+    if merged.is_empty() {
+        return;
+    }
+
+    // References to placeholders are redirected to the instance entities, all other references are kept
+    let mut entity_map = world
+        .iter_entities()
+        .map(|e| (e.id(), e.id()))
+        .collect::<EntityHashMap<_>>();
+
+    entity_map.extend(merged.iter().copied());
+
+    for &(placeholder, entity) in merged {
+        let components = world
+            .entity(placeholder)
+            .archetype()
+            .components()
+            .filter_map(|id| world.components().get_info(id))
+            .filter_map(|info| info.type_id())
+            .filter(|id| {
+                ![
+                    TypeId::of::<Parent>(),
+                    TypeId::of::<Children>(),
+                    TypeId::of::<SceneOrigin>(),
+                ]
+                .contains(id)
+            })
+            .filter_map(|id| registry.get(id))
+            .filter_map(|reg| {
+                let reflect = reg.data::<ReflectComponent>()?;
+                let value = reflect.reflect(world.entity(placeholder))?.clone_value();
+
+                Some((
+                    reflect.clone(),
+                    value,
+                    reg.data::<ReflectMapEntities>().cloned(),
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        for (reflect, value, map) in components {
+            reflect.apply_or_insert(&mut world.entity_mut(entity), &*value, registry);
+
+            if let Some(map) = map {
+                map.map_entities(world, &mut entity_map, &[entity]);
+            }
+        }
+
+        world.entity_mut(placeholder).remove_parent();
+        world.despawn(placeholder);
+    }
+}
+
+/// Removes the components of a scene instance entity that are equal to the components of its scene entity.
+pub(crate) fn diff_scene_instance(entity: &mut DynamicEntity, world: &World) {
+    let Some(origin) = entity
+        .components
+        .iter()
+        .find(|c| c.represents::<SceneOrigin>())
+        .and_then(|c| SceneOrigin::from_reflect(&**c))
+    else {
+        return;
+    };
+
+    let Some(scene_entity) = world
+        .get::<Handle<DynamicScene>>(origin.root)
+        .and_then(|h| world.get_resource::<Assets<DynamicScene>>()?.get(h))
+        .and_then(|s| s.entities.get(origin.index as usize))
+    else {
+        return;
+    };
+
+    entity.components.retain(|component| {
+        !scene_entity
+            .components
+            .iter()
+            .any(|c| c.reflect_partial_eq(&**component) == Some(true))
+    });
+}
@@ -0,0 +1,191 @@
+use std::{
+    any::type_name,
+    fmt::Display,
+};
+
+use bevy::prelude::*;
+use serde::Serialize;
+
+use crate::{
+    Backend,
+    Error,
+    Format,
+    Pipeline,
+    Snapshot,
+};
+
+/// A save operation about to be performed, passed to each save interceptor.
+///
+/// Interceptors can veto the save, change its key, or add metadata before the [`Snapshot`] is captured.
+pub struct SaveContext<'w> {
+    world: &'w World,
+    pipeline: &'static str,
+    key: Option<String>,
+    metadata: Vec<Box<dyn Reflect>>,
+    veto: Option<String>,
+}
+
+impl<'w> SaveContext<'w> {
+    /// Returns the [`World`] being saved.
+    pub fn world(&self) -> &'w World {
+        self.world
+    }
+
+    /// Returns the type name of the [`Pipeline`] performing the save.
+    pub fn pipeline(&self) -> &'static str {
+        self.pipeline
+    }
+
+    /// Returns the key set by a previous interceptor, if any.
+    pub fn key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+
+    /// Save with the given key instead of the key of the [`Pipeline`].
+    pub fn set_key(&mut self, key: impl Into<String>) {
+        self.key = Some(key.into());
+    }
+
+    /// Store the resource `T` in the saved [`Snapshot`], replacing any captured value.
+    ///
+    /// The type must be registered to be loaded again.
+    pub fn insert_metadata<T: Resource + Reflect + TypePath>(&mut self, value: T) {
+        self.metadata.retain(|m| !m.represents::<T>());
+        self.metadata.push(Box::new(value));
+    }
+
+    /// Cancel the save. Later interceptors are not run, and the save fails with [`Error::Vetoed`].
+    pub fn veto(&mut self, reason: impl Into<String>) {
+        self.veto = Some(reason.into());
+    }
+
+    /// Returns `true` if the save has been vetoed.
+    pub fn is_vetoed(&self) -> bool {
+        self.veto.is_some()
+    }
+}
+
+type Interceptor = Box<dyn Fn(&mut SaveContext) + Send + Sync>;
+
+/// The ordered chain of world-level save interceptors, run before every save is captured.
+///
+/// Interceptors run in the order they were added with
+/// [`AppSaveableExt::add_save_interceptor`](crate::AppSaveableExt::add_save_interceptor).
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// #[derive(Resource)]
+/// struct Cutscene;
+///
+/// # let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, SavePlugins))
+///     .add_save_interceptor(|ctx: &mut SaveContext| {
+///         if ctx.world().contains_resource::<Cutscene>() {
+///             ctx.veto("cannot save during cutscenes");
+///         }
+///     })
+///     .insert_resource(Cutscene);
+///
+/// let err = app.world.save("example").unwrap_err();
+///
+/// assert!(matches!(err, bevy_save::Error::Vetoed(_)));
+/// ```
+#[derive(Resource, Default)]
+pub struct SaveInterceptors {
+    interceptors: Vec<Interceptor>,
+}
+
+impl SaveInterceptors {
+    /// Add an interceptor to the end of the chain.
+    pub fn add(&mut self, interceptor: impl Fn(&mut SaveContext) + Send + Sync + 'static) {
+        self.interceptors.push(Box::new(interceptor));
+    }
+
+    /// Returns the number of interceptors.
+    pub fn len(&self) -> usize {
+        self.interceptors.len()
+    }
+
+    /// Returns `true` if there are no interceptors.
+    pub fn is_empty(&self) -> bool {
+        self.interceptors.is_empty()
+    }
+}
+
+/// A [`Pipeline`] together with the key and metadata set by the [`SaveInterceptors`].
+pub(crate) struct Intercepted<P> {
+    pub(crate) pipeline: P,
+    pub(crate) metadata: Vec<Box<dyn Reflect>>,
+    key: Option<String>,
+}
+
+impl<P: Pipeline> Intercepted<P> {
+    /// Returns the key to save with, preferring the key set by an interceptor.
+    pub(crate) fn key(&self) -> String
+    where
+        for<'a> P::Key<'a>: Display,
+    {
+        self.key
+            .clone()
+            .unwrap_or_else(|| self.pipeline.key().to_string())
+    }
+
+    /// Saves the value with the backend, using the key set by an interceptor if any.
+    pub(crate) fn save<F: Format, T: Serialize>(
+        &self,
+        backend: &P::Backend,
+        value: &T,
+    ) -> Result<(), Error> {
+        match &self.key {
+            Some(key) => backend.save::<F, T>(key.clone(), value),
+            None => backend.save::<F, T>(self.pipeline.key(), value),
+        }
+    }
+}
+
+/// Runs the [`SaveInterceptors`] of the [`World`] for a save with the [`Pipeline`].
+pub(crate) fn intercept_save<P: Pipeline>(
+    world: &World,
+    pipeline: P,
+) -> Result<Intercepted<P>, Error> {
+    let Some(interceptors) = world.get_resource::<SaveInterceptors>() else {
+        return Ok(Intercepted {
+            pipeline,
+            metadata: Vec::new(),
+            key: None,
+        });
+    };
+
+    let mut ctx = SaveContext {
+        world,
+        pipeline: type_name::<P>(),
+        key: None,
+        metadata: Vec::new(),
+        veto: None,
+    };
+
+    for interceptor in &interceptors.interceptors {
+        interceptor(&mut ctx);
+
+        if let Some(reason) = ctx.veto {
+            return Err(Error::Vetoed(reason));
+        }
+    }
+
+    Ok(Intercepted {
+        pipeline,
+        metadata: ctx.metadata,
+        key: ctx.key,
+    })
+}
+
+impl Snapshot {
+    /// Stores the metadata added by save interceptors, replacing captured resources of the same type.
+    pub(crate) fn stamp_metadata(&mut self, metadata: Vec<Box<dyn Reflect>>) {
+        for value in metadata {
+            self.insert_boxed_resource(value);
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use std::{
+    fmt::{
+        Display,
+        Formatter,
+    },
+    path::{
+        Component,
+        Path,
+        PathBuf,
+    },
+};
+
+/// Encodes a structured key into the path segments used by the built-in backends.
+///
+/// Wrap the key in [`EncodedKey`] to use it as the [`Pipeline::Key`](crate::Pipeline::Key) of any backend accepting
+/// string keys. Segments are joined with `/`, so `("slot", 3)` is stored as `slot/3`.
+pub trait KeyEncoder {
+    /// Append the path segments of the key.
+    fn encode(&self, segments: &mut Vec<String>);
+}
+
+/// A structured key, displayed as the path built by its [`KeyEncoder`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// struct SlotPipeline {
+///     profile: String,
+///     slot: u32,
+/// }
+///
+/// impl Pipeline for SlotPipeline {
+///     type Backend = DefaultBackend;
+///     type Format = DefaultFormat;
+///
+///     type Key<'a> = EncodedKey<(&'a str, u32)>;
+///
+///     fn key(&self) -> Self::Key<'_> {
+///         EncodedKey((&self.profile, self.slot))
+///     }
+/// }
+///
+/// assert_eq!(EncodedKey(("alice", 3)).to_string(), "alice/3");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EncodedKey<K>(pub K);
+
+impl<K: KeyEncoder> EncodedKey<K> {
+    /// Returns the path segments of the key.
+    pub fn segments(&self) -> Vec<String> {
+        let mut segments = Vec::new();
+        self.0.encode(&mut segments);
+        segments
+    }
+}
+
+impl<K: KeyEncoder> Display for EncodedKey<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.segments().join("/"))
+    }
+}
+
+impl<T: KeyEncoder + ?Sized> KeyEncoder for &T {
+    fn encode(&self, segments: &mut Vec<String>) {
+        (**self).encode(segments);
+    }
+}
+
+impl KeyEncoder for str {
+    fn encode(&self, segments: &mut Vec<String>) {
+        segments.push(self.to_owned());
+    }
+}
+
+impl KeyEncoder for String {
+    fn encode(&self, segments: &mut Vec<String>) {
+        segments.push(self.clone());
+    }
+}
+
+impl KeyEncoder for Path {
+    fn encode(&self, segments: &mut Vec<String>) {
+        segments.extend(self.components().filter_map(|c| match c {
+            Component::Normal(s) => Some(s.to_string_lossy().into_owned()),
+            _ => None,
+        }));
+    }
+}
+
+impl KeyEncoder for PathBuf {
+    fn encode(&self, segments: &mut Vec<String>) {
+        self.as_path().encode(segments);
+    }
+}
+
+macro_rules! impl_display_key {
+    ($($t:ty),*) => {
+        $(
+            impl KeyEncoder for $t {
+                fn encode(&self, segments: &mut Vec<String>) {
+                    segments.push(self.to_string());
+                }
+            }
+        )*
+    };
+}
+
+impl_display_key!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool, char);
+
+macro_rules! impl_tuple_key {
+    ($($name:ident),*) => {
+        impl<$($name: KeyEncoder),*> KeyEncoder for ($($name,)*) {
+            #[allow(non_snake_case)]
+            fn encode(&self, segments: &mut Vec<String>) {
+                let ($($name,)*) = self;
+                $($name.encode(segments);)*
+            }
+        }
+    };
+}
+
+impl_tuple_key!(A);
+impl_tuple_key!(A, B);
+impl_tuple_key!(A, B, C);
+impl_tuple_key!(A, B, C, D);
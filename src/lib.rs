@@ -7,60 +7,195 @@
 #![allow(clippy::return_self_not_must_use)]
 #![allow(clippy::too_many_lines)]
 #![doc = include_str!("../README.md")]
+#![cfg_attr(not(feature = "mmap"), forbid(unsafe_code))]
+#![cfg_attr(feature = "mmap", deny(unsafe_code))]
 
+#[cfg(feature = "dedup")]
+pub use crate::dedup::*;
+#[cfg(feature = "scripting")]
+pub use crate::scripting::*;
 pub use crate::{
     app::*,
     applier::*,
+    archive::*,
     backend::*,
+    batch::*,
     builder::*,
     clone::*,
+    delta::*,
+    diff::*,
     dir::*,
+    domain::*,
     error::*,
+    expr::*,
+    extras::*,
     format::*,
+    header::*,
+    incremental::*,
+    inspect::*,
+    instance::*,
+    intercept::*,
+    key::*,
+    listing::*,
     middleware::*,
+    mods::*,
+    nonsend::*,
+    patch::*,
     pipeline::*,
+    plan::*,
+    playtime::*,
     plugins::*,
+    preflight::*,
+    quantize::*,
+    quick::*,
+    quota::*,
+    reader::*,
     registry::*,
+    report::*,
+    request::*,
+    retry::*,
+    rng::*,
     rollbacks::*,
+    sanitize::*,
+    schedule::*,
     serde::*,
+    shared::*,
     snapshot::*,
+    sparse::*,
+    split::*,
+    summary::*,
+    sync::*,
+    tombstone::*,
+    unknown::*,
+    validate::*,
+    version::*,
     world::*,
 };
 
 mod app;
 mod applier;
+mod archive;
 mod backend;
+mod batch;
 mod builder;
 mod clone;
+mod content;
+#[cfg(feature = "dedup")]
+mod dedup;
+mod delta;
+mod diff;
 mod dir;
+mod domain;
 mod error;
+mod expr;
+mod extras;
 mod format;
+mod header;
+mod incremental;
+mod inspect;
+mod instance;
+mod intercept;
+mod key;
+mod listing;
 mod middleware;
+mod mods;
+mod nonsend;
+mod patch;
 mod pipeline;
+mod plan;
+mod playtime;
 mod plugins;
+mod preflight;
+mod profile;
+mod quantize;
+mod quick;
+mod quota;
+mod reader;
 mod registry;
+mod report;
+pub mod repro;
+mod request;
+mod retry;
+mod rng;
 mod rollbacks;
+mod sanitize;
+mod schedule;
+#[cfg(feature = "scripting")]
+mod scripting;
 mod serde;
+mod shared;
 mod snapshot;
+mod sparse;
+mod split;
+mod summary;
+mod sync;
+pub mod testing;
+mod tombstone;
+mod unknown;
+mod validate;
+mod version;
 mod world;
 
 /// Prelude: convenient import for all the user-facing APIs provided by the crate
 pub mod prelude {
+    #[cfg(feature = "dedup")]
+    pub use crate::dedup::*;
+    #[cfg(feature = "scripting")]
+    pub use crate::scripting::*;
     pub use crate::{
         app::*,
         applier::*,
+        archive::*,
         backend::*,
+        batch::*,
         builder::*,
         clone::*,
+        delta::*,
+        diff::*,
         dir::*,
+        domain::*,
+        expr::*,
+        extras::*,
         format::*,
+        header::*,
+        incremental::*,
+        inspect::*,
+        instance::*,
+        intercept::*,
+        key::*,
+        listing::*,
         middleware::*,
+        mods::*,
+        nonsend::*,
+        patch::*,
         pipeline::*,
+        plan::*,
+        playtime::*,
         plugins::*,
+        preflight::*,
+        quantize::*,
+        quick::*,
+        quota::*,
+        reader::*,
         registry::*,
+        report::*,
+        request::*,
+        retry::*,
+        rng::*,
         rollbacks::*,
+        sanitize::*,
+        schedule::*,
         serde::*,
+        shared::*,
         snapshot::*,
+        sparse::*,
+        split::*,
+        summary::*,
+        sync::*,
+        tombstone::*,
+        unknown::*,
+        validate::*,
+        version::*,
         world::*,
     };
 }
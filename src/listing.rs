@@ -0,0 +1,112 @@
+use std::{
+    cmp::Ordering,
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
+
+use crate::{
+    SaveSummary,
+    SlotInfo,
+};
+
+/// A save listed in a load menu, which can be sorted with [`SaveOrder`].
+pub trait SaveListing {
+    /// Returns the name of the save.
+    fn name(&self) -> &str;
+
+    /// Returns when the save was last written, if known.
+    fn last_played(&self) -> Option<SystemTime>;
+
+    /// Returns the [`PlayTime`](crate::PlayTime) stored in the save, if known.
+    fn play_time(&self) -> Option<Duration> {
+        None
+    }
+}
+
+impl SaveListing for SlotInfo {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn last_played(&self) -> Option<SystemTime> {
+        Some(self.modified)
+    }
+}
+
+impl<K: AsRef<str>> SaveListing for (K, SaveSummary) {
+    fn name(&self) -> &str {
+        self.0.as_ref()
+    }
+
+    fn last_played(&self) -> Option<SystemTime> {
+        self.1.saved_at
+    }
+
+    fn play_time(&self) -> Option<Duration> {
+        self.1.play_time
+    }
+}
+
+/// How saves are ordered in a load menu.
+///
+/// Saves missing the compared value are placed last, and ties are ordered by name.
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use bevy_save::prelude::*;
+/// let mut saves = vec![
+///     ("slot1", SaveSummary {
+///         play_time: Some(Duration::from_secs(60)),
+///         ..Default::default()
+///     }),
+///     ("slot2", SaveSummary {
+///         play_time: Some(Duration::from_secs(3600)),
+///         ..Default::default()
+///     }),
+/// ];
+///
+/// SaveOrder::PlayTime.sort(&mut saves);
+///
+/// assert_eq!(saves[0].0, "slot2");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SaveOrder {
+    /// Most recently played first.
+    #[default]
+    LastPlayed,
+    /// Longest play time first.
+    PlayTime,
+    /// Alphabetically by name.
+    Name,
+}
+
+/// Orders present values before missing values, in descending order.
+fn descending<T: Ord>(a: Option<T>, b: Option<T>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => b.cmp(&a),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
+
+impl SaveOrder {
+    /// Compare two saves in this order.
+    pub fn compare<T: SaveListing>(self, a: &T, b: &T) -> Ordering {
+        let order = match self {
+            Self::LastPlayed => descending(a.last_played(), b.last_played()),
+            Self::PlayTime => descending(a.play_time(), b.play_time()),
+            Self::Name => Ordering::Equal,
+        };
+
+        order.then_with(|| a.name().cmp(b.name()))
+    }
+
+    /// Sort the saves in this order.
+    pub fn sort<T: SaveListing>(self, saves: &mut [T]) {
+        saves.sort_by(|a, b| self.compare(a, b));
+    }
+}
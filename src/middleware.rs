@@ -1,10 +1,142 @@
+mod limited {
+    use std::{
+        io::Read,
+        marker::PhantomData,
+    };
+
+    use crate::{
+        Error,
+        FloatPrecision,
+        Format,
+    };
+
+    /// The default maximum size of a save read by [`Limited`], 256 MiB.
+    pub const DEFAULT_MAX_BYTES: u64 = 1 << 28;
+
+    /// Middleware limiting the number of bytes read when loading, protecting against corrupted or malicious saves.
+    ///
+    /// Loading a save larger than `MAX_BYTES` fails with [`Error::Custom`].
+    /// Place it inside compression middleware to limit the decompressed size instead.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use bevy_save::prelude::*;
+    /// struct MyPipeline;
+    ///
+    /// impl Pipeline for MyPipeline {
+    ///     type Backend = DefaultBackend;
+    ///     /// This will refuse to load saves larger than 16 MiB
+    ///     type Format = Limited<DefaultFormat, { 16 * 1024 * 1024 }>;
+    ///     type Key<'a> = &'a str;
+    ///
+    ///     fn key(&self) -> Self::Key<'_> {
+    ///         "my_pipeline"
+    ///     }
+    /// }
+    /// ```
+    pub struct Limited<F, const MAX_BYTES: u64 = DEFAULT_MAX_BYTES>(PhantomData<F>);
+
+    impl<F, const MAX_BYTES: u64> Default for Limited<F, MAX_BYTES> {
+        fn default() -> Self {
+            Self(PhantomData)
+        }
+    }
+
+    struct LimitedReader<R> {
+        inner: R,
+        remaining: u64,
+        exceeded: bool,
+    }
+
+    impl<R: Read> Read for LimitedReader<R> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            if buf.is_empty() {
+                return Ok(0);
+            }
+
+            // Read one byte past the limit to tell a save of exactly `MAX_BYTES` from a larger one
+            let max = usize::try_from(self.remaining.saturating_add(1)).unwrap_or(usize::MAX);
+            let len = buf.len().min(max);
+            let read = self.inner.read(&mut buf[..len])?;
+
+            if read as u64 > self.remaining {
+                self.exceeded = true;
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "save size limit exceeded",
+                ));
+            }
+
+            self.remaining -= read as u64;
+
+            Ok(read)
+        }
+    }
+
+    impl<F: Format, const MAX_BYTES: u64> Format for Limited<F, MAX_BYTES> {
+        fn name() -> &'static str {
+            F::name()
+        }
+
+        fn extension() -> &'static str {
+            F::extension()
+        }
+
+        fn mime_type() -> &'static str {
+            F::mime_type()
+        }
+
+        fn float_precision() -> FloatPrecision {
+            F::float_precision()
+        }
+
+        fn self_describing() -> bool {
+            F::self_describing()
+        }
+
+        fn serialize<W: std::io::Write, T: serde::Serialize>(
+            writer: W,
+            value: &T,
+        ) -> Result<(), Error> {
+            F::serialize(writer, value)
+        }
+
+        fn deserialize<R: Read, S: for<'de> serde::de::DeserializeSeed<'de, Value = T>, T>(
+            reader: R,
+            seed: S,
+        ) -> Result<T, Error> {
+            let mut reader = LimitedReader {
+                inner: reader,
+                remaining: MAX_BYTES,
+                exceeded: false,
+            };
+
+            let result = F::deserialize(&mut reader, seed);
+
+            if reader.exceeded {
+                return Err(Error::custom(format_args!(
+                    "save exceeds the limit of {MAX_BYTES} bytes"
+                )));
+            }
+
+            result
+        }
+    }
+}
+
+pub use limited::*;
+
 #[cfg(feature = "brotli")]
 mod brotli {
     use std::marker::PhantomData;
 
     use brotli::enc::BrotliEncoderParams;
 
-    use crate::Format;
+    use crate::{
+        format::wrapped_name,
+        FloatPrecision,
+        Format,
+    };
 
     /// Brotli middleware for compressing your data after serializing
     ///
@@ -32,11 +164,27 @@ mod brotli {
     }
 
     impl<F: Format> Format for Brotli<F> {
+        fn name() -> &'static str {
+            wrapped_name("brotli", F::name())
+        }
+
         fn extension() -> &'static str {
             // TODO: Should be `format!("{}.br", F::extension())`
             ".br"
         }
 
+        fn mime_type() -> &'static str {
+            "application/x-brotli"
+        }
+
+        fn float_precision() -> FloatPrecision {
+            F::float_precision()
+        }
+
+        fn self_describing() -> bool {
+            F::self_describing()
+        }
+
         fn serialize<W: std::io::prelude::Write, T: serde::Serialize>(
             writer: W,
             value: &T,
@@ -62,3 +210,340 @@ mod brotli {
 
 #[cfg(feature = "brotli")]
 pub use brotli::*;
+
+#[cfg(feature = "zstd")]
+mod zstd {
+    use std::{
+        io::{
+            Read,
+            Write,
+        },
+        marker::PhantomData,
+    };
+
+    use crate::{
+        format::wrapped_name,
+        Error,
+        FloatPrecision,
+        Format,
+    };
+
+    /// Provides the compression dictionary and level used by the [`Zstd`] middleware.
+    ///
+    /// Saves must be loaded with the same dictionary they were saved with.
+    pub trait ZstdDictionary {
+        /// The pre-trained dictionary, usually created with [`train_zstd_dictionary`] and included with `include_bytes!`.
+        ///
+        /// An empty dictionary disables dictionary compression.
+        fn dictionary() -> &'static [u8];
+
+        /// The compression level.
+        ///
+        /// Defaults to `3`.
+        fn level() -> i32 {
+            3
+        }
+    }
+
+    /// A [`ZstdDictionary`] without a dictionary.
+    pub struct NoDictionary;
+
+    impl ZstdDictionary for NoDictionary {
+        fn dictionary() -> &'static [u8] {
+            &[]
+        }
+    }
+
+    /// Zstandard middleware for compressing your data after serializing, optionally with a pre-trained dictionary.
+    ///
+    /// Dictionaries greatly improve the compression of small saves, such as frequent autosaves.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use bevy_save::prelude::*;
+    /// struct MyDictionary;
+    ///
+    /// impl ZstdDictionary for MyDictionary {
+    ///     fn dictionary() -> &'static [u8] {
+    ///         // include_bytes!("../assets/saves.dict")
+    /// #       &[]
+    ///     }
+    /// }
+    ///
+    /// struct MyPipeline;
+    ///
+    /// impl Pipeline for MyPipeline {
+    ///     type Backend = DefaultBackend;
+    ///     /// This will emit zstd-compressed MessagePack
+    ///     type Format = Zstd<DefaultFormat, MyDictionary>;
+    ///     type Key<'a> = &'a str;
+    ///
+    ///     fn key(&self) -> Self::Key<'_> {
+    ///         "my_pipeline"
+    ///     }
+    /// }
+    /// ```
+    pub struct Zstd<F, D = NoDictionary>(PhantomData<(F, D)>);
+
+    impl<F, D> Default for Zstd<F, D> {
+        fn default() -> Self {
+            Self(PhantomData)
+        }
+    }
+
+    impl<F: Format, D: ZstdDictionary> Format for Zstd<F, D> {
+        fn name() -> &'static str {
+            wrapped_name("zstd", F::name())
+        }
+
+        fn extension() -> &'static str {
+            ".zst"
+        }
+
+        fn mime_type() -> &'static str {
+            "application/zstd"
+        }
+
+        fn float_precision() -> FloatPrecision {
+            F::float_precision()
+        }
+
+        fn self_describing() -> bool {
+            F::self_describing()
+        }
+
+        fn serialize<W: Write, T: serde::Serialize>(writer: W, value: &T) -> Result<(), Error> {
+            let mut encoder =
+                ::zstd::Encoder::with_dictionary(writer, D::level(), D::dictionary())?;
+            F::serialize(&mut encoder, value)?;
+            encoder.finish()?;
+            Ok(())
+        }
+
+        fn deserialize<R: Read, S: for<'de> serde::de::DeserializeSeed<'de, Value = T>, T>(
+            reader: R,
+            seed: S,
+        ) -> Result<T, Error> {
+            let decoder =
+                ::zstd::Decoder::with_dictionary(std::io::BufReader::new(reader), D::dictionary())?;
+            F::deserialize(decoder, seed)
+        }
+    }
+
+    /// Trains a dictionary for the [`Zstd`] middleware from a corpus of uncompressed sample saves.
+    ///
+    /// Samples should be serialized with the [`Format`] the dictionary will be used with.
+    /// Training requires a reasonably large number of samples, usually at least a hundred.
+    ///
+    /// # Errors
+    /// - [`Error::IO`] if there are not enough samples to train a dictionary
+    ///
+    /// # Example
+    /// ```rust
+    /// # use std::{marker::PhantomData, sync::OnceLock};
+    /// # use bevy_save::prelude::*;
+    /// static DICTIONARY: OnceLock<Vec<u8>> = OnceLock::new();
+    ///
+    /// struct Trained;
+    ///
+    /// impl ZstdDictionary for Trained {
+    ///     fn dictionary() -> &'static [u8] {
+    ///         DICTIONARY.get().unwrap()
+    ///     }
+    /// }
+    ///
+    /// let samples = (0..1000u32)
+    ///     .map(|i| {
+    ///         let mut data = Vec::new();
+    ///         JSONFormat::serialize(&mut data, &vec![i % 100, i % 7, 42]).unwrap();
+    ///         data
+    ///     })
+    ///     .collect::<Vec<_>>();
+    ///
+    /// DICTIONARY.set(train_zstd_dictionary(&samples, 1024).unwrap()).unwrap();
+    ///
+    /// let mut data = Vec::new();
+    /// Zstd::<JSONFormat, Trained>::serialize(&mut data, &vec![1u32, 2, 42]).unwrap();
+    ///
+    /// let value: Vec<u32> = Zstd::<JSONFormat, Trained>::deserialize(&*data, PhantomData).unwrap();
+    ///
+    /// assert_eq!(value, [1, 2, 42]);
+    /// ```
+    pub fn train_zstd_dictionary<S: AsRef<[u8]>>(
+        samples: &[S],
+        max_size: usize,
+    ) -> Result<Vec<u8>, Error> {
+        Ok(::zstd::dict::from_samples(samples, max_size)?)
+    }
+}
+
+#[cfg(feature = "zstd")]
+pub use self::zstd::*;
+
+#[cfg(feature = "signing")]
+mod signing {
+    use std::{
+        io::{
+            Read,
+            Write,
+        },
+        marker::PhantomData,
+    };
+
+    use hmac::{
+        Hmac,
+        Mac,
+    };
+    use sha2::Sha256;
+
+    use crate::{
+        Error,
+        FloatPrecision,
+        Format,
+    };
+
+    type HmacSha256 = Hmac<Sha256>;
+
+    const SIGNATURE_MAGIC: &[u8; 4] = b"BSIG";
+    const SIGNATURE_LEN: usize = 32;
+
+    /// Provides the secret key used by the [`Signed`] middleware.
+    pub trait SigningKey {
+        /// The secret key used to sign and verify saves.
+        fn key() -> &'static [u8];
+
+        /// Whether saves without a signature (e.g. created before signing was enabled) are accepted.
+        ///
+        /// Defaults to `false`.
+        fn accept_unsigned() -> bool {
+            false
+        }
+    }
+
+    /// Signing middleware that makes saves tamper-evident without encrypting them.
+    ///
+    /// Each save is prefixed with an HMAC-SHA256 signature of its contents.
+    /// Loading a save with a missing or mismatched signature fails with [`Error::InvalidSignature`].
+    ///
+    /// # Example
+    /// ```rust
+    /// # use bevy_save::prelude::*;
+    /// struct MyKey;
+    ///
+    /// impl SigningKey for MyKey {
+    ///     fn key() -> &'static [u8] {
+    ///         b"my secret key"
+    ///     }
+    /// }
+    ///
+    /// struct MyPipeline;
+    ///
+    /// impl Pipeline for MyPipeline {
+    ///     type Backend = DefaultBackend;
+    ///     /// This will emit signed MessagePack
+    ///     type Format = Signed<DefaultFormat, MyKey>;
+    ///     type Key<'a> = &'a str;
+    ///
+    ///     fn key(&self) -> Self::Key<'_> {
+    ///         "my_pipeline"
+    ///     }
+    /// }
+    /// ```
+    pub struct Signed<F, K>(PhantomData<(F, K)>);
+
+    impl<F, K> Default for Signed<F, K> {
+        fn default() -> Self {
+            Self(PhantomData)
+        }
+    }
+
+    fn mac<K: SigningKey>() -> HmacSha256 {
+        HmacSha256::new_from_slice(K::key()).expect("HMAC can take a key of any size")
+    }
+
+    impl<F: Format, K: SigningKey> Format for Signed<F, K> {
+        fn name() -> &'static str {
+            F::name()
+        }
+
+        fn extension() -> &'static str {
+            F::extension()
+        }
+
+        fn key_suffix() -> &'static str {
+            F::key_suffix()
+        }
+
+        fn mime_type() -> &'static str {
+            F::mime_type()
+        }
+
+        fn float_precision() -> FloatPrecision {
+            F::float_precision()
+        }
+
+        fn self_describing() -> bool {
+            F::self_describing()
+        }
+
+        fn matches(prefix: &[u8]) -> bool {
+            match prefix.strip_prefix(SIGNATURE_MAGIC.as_slice()) {
+                Some(signed) => signed.get(SIGNATURE_LEN..).is_some_and(F::matches),
+                None => K::accept_unsigned() && F::matches(prefix),
+            }
+        }
+
+        fn serialize<W: Write, T: serde::Serialize>(mut writer: W, value: &T) -> Result<(), Error> {
+            let mut payload = Vec::new();
+            F::serialize(&mut payload, value)?;
+
+            let mut mac = mac::<K>();
+            mac.update(&payload);
+
+            writer.write_all(SIGNATURE_MAGIC)?;
+            writer.write_all(&mac.finalize().into_bytes())?;
+            writer.write_all(&payload)?;
+
+            Ok(())
+        }
+
+        fn deserialize<R: Read, S: for<'de> serde::de::DeserializeSeed<'de, Value = T>, T>(
+            mut reader: R,
+            seed: S,
+        ) -> Result<T, Error> {
+            let mut data = Vec::new();
+            reader.read_to_end(&mut data)?;
+
+            Self::deserialize_slice(&data, seed)
+        }
+
+        fn deserialize_slice<S: for<'de> serde::de::DeserializeSeed<'de, Value = T>, T>(
+            bytes: &[u8],
+            seed: S,
+        ) -> Result<T, Error> {
+            let Some(signed) = bytes.strip_prefix(SIGNATURE_MAGIC.as_slice()) else {
+                return if K::accept_unsigned() {
+                    F::deserialize_slice(bytes, seed)
+                } else {
+                    Err(Error::InvalidSignature)
+                };
+            };
+
+            if signed.len() < SIGNATURE_LEN {
+                return Err(Error::InvalidSignature);
+            }
+
+            let (signature, payload) = signed.split_at(SIGNATURE_LEN);
+
+            let mut mac = mac::<K>();
+            mac.update(payload);
+            mac.verify_slice(signature)
+                .map_err(|_| Error::InvalidSignature)?;
+
+            F::deserialize_slice(payload, seed)
+        }
+    }
+}
+
+#[cfg(feature = "signing")]
+pub use signing::*;
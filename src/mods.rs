@@ -0,0 +1,144 @@
+use bevy::prelude::*;
+
+use crate::{
+    Error,
+    Snapshot,
+};
+
+/// A mod and the types it registers.
+#[derive(Reflect, Default, Debug, Clone, PartialEq, Eq)]
+pub struct ModInfo {
+    /// The name of the mod.
+    pub name: String,
+    /// The type paths of the types registered by the mod.
+    pub types: Vec<String>,
+}
+
+/// The mods loaded by the game and the types they register, stored alongside every save.
+///
+/// While the [`World`] has a manifest, saves are loaded leniently: components and resources of unregistered types are
+/// skipped if they belong to a mod which is no longer loaded. These mods are listed by [`MissingMods`].
+///
+/// Built with [`AppSaveableExt::register_mod_type`](crate::AppSaveableExt::register_mod_type).
+#[derive(Resource, Reflect, Default, Debug, Clone, PartialEq, Eq)]
+#[reflect(Resource)]
+pub struct ModManifest {
+    /// The loaded mods.
+    pub mods: Vec<ModInfo>,
+}
+
+impl ModManifest {
+    /// Returns the mod with the given name, if it is loaded.
+    pub fn get(&self, name: &str) -> Option<&ModInfo> {
+        self.mods.iter().find(|m| m.name == name)
+    }
+
+    /// Returns the mod registering the type with the given type path, if any.
+    pub fn mod_of(&self, type_path: &str) -> Option<&ModInfo> {
+        self.mods
+            .iter()
+            .find(|m| m.types.iter().any(|t| t == type_path))
+    }
+
+    /// Record a type as registered by the mod with the given name, adding the mod if needed.
+    pub fn add_type(&mut self, name: &str, type_path: impl Into<String>) {
+        let type_path = type_path.into();
+
+        if let Some(info) = self.mods.iter_mut().find(|m| m.name == name) {
+            if !info.types.contains(&type_path) {
+                info.types.push(type_path);
+            }
+        } else {
+            self.mods.push(ModInfo {
+                name: name.to_owned(),
+                types: vec![type_path],
+            });
+        }
+    }
+}
+
+/// The mods of the last loaded save which are missing from the current [`ModManifest`].
+///
+/// Inserted after loading a save while the [`World`] has a [`ModManifest`], so the game can warn the player.
+/// Each [`ModInfo`] lists the types the mod registered when the save was written.
+#[derive(Resource, Default, Debug, Clone, PartialEq, Eq)]
+pub struct MissingMods(pub Vec<ModInfo>);
+
+impl MissingMods {
+    /// Returns `true` if no mods are missing.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl Snapshot {
+    /// Returns the [`ModManifest`] the snapshot was saved with, if any.
+    pub fn mod_manifest(&self) -> Option<ModManifest> {
+        self.get_resource::<ModManifest>()
+    }
+
+    /// Stores the [`ModManifest`] of the [`World`] in the snapshot, replacing any existing one.
+    ///
+    /// [`MissingMods`] are kept in the manifest, as their data is retained by [`UnknownComponents`](crate::UnknownComponents)
+    /// and [`UnknownResources`](crate::UnknownResources).
+    pub(crate) fn stamp_mods(&mut self, world: &World) {
+        self.remove_resource::<ModManifest>();
+
+        if let Some(manifest) = world.get_resource::<ModManifest>() {
+            let mut manifest = manifest.clone();
+
+            if let Some(missing) = world.get_resource::<MissingMods>() {
+                for info in &missing.0 {
+                    if manifest.get(&info.name).is_none() {
+                        manifest.mods.push(info.clone());
+                    }
+                }
+            }
+
+            self.insert_resource(manifest);
+        }
+    }
+
+    /// Compares the [`ModManifest`] of the snapshot with the [`World`], inserting [`MissingMods`], then removes it.
+    ///
+    /// Does nothing if the [`World`] does not have a [`ModManifest`].
+    pub(crate) fn check_mods(&mut self, world: &mut World) -> Result<(), Error> {
+        let missing = self.missing_mods(world);
+
+        self.remove_resource::<ModManifest>();
+
+        if let Some(missing) = missing? {
+            world.insert_resource(MissingMods(missing));
+        }
+
+        Ok(())
+    }
+
+    /// Compares the [`ModManifest`] of the snapshot with the [`World`], returning the mods that are not loaded.
+    ///
+    /// Returns `None` if the [`World`] does not have a [`ModManifest`].
+    pub(crate) fn missing_mods(&self, world: &World) -> Result<Option<Vec<ModInfo>>, Error> {
+        let Some(current) = world.get_resource::<ModManifest>() else {
+            return Ok(None);
+        };
+
+        let missing = self
+            .mod_manifest()
+            .unwrap_or_default()
+            .mods
+            .into_iter()
+            .filter(|m| current.get(&m.name).is_none())
+            .collect::<Vec<_>>();
+
+        if let Some(type_path) = self
+            .skipped_types()
+            .find(|t| !missing.iter().any(|m| m.types.iter().any(|p| p == t)))
+        {
+            return Err(Error::custom(format_args!(
+                "unregistered type `{type_path}` does not belong to a missing mod"
+            )));
+        }
+
+        Ok(Some(missing))
+    }
+}
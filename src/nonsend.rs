@@ -0,0 +1,130 @@
+use std::{
+    any::TypeId,
+    sync::Arc,
+};
+
+use bevy::{
+    ecs::component::ComponentId,
+    prelude::*,
+};
+
+type CaptureFn = Arc<dyn Fn(&World) -> Option<(ComponentId, Box<dyn Reflect>)> + Send + Sync>;
+type ApplyFn = Arc<dyn Fn(&mut World, &dyn Reflect) + Send + Sync>;
+
+/// A non-send resource saved through a reflectable proxy type.
+#[derive(Clone)]
+pub(crate) struct NonSendSaveable {
+    pub(crate) proxy: TypeId,
+    pub(crate) type_path: &'static str,
+    pub(crate) capture: CaptureFn,
+    pub(crate) apply: ApplyFn,
+}
+
+/// The non-send resources captured and applied on the main thread.
+///
+/// Non-send resources cannot be reflected from other threads, so each one is converted to a reflectable proxy value
+/// when a [`Snapshot`](crate::Snapshot) is captured, and the proxy is stored in the snapshot's resources. When applying,
+/// the proxy is passed back to the non-send resource if it exists in the [`World`].
+///
+/// Registered with
+/// [`AppSaveableExt::register_nonsend_saveable`](crate::AppSaveableExt::register_nonsend_saveable).
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// // Not `Send`, for example a handle to a platform API
+/// struct Audio {
+///     volume: std::rc::Rc<f32>,
+/// }
+///
+/// #[derive(Reflect)]
+/// struct AudioSettings {
+///     volume: f32,
+/// }
+///
+/// # let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, SavePlugins))
+///     .insert_non_send_resource(Audio {
+///         volume: std::rc::Rc::new(0.5),
+///     })
+///     .register_nonsend_saveable(
+///         |audio: &Audio| AudioSettings {
+///             volume: *audio.volume,
+///         },
+///         |audio: &mut Audio, settings: AudioSettings| {
+///             audio.volume = std::rc::Rc::new(settings.volume);
+///         },
+///     );
+///
+/// let snapshot = Snapshot::builder(&app.world)
+///     .extract_all_resources()
+///     .build();
+///
+/// assert!(snapshot.get_resource::<AudioSettings>().is_some());
+/// ```
+#[derive(Resource, Default)]
+pub struct NonSendSaveables {
+    saveables: Vec<NonSendSaveable>,
+}
+
+impl NonSendSaveables {
+    /// Save the non-send resource `T` as the proxy `R`, replacing any previous registration of `R`.
+    ///
+    /// `capture` converts the resource to the proxy when capturing, `apply` updates the resource from the proxy when
+    /// applying. The proxy type must be registered to be loaded again.
+    pub fn register<T, R>(
+        &mut self,
+        capture: impl Fn(&T) -> R + Send + Sync + 'static,
+        apply: impl Fn(&mut T, R) + Send + Sync + 'static,
+    ) where
+        T: 'static,
+        R: Reflect + FromReflect + TypePath,
+    {
+        self.saveables.retain(|s| s.proxy != TypeId::of::<R>());
+        self.saveables.push(NonSendSaveable {
+            proxy: TypeId::of::<R>(),
+            type_path: R::type_path(),
+            capture: Arc::new(move |world| {
+                let id = world.components().get_resource_id(TypeId::of::<T>())?;
+                let value = world.get_non_send_resource::<T>()?;
+                Some((id, Box::new(capture(value))))
+            }),
+            apply: Arc::new(move |world, proxy| {
+                let Some(proxy) = R::from_reflect(proxy) else {
+                    warn!(
+                        "Failed to apply non-send resource proxy `{}`",
+                        R::type_path()
+                    );
+                    return;
+                };
+
+                if let Some(mut value) = world.get_non_send_resource_mut::<T>() {
+                    apply(&mut value, proxy);
+                }
+            }),
+        });
+    }
+
+    /// Returns the number of registered non-send resources.
+    pub fn len(&self) -> usize {
+        self.saveables.len()
+    }
+
+    /// Returns `true` if no non-send resources are registered.
+    pub fn is_empty(&self) -> bool {
+        self.saveables.is_empty()
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &NonSendSaveable> {
+        self.saveables.iter()
+    }
+
+    pub(crate) fn get_by_path(&self, type_path: &str) -> Option<&NonSendSaveable> {
+        self.saveables.iter().find(|s| s.type_path == type_path)
+    }
+
+    pub(crate) fn get_by_proxy(&self, proxy: TypeId) -> Option<&NonSendSaveable> {
+        self.saveables.iter().find(|s| s.proxy == proxy)
+    }
+}
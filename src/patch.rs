@@ -0,0 +1,331 @@
+use std::fmt::Formatter;
+
+use bevy::{
+    prelude::*,
+    reflect::{
+        serde::{
+            ReflectSerializer,
+            UntypedReflectDeserializer,
+        },
+        GetPath,
+        ReflectFromReflect,
+        TypeRegistry,
+    },
+};
+use serde::{
+    de::{
+        DeserializeSeed,
+        Error as _,
+        SeqAccess,
+        Visitor,
+    },
+    ser::SerializeSeq,
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+
+use crate::Error;
+
+/// The value targeted by a [`PatchEntry`].
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum PatchTarget {
+    /// A component of an entity.
+    Component {
+        /// The entity containing the component.
+        entity: Entity,
+        /// The type path of the component.
+        type_path: String,
+    },
+
+    /// A resource.
+    Resource {
+        /// The type path of the resource.
+        type_path: String,
+    },
+}
+
+/// A single update applied by a [`ReflectPatch`].
+pub struct PatchEntry {
+    /// The component or resource to update.
+    pub target: PatchTarget,
+    /// The reflect path of the field within the target, or an empty string for the entire value.
+    pub path: String,
+    /// The new value of the field.
+    pub value: Box<dyn Reflect>,
+}
+
+/// A list of partial updates to components and resources, addressed by reflect path.
+///
+/// Complements full snapshots for tiny incremental updates, and can be serialized with
+/// [`ReflectPatchSerializer`] and [`ReflectPatchDeserializer`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component)]
+/// struct Player {
+///     health: u32,
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # app.register_type::<Player>();
+/// # let world = &mut app.world;
+/// let player = world.spawn(Player { health: 10 }).id();
+///
+/// ReflectPatch::default()
+///     .set_component::<Player>(player, "health", 50u32)
+///     .apply(world)
+///     .unwrap();
+///
+/// assert_eq!(world.get::<Player>(player).unwrap().health, 50);
+/// ```
+#[derive(Default)]
+pub struct ReflectPatch {
+    /// The updates contained in the patch, applied in order.
+    pub entries: Vec<PatchEntry>,
+}
+
+impl ReflectPatch {
+    /// Returns true if the patch contains no updates.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Set the field at `path` of the component `T` of the given entity.
+    pub fn set_component<T: Component + TypePath>(
+        mut self,
+        entity: Entity,
+        path: impl Into<String>,
+        value: impl Reflect,
+    ) -> Self {
+        self.entries.push(PatchEntry {
+            target: PatchTarget::Component {
+                entity,
+                type_path: T::type_path().to_owned(),
+            },
+            path: path.into(),
+            value: Box::new(value),
+        });
+        self
+    }
+
+    /// Set the field at `path` of the resource `T`.
+    pub fn set_resource<T: Resource + TypePath>(
+        mut self,
+        path: impl Into<String>,
+        value: impl Reflect,
+    ) -> Self {
+        self.entries.push(PatchEntry {
+            target: PatchTarget::Resource {
+                type_path: T::type_path().to_owned(),
+            },
+            path: path.into(),
+            value: Box::new(value),
+        });
+        self
+    }
+
+    /// Apply the patch to the [`World`].
+    ///
+    /// Entries are applied in order, stopping at the first failure.
+    ///
+    /// # Errors
+    /// - If a target type is not registered, or the target entity or resource does not exist
+    /// - If a path is invalid or the value does not match the type of the field
+    pub fn apply(&self, world: &mut World) -> Result<(), Error> {
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+
+        for entry in &self.entries {
+            let type_path = match &entry.target {
+                PatchTarget::Component { type_path, .. } | PatchTarget::Resource { type_path } => {
+                    type_path
+                }
+            };
+
+            let registration = registry
+                .get_with_type_path(type_path)
+                .ok_or_else(|| Error::custom(format!("unregistered type `{type_path}`")))?;
+
+            match &entry.target {
+                PatchTarget::Component { entity, .. } => {
+                    let reflect = registration.data::<ReflectComponent>().ok_or_else(|| {
+                        Error::custom(format!("`{type_path}` does not reflect `Component`"))
+                    })?;
+
+                    let mut entity = world
+                        .get_entity_mut(*entity)
+                        .ok_or_else(|| Error::custom(format!("missing entity {entity:?}")))?;
+
+                    let mut target = reflect
+                        .reflect_mut(&mut entity)
+                        .ok_or_else(|| Error::custom(format!("missing component `{type_path}`")))?;
+
+                    set_path(&mut *target, entry, &registry)?;
+                }
+                PatchTarget::Resource { .. } => {
+                    let reflect = registration.data::<ReflectResource>().ok_or_else(|| {
+                        Error::custom(format!("`{type_path}` does not reflect `Resource`"))
+                    })?;
+
+                    let mut target = reflect
+                        .reflect_mut(world)
+                        .ok_or_else(|| Error::custom(format!("missing resource `{type_path}`")))?;
+
+                    set_path(&mut *target, entry, &registry)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn set_path(
+    target: &mut dyn Reflect,
+    entry: &PatchEntry,
+    registry: &TypeRegistry,
+) -> Result<(), Error> {
+    let field = if entry.path.is_empty() {
+        target
+    } else {
+        target
+            .reflect_path_mut(entry.path.as_str())
+            .map_err(|e| Error::custom(format!("invalid path `{}`: {e}", entry.path)))?
+    };
+
+    let expected = field.get_represented_type_info().map(|i| i.type_id());
+    let actual = entry.value.get_represented_type_info().map(|i| i.type_id());
+
+    if expected != actual {
+        return Err(Error::custom(format!(
+            "mismatched type at `{}`: expected `{}`, found `{}`",
+            entry.path,
+            field.reflect_type_path(),
+            entry.value.reflect_type_path()
+        )));
+    }
+
+    let value = actual
+        .and_then(|id| registry.get_type_data::<ReflectFromReflect>(id))
+        .and_then(|r| r.from_reflect(&*entry.value));
+
+    if let Some(value) = value {
+        field
+            .set(value)
+            .map_err(|_| Error::custom(format!("failed to set `{}`", entry.path)))
+    } else {
+        field.apply(&*entry.value);
+        Ok(())
+    }
+}
+
+/// Serializer for a [`ReflectPatch`].
+pub struct ReflectPatchSerializer<'a> {
+    /// The patch to serialize.
+    pub patch: &'a ReflectPatch,
+    /// Type registry.
+    pub registry: &'a TypeRegistry,
+}
+
+impl Serialize for ReflectPatchSerializer<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.patch.entries.len()))?;
+
+        for entry in &self.patch.entries {
+            seq.serialize_element(&(
+                &entry.target,
+                &entry.path,
+                ReflectSerializer::new(&*entry.value, self.registry),
+            ))?;
+        }
+
+        seq.end()
+    }
+}
+
+/// Deserializer for a [`ReflectPatch`].
+pub struct ReflectPatchDeserializer<'a> {
+    /// Type registry.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'de> DeserializeSeed<'de> for ReflectPatchDeserializer<'_> {
+    type Value = ReflectPatch;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(PatchVisitor {
+            registry: self.registry,
+        })
+    }
+}
+
+struct PatchVisitor<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'de> Visitor<'de> for PatchVisitor<'_> {
+    type Value = ReflectPatch;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a list of patch entries")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut entries = Vec::new();
+
+        while let Some(entry) = seq.next_element_seed(EntryDeserializer {
+            registry: self.registry,
+        })? {
+            entries.push(entry);
+        }
+
+        Ok(ReflectPatch { entries })
+    }
+}
+
+struct EntryDeserializer<'a> {
+    registry: &'a TypeRegistry,
+}
+
+impl<'de> DeserializeSeed<'de> for EntryDeserializer<'_> {
+    type Value = PatchEntry;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_tuple(3, self)
+    }
+}
+
+impl<'de> Visitor<'de> for EntryDeserializer<'_> {
+    type Value = PatchEntry;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("a patch entry")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let target = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(0, &self))?;
+
+        let path = seq
+            .next_element()?
+            .ok_or_else(|| A::Error::invalid_length(1, &self))?;
+
+        let value = seq
+            .next_element_seed(UntypedReflectDeserializer::new(self.registry))?
+            .ok_or_else(|| A::Error::invalid_length(2, &self))?;
+
+        Ok(PatchEntry {
+            target,
+            path,
+            value,
+        })
+    }
+}
@@ -8,7 +8,9 @@ use crate::{
 /// Trait that defines how exactly your app saves and loads.
 pub trait Pipeline: Sized {
     /// The interface between the saver / loader and data storage.
-    type Backend: for<'a> Backend<Self::Key<'a>> + Resource + Default;
+    ///
+    /// Must also accept [`String`] keys, used when a [`SaveContext`] changes the key of a save.
+    type Backend: for<'a> Backend<Self::Key<'a>> + Backend<String> + Resource + Default;
     /// The format used for serializing and deserializing data.
     type Format: Format;
 
@@ -20,6 +22,86 @@ pub trait Pipeline: Sized {
         app.world.insert_resource(Self::Backend::default());
     }
 
+    /// Called with the [`World`] before the [`Pipeline`] is used to save or load.
+    ///
+    /// Override this to derive the key or capture filter from world state, such as the current level.
+    ///
+    /// The [`World`] is also available while capturing through [`SnapshotBuilder::world`].
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Resource)]
+    /// struct CurrentLevel(String);
+    ///
+    /// #[derive(Default)]
+    /// struct LevelPipeline(String);
+    ///
+    /// impl Pipeline for LevelPipeline {
+    ///     type Backend = DefaultBackend;
+    ///     type Format = DefaultFormat;
+    ///
+    ///     type Key<'a> = &'a str;
+    ///
+    ///     fn with_context(self, world: &World) -> Self {
+    ///         Self(format!("levels/{}", world.resource::<CurrentLevel>().0))
+    ///     }
+    ///
+    ///     fn key(&self) -> Self::Key<'_> {
+    ///         &self.0
+    ///     }
+    /// }
+    /// ```
+    fn with_context(self, world: &World) -> Self {
+        let _ = world;
+        self
+    }
+
+    /// The exclusion group of the [`Pipeline`].
+    ///
+    /// Deferred operations in the same group are never interleaved with operations of other groups.
+    /// See [`SaveQueue`].
+    fn group() -> Option<&'static str> {
+        None
+    }
+
+    /// The priority of deferred operations of the [`Pipeline`], higher priority groups are performed first.
+    ///
+    /// See [`SaveQueue`].
+    fn priority() -> i32 {
+        0
+    }
+
+    /// The [`SaveDomain`] of the [`Pipeline`], holding its own [`Rollbacks`] and [`RollbackRegistry`].
+    ///
+    /// Pipelines without a domain use the global resources. See [`SaveDomains`].
+    fn domain() -> Option<&'static str> {
+        None
+    }
+
+    /// How many checkpoints are kept when the [`Pipeline`] extracts [`Rollbacks`].
+    ///
+    /// Saving every checkpoint can make save files very large.
+    fn checkpoint_persistence() -> CheckpointPersistence {
+        CheckpointPersistence::All
+    }
+
+    /// Whether a [`SaveSummary`] is written next to each save of the [`Pipeline`].
+    ///
+    /// The summary lists the contained resources and component types, and can be read with [`SaveSummary::load`]
+    /// without loading the save. Defaults to `false`.
+    fn summary() -> bool {
+        false
+    }
+
+    /// How failed backend operations of the [`Pipeline`] are retried when saving and loading.
+    ///
+    /// A [`BackendRetry`] event is sent for every retry. Defaults to [`RetryPolicy::none`].
+    fn retry_policy() -> RetryPolicy {
+        RetryPolicy::none()
+    }
+
     /// Retrieve the unique identifier for the [`Snapshot`] being processed by the [`Pipeline`].
     fn key(&self) -> Self::Key<'_>;
 
@@ -43,6 +125,71 @@ pub trait Pipeline: Sized {
         Self::capture(builder)
     }
 
+    /// The [`SceneFilter`] applied when capturing rollback checkpoints with the [`Pipeline`].
+    ///
+    /// Types must also be allowed by the [`RollbackRegistry`] of the [`Pipeline::domain`]. Override this when whether a type rolls back depends on
+    /// world state, such as the current game mode, rather than on the type itself.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Resource)]
+    /// struct Hardcore;
+    ///
+    /// #[derive(Resource, Reflect, Default)]
+    /// #[reflect(Resource)]
+    /// struct Lives(u32);
+    ///
+    /// struct GamePipeline;
+    ///
+    /// impl Pipeline for GamePipeline {
+    ///     type Backend = DefaultBackend;
+    ///     type Format = DefaultFormat;
+    ///
+    ///     type Key<'a> = &'a str;
+    ///
+    ///     fn key(&self) -> Self::Key<'_> {
+    ///         "game"
+    ///     }
+    ///
+    ///     fn capture(builder: SnapshotBuilder) -> Snapshot {
+    ///         builder.extract_all_resources().build()
+    ///     }
+    ///
+    ///     fn checkpoint_filter(world: &World) -> SceneFilter {
+    ///         // Lives are lost for good in hardcore mode
+    ///         if world.contains_resource::<Hardcore>() {
+    ///             SceneFilter::default().deny::<Lives>()
+    ///         } else {
+    ///             SceneFilter::default()
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let mut app = App::new();
+    ///
+    /// app.add_plugins((MinimalPlugins, SavePlugins))
+    ///     .init_pipeline::<GamePipeline>()
+    ///     .register_type::<Lives>()
+    ///     .allow_rollback::<Lives>()
+    ///     .insert_resource(Lives(3))
+    ///     .insert_resource(Hardcore);
+    ///
+    /// let world = &mut app.world;
+    ///
+    /// world.checkpoint::<GamePipeline>();
+    /// world.resource_mut::<Lives>().0 = 2;
+    /// world.checkpoint::<GamePipeline>();
+    /// world.rollback::<GamePipeline>(1).unwrap();
+    ///
+    /// assert_eq!(world.resource::<Lives>().0, 2);
+    /// ```
+    fn checkpoint_filter(world: &World) -> SceneFilter {
+        let _ = world;
+        SceneFilter::default()
+    }
+
     /// Apply a [`Snapshot`] to the [`World`].
     ///
     /// Entity mapping goes here, along with your spawn hook and any other transformations you might need to perform.
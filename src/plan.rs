@@ -0,0 +1,133 @@
+use bevy::{
+    ecs::component::ComponentId,
+    prelude::*,
+};
+
+use crate::domain::rollback_registry;
+
+/// A precomputed list of the archetypes, components, and resources to visit when capturing a [`Snapshot`](crate::Snapshot).
+///
+/// Building a plan performs all filter and type registry lookups once, allowing repeated captures
+/// (e.g. every tick for rollback netcode) to skip them entirely.
+///
+/// The plan is invalidated when new archetypes or resources are added to the [`World`], or when the
+/// [`RollbackRegistry`](crate::RollbackRegistry) of a rollback plan changes, and is refreshed by
+/// [`SnapshotBuilder::extract_with_plan`](crate::SnapshotBuilder::extract_with_plan).
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # let world = &mut app.world;
+/// let mut plan = SnapshotBuilder::rollback(world).plan();
+///
+/// // Every tick
+/// let snapshot = SnapshotBuilder::rollback(world)
+///     .extract_with_plan(&mut plan)
+///     .build();
+/// ```
+pub struct CapturePlan {
+    filter: SceneFilter,
+    is_rollback: bool,
+    domain: Option<&'static str>,
+    pub(crate) archetypes: Vec<Vec<ReflectComponent>>,
+    pub(crate) resources: Vec<(ComponentId, ReflectResource)>,
+    archetype_count: usize,
+    resource_count: usize,
+    rollback_generation: u64,
+}
+
+impl CapturePlan {
+    /// Build a new [`CapturePlan`] for the [`World`], extracting only types allowed by the filter.
+    ///
+    /// If `is_rollback` is set, types will also be filtered by the [`RollbackRegistry`](crate::RollbackRegistry).
+    pub fn new(world: &World, filter: SceneFilter, is_rollback: bool) -> Self {
+        Self::with_domain(world, filter, is_rollback, None)
+    }
+
+    /// Build a new [`CapturePlan`] filtering rollbacks by the [`RollbackRegistry`](crate::RollbackRegistry) of the given
+    /// [`SaveDomain`](crate::SaveDomain).
+    pub fn with_domain(
+        world: &World,
+        filter: SceneFilter,
+        is_rollback: bool,
+        domain: Option<&'static str>,
+    ) -> Self {
+        let mut plan = Self {
+            filter,
+            is_rollback,
+            domain,
+            archetypes: Vec::new(),
+            resources: Vec::new(),
+            archetype_count: 0,
+            resource_count: 0,
+            rollback_generation: 0,
+        };
+
+        plan.rebuild(world);
+        plan
+    }
+
+    /// Returns true if the plan still matches the archetypes, resources, and rollback configuration of the [`World`].
+    pub fn is_valid(&self, world: &World) -> bool {
+        self.archetype_count == world.archetypes().len()
+            && self.resource_count == world.storages().resources.len()
+            && (!self.is_rollback
+                || rollback_registry(world, self.domain).generation() == self.rollback_generation)
+    }
+
+    /// Rebuild the plan if it has been invalidated.
+    ///
+    /// Returns true if the plan was rebuilt.
+    pub fn refresh(&mut self, world: &World) -> bool {
+        if self.is_valid(world) {
+            false
+        } else {
+            self.rebuild(world);
+            true
+        }
+    }
+
+    /// Rebuild the plan from the current state of the [`World`].
+    pub fn rebuild(&mut self, world: &World) {
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let rollbacks = rollback_registry(world, self.domain);
+
+        let reflect = |id: ComponentId| {
+            world
+                .components()
+                .get_info(id)
+                .and_then(|info| info.type_id())
+                .filter(|id| self.filter.is_allowed_by_id(*id))
+                .filter(|id| !self.is_rollback || rollbacks.is_allowed_by_id(*id))
+                .and_then(|id| registry.get(id))
+        };
+
+        self.archetypes = world
+            .archetypes()
+            .iter()
+            .map(|archetype| {
+                archetype
+                    .components()
+                    .filter_map(reflect)
+                    .filter_map(|reg| reg.data::<ReflectComponent>().cloned())
+                    .collect()
+            })
+            .collect();
+
+        self.resources = world
+            .storages()
+            .resources
+            .iter()
+            .map(|(id, _)| id)
+            .filter_map(|id| Some((id, reflect(id)?.data::<ReflectResource>()?.clone())))
+            .collect();
+
+        self.archetype_count = world.archetypes().len();
+        self.resource_count = world.storages().resources.len();
+        self.rollback_generation = rollbacks.generation();
+    }
+}
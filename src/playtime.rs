@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+/// The total time spent playing, accumulated by the [`SavePlugin`](crate::SavePlugin) every frame.
+///
+/// Uses virtual [`Time`], so time spent paused is not counted. Saved and restored like any other resource, and
+/// included in the [`SaveSummary`](crate::SaveSummary) of a save for load menus.
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use bevy_save::prelude::*;
+/// let play_time = PlayTime {
+///     total: Duration::from_secs(3 * 3600 + 25 * 60 + 7),
+/// };
+///
+/// assert_eq!(play_time.hms(), (3, 25, 7));
+/// ```
+#[derive(Resource, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Resource)]
+pub struct PlayTime {
+    /// The total time played.
+    pub total: Duration,
+}
+
+impl PlayTime {
+    /// Returns the whole hours, minutes and seconds played, for formatting in the player's locale.
+    pub fn hms(&self) -> (u64, u64, u64) {
+        let secs = self.total.as_secs();
+        (secs / 3600, secs / 60 % 60, secs % 60)
+    }
+
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn accumulate(time: Option<Res<Time>>, mut play_time: ResMut<PlayTime>) {
+        if let Some(time) = time {
+            play_time.total += time.delta();
+        }
+    }
+}
@@ -1,9 +1,18 @@
 use bevy::{
     app::PluginGroupBuilder,
     prelude::*,
+    transform::TransformSystem,
+    utils::{
+        HashMap,
+        HashSet,
+    },
 };
 
-use crate::prelude::*;
+use crate::{
+    incremental::IncrementalCaptures,
+    prelude::*,
+    retry::RetryEvents,
+};
 
 /// Default plugins for `bevy_save`.
 pub struct SavePlugins;
@@ -25,9 +34,56 @@ impl Plugin for SavePlugin {
         app
             .init_pipeline::<&str>()
             .init_pipeline::<DebugPipeline>()
+
+            .register_type::<DefaultComponents>()
+            .register_type::<Extras>()
+            .register_type::<FromSnapshot>()
+            .register_type::<MarkerTypes>()
+            .register_type::<Markers>()
+            .register_type::<PlayTime>()
+            .register_type::<RollbackConfig>()
+            .register_type::<SaveRevision>()
+            .register_type::<HashMap<String, String>>()
+            .register_type::<HashMap<String, u64>>()
+            .register_type::<SaveId>()
+            .register_type::<SaveableRng>()
+            .register_type::<Tombstones>()
+            .register_type::<HashSet<SaveId>>()
+            .register_type_data::<HashSet<SaveId>, ReflectSerialize>()
+            .register_type_data::<HashSet<SaveId>, ReflectDeserialize>()
+            .register_type::<Vec<String>>()
+            .register_type::<Vec<u32>>()
+
+            .add_event::<BackendRetry>()
+            .add_event::<LoadRequested>()
+            .add_event::<SaveConflict>()
+            .add_event::<SaveRequested>()
             
+            .init_resource::<ComponentOrder>()
+            .init_resource::<ComponentRemaps>()
+            .init_resource::<EntityKeyRemaps>()
+            .init_resource::<ExitSaves>()
+            .init_resource::<IncrementalCaptures>()
+            .init_resource::<PlayTime>()
+            .init_resource::<ResourceOrder>()
+            .init_resource::<RetryEvents>()
             .init_resource::<RollbackRegistry>()
-            .init_resource::<Rollbacks>();
+            .init_resource::<Rollbacks>()
+            .init_resource::<SaveDomains>()
+            .init_resource::<SaveInterceptors>()
+            .init_resource::<SaveQueue>()
+            .init_resource::<Tombstones>()
+
+            .configure_sets(PostUpdate, SaveSet.after(TransformSystem::TransformPropagate))
+            .add_systems(PreUpdate, PlayTime::accumulate)
+            .add_systems(PostUpdate, Tombstones::track.before(SaveSet))
+            .add_systems(PostUpdate, IncrementalCaptures::advance.in_set(SaveSet))
+            .add_systems(PostUpdate, SaveQueue::apply.in_set(SaveSet))
+            .add_systems(PostUpdate, RetryEvents::flush.after(SaveSet))
+            .add_systems(Last, ExitSaves::apply);
+
+        #[cfg(debug_assertions)]
+        app.add_systems(PostStartup, warn_unmapped_entities);
     }
 }
 
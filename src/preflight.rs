@@ -0,0 +1,348 @@
+use std::{
+    any::TypeId,
+    collections::BTreeSet,
+    fmt::Display,
+};
+
+use bevy::{
+    prelude::*,
+    reflect::TypeRegistry,
+};
+
+use crate::{
+    sparse::marker_value,
+    Backend,
+    ComponentRemaps,
+    DefaultComponents,
+    DeserializeLimits,
+    Error,
+    Format,
+    MarkerTypes,
+    Markers,
+    ModInfo,
+    ModManifest,
+    NonSendSaveables,
+    Pipeline,
+    Snapshot,
+    SnapshotDeserializer,
+    Stamped,
+};
+
+/// A reason loading a save would fail, found by [`WorldPreflightExt::preflight_load`].
+#[derive(Debug)]
+pub enum PreflightIssue {
+    /// The save could not be read or parsed.
+    Unreadable(Error),
+    /// The save was written by an incompatible version of the game.
+    Incompatible(Error),
+    /// A type in the save is not registered, and does not belong to a missing mod.
+    Unregistered {
+        /// The type path of the type.
+        type_path: String,
+    },
+    /// A component type is registered without `#[reflect(Component)]`, or a default or marker component cannot be
+    /// created.
+    NotComponent {
+        /// The type path of the type.
+        type_path: String,
+    },
+    /// A resource type is registered without `#[reflect(Resource)]`.
+    NotResource {
+        /// The type path of the type.
+        type_path: String,
+    },
+}
+
+impl Display for PreflightIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unreadable(err) => write!(f, "save could not be read: {err}"),
+            Self::Incompatible(err) => write!(f, "{err}"),
+            Self::Unregistered { type_path } => write!(f, "type `{type_path}` is not registered"),
+            Self::NotComponent { type_path } => {
+                write!(f, "type `{type_path}` is not registered as a component")
+            }
+            Self::NotResource { type_path } => {
+                write!(f, "type `{type_path}` is not registered as a resource")
+            }
+        }
+    }
+}
+
+/// The result of checking a save before loading it, created by [`WorldPreflightExt::preflight_load`].
+///
+/// Each type is only reported once, even if it is used by many entities.
+#[derive(Debug, Default)]
+pub struct PreflightReport {
+    /// Everything that would make the load fail.
+    pub issues: Vec<PreflightIssue>,
+    /// The mods the save was written with that are missing from the current [`ModManifest`].
+    ///
+    /// Their data is retained instead of failing the load, see [`MissingMods`](crate::MissingMods).
+    pub missing_mods: Vec<ModInfo>,
+}
+
+impl PreflightReport {
+    /// Returns `true` if the save can be loaded.
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+impl Display for PreflightReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for issue in &self.issues {
+            writeln!(f, "{issue}")?;
+        }
+
+        for info in &self.missing_mods {
+            writeln!(f, "mod `{}` is missing", info.name)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Collects the types that could not be resolved, reporting each once.
+#[derive(Default)]
+struct Resolver {
+    unregistered: BTreeSet<String>,
+    components: BTreeSet<String>,
+    resources: BTreeSet<String>,
+}
+
+impl Resolver {
+    fn component(&mut self, registry: &TypeRegistry, type_path: &str) {
+        let Some(registration) = registry.get_with_type_path(type_path) else {
+            self.unregistered.insert(type_path.to_owned());
+            return;
+        };
+
+        if registration.data::<ReflectComponent>().is_none() {
+            self.components.insert(type_path.to_owned());
+        }
+    }
+
+    fn default_component(&mut self, registry: &TypeRegistry, type_path: &str) {
+        let Some(registration) = registry.get_with_type_path(type_path) else {
+            self.unregistered.insert(type_path.to_owned());
+            return;
+        };
+
+        if registration.data::<ReflectComponent>().is_none()
+            || registration.data::<ReflectDefault>().is_none()
+        {
+            self.components.insert(type_path.to_owned());
+        }
+    }
+
+    fn marker(&mut self, registry: &TypeRegistry, type_path: &str) {
+        let Some(registration) = registry.get_with_type_path(type_path) else {
+            self.unregistered.insert(type_path.to_owned());
+            return;
+        };
+
+        if registration.data::<ReflectComponent>().is_none()
+            || marker_value(registration.type_info()).is_none()
+        {
+            self.components.insert(type_path.to_owned());
+        }
+    }
+
+    fn into_issues(self) -> impl Iterator<Item = PreflightIssue> {
+        let unregistered = self
+            .unregistered
+            .into_iter()
+            .map(|type_path| PreflightIssue::Unregistered { type_path });
+        let components = self
+            .components
+            .into_iter()
+            .map(|type_path| PreflightIssue::NotComponent { type_path });
+        let resources = self
+            .resources
+            .into_iter()
+            .map(|type_path| PreflightIssue::NotResource { type_path });
+
+        unregistered.chain(components).chain(resources)
+    }
+}
+
+fn resolve(
+    world: &World,
+    snapshot: &Snapshot,
+    registry: &TypeRegistry,
+    report: &mut PreflightReport,
+) {
+    let mut resolver = Resolver::default();
+
+    // Unregistered types are only skipped if they belong to a mod that is no longer loaded
+    let missing_mods = match world.get_resource::<ModManifest>() {
+        Some(current) => snapshot
+            .mod_manifest()
+            .unwrap_or_default()
+            .mods
+            .into_iter()
+            .filter(|m| current.get(&m.name).is_none())
+            .collect(),
+        None => Vec::new(),
+    };
+
+    for type_path in snapshot.skipped_types() {
+        if !missing_mods
+            .iter()
+            .any(|m| m.types.iter().any(|t| t == type_path))
+        {
+            resolver.unregistered.insert(type_path.to_owned());
+        }
+    }
+
+    report.missing_mods = missing_mods;
+
+    let non_send = world.get_resource::<NonSendSaveables>();
+
+    for resource in &snapshot.resources {
+        let Some(info) = resource.get_represented_type_info() else {
+            resolver
+                .unregistered
+                .insert(resource.reflect_type_path().to_owned());
+            continue;
+        };
+
+        if non_send.is_some_and(|n| n.get_by_proxy(info.type_id()).is_some()) {
+            continue;
+        }
+
+        match registry.get(info.type_id()) {
+            Some(registration) if registration.data::<ReflectResource>().is_some() => {}
+            Some(_) => {
+                resolver.resources.insert(info.type_path().to_owned());
+            }
+            None => {
+                resolver.unregistered.insert(info.type_path().to_owned());
+            }
+        }
+    }
+
+    let remaps = world.get_resource::<ComponentRemaps>();
+    let marker_types = snapshot.get_resource::<MarkerTypes>().unwrap_or_default();
+
+    for entity in &snapshot.entities {
+        for component in &entity.components {
+            let Some(info) = component.get_represented_type_info() else {
+                resolver
+                    .unregistered
+                    .insert(component.reflect_type_path().to_owned());
+                continue;
+            };
+
+            let type_id = info.type_id();
+
+            // Handled by the applier without being inserted
+            if type_id == TypeId::of::<Children>()
+                || type_id == TypeId::of::<Parent>()
+                || remaps.is_some_and(|r| r.is_remapped_by_id(type_id))
+            {
+                continue;
+            }
+
+            if type_id == TypeId::of::<DefaultComponents>() {
+                if let Some(defaults) = DefaultComponents::from_reflect(&**component) {
+                    for type_path in &defaults.types {
+                        resolver.default_component(registry, type_path);
+                    }
+                }
+                continue;
+            }
+
+            if type_id == TypeId::of::<Markers>() {
+                if let Some(markers) = Markers::from_reflect(&**component) {
+                    for index in markers.indices {
+                        match marker_types.types.get(index as usize) {
+                            Some(type_path) => resolver.marker(registry, type_path),
+                            None => report.issues.push(PreflightIssue::Unreadable(Error::custom(
+                                format!("invalid marker index {index}"),
+                            ))),
+                        }
+                    }
+                }
+                continue;
+            }
+
+            resolver.component(registry, info.type_path());
+        }
+    }
+
+    report.issues.extend(resolver.into_issues());
+}
+
+/// Extension trait that adds load preflight checks to Bevy's [`World`].
+pub trait WorldPreflightExt {
+    /// Reads the save of the given [`Pipeline`] and checks it against the current [`World`] without modifying it.
+    ///
+    /// Reports saves that cannot be read, incompatible game versions, and types that are not registered or cannot
+    /// be inserted, so the game can decline the load or prompt the player before anything is changed.
+    ///
+    /// # Example
+    /// ```no_run
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// struct Slot;
+    ///
+    /// impl Pipeline for Slot {
+    ///     type Backend = DefaultBackend;
+    ///     type Format = DefaultFormat;
+    ///
+    ///     type Key<'a> = &'a str;
+    ///
+    ///     fn key(&self) -> Self::Key<'_> {
+    ///         "slot1"
+    ///     }
+    /// }
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins((MinimalPlugins, SavePlugins));
+    /// # app.init_pipeline::<Slot>();
+    /// # let world = &mut app.world;
+    /// let report = world.preflight_load(Slot);
+    ///
+    /// if report.is_ok() {
+    ///     world.load(Slot).unwrap();
+    /// } else {
+    ///     println!("Cannot load save:\n{report}");
+    /// }
+    /// ```
+    fn preflight_load<P: Pipeline>(&self, pipeline: P) -> PreflightReport;
+}
+
+impl WorldPreflightExt for World {
+    fn preflight_load<P: Pipeline>(&self, pipeline: P) -> PreflightReport {
+        let pipeline = pipeline.with_context(self);
+
+        let registry = self.resource::<AppTypeRegistry>().read();
+        let backend = self.resource::<P::Backend>();
+
+        let mut report = PreflightReport::default();
+
+        // Read leniently when possible, so every unregistered type is reported instead of only the first
+        let de = SnapshotDeserializer::new(&registry)
+            .limits(DeserializeLimits::from_world(self))
+            .parallel(P::Format::self_describing())
+            .lenient(P::Format::self_describing());
+
+        let mut snapshot = match backend.load::<Stamped<P::Format>, _, _>(pipeline.key(), de) {
+            Ok(snapshot) => snapshot,
+            Err(err) => {
+                report.issues.push(PreflightIssue::Unreadable(err));
+                return report;
+            }
+        };
+
+        if let Err(err) = snapshot.check_version(self) {
+            report.issues.push(PreflightIssue::Incompatible(err));
+        }
+
+        resolve(self, &snapshot, &registry, &mut report);
+
+        report
+    }
+}
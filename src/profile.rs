@@ -0,0 +1,12 @@
+/// Enters a `tracing` span named `bevy_save::<stage>` until the end of the current scope.
+///
+/// Only enabled with the `profile` feature, so spans can be as fine-grained as needed without affecting release builds.
+macro_rules! profile_span {
+    ($stage:literal $(, $($fields:tt)*)?) => {
+        #[cfg(feature = "profile")]
+        let _span =
+            bevy::utils::tracing::info_span!(concat!("bevy_save::", $stage) $(, $($fields)*)?).entered();
+    };
+}
+
+pub(crate) use profile_span;
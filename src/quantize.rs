@@ -0,0 +1,238 @@
+use std::marker::PhantomData;
+
+use bevy::reflect::{
+    FromType,
+    Reflect,
+    ReflectMut,
+    TypeRegistry,
+};
+
+use crate::Format;
+
+/// Controls how floating point values are quantized before serialization.
+///
+/// Quantizing floats shrinks saves and keeps text diffs stable across small simulation jitter.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FloatPrecision {
+    /// Floats are serialized exactly.
+    #[default]
+    Exact,
+
+    /// Floats are rounded to the given number of decimal places.
+    Decimals(u32),
+
+    /// Floats are rounded to the given number of mantissa bits.
+    ///
+    /// `SignificantBits(10)` matches the precision of an `f16`.
+    SignificantBits(u32),
+}
+
+impl FloatPrecision {
+    /// Quantize a single `f32`.
+    ///
+    /// Values that would not stay finite, such as rounding up near [`f32::MAX`], are returned unchanged.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn quantize_f32(self, value: f32) -> f32 {
+        if !value.is_finite() {
+            return value;
+        }
+
+        let quantized = match self {
+            Self::Decimals(_) => self.quantize_f64(f64::from(value)) as f32,
+            Self::SignificantBits(bits) if bits < 23 => {
+                let shift = 23 - bits;
+                let rounded = value.to_bits() + (1 << (shift - 1));
+                f32::from_bits(rounded & !((1 << shift) - 1))
+            }
+            Self::Exact | Self::SignificantBits(_) => value,
+        };
+
+        if quantized.is_finite() {
+            quantized
+        } else {
+            value
+        }
+    }
+
+    /// Quantize a single `f64`.
+    ///
+    /// Values that would not stay finite, such as rounding up near [`f64::MAX`], are returned unchanged.
+    pub fn quantize_f64(self, value: f64) -> f64 {
+        if !value.is_finite() {
+            return value;
+        }
+
+        let quantized = match self {
+            Self::Decimals(places) => {
+                let scale = 10f64.powi(i32::try_from(places).unwrap_or(i32::MAX));
+                (value * scale).round() / scale
+            }
+            Self::SignificantBits(bits) if bits < 52 => {
+                let shift = 52 - bits;
+                let rounded = value.to_bits() + (1 << (shift - 1));
+                f64::from_bits(rounded & !((1 << shift) - 1))
+            }
+            Self::Exact | Self::SignificantBits(_) => value,
+        };
+
+        if quantized.is_finite() {
+            quantized
+        } else {
+            value
+        }
+    }
+
+    /// Recursively quantize every float contained in the reflected value.
+    ///
+    /// Types registered with [`ReflectExactFloats`] are left untouched.
+    pub fn quantize(self, value: &mut dyn Reflect, registry: &TypeRegistry) {
+        if self == Self::Exact {
+            return;
+        }
+
+        let exact = value
+            .get_represented_type_info()
+            .and_then(|info| registry.get_type_data::<ReflectExactFloats>(info.type_id()))
+            .is_some();
+
+        if exact {
+            return;
+        }
+
+        match value.reflect_mut() {
+            ReflectMut::Struct(s) => {
+                for i in 0..s.field_len() {
+                    if let Some(field) = s.field_at_mut(i) {
+                        self.quantize(field, registry);
+                    }
+                }
+            }
+            ReflectMut::TupleStruct(s) => {
+                for i in 0..s.field_len() {
+                    if let Some(field) = s.field_mut(i) {
+                        self.quantize(field, registry);
+                    }
+                }
+            }
+            ReflectMut::Tuple(t) => {
+                for i in 0..t.field_len() {
+                    if let Some(field) = t.field_mut(i) {
+                        self.quantize(field, registry);
+                    }
+                }
+            }
+            ReflectMut::List(l) => {
+                for i in 0..l.len() {
+                    if let Some(item) = l.get_mut(i) {
+                        self.quantize(item, registry);
+                    }
+                }
+            }
+            ReflectMut::Array(a) => {
+                for i in 0..a.len() {
+                    if let Some(item) = a.get_mut(i) {
+                        self.quantize(item, registry);
+                    }
+                }
+            }
+            ReflectMut::Map(m) => {
+                for i in 0..m.len() {
+                    if let Some((_, item)) = m.get_at_mut(i) {
+                        self.quantize(item, registry);
+                    }
+                }
+            }
+            ReflectMut::Enum(e) => {
+                for i in 0..e.field_len() {
+                    if let Some(field) = e.field_at_mut(i) {
+                        self.quantize(field, registry);
+                    }
+                }
+            }
+            ReflectMut::Value(v) => {
+                if let Some(v) = v.downcast_mut::<f32>() {
+                    *v = self.quantize_f32(*v);
+                } else if let Some(v) = v.downcast_mut::<f64>() {
+                    *v = self.quantize_f64(*v);
+                }
+            }
+        }
+    }
+}
+
+/// Type data that opts a type out of float quantization, preserving an exact round-trip.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component, ExactFloats)]
+/// struct Seed(f64);
+/// ```
+#[derive(Clone)]
+pub struct ReflectExactFloats;
+
+impl<T: Reflect> FromType<T> for ReflectExactFloats {
+    fn from_type() -> Self {
+        Self
+    }
+}
+
+/// Middleware that quantizes floats to the given number of decimal places before serializing with `F`.
+///
+/// # Example
+/// ```rust
+/// # use bevy_save::prelude::*;
+/// struct MyPipeline;
+///
+/// impl Pipeline for MyPipeline {
+///     type Backend = DefaultDebugBackend;
+///     /// This will emit JSON with floats rounded to 3 decimal places
+///     type Format = Quantized<DefaultDebugFormat, 3>;
+///     type Key<'a> = &'a str;
+///
+///     fn key(&self) -> Self::Key<'_> {
+///         "my_pipeline"
+///     }
+/// }
+/// ```
+pub struct Quantized<F, const DECIMALS: u32>(PhantomData<F>);
+
+impl<F, const DECIMALS: u32> Default for Quantized<F, DECIMALS> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<F: Format, const DECIMALS: u32> Format for Quantized<F, DECIMALS> {
+    fn name() -> &'static str {
+        F::name()
+    }
+
+    fn extension() -> &'static str {
+        F::extension()
+    }
+
+    fn mime_type() -> &'static str {
+        F::mime_type()
+    }
+
+    fn float_precision() -> FloatPrecision {
+        FloatPrecision::Decimals(DECIMALS)
+    }
+
+    fn serialize<W: std::io::Write, T: serde::Serialize>(
+        writer: W,
+        value: &T,
+    ) -> Result<(), crate::Error> {
+        F::serialize(writer, value)
+    }
+
+    fn deserialize<R: std::io::Read, S: for<'de> serde::de::DeserializeSeed<'de, Value = T>, T>(
+        reader: R,
+        seed: S,
+    ) -> Result<T, crate::Error> {
+        F::deserialize(reader, seed)
+    }
+}
@@ -0,0 +1,209 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{
+    Error,
+    Pipeline,
+    SaveQueue,
+    SaveSet,
+    WorldRollbackExt,
+    WorldSaveableExt,
+};
+
+/// An operation performed by the [`QuickSavePlugin`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuickAction {
+    /// Save with the pipeline.
+    Save,
+    /// Load with the pipeline.
+    Load,
+    /// Create a rollback checkpoint.
+    Checkpoint,
+    /// Roll back by one checkpoint.
+    Rollback,
+    /// Roll forward by one checkpoint.
+    Rollforward,
+}
+
+/// Event requesting a [`QuickAction`], sent by key bindings or by the game.
+#[derive(Event, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuickSaveRequest(pub QuickAction);
+
+/// Event reporting the progress of a [`QuickAction`], for example to show a "Saving..." indicator.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub enum QuickSaveEvent {
+    /// The action was accepted and will be performed at the [`SaveSet`] barrier.
+    Started(QuickAction),
+    /// The action completed successfully.
+    Finished(QuickAction),
+    /// The action failed with the given error message.
+    Failed(QuickAction, String),
+    /// The action was ignored, because another action was still in progress or the cooldown had not elapsed.
+    Rejected(QuickAction),
+}
+
+/// Adds quick save / quick load to an app with the [`Pipeline`] `P`.
+///
+/// Actions are requested with key bindings or [`QuickSaveRequest`] events, and performed through the [`SaveQueue`].
+/// While an action is in progress, or until the cooldown has elapsed, further requests are rejected, so a load can
+/// never start during an ongoing save. Progress is reported with [`QuickSaveEvent`]s.
+///
+/// # Example
+/// ```no_run
+/// # use std::time::Duration;
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// App::new()
+///     .add_plugins((DefaultPlugins, SavePlugins))
+///     .add_plugins(
+///         QuickSavePlugin::new("quicksave")
+///             .bind(KeyCode::F5, QuickAction::Save)
+///             .bind(KeyCode::F9, QuickAction::Load)
+///             .cooldown(Duration::from_secs(1)),
+///     );
+/// ```
+pub struct QuickSavePlugin<P> {
+    pipeline: P,
+    bindings: Vec<(KeyCode, QuickAction)>,
+    cooldown: Duration,
+}
+
+impl<P> QuickSavePlugin<P> {
+    /// Create a [`QuickSavePlugin`] without key bindings or cooldown.
+    pub fn new(pipeline: P) -> Self {
+        Self {
+            pipeline,
+            bindings: Vec::new(),
+            cooldown: Duration::ZERO,
+        }
+    }
+
+    /// Request the action when the key is pressed.
+    pub fn bind(mut self, key: KeyCode, action: QuickAction) -> Self {
+        self.bindings.push((key, action));
+        self
+    }
+
+    /// Reject requests until the given duration has elapsed since the last accepted request.
+    pub fn cooldown(mut self, cooldown: Duration) -> Self {
+        self.cooldown = cooldown;
+        self
+    }
+}
+
+impl<P: Pipeline + Clone + Send + Sync + 'static> Plugin for QuickSavePlugin<P> {
+    fn build(&self, app: &mut App) {
+        app.add_event::<QuickSaveRequest>()
+            .add_event::<QuickSaveEvent>()
+            .insert_resource(QuickSave {
+                pipeline: self.pipeline.clone(),
+                bindings: self.bindings.clone(),
+                cooldown: self.cooldown,
+                last: None,
+                pending: None,
+            })
+            .add_systems(PreUpdate, QuickSave::<P>::input)
+            .add_systems(PostUpdate, QuickSave::<P>::handle.before(SaveSet));
+    }
+}
+
+/// The state of the [`QuickSavePlugin`] for the [`Pipeline`] `P`.
+#[derive(Resource)]
+pub struct QuickSave<P> {
+    pipeline: P,
+    bindings: Vec<(KeyCode, QuickAction)>,
+    cooldown: Duration,
+    last: Option<Duration>,
+    pending: Option<QuickAction>,
+}
+
+impl<P> QuickSave<P> {
+    /// Returns the action in progress, if any.
+    pub fn pending(&self) -> Option<QuickAction> {
+        self.pending
+    }
+
+    /// Change the cooldown between accepted requests.
+    pub fn set_cooldown(&mut self, cooldown: Duration) {
+        self.cooldown = cooldown;
+    }
+}
+
+impl<P: Pipeline + Clone + Send + Sync + 'static> QuickSave<P> {
+    /// System sending a [`QuickSaveRequest`] for each pressed key binding.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn input(
+        quick: Res<Self>,
+        keys: Option<Res<ButtonInput<KeyCode>>>,
+        mut requests: EventWriter<QuickSaveRequest>,
+    ) {
+        let Some(keys) = keys else {
+            return;
+        };
+
+        for (key, action) in &quick.bindings {
+            if keys.just_pressed(*key) {
+                requests.send(QuickSaveRequest(*action));
+            }
+        }
+    }
+
+    /// System enqueueing the requested actions, unless one is in progress or the cooldown has not elapsed.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn handle(
+        mut quick: ResMut<Self>,
+        mut requests: EventReader<QuickSaveRequest>,
+        mut events: EventWriter<QuickSaveEvent>,
+        mut queue: ResMut<SaveQueue>,
+        time: Option<Res<Time<Real>>>,
+    ) {
+        let now = time.map_or(Duration::ZERO, |t| t.elapsed());
+
+        for &QuickSaveRequest(action) in requests.read() {
+            let cooling = quick
+                .last
+                .is_some_and(|last| now.saturating_sub(last) < quick.cooldown);
+
+            if quick.pending.is_some() || cooling {
+                events.send(QuickSaveEvent::Rejected(action));
+                continue;
+            }
+
+            quick.pending = Some(action);
+            quick.last = Some(now);
+
+            events.send(QuickSaveEvent::Started(action));
+
+            let pipeline = quick.pipeline.clone();
+
+            queue.push_grouped(P::group(), P::priority(), move |world| {
+                let result = perform(world, pipeline, action);
+
+                if let Some(mut quick) = world.get_resource_mut::<Self>() {
+                    quick.pending = None;
+                }
+
+                world.send_event(match &result {
+                    Ok(()) => QuickSaveEvent::Finished(action),
+                    Err(err) => QuickSaveEvent::Failed(action, err.to_string()),
+                });
+
+                result
+            });
+        }
+    }
+}
+
+fn perform<P: Pipeline>(world: &mut World, pipeline: P, action: QuickAction) -> Result<(), Error> {
+    match action {
+        QuickAction::Save => world.save(pipeline),
+        QuickAction::Load => world.load(pipeline),
+        QuickAction::Checkpoint => {
+            world.checkpoint::<P>();
+            Ok(())
+        }
+        QuickAction::Rollback => world.rollback::<P>(1),
+        QuickAction::Rollforward => world.rollback::<P>(-1),
+    }
+}
@@ -0,0 +1,344 @@
+use std::{
+    fmt::Display,
+    sync::Mutex,
+    time::SystemTime,
+};
+
+use bevy::prelude::*;
+use serde::{
+    de::DeserializeSeed,
+    Serialize,
+};
+
+use crate::{
+    batch::{
+        Payload,
+        Raw,
+    },
+    Backend,
+    Error,
+    Format,
+};
+
+/// A save slot listed by a [`SlotStorage`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlotInfo {
+    /// The name of the slot, such as `profile/slot1`.
+    pub name: String,
+    /// The total size of the slot in bytes.
+    pub bytes: u64,
+    /// When the slot was last written.
+    pub modified: SystemTime,
+}
+
+impl SlotInfo {
+    /// Returns the group of the slot, the part of its name before the last `/`.
+    ///
+    /// Slots of different groups, such as different player profiles, do not count towards each other's quota.
+    pub fn group(&self) -> &str {
+        slot_group(&self.name)
+    }
+}
+
+fn slot_group(name: &str) -> &str {
+    name.rsplit_once('/').map_or("", |(group, _)| group)
+}
+
+/// Storage that can list and delete whole save slots, allowing a [`SaveQuota`] to be enforced.
+pub trait SlotStorage {
+    /// Returns the name of the slot the key is stored in.
+    fn slot_of(&self, key: &str) -> String;
+
+    /// Returns all slots in the storage.
+    ///
+    /// # Errors
+    /// If the slots could not be listed.
+    fn slots(&self) -> Result<Vec<SlotInfo>, Error>;
+
+    /// Delete the slot and everything stored in it.
+    ///
+    /// # Errors
+    /// If the slot could not be deleted.
+    fn delete_slot(&self, name: &str) -> Result<(), Error>;
+}
+
+/// What happens when a save would exceed its [`SaveQuota`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum QuotaPolicy {
+    /// Refuse the save with [`Error::QuotaExceeded`].
+    #[default]
+    Refuse,
+
+    /// Delete the oldest autosaves of the same group until the save fits, refusing it if that is not enough.
+    DeleteOldestAutosave,
+}
+
+/// Limits on the number and size of save slots, checked whenever a save is written.
+///
+/// Limits apply per group of slots, so saves of different player profiles are counted separately.
+/// See [`SlotInfo::group`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SaveQuota {
+    /// The maximum number of slots in a group.
+    pub max_slots: Option<usize>,
+    /// The maximum total size of the slots in a group, in bytes.
+    pub max_bytes: Option<u64>,
+    /// What happens when a save would exceed the quota.
+    pub policy: QuotaPolicy,
+    /// Slots whose name within their group starts with this prefix are autosaves.
+    pub autosave_prefix: String,
+}
+
+impl Default for SaveQuota {
+    fn default() -> Self {
+        Self {
+            max_slots: None,
+            max_bytes: None,
+            policy: QuotaPolicy::default(),
+            autosave_prefix: "autosave".into(),
+        }
+    }
+}
+
+impl SaveQuota {
+    /// Limit the number of slots in each group.
+    pub fn max_slots(mut self, max_slots: usize) -> Self {
+        self.max_slots = Some(max_slots);
+        self
+    }
+
+    /// Limit the total size of the slots in each group, in bytes.
+    pub fn max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Set what happens when a save would exceed the quota.
+    pub fn policy(mut self, policy: QuotaPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Set the prefix identifying autosave slots.
+    pub fn autosave_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.autosave_prefix = prefix.into();
+        self
+    }
+
+    /// Returns `true` if the slot is an autosave.
+    pub fn is_autosave(&self, slot: &SlotInfo) -> bool {
+        let name = slot.name.rsplit_once('/').map_or(&*slot.name, |(_, n)| n);
+        name.starts_with(&self.autosave_prefix)
+    }
+
+    fn fits(&self, slots: &[SlotInfo], bytes: u64) -> bool {
+        if let Some(max) = self.max_slots {
+            if slots.len() >= max {
+                return false;
+            }
+        }
+
+        if let Some(max) = self.max_bytes {
+            if slots.iter().map(|s| s.bytes).sum::<u64>() + bytes > max {
+                return false;
+            }
+        }
+
+        true
+    }
+}
+
+/// Sent when a slot is deleted to enforce a [`SaveQuota`], so the game can inform the player.
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct SlotCleanup {
+    /// The deleted slot.
+    pub slot: SlotInfo,
+    /// The slot whose save caused the cleanup.
+    pub saved: String,
+}
+
+/// Backend middleware enforcing a [`SaveQuota`] on a [`SlotStorage`] whenever a save is written.
+///
+/// Saves are serialized first, so their size is known before anything is deleted. Overwriting a slot replaces its
+/// size rather than adding to it.
+///
+/// Added by [`AppSaveableExt::save_quota`](crate::AppSaveableExt::save_quota), which also sends a [`SlotCleanup`]
+/// event for each deleted slot.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// struct Autosave;
+///
+/// impl Pipeline for Autosave {
+///     type Backend = Quota<DirBackend>;
+///     type Format = DefaultFormat;
+///
+///     type Key<'a> = &'a str;
+///
+///     fn key(&self) -> Self::Key<'_> {
+///         "autosave"
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugins(SavePlugins)
+///     .init_pipeline::<Autosave>()
+///     .save_quota::<DirBackend>(
+///         SaveQuota::default()
+///             .max_slots(10)
+///             .policy(QuotaPolicy::DeleteOldestAutosave),
+///     );
+/// ```
+#[derive(Resource)]
+pub struct Quota<B> {
+    inner: B,
+    limits: SaveQuota,
+    cleanups: Mutex<Vec<SlotCleanup>>,
+}
+
+impl<B: Default> Default for Quota<B> {
+    fn default() -> Self {
+        Self::new(B::default(), SaveQuota::default())
+    }
+}
+
+impl<B> Quota<B> {
+    /// Create a new [`Quota`] backend wrapping the given backend.
+    pub fn new(inner: B, quota: SaveQuota) -> Self {
+        Self {
+            inner,
+            limits: quota,
+            cleanups: Mutex::default(),
+        }
+    }
+
+    /// Returns the wrapped backend.
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Returns the enforced quota.
+    pub fn quota(&self) -> &SaveQuota {
+        &self.limits
+    }
+
+    /// Replace the enforced quota.
+    pub fn set_quota(&mut self, quota: SaveQuota) {
+        self.limits = quota;
+    }
+
+    /// Returns the slots deleted since the last call.
+    ///
+    /// # Panics
+    /// If a thread panicked while saving.
+    pub fn take_cleanups(&self) -> Vec<SlotCleanup> {
+        std::mem::take(&mut *self.cleanups.lock().expect("Quota lock poisoned"))
+    }
+}
+
+impl<B: Resource> Quota<B> {
+    /// System sending a [`SlotCleanup`] event for each deleted slot.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn send_cleanup_events(quota: Res<Self>, mut events: EventWriter<SlotCleanup>) {
+        events.send_batch(quota.take_cleanups());
+    }
+}
+
+impl<B: SlotStorage> Quota<B> {
+    /// Make room for a save of the given size to the slot, according to the [`QuotaPolicy`].
+    ///
+    /// Nothing is deleted if the save cannot be made to fit.
+    fn enforce(&self, slot: &str, bytes: u64) -> Result<(), Error> {
+        let mut slots = self
+            .inner
+            .slots()?
+            .into_iter()
+            .filter(|s| s.name != slot && s.group() == slot_group(slot))
+            .collect::<Vec<_>>();
+
+        let mut autosaves = match self.limits.policy {
+            QuotaPolicy::Refuse => Vec::new(),
+            QuotaPolicy::DeleteOldestAutosave => slots
+                .iter()
+                .filter(|s| self.limits.is_autosave(s))
+                .cloned()
+                .collect(),
+        };
+
+        autosaves.sort_by_key(|s| s.modified);
+
+        // Deleting more slots only frees more room, so the oldest autosaves are planned first
+        let mut deleted = Vec::new();
+
+        for autosave in autosaves {
+            if self.limits.fits(&slots, bytes) {
+                break;
+            }
+
+            slots.retain(|s| s.name != autosave.name);
+            deleted.push(autosave);
+        }
+
+        if !self.limits.fits(&slots, bytes) {
+            return Err(Error::QuotaExceeded);
+        }
+
+        for deleted in deleted {
+            self.inner.delete_slot(&deleted.name)?;
+
+            self.cleanups
+                .lock()
+                .expect("Quota lock poisoned")
+                .push(SlotCleanup {
+                    slot: deleted,
+                    saved: slot.to_owned(),
+                });
+        }
+
+        Ok(())
+    }
+}
+
+impl<K: Display, B: Backend<String> + SlotStorage> Backend<K> for Quota<B> {
+    fn save<F: Format, T: Serialize>(&self, key: K, value: &T) -> Result<(), Error> {
+        let key = key.to_string();
+
+        let mut data = Vec::new();
+        F::serialize(&mut data, value)?;
+
+        self.enforce(&self.inner.slot_of(&key), data.len() as u64)?;
+
+        self.inner.save::<Raw<F>, _>(key, &Payload(&data))
+    }
+
+    fn load<F: Format, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        &self,
+        key: K,
+        seed: S,
+    ) -> Result<T, Error> {
+        self.inner.load::<F, S, T>(key.to_string(), seed)
+    }
+
+    fn etag<F: Format>(&self, key: K) -> Result<Option<String>, Error> {
+        self.inner.etag::<F>(key.to_string())
+    }
+
+    fn save_if_match<F: Format, T: Serialize>(
+        &self,
+        key: K,
+        value: &T,
+        etag: Option<&str>,
+    ) -> Result<(), Error> {
+        let key = key.to_string();
+
+        let mut data = Vec::new();
+        F::serialize(&mut data, value)?;
+
+        self.enforce(&self.inner.slot_of(&key), data.len() as u64)?;
+
+        self.inner
+            .save_if_match::<Raw<F>, _>(key, &Payload(&data), etag)
+    }
+}
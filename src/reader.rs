@@ -0,0 +1,458 @@
+use std::{
+    fmt::Formatter,
+    io::Read,
+};
+
+use bevy::{
+    ecs::entity::EntityHashMap,
+    prelude::*,
+    reflect::TypeRegistry,
+};
+use serde::de::{
+    value::Error as ValueError,
+    DeserializeSeed,
+    Error as _,
+    IgnoredAny,
+    MapAccess,
+    SeqAccess,
+    Visitor,
+};
+
+use crate::{
+    content::{
+        Content,
+        ContentDeserializer,
+    },
+    serde::{
+        EntityDeserializer,
+        ReflectMapDeserializer,
+        SnapshotField,
+        ENTITY_COMPONENTS,
+        SNAPSHOT_ENTITIES,
+        SNAPSHOT_RESOURCES,
+        SNAPSHOT_ROLLBACKS,
+        SNAPSHOT_STRUCT,
+    },
+    Backend,
+    DeserializeLimits,
+    Error,
+    Format,
+    Pipeline,
+    Snapshot,
+    Stamped,
+    UnknownData,
+};
+
+/// An entity of a [`SnapshotReader`], buffered without deserializing its components.
+struct LazyEntity {
+    entity: Entity,
+    types: Vec<String>,
+    content: Content,
+}
+
+/// Reads a save lazily, deserializing and applying only the entities needed at the moment.
+///
+/// Reading a save only indexes its entities, keeping each one as a buffered fragment of the format along with the type
+/// paths of its components. Entities are then deserialized on demand by entity or by component type, which avoids the
+/// cost of reflecting the entire save, for example in large open worlds.
+///
+/// Requires a [self-describing](Format::self_describing) format. Rollbacks are skipped.
+///
+/// # Example
+/// ```
+/// # use bevy::{ecs::entity::EntityHashMap, prelude::*};
+/// # use bevy_save::prelude::*;
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component)]
+/// struct Chunk(u32);
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # app.register_type::<Chunk>();
+/// # let world = &mut app.world;
+/// for i in 0..100 {
+///     world.spawn(Chunk(i));
+/// }
+///
+/// let snapshot = Snapshot::builder(world).extract_all_entities().build();
+///
+/// let mut data = Vec::new();
+/// DefaultFormat::serialize(
+///     &mut data,
+///     &SnapshotSerializer::new(&snapshot, world.resource::<AppTypeRegistry>()),
+/// )
+/// .unwrap();
+///
+/// world.clear_entities();
+///
+/// let reader = SnapshotReader::from_reader::<DefaultFormat>(&*data).unwrap();
+/// let nearby = reader.entities().take(10).collect::<Vec<_>>();
+///
+/// let mut entity_map = EntityHashMap::default();
+/// reader.apply_entities(world, nearby, &mut entity_map).unwrap();
+///
+/// assert_eq!(world.entities().len(), 10);
+/// ```
+pub struct SnapshotReader {
+    entities: Vec<LazyEntity>,
+    index: EntityHashMap<usize>,
+    resources: Option<Content>,
+    limits: DeserializeLimits,
+}
+
+impl SnapshotReader {
+    /// Index a save written with the [`Format`] `F`, with or without a [`SaveHeader`](crate::SaveHeader).
+    ///
+    /// # Errors
+    /// - If the format is not self-describing
+    /// - If the save could not be read or indexed
+    pub fn from_reader<F: Format>(reader: impl Read) -> Result<Self, Error> {
+        Self::read::<F>(reader, DeserializeLimits::default())
+    }
+
+    /// Index the save of the [`Pipeline`], using the [`DeserializeLimits`] of the [`World`].
+    ///
+    /// # Errors
+    /// - If the format of the pipeline is not self-describing
+    /// - If the save could not be loaded or indexed
+    pub fn load<P: Pipeline>(world: &World, pipeline: P) -> Result<Self, Error> {
+        check_self_describing::<P::Format>()?;
+
+        let pipeline = pipeline.with_context(world);
+        let limits = DeserializeLimits::from_world(world);
+
+        let (entities, resources) = world
+            .resource::<P::Backend>()
+            .load::<Stamped<P::Format>, _, _>(pipeline.key(), IndexDeserializer { limits })?;
+
+        Ok(Self::new(entities, resources, limits))
+    }
+
+    /// Index a save written with the [`Format`] `F`, enforcing the given [`DeserializeLimits`].
+    ///
+    /// # Errors
+    /// - If the format is not self-describing
+    /// - If the save could not be read or indexed
+    pub fn read<F: Format>(reader: impl Read, limits: DeserializeLimits) -> Result<Self, Error> {
+        check_self_describing::<F>()?;
+
+        let (entities, resources) =
+            Stamped::<F>::deserialize(reader, IndexDeserializer { limits })?;
+
+        Ok(Self::new(entities, resources, limits))
+    }
+
+    fn new(
+        entities: Vec<LazyEntity>,
+        resources: Option<Content>,
+        limits: DeserializeLimits,
+    ) -> Self {
+        let index = entities
+            .iter()
+            .enumerate()
+            .map(|(i, e)| (e.entity, i))
+            .collect();
+
+        Self {
+            entities,
+            index,
+            resources,
+            limits,
+        }
+    }
+
+    /// Returns the number of entities in the save.
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Returns `true` if the save contains no entities.
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    /// Returns `true` if the save contains the entity.
+    pub fn contains(&self, entity: Entity) -> bool {
+        self.index.contains_key(&entity)
+    }
+
+    /// Returns the entities of the save, in the order they were saved.
+    pub fn entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.entities.iter().map(|e| e.entity)
+    }
+
+    /// Returns the type paths of the components saved for the entity.
+    pub fn component_types(&self, entity: Entity) -> Option<impl Iterator<Item = &str>> {
+        let lazy = &self.entities[*self.index.get(&entity)?];
+        Some(lazy.types.iter().map(String::as_str))
+    }
+
+    /// Returns the entities with a saved component of the type `T`.
+    pub fn entities_with<T: TypePath>(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.entities_with_path(T::type_path())
+    }
+
+    /// Returns the entities with a saved component with the given type path.
+    pub fn entities_with_path<'a>(
+        &'a self,
+        type_path: &'a str,
+    ) -> impl Iterator<Item = Entity> + 'a {
+        self.entities
+            .iter()
+            .filter(move |e| e.types.iter().any(|t| t == type_path))
+            .map(|e| e.entity)
+    }
+
+    /// Deserialize the given entities into a [`Snapshot`] without resources.
+    ///
+    /// Entities that are not in the save are skipped.
+    ///
+    /// # Errors
+    /// If an entity could not be deserialized.
+    pub fn read_entities(
+        &self,
+        entities: impl IntoIterator<Item = Entity>,
+        registry: &TypeRegistry,
+    ) -> Result<Snapshot, Error> {
+        let entities = entities
+            .into_iter()
+            .filter_map(|e| self.index.get(&e))
+            .map(|i| {
+                let lazy = &self.entities[*i];
+
+                EntityDeserializer {
+                    entity: lazy.entity,
+                    registry,
+                    limits: self.limits,
+                    unknown: None,
+                }
+                .deserialize(ContentDeserializer::<ValueError>::new(lazy.content.clone()))
+                .map_err(Error::loading)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Snapshot {
+            entities,
+            resources: Vec::new(),
+            rollbacks: None,
+            unknown: UnknownData::default(),
+        })
+    }
+
+    /// Deserialize the entities with a saved component of the type `T` into a [`Snapshot`] without resources.
+    ///
+    /// # Errors
+    /// If an entity could not be deserialized.
+    pub fn read_entities_with<T: TypePath>(
+        &self,
+        registry: &TypeRegistry,
+    ) -> Result<Snapshot, Error> {
+        self.read_entities(self.entities_with::<T>().collect::<Vec<_>>(), registry)
+    }
+
+    /// Deserialize the resources of the save into a [`Snapshot`] without entities.
+    ///
+    /// # Errors
+    /// If a resource could not be deserialized.
+    pub fn read_resources(&self, registry: &TypeRegistry) -> Result<Snapshot, Error> {
+        let resources = match &self.resources {
+            Some(content) => ReflectMapDeserializer {
+                registry,
+                limits: self.limits,
+                unknown: None,
+                owner: None,
+            }
+            .deserialize(ContentDeserializer::<ValueError>::new(content.clone()))
+            .map_err(Error::loading)?,
+            None => Vec::new(),
+        };
+
+        Ok(Snapshot {
+            entities: Vec::new(),
+            resources,
+            rollbacks: None,
+            unknown: UnknownData::default(),
+        })
+    }
+
+    /// Deserialize and apply the given entities to the [`World`].
+    ///
+    /// Pass the same `entity_map` to each call, so references between entities applied at different times are mapped
+    /// to the same entities in the world.
+    ///
+    /// # Errors
+    /// - If an entity could not be deserialized
+    /// - See [`SnapshotApplier::apply`](crate::SnapshotApplier::apply)
+    pub fn apply_entities(
+        &self,
+        world: &mut World,
+        entities: impl IntoIterator<Item = Entity>,
+        entity_map: &mut EntityHashMap<Entity>,
+    ) -> Result<(), Error> {
+        let snapshot = {
+            let registry = world.resource::<AppTypeRegistry>().read();
+            self.read_entities(entities, &registry)?
+        };
+
+        snapshot.applier(world).entity_map(entity_map).apply()
+    }
+}
+
+fn check_self_describing<F: Format>() -> Result<(), Error> {
+    if F::self_describing() {
+        Ok(())
+    } else {
+        Err(Error::custom(format_args!(
+            "`{}` is not self-describing and cannot be read lazily",
+            F::name()
+        )))
+    }
+}
+
+/// Returns the type paths of the components of a buffered entity.
+fn component_types(content: &Content) -> Vec<String> {
+    let components = match content {
+        Content::Map(fields) => fields
+            .iter()
+            .find(|(k, _)| matches!(k, Content::String(s) if s == ENTITY_COMPONENTS))
+            .map(|(_, v)| v),
+        Content::Seq(fields) => fields.first(),
+        _ => None,
+    };
+
+    let Some(Content::Map(entries)) = components else {
+        return Vec::new();
+    };
+
+    entries
+        .iter()
+        .filter_map(|(k, _)| match k {
+            Content::String(s) => Some(s.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+type Index = (Vec<LazyEntity>, Option<Content>);
+
+struct IndexDeserializer {
+    limits: DeserializeLimits,
+}
+
+impl<'de> DeserializeSeed<'de> for IndexDeserializer {
+    type Value = Index;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            SNAPSHOT_STRUCT,
+            &[SNAPSHOT_ENTITIES, SNAPSHOT_RESOURCES, SNAPSHOT_ROLLBACKS],
+            self,
+        )
+    }
+}
+
+impl<'de> Visitor<'de> for IndexDeserializer {
+    type Value = Index;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("snapshot struct")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entities = None;
+        let mut resources = None;
+
+        while let Some(key) = map.next_key()? {
+            match key {
+                SnapshotField::Entities => {
+                    entities = Some(map.next_value_seed(EntityIndexDeserializer {
+                        limits: self.limits,
+                    })?);
+                }
+                SnapshotField::Resources => {
+                    resources = Some(map.next_value::<Content>()?);
+                }
+                SnapshotField::Rollbacks => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        let entities = entities.ok_or_else(|| A::Error::missing_field(SNAPSHOT_ENTITIES))?;
+
+        Ok((entities, resources))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let entities = seq
+            .next_element_seed(EntityIndexDeserializer {
+                limits: self.limits,
+            })?
+            .ok_or_else(|| A::Error::missing_field(SNAPSHOT_ENTITIES))?;
+
+        let resources = seq.next_element::<Content>()?;
+
+        while seq.next_element::<IgnoredAny>()?.is_some() {}
+
+        Ok((entities, resources))
+    }
+}
+
+struct EntityIndexDeserializer {
+    limits: DeserializeLimits,
+}
+
+impl<'de> DeserializeSeed<'de> for EntityIndexDeserializer {
+    type Value = Vec<LazyEntity>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for EntityIndexDeserializer {
+    type Value = Vec<LazyEntity>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
+        formatter.write_str("map of entities")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entities = Vec::new();
+
+        while let Some(entity) = map.next_key::<Entity>()? {
+            if entities.len() >= self.limits.max_entities {
+                return Err(A::Error::custom(format_args!(
+                    "snapshot exceeds the limit of {} entities",
+                    self.limits.max_entities,
+                )));
+            }
+
+            let content = map.next_value::<Content>()?;
+
+            entities.push(LazyEntity {
+                entity,
+                types: component_types(&content),
+                content,
+            });
+        }
+
+        Ok(entities)
+    }
+}
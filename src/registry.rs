@@ -1,51 +1,88 @@
-use std::any::{
-    Any,
-    TypeId,
+use std::{
+    any::{
+        Any,
+        TypeId,
+    },
+    sync::{
+        atomic::{
+            AtomicU64,
+            Ordering,
+        },
+        Arc,
+    },
 };
 
-use bevy::prelude::*;
+use bevy::{
+    prelude::*,
+    reflect::{
+        TypeRegistration,
+        TypeRegistry,
+    },
+};
+
+use crate::Snapshot;
 
-fn take<T, F>(mut_ref: &mut T, closure: F)
+/// Replaces the value with the result of the closure, leaving the default value if the closure panics.
+fn take<T: Default, F>(mut_ref: &mut T, closure: F)
 where
     F: FnOnce(T) -> T,
 {
-    use std::ptr;
-
-    unsafe {
-        let old_t = ptr::read(mut_ref);
-        ptr::write(mut_ref, closure(old_t));
-    }
+    let old_t = std::mem::take(mut_ref);
+    *mut_ref = closure(old_t);
 }
 
+/// The source of [`RollbackRegistry::generation`], shared so replaced registries never reuse a generation.
+static NEXT_GENERATION: AtomicU64 = AtomicU64::new(1);
+
 /// The global registry of types that should be included in [`Rollbacks`](crate::Rollbacks).
 ///
 /// Only types that are registered in here and [`AppTypeRegistry`] are included in rollbacks.
 #[derive(Resource, Default)]
 pub struct RollbackRegistry {
     types: SceneFilter,
+    generation: u64,
 }
 
 impl RollbackRegistry {
+    pub(crate) const fn new() -> Self {
+        Self {
+            types: SceneFilter::Unset,
+            generation: 0,
+        }
+    }
+
+    /// Returns a value that changes whenever the registry is modified.
+    ///
+    /// Used to invalidate [`CapturePlan`](crate::CapturePlan)s built with an outdated configuration.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    fn update(&mut self, closure: impl FnOnce(SceneFilter) -> SceneFilter) {
+        take(&mut self.types, closure);
+        self.generation = NEXT_GENERATION.fetch_add(1, Ordering::Relaxed);
+    }
+
     /// Allow all types to roll back.
     pub fn allow_all(&mut self) {
-        self.types = SceneFilter::allow_all();
+        self.update(|_| SceneFilter::allow_all());
     }
 
     /// Deny all types from rolling back.
     pub fn deny_all(&mut self) {
-        self.types = SceneFilter::deny_all();
+        self.update(|_| SceneFilter::deny_all());
     }
 
     /// Include a type in rollbacks.
     pub fn allow<T: Any>(&mut self) {
-        take(&mut self.types, |types| types.allow::<T>());
+        self.update(|types| types.allow::<T>());
     }
 
     /// Exclude a type from rollback.
     ///
     /// The type is still included in normal snapshots.
     pub fn deny<T: Any>(&mut self) {
-        take(&mut self.types, |types| types.deny::<T>());
+        self.update(|types| types.deny::<T>());
     }
 
     /// Check if a type is allowed to roll back.
@@ -67,4 +104,380 @@ impl RollbackRegistry {
     pub fn is_denied_by_id(&self, type_id: TypeId) -> bool {
         self.types.is_denied_by_id(type_id)
     }
+
+    /// Include or exclude a type from rollbacks by id, for example from a debug menu.
+    pub fn set_allowed_by_id(&mut self, type_id: TypeId, allowed: bool) {
+        if allowed {
+            self.update(|types| types.allow_by_id(type_id));
+        } else {
+            self.update(|types| types.deny_by_id(type_id));
+        }
+    }
+
+    /// Returns the registered components and resources allowed to roll back.
+    pub fn allowed<'r>(
+        &'r self,
+        registry: &'r TypeRegistry,
+    ) -> impl Iterator<Item = &'r TypeRegistration> + 'r {
+        saveables(registry).filter(move |r| self.is_allowed_by_id(r.type_info().type_id()))
+    }
+
+    /// Returns the registered components and resources denied from rolling back.
+    pub fn denied<'r>(
+        &'r self,
+        registry: &'r TypeRegistry,
+    ) -> impl Iterator<Item = &'r TypeRegistration> + 'r {
+        saveables(registry).filter(move |r| !self.is_allowed_by_id(r.type_info().type_id()))
+    }
+
+    /// Returns the current configuration by type path, for diagnostics.
+    pub fn config(&self, registry: &TypeRegistry) -> RollbackConfig {
+        let mut allowed = self
+            .allowed(registry)
+            .map(|r| r.type_info().type_path().to_owned())
+            .collect::<Vec<_>>();
+
+        let mut denied = self
+            .denied(registry)
+            .map(|r| r.type_info().type_path().to_owned())
+            .collect::<Vec<_>>();
+
+        allowed.sort();
+        denied.sort();
+
+        RollbackConfig { allowed, denied }
+    }
+}
+
+fn saveables(registry: &TypeRegistry) -> impl Iterator<Item = &TypeRegistration> {
+    registry
+        .iter()
+        .filter(|r| r.data::<ReflectComponent>().is_some() || r.data::<ReflectResource>().is_some())
+}
+
+/// The types allowed and denied by a [`RollbackRegistry`], stored in a [`Snapshot`] for diagnostics.
+///
+/// Added by [`SnapshotBuilder::extract_rollback_config`](crate::SnapshotBuilder::extract_rollback_config).
+/// The [`SnapshotApplier`](crate::SnapshotApplier) never inserts it into the world.
+#[derive(Resource, Reflect, Default, Debug, Clone, PartialEq, Eq)]
+#[reflect(Resource)]
+pub struct RollbackConfig {
+    /// The type paths of the components and resources allowed to roll back, sorted.
+    pub allowed: Vec<String>,
+    /// The type paths of the components and resources denied from rolling back, sorted.
+    pub denied: Vec<String>,
+}
+
+impl Snapshot {
+    /// Returns the [`RollbackConfig`] stored in the snapshot, if any.
+    pub fn rollback_config(&self) -> Option<RollbackConfig> {
+        self.get_resource::<RollbackConfig>()
+    }
+}
+
+/// Declared ordering between resources when applying a [`Snapshot`](crate::Snapshot).
+///
+/// Resources are applied in the order they were captured, except that a resource is always applied after the
+/// resources it was declared to depend on with [`apply_after`](Self::apply_after).
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// #[derive(Resource, Reflect, Default)]
+/// #[reflect(Resource)]
+/// struct Inventory(Vec<u32>);
+///
+/// #[derive(Resource, Reflect, Default)]
+/// #[reflect(Resource)]
+/// struct InventoryIndex(Vec<u32>);
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// app.register_type::<Inventory>()
+///     .register_type::<InventoryIndex>()
+///     .apply_resource_after::<InventoryIndex, Inventory>();
+///
+/// # app.init_resource::<Inventory>();
+/// # app.init_resource::<InventoryIndex>();
+/// let snapshot = Snapshot::builder(&app.world)
+///     .extract_resource::<InventoryIndex>()
+///     .extract_resource::<Inventory>()
+///     .build();
+///
+/// let order = app.world.resource::<ResourceOrder>().sort(&snapshot.resources);
+///
+/// assert!(snapshot.resources[order[0]].represents::<Inventory>());
+/// assert!(snapshot.resources[order[1]].represents::<InventoryIndex>());
+/// ```
+#[derive(Resource, Default)]
+pub struct ResourceOrder {
+    edges: Vec<(TypeId, TypeId)>,
+}
+
+impl ResourceOrder {
+    /// Apply the resource `A` after the resource `B`.
+    pub fn apply_after<A: Resource, B: Resource>(&mut self) {
+        let edge = (TypeId::of::<A>(), TypeId::of::<B>());
+
+        if !self.edges.contains(&edge) {
+            self.edges.push(edge);
+        }
+    }
+
+    /// Returns the order in which the resources should be applied, as indices into `resources`.
+    ///
+    /// Resources involved in a cycle are applied in their captured order.
+    pub fn sort(&self, resources: &[Box<dyn Reflect>]) -> Vec<usize> {
+        sort_after(&self.edges, resources, "resource")
+    }
+}
+
+/// Components of each entity are inserted in the order they were captured, except that a component is always
+/// inserted after the components it was declared to depend on with [`apply_after`](Self::apply_after).
+///
+/// Useful when reacting to the insertion of a component requires another component to exist on the entity.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component)]
+/// struct RigidBody;
+///
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component)]
+/// struct Collider;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// app.register_type::<RigidBody>()
+///     .register_type::<Collider>()
+///     .apply_component_after::<Collider, RigidBody>();
+///
+/// let components: Vec<Box<dyn Reflect>> = vec![Box::new(Collider), Box::new(RigidBody)];
+///
+/// let order = app.world.resource::<ComponentOrder>().sort(&components);
+///
+/// assert!(components[order[0]].represents::<RigidBody>());
+/// assert!(components[order[1]].represents::<Collider>());
+/// ```
+#[derive(Resource, Default)]
+pub struct ComponentOrder {
+    edges: Vec<(TypeId, TypeId)>,
+}
+
+impl ComponentOrder {
+    /// Insert the component `A` after the component `B`.
+    pub fn apply_after<A: Component, B: Component>(&mut self) {
+        let edge = (TypeId::of::<A>(), TypeId::of::<B>());
+
+        if !self.edges.contains(&edge) {
+            self.edges.push(edge);
+        }
+    }
+
+    /// Returns the order in which the components of an entity should be inserted, as indices into `components`.
+    ///
+    /// Components involved in a cycle are inserted in their captured order.
+    pub fn sort(&self, components: &[Box<dyn Reflect>]) -> Vec<usize> {
+        sort_after(&self.edges, components, "component")
+    }
+}
+
+type RemapFn = Arc<dyn Fn(&dyn Reflect, &mut EntityWorldMut) + Send + Sync>;
+
+/// Apply-time remapping of saved component types onto other components, for refactors that move data between types.
+///
+/// When applying a snapshot, each saved value of a remapped type is passed to its closure instead of being inserted,
+/// after the other components of the entity have been applied. The saved type only needs to be registered, so it can
+/// be kept around as a plain [`Reflect`] type once it is no longer a component.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// /// The component used by older versions of the game.
+/// #[derive(Reflect, Default)]
+/// struct OldHealth(u32);
+///
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component)]
+/// struct Stats {
+///     health: u32,
+///     armor: u32,
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # app.register_type::<Stats>();
+/// app.remap_component(|old: OldHealth, entity: &mut EntityWorldMut| {
+///     match entity.get_mut::<Stats>() {
+///         Some(mut stats) => stats.health = old.0,
+///         None => {
+///             entity.insert(Stats {
+///                 health: old.0,
+///                 ..default()
+///             });
+///         }
+///     }
+/// });
+///
+/// let mut snapshot = Snapshot::builder(&app.world).build();
+/// let saved = app.world.spawn_empty().id();
+/// snapshot.insert_component(saved, Stats { health: 0, armor: 5 });
+/// snapshot.insert_component(saved, OldHealth(80));
+///
+/// snapshot.apply(&mut app.world).unwrap();
+///
+/// let stats = app.world.query::<&Stats>().single(&app.world);
+///
+/// assert_eq!((stats.health, stats.armor), (80, 5));
+/// ```
+#[derive(Resource, Default, Clone)]
+pub struct ComponentRemaps {
+    remaps: Vec<(TypeId, RemapFn)>,
+}
+
+impl ComponentRemaps {
+    /// Pass saved values of the type `T` to the closure instead of inserting them, replacing any previous remap of `T`.
+    pub fn remap<T: FromReflect + TypePath>(
+        &mut self,
+        remap: impl Fn(T, &mut EntityWorldMut) + Send + Sync + 'static,
+    ) {
+        self.remaps.retain(|(id, _)| *id != TypeId::of::<T>());
+        self.remaps.push((
+            TypeId::of::<T>(),
+            Arc::new(move |value, entity| {
+                let Some(value) = T::from_reflect(value) else {
+                    warn!("Failed to remap component `{}`", T::type_path());
+                    return;
+                };
+
+                remap(value, entity);
+            }),
+        ));
+    }
+
+    /// Returns `true` if the type is remapped.
+    pub fn is_remapped_by_id(&self, type_id: TypeId) -> bool {
+        self.remaps.iter().any(|(id, _)| *id == type_id)
+    }
+
+    /// Applies the saved value of a remapped type to the entity, returning `false` if the type is not remapped.
+    pub fn apply(&self, value: &dyn Reflect, entity: &mut EntityWorldMut) -> bool {
+        let Some(type_id) = value.get_represented_type_info().map(|i| i.type_id()) else {
+            return false;
+        };
+
+        let Some((_, remap)) = self.remaps.iter().find(|(id, _)| *id == type_id) else {
+            return false;
+        };
+
+        remap(value, entity);
+        true
+    }
+}
+
+/// Resources whose [`Entity`] map keys are remapped when applying a snapshot, for maps that do not implement `MapEntities`.
+///
+/// Entries keyed by entities that are not in the snapshot are dropped. Maps nested anywhere in the resource are
+/// remapped, so wrappers around a `HashMap<Entity, T>` or [`EntityHashMap`](bevy::ecs::entity::EntityHashMap) work
+/// as-is.
+///
+/// # Example
+/// ```
+/// # use bevy::{prelude::*, utils::HashMap};
+/// # use bevy_save::prelude::*;
+/// #[derive(Resource, Reflect, Default)]
+/// #[reflect(Resource)]
+/// struct Owners(HashMap<Entity, String>);
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// app.remap_entity_keys::<Owners>();
+///
+/// let world = &mut app.world;
+/// let sword = world.spawn_empty().id();
+/// world.insert_resource(Owners(HashMap::from([(sword, "Alice".to_owned())])));
+///
+/// let snapshot = Snapshot::builder(world)
+///     .extract_all_entities()
+///     .extract_resource::<Owners>()
+///     .build();
+///
+/// world.clear_entities();
+/// world.remove_resource::<Owners>();
+/// world.spawn_empty();
+///
+/// let mut entity_map = Default::default();
+///
+/// snapshot
+///     .applier(world)
+///     .entity_map(&mut entity_map)
+///     .apply()
+///     .unwrap();
+///
+/// let owners = world.resource::<Owners>();
+///
+/// assert_eq!(owners.0.get(&entity_map[&sword]).unwrap(), "Alice");
+/// ```
+#[derive(Resource, Default, Clone)]
+pub struct EntityKeyRemaps {
+    types: Vec<TypeId>,
+}
+
+impl EntityKeyRemaps {
+    /// Remap the entity keys of the resource `R` when applying a snapshot.
+    pub fn remap<R: Resource>(&mut self) {
+        if !self.is_remapped::<R>() {
+            self.types.push(TypeId::of::<R>());
+        }
+    }
+
+    /// Returns `true` if the entity keys of the resource `R` are remapped.
+    pub fn is_remapped<R: Resource>(&self) -> bool {
+        self.is_remapped_by_id(TypeId::of::<R>())
+    }
+
+    /// Returns `true` if the entity keys of the type are remapped.
+    pub fn is_remapped_by_id(&self, type_id: TypeId) -> bool {
+        self.types.contains(&type_id)
+    }
+}
+
+/// Sorts `values` such that for each edge `(a, b)`, values of type `a` come after values of type `b`.
+fn sort_after(edges: &[(TypeId, TypeId)], values: &[Box<dyn Reflect>], kind: &str) -> Vec<usize> {
+    let ids = values
+        .iter()
+        .map(|r| r.get_represented_type_info().map(|i| i.type_id()))
+        .collect::<Vec<_>>();
+
+    let mut order = Vec::with_capacity(values.len());
+    let mut remaining = (0..values.len()).collect::<Vec<_>>();
+
+    while !remaining.is_empty() {
+        let is_ready = |i: usize| {
+            !edges.iter().any(|(a, b)| {
+                ids[i] == Some(*a) && remaining.iter().any(|j| *j != i && ids[*j] == Some(*b))
+            })
+        };
+
+        let next = remaining
+            .iter()
+            .position(|i| is_ready(*i))
+            .unwrap_or_else(|| {
+                warn!("Cycle detected in {kind} apply order");
+                0
+            });
+
+        order.push(remaining.remove(next));
+    }
+
+    order
 }
@@ -0,0 +1,227 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    io::Write,
+};
+
+use bevy::{
+    prelude::*,
+    reflect::serde::TypedReflectSerializer,
+};
+
+use crate::{
+    Error,
+    Format,
+    RegistryRef,
+    Snapshot,
+    SnapshotSerializer,
+};
+
+/// The serialized size of all values of a single type in a [`SizeReport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeSize {
+    /// The type path of the type.
+    pub type_path: String,
+    /// The number of serialized bytes of all values of the type.
+    pub bytes: usize,
+    /// The number of values of the type.
+    pub count: usize,
+}
+
+/// The serialized size of all components of a single entity in a [`SizeReport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntitySize {
+    /// The entity.
+    pub entity: Entity,
+    /// The number of serialized bytes of all components of the entity.
+    pub bytes: usize,
+}
+
+/// A breakdown of the serialized size of a [`Snapshot`] per type and per entity.
+///
+/// Created by [`Snapshot::size_report`]. Sizes of individual values do not include the framing of the format,
+/// so they do not add up to the [`total`](Self::total).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SizeReport {
+    /// The number of bytes of the entire serialized snapshot.
+    pub total: usize,
+    /// The size of each component and resource type, largest first.
+    pub types: Vec<TypeSize>,
+    /// The size of each entity, largest first.
+    pub entities: Vec<EntitySize>,
+}
+
+impl SizeReport {
+    /// Returns the `n` largest types.
+    pub fn top_types(&self, n: usize) -> &[TypeSize] {
+        &self.types[..n.min(self.types.len())]
+    }
+
+    /// Returns the `n` largest entities.
+    pub fn top_entities(&self, n: usize) -> &[EntitySize] {
+        &self.entities[..n.min(self.entities.len())]
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn percent(&self, bytes: usize) -> f64 {
+        if self.total == 0 {
+            0.0
+        } else {
+            bytes as f64 / self.total as f64 * 100.0
+        }
+    }
+}
+
+impl Display for SizeReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const TOP: usize = 10;
+
+        writeln!(f, "Total: {} bytes", self.total)?;
+
+        writeln!(f, "Types:")?;
+        for t in self.top_types(TOP) {
+            writeln!(
+                f,
+                "  {:>10} bytes {:>5.1}% {:>6}x {}",
+                t.bytes,
+                self.percent(t.bytes),
+                t.count,
+                t.type_path
+            )?;
+        }
+
+        writeln!(f, "Entities:")?;
+        for e in self.top_entities(TOP) {
+            writeln!(
+                f,
+                "  {:>10} bytes {:>5.1}% {:?}",
+                e.bytes,
+                self.percent(e.bytes),
+                e.entity
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writer counting the bytes written to it.
+#[derive(Default)]
+struct ByteCounter(usize);
+
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0 += buf.len();
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Snapshot {
+    /// Returns a breakdown of the size of the [`Snapshot`] serialized with the [`Format`] `F`, per type and per entity.
+    ///
+    /// Useful for finding the types responsible for large saves.
+    ///
+    /// # Errors
+    /// If the snapshot could not be serialized.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct DebugLog(Vec<String>);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # app.register_type::<DebugLog>();
+    /// # let world = &mut app.world;
+    /// world.spawn(DebugLog(vec!["x".repeat(1000)]));
+    ///
+    /// let snapshot = Snapshot::builder(world).extract_all_entities().build();
+    /// let report = snapshot
+    ///     .size_report::<DefaultFormat>(world.resource::<AppTypeRegistry>())
+    ///     .unwrap();
+    ///
+    /// assert!(report.top_types(1)[0].type_path.ends_with("DebugLog"));
+    ///
+    /// println!("{report}");
+    /// ```
+    pub fn size_report<'a, F: Format>(
+        &'a self,
+        registry: impl Into<RegistryRef<'a>>,
+    ) -> Result<SizeReport, Error> {
+        let registry = registry.into();
+
+        let mut total = ByteCounter::default();
+        F::serialize(&mut total, &SnapshotSerializer::new(self, registry))?;
+
+        let reg = registry.read();
+
+        let size_of = |value: &dyn Reflect| -> Result<usize, Error> {
+            let mut counter = ByteCounter::default();
+            F::serialize(&mut counter, &TypedReflectSerializer::new(value, &reg))?;
+            Ok(counter.0)
+        };
+
+        let mut types: HashMap<String, (usize, usize)> = HashMap::new();
+        let mut entities = Vec::with_capacity(self.entities.len());
+
+        let mut add = |value: &dyn Reflect| -> Result<usize, Error> {
+            let bytes = size_of(value)?;
+            let type_path = value
+                .get_represented_type_info()
+                .map_or_else(|| value.reflect_type_path(), |i| i.type_path());
+            let entry = types.entry(type_path.to_owned()).or_default();
+
+            entry.0 += bytes;
+            entry.1 += 1;
+
+            Ok(bytes)
+        };
+
+        for entity in &self.entities {
+            let mut bytes = 0;
+
+            for component in &entity.components {
+                bytes += add(&**component)?;
+            }
+
+            entities.push(EntitySize {
+                entity: entity.entity,
+                bytes,
+            });
+        }
+
+        for resource in &self.resources {
+            add(&**resource)?;
+        }
+
+        let mut types = types
+            .into_iter()
+            .map(|(type_path, (bytes, count))| TypeSize {
+                type_path,
+                bytes,
+                count,
+            })
+            .collect::<Vec<_>>();
+
+        types.sort_by(|a, b| {
+            b.bytes
+                .cmp(&a.bytes)
+                .then_with(|| a.type_path.cmp(&b.type_path))
+        });
+        entities.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.entity.cmp(&b.entity)));
+
+        Ok(SizeReport {
+            total: total.0,
+            types,
+            entities,
+        })
+    }
+}
@@ -0,0 +1,115 @@
+//! Utilities for creating minimal reproductions of save issues.
+//!
+//! Attach the output of [`capture_minimal`] to a bug report, so the save can be loaded in a test harness
+//! without the rest of the game.
+
+use std::collections::BTreeSet;
+
+use bevy::{
+    ecs::entity::EntityHashSet,
+    prelude::*,
+    reflect::ReflectRef,
+};
+
+use crate::Snapshot;
+
+/// A minimal, self-contained [`Snapshot`] and the types required to load it.
+pub struct Repro {
+    /// The captured entities.
+    pub snapshot: Snapshot,
+    /// The type paths of every type contained in the snapshot, which must be registered to load it.
+    pub types: Vec<String>,
+}
+
+/// Capture the given entities and every entity they reference, such as their [`Parent`] and [`Children`].
+///
+/// Resources are not captured, and [`Name`] components are replaced with placeholders to anonymize the save.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # app.register_type::<Parent>();
+/// # app.register_type::<Children>();
+/// # let world = &mut app.world;
+/// let mut child = Entity::PLACEHOLDER;
+///
+/// world.spawn_empty().with_children(|p| {
+///     child = p.spawn_empty().id();
+/// });
+///
+/// let repro = bevy_save::repro::capture_minimal(world, [child]);
+///
+/// assert_eq!(repro.snapshot.entities.len(), 2);
+/// assert!(repro.types.iter().any(|t| t == "bevy_hierarchy::components::parent::Parent"));
+/// ```
+pub fn capture_minimal(world: &World, entities: impl IntoIterator<Item = Entity>) -> Repro {
+    let registry = world.resource::<AppTypeRegistry>().read();
+
+    let mut included = EntityHashSet::default();
+    let mut pending = entities.into_iter().collect::<Vec<_>>();
+    let mut types = BTreeSet::new();
+
+    while let Some(entity) = pending.pop() {
+        if world.get_entity(entity).is_none() || !included.insert(entity) {
+            continue;
+        }
+
+        let snapshot = Snapshot::builder(world).extract_entity(entity).build();
+
+        for component in snapshot.entities.iter().flat_map(|e| &e.components) {
+            walk(&**component, &mut types, &mut pending);
+        }
+    }
+
+    let mut snapshot = Snapshot::builder(world)
+        .extract_entities(included.iter().copied())
+        .build();
+
+    snapshot.entities.sort_by_key(|e| e.entity);
+
+    for (i, entity) in snapshot.entities.iter_mut().enumerate() {
+        for component in &mut entity.components {
+            if component.represents::<Name>() {
+                *component = Box::new(Name::new(format!("Entity {i}")));
+            }
+        }
+    }
+
+    types.retain(|t| registry.get_with_type_path(t).is_some());
+
+    Repro {
+        snapshot,
+        types: types.into_iter().collect(),
+    }
+}
+
+/// Collects the type paths and referenced entities contained in the value.
+fn walk(value: &dyn Reflect, types: &mut BTreeSet<String>, entities: &mut Vec<Entity>) {
+    if let Some(info) = value.get_represented_type_info() {
+        types.insert(info.type_path().to_owned());
+    }
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(s) => s.iter_fields().for_each(|f| walk(f, types, entities)),
+        ReflectRef::TupleStruct(s) => s.iter_fields().for_each(|f| walk(f, types, entities)),
+        ReflectRef::Tuple(s) => s.iter_fields().for_each(|f| walk(f, types, entities)),
+        ReflectRef::List(s) => s.iter().for_each(|f| walk(f, types, entities)),
+        ReflectRef::Array(s) => s.iter().for_each(|f| walk(f, types, entities)),
+        ReflectRef::Map(s) => s.iter().for_each(|(k, v)| {
+            walk(k, types, entities);
+            walk(v, types, entities);
+        }),
+        ReflectRef::Enum(s) => s
+            .iter_fields()
+            .for_each(|f| walk(f.value(), types, entities)),
+        ReflectRef::Value(v) => {
+            if let Some(entity) = v.downcast_ref::<Entity>() {
+                entities.push(*entity);
+            }
+        }
+    }
+}
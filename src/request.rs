@@ -0,0 +1,154 @@
+use std::{
+    any::type_name,
+    sync::{
+        Arc,
+        Mutex,
+    },
+};
+
+use bevy::prelude::*;
+
+use crate::{
+    Pipeline,
+    SaveQueue,
+    WorldSaveableExt,
+};
+
+/// Shared handle allowing a requested save or load to be cancelled before it is performed.
+///
+/// Clones refer to the same request, so the token returned by [`WorldRequestExt::request_save`] reports whether a
+/// system cancelled it.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<Mutex<Option<String>>>);
+
+impl CancelToken {
+    /// Cancel the request with the given reason. The first reason is kept if cancelled multiple times.
+    ///
+    /// # Panics
+    /// If a thread panicked while cancelling.
+    pub fn cancel(&self, reason: impl Into<String>) {
+        self.0
+            .lock()
+            .expect("CancelToken lock poisoned")
+            .get_or_insert_with(|| reason.into());
+    }
+
+    /// Returns `true` if the request has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.reason().is_some()
+    }
+
+    /// Returns the reason the request was cancelled, if it was.
+    ///
+    /// # Panics
+    /// If a thread panicked while cancelling.
+    pub fn reason(&self) -> Option<String> {
+        self.0.lock().expect("CancelToken lock poisoned").clone()
+    }
+}
+
+/// Sent when a save is requested with [`WorldRequestExt::request_save`], allowing systems to veto it.
+#[derive(Event, Debug, Clone)]
+pub struct SaveRequested {
+    /// The type name of the [`Pipeline`] performing the save.
+    pub pipeline: &'static str,
+    /// Cancels the save when used before the [`SaveSet`](crate::SaveSet).
+    pub cancel_token: CancelToken,
+}
+
+/// Sent when a load is requested with [`WorldRequestExt::request_load`], allowing systems to veto it.
+#[derive(Event, Debug, Clone)]
+pub struct LoadRequested {
+    /// The type name of the [`Pipeline`] performing the load.
+    pub pipeline: &'static str,
+    /// Cancels the load when used before the [`SaveSet`](crate::SaveSet).
+    pub cancel_token: CancelToken,
+}
+
+/// Extension trait that adds cancellable save and load requests to Bevy's [`World`].
+///
+/// Requests are performed at the [`SaveSet`](crate::SaveSet) barrier like deferred operations, unless a system
+/// reading the [`SaveRequested`] or [`LoadRequested`] event cancelled them first. Vetoing systems must run after the
+/// request is made and before the [`SaveSet`](crate::SaveSet), usually in [`Update`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// #[derive(Resource)]
+/// struct InCombat;
+///
+/// fn no_saves_in_combat(mut requests: EventReader<SaveRequested>, combat: Option<Res<InCombat>>) {
+///     for request in requests.read() {
+///         if combat.is_some() {
+///             request.cancel_token.cancel("cannot save during combat");
+///         }
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// app.add_plugins((MinimalPlugins, SavePlugins))
+///     .add_systems(Update, no_saves_in_combat)
+///     .insert_resource(InCombat);
+///
+/// let token = app.world.request_save(DebugPipeline("example"));
+///
+/// app.update();
+///
+/// assert_eq!(token.reason().as_deref(), Some("cannot save during combat"));
+/// ```
+pub trait WorldRequestExt {
+    /// Requests a save with the given [`Pipeline`], sending a [`SaveRequested`] event.
+    ///
+    /// The save is performed at the [`SaveSet`](crate::SaveSet) barrier if it has not been cancelled.
+    fn request_save<P: Pipeline + Send + Sync + 'static>(&mut self, pipeline: P) -> CancelToken;
+
+    /// Requests a load with the given [`Pipeline`], sending a [`LoadRequested`] event.
+    ///
+    /// The load is performed at the [`SaveSet`](crate::SaveSet) barrier if it has not been cancelled.
+    fn request_load<P: Pipeline + Send + Sync + 'static>(&mut self, pipeline: P) -> CancelToken;
+}
+
+impl WorldRequestExt for World {
+    fn request_save<P: Pipeline + Send + Sync + 'static>(&mut self, pipeline: P) -> CancelToken {
+        let token = CancelToken::default();
+
+        self.send_event(SaveRequested {
+            pipeline: type_name::<P>(),
+            cancel_token: token.clone(),
+        });
+
+        let cancel = token.clone();
+        self.resource_mut::<SaveQueue>()
+            .push_grouped(P::group(), P::priority(), move |world| {
+                match cancel.reason() {
+                    Some(reason) => debug!("Save cancelled: {reason}"),
+                    None => world.save(pipeline)?,
+                }
+                Ok(())
+            });
+
+        token
+    }
+
+    fn request_load<P: Pipeline + Send + Sync + 'static>(&mut self, pipeline: P) -> CancelToken {
+        let token = CancelToken::default();
+
+        self.send_event(LoadRequested {
+            pipeline: type_name::<P>(),
+            cancel_token: token.clone(),
+        });
+
+        let cancel = token.clone();
+        self.resource_mut::<SaveQueue>()
+            .push_grouped(P::group(), P::priority(), move |world| {
+                match cancel.reason() {
+                    Some(reason) => debug!("Load cancelled: {reason}"),
+                    None => world.load(pipeline)?,
+                }
+                Ok(())
+            });
+
+        token
+    }
+}
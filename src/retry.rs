@@ -0,0 +1,188 @@
+use std::{
+    any::type_name,
+    sync::Mutex,
+    time::Duration,
+};
+
+use bevy::prelude::*;
+
+use crate::{
+    Error,
+    Pipeline,
+};
+
+/// The kind of backend operation being retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BackendOperation {
+    /// Writing a save.
+    Save,
+    /// Reading a save.
+    Load,
+}
+
+/// Sent for every failed backend operation that is retried, see [`RetryPolicy`].
+#[derive(Event, Debug, Clone)]
+pub struct BackendRetry {
+    /// The type name of the [`Pipeline`] performing the operation.
+    pub pipeline: &'static str,
+    /// The operation that failed.
+    pub operation: BackendOperation,
+    /// The number of the failed attempt, starting at `1`.
+    pub attempt: u32,
+    /// The error the attempt failed with.
+    pub error: String,
+    /// How long was waited before the next attempt.
+    pub delay: Duration,
+}
+
+/// How failed backend operations of a [`Pipeline`] are retried, see [`Pipeline::retry_policy`].
+///
+/// Only errors accepted by the [`retry_if`](Self::retry_if) predicate are retried, which defaults to
+/// [`Error::is_transient`]. Waiting between attempts blocks the calling thread, so keep the backoff short when
+/// saving from the main schedule.
+///
+/// # Example
+/// ```
+/// # use std::time::Duration;
+/// # use bevy_save::prelude::*;
+/// // Up to 4 attempts, waiting 10ms, 20ms and 40ms between them
+/// let policy = RetryPolicy::new(4)
+///     .backoff(Duration::from_millis(10))
+///     .exponential(2);
+///
+/// assert_eq!(policy.delay(1), Duration::from_millis(10));
+/// assert_eq!(policy.delay(3), Duration::from_millis(40));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    backoff: Duration,
+    multiplier: u32,
+    retry_if: fn(&Error) -> bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl RetryPolicy {
+    /// Never retry, returning the first error.
+    pub fn none() -> Self {
+        Self::new(1)
+    }
+
+    /// Attempt each operation up to `max_attempts` times, without waiting between attempts.
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            backoff: Duration::ZERO,
+            multiplier: 1,
+            retry_if: Error::is_transient,
+        }
+    }
+
+    /// Wait for the given duration after the first failed attempt.
+    pub fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Multiply the backoff by the given factor after each failed attempt.
+    pub fn exponential(mut self, multiplier: u32) -> Self {
+        self.multiplier = multiplier.max(1);
+        self
+    }
+
+    /// Only retry errors accepted by the predicate.
+    pub fn retry_if(mut self, retry_if: fn(&Error) -> bool) -> Self {
+        self.retry_if = retry_if;
+        self
+    }
+
+    /// Returns the maximum number of attempts of each operation.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Returns how long to wait after the given failed attempt, starting at `1`.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let factor = self.multiplier.saturating_pow(attempt.saturating_sub(1));
+
+        self.backoff.saturating_mul(factor)
+    }
+
+    /// Performs the operation until it succeeds, fails with an error that is not retried, or runs out of attempts.
+    ///
+    /// Calls `on_retry` with the attempt number, error and delay before each retry.
+    ///
+    /// # Errors
+    /// The error of the last attempt.
+    pub fn run<T>(
+        &self,
+        mut operation: impl FnMut() -> Result<T, Error>,
+        mut on_retry: impl FnMut(u32, &Error, Duration),
+    ) -> Result<T, Error> {
+        let mut attempt = 1;
+
+        loop {
+            match operation() {
+                Err(err) if attempt < self.max_attempts && (self.retry_if)(&err) => {
+                    let delay = self.delay(attempt);
+
+                    on_retry(attempt, &err, delay);
+
+                    if !delay.is_zero() {
+                        std::thread::sleep(delay);
+                    }
+
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+/// Retries recorded while saving, sent as [`BackendRetry`] events by [`RetryEvents::flush`].
+///
+/// Saving only borrows the [`World`] immutably, so events cannot be sent directly.
+#[derive(Resource, Default)]
+pub(crate) struct RetryEvents(Mutex<Vec<BackendRetry>>);
+
+impl RetryEvents {
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn flush(events: Res<RetryEvents>, mut writer: EventWriter<BackendRetry>) {
+        let mut events = events.0.lock().expect("RetryEvents lock poisoned");
+
+        if !events.is_empty() {
+            writer.send_batch(events.drain(..));
+        }
+    }
+}
+
+/// Performs the backend operation with the [`RetryPolicy`] of the [`Pipeline`], recording each retry.
+pub(crate) fn retry<P: Pipeline, T>(
+    world: &World,
+    operation: BackendOperation,
+    f: impl FnMut() -> Result<T, Error>,
+) -> Result<T, Error> {
+    P::retry_policy().run(f, |attempt, err, delay| {
+        warn!("{operation:?} attempt {attempt} failed, retrying in {delay:?}: {err}");
+
+        if let Some(events) = world.get_resource::<RetryEvents>() {
+            events
+                .0
+                .lock()
+                .expect("RetryEvents lock poisoned")
+                .push(BackendRetry {
+                    pipeline: type_name::<P>(),
+                    operation,
+                    attempt,
+                    error: err.to_string(),
+                    delay,
+                });
+        }
+    })
+}
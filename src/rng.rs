@@ -0,0 +1,157 @@
+use bevy::prelude::*;
+
+const INCREMENT: u64 = 0x2d35_8dcc_aa6c_78a5;
+const MULTIPLIER: u64 = 0x8bb8_4b93_962e_acc9;
+
+/// A deterministic random number generator whose entire state is saved with the [`World`].
+///
+/// Using the RNG only through this resource guarantees that loading a save reproduces the same sequence of values
+/// that would have been generated had the game kept running.
+///
+/// The generator is wyrand, and its sequence will not change between releases so saves stay deterministic.
+/// Enable the `rand` feature to use it with `rand` distributions, or the `fastrand` feature to convert the seed
+/// to and from a `fastrand::Rng`.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # let world = &mut app.world;
+/// world.insert_resource(SaveableRng::with_seed(42));
+/// world.resource_mut::<SaveableRng>().u64();
+///
+/// let snapshot = Snapshot::builder(world)
+///     .extract_resource::<SaveableRng>()
+///     .build();
+///
+/// // Round trip through a serialized save
+/// let registry = world.resource::<AppTypeRegistry>().clone();
+/// let mut data = Vec::new();
+/// JSONFormat::serialize(&mut data, &SnapshotSerializer::new(&snapshot, &registry)).unwrap();
+/// let loaded: Snapshot = JSONFormat::deserialize(&*data, SnapshotDeserializer::new(&registry.read())).unwrap();
+///
+/// let expected = (0..8)
+///     .map(|_| world.resource_mut::<SaveableRng>().u64())
+///     .collect::<Vec<_>>();
+///
+/// loaded.apply(world).unwrap();
+///
+/// let actual = (0..8)
+///     .map(|_| world.resource_mut::<SaveableRng>().u64())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(expected, actual);
+/// ```
+#[derive(Resource, Reflect, Default, Debug, Clone, PartialEq, Eq)]
+#[reflect(Resource)]
+pub struct SaveableRng {
+    state: u64,
+}
+
+impl SaveableRng {
+    /// Create a new [`SaveableRng`] with the given seed.
+    pub fn with_seed(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Returns the current state of the generator, which can be used as a seed to continue the sequence.
+    pub fn seed(&self) -> u64 {
+        self.state
+    }
+
+    /// Create an independent generator seeded from this one, e.g. for a separate system.
+    pub fn fork(&mut self) -> Self {
+        Self::with_seed(self.u64())
+    }
+
+    /// Generate a random `u64`.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(INCREMENT);
+        let t = u128::from(self.state) * u128::from(self.state ^ MULTIPLIER);
+        (t as u64) ^ (t >> 64) as u64
+    }
+
+    /// Generate a random `u32`.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn u32(&mut self) -> u32 {
+        self.u64() as u32
+    }
+
+    /// Generate a random `u64` in `0..bound`.
+    ///
+    /// # Panics
+    /// If `bound` is zero.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn below(&mut self, bound: u64) -> u64 {
+        assert!(bound > 0, "bound must be greater than zero");
+        ((u128::from(self.u64()) * u128::from(bound)) >> 64) as u64
+    }
+
+    /// Generate a random `bool`.
+    pub fn bool(&mut self) -> bool {
+        self.u64() & 1 == 1
+    }
+
+    /// Generate a random `f32` in `0.0..1.0`.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn f32(&mut self) -> f32 {
+        (self.u32() >> 8) as f32 / (1u32 << 24) as f32
+    }
+
+    /// Generate a random `f64` in `0.0..1.0`.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn f64(&mut self) -> f64 {
+        (self.u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand_core::RngCore for SaveableRng {
+    fn next_u32(&mut self) -> u32 {
+        self.u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        rand_core::impls::fill_bytes_via_next(self, dest);
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "rand")]
+impl rand_core::SeedableRng for SaveableRng {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::with_seed(u64::from_le_bytes(seed))
+    }
+
+    fn seed_from_u64(state: u64) -> Self {
+        Self::with_seed(state)
+    }
+}
+
+#[cfg(feature = "fastrand")]
+impl From<fastrand::Rng> for SaveableRng {
+    fn from(rng: fastrand::Rng) -> Self {
+        Self::with_seed(rng.get_seed())
+    }
+}
+
+#[cfg(feature = "fastrand")]
+impl From<SaveableRng> for fastrand::Rng {
+    fn from(rng: SaveableRng) -> Self {
+        Self::with_seed(rng.seed())
+    }
+}
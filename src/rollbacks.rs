@@ -2,35 +2,335 @@ use bevy::prelude::*;
 
 use crate::prelude::*;
 
+/// The name of the branch created by the first checkpoint.
+pub const DEFAULT_BRANCH: &str = "main";
+
+/// How many checkpoints of the [`Rollbacks`] are kept when they are extracted into a [`Snapshot`].
+///
+/// Configured per pipeline with [`Pipeline::checkpoint_persistence`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CheckpointPersistence {
+    /// Checkpoints are never saved.
+    Never,
+    /// Only the newest `n` checkpoints of the current branch are saved.
+    LastN(usize),
+    /// All checkpoints of the current branch are saved.
+    #[default]
+    All,
+}
+
+#[derive(Clone)]
+pub(crate) struct Branch {
+    pub(crate) name: String,
+    pub(crate) tip: usize,
+}
+
 /// The global registry of snapshots used for rollback / rollforward.
+///
+/// Checkpoints form a tree: creating a checkpoint after rolling back starts a new branch instead of erasing the
+/// rollforward snapshots, allowing undo trees. Rolling back / forward moves along the current branch.
+///
+/// Only the current branch is included when [`Rollbacks`] are serialized.
+///
+/// Checkpoints are kept until they are removed with [`Rollbacks::prune`] or [`Rollbacks::delete_branch`].
 #[derive(Resource, Default)]
 pub struct Rollbacks {
     pub(crate) checkpoints: Vec<Snapshot>,
+    pub(crate) parents: Vec<Option<usize>>,
+    pub(crate) branches: Vec<Branch>,
+    pub(crate) branch: usize,
     pub(crate) active: Option<usize>,
+    path: Vec<usize>,
 }
 
 impl Rollbacks {
+    /// Create [`Rollbacks`] from a linear list of checkpoints on a single branch.
+    ///
+    /// Returns `None` if the active checkpoint is out of bounds.
+    pub(crate) fn linear(checkpoints: Vec<Snapshot>, active: Option<usize>) -> Option<Self> {
+        let len = checkpoints.len();
+
+        if active.is_some_and(|active| active >= len) {
+            return None;
+        }
+
+        Some(Self {
+            parents: (0..len).map(|i| i.checked_sub(1)).collect(),
+            branches: len
+                .checked_sub(1)
+                .map(|tip| Branch {
+                    name: DEFAULT_BRANCH.into(),
+                    tip,
+                })
+                .into_iter()
+                .collect(),
+            checkpoints,
+            branch: 0,
+            active,
+            path: (0..len).collect(),
+        })
+    }
+
+    /// Returns the checkpoints of the current branch from oldest to newest, and the active position within them.
+    pub(crate) fn current_path(&self) -> (Vec<&Snapshot>, Option<usize>) {
+        let path = self.path.iter().map(|i| &self.checkpoints[*i]).collect();
+
+        (path, self.position())
+    }
+
+    /// Returns a copy of the [`Rollbacks`] holding only the checkpoints kept by the [`CheckpointPersistence`].
+    pub(crate) fn persisted(&self, persistence: CheckpointPersistence) -> Option<Self> {
+        match persistence {
+            CheckpointPersistence::Never => None,
+            CheckpointPersistence::All => Some(self.clone_value()),
+            CheckpointPersistence::LastN(n) => {
+                let (path, active) = self.current_path();
+                let start = path.len().saturating_sub(n);
+
+                let checkpoints = path[start..]
+                    .iter()
+                    .map(|s| s.clone_value())
+                    .collect::<Vec<_>>();
+                let active = active
+                    .filter(|_| !checkpoints.is_empty())
+                    .map(|a| a.saturating_sub(start));
+
+                Self::linear(checkpoints, active)
+            }
+        }
+    }
+
+    /// Returns the indices of the checkpoints leading up to the given checkpoint from oldest to newest.
+    fn path_to(&self, mut next: Option<usize>) -> Vec<usize> {
+        let mut path = Vec::new();
+
+        while let Some(index) = next {
+            path.push(index);
+            next = self.parents[index];
+        }
+
+        path.reverse();
+        path
+    }
+
+    /// Rebuilds the cached path of the current branch.
+    fn refresh_path(&mut self) {
+        self.path = self.path_to(self.branches.get(self.branch).map(|b| b.tip));
+    }
+
+    /// Returns the position of the active checkpoint within the current branch.
+    fn position(&self) -> Option<usize> {
+        let active = self.active?;
+
+        self.path.iter().rposition(|i| *i == active)
+    }
+
     /// Returns true if no checkpoints have been created.
     pub fn is_empty(&self) -> bool {
         self.checkpoints.is_empty()
     }
 
+    /// Returns the number of checkpoints on all branches.
+    pub fn len(&self) -> usize {
+        self.checkpoints.len()
+    }
+
+    /// Returns the name of the current branch, or `None` if no checkpoints have been created.
+    pub fn branch(&self) -> Option<&str> {
+        self.branches.get(self.branch).map(|b| b.name.as_str())
+    }
+
+    /// Returns the names of all branches.
+    pub fn branches(&self) -> impl Iterator<Item = &str> {
+        self.branches.iter().map(|b| b.name.as_str())
+    }
+
     /// Given a new rollback [`Snapshot`], insert it and set it as the currently active rollback.
     ///
-    /// If you rollback and then insert a checkpoint, it will erase all rollforward snapshots.
-    pub fn checkpoint(&mut self, mut rollback: Snapshot) {
-        let active = self.active.unwrap_or(0);
+    /// If you rollback and then insert a checkpoint, a new branch is created, preserving the rollforward snapshots.
+    pub fn checkpoint(&mut self, rollback: Snapshot) {
+        let is_tip =
+            self.active.is_none() || self.branches.get(self.branch).map(|b| b.tip) == self.active;
+
+        if is_tip {
+            self.push(rollback);
+        } else {
+            let mut n = self.branches.len();
+
+            while self
+                .branches
+                .iter()
+                .any(|b| b.name == format!("branch-{n}"))
+            {
+                n += 1;
+            }
+
+            self.checkpoint_branch(format!("branch-{n}"), rollback);
+        }
+    }
+
+    /// Insert a new rollback [`Snapshot`] on a new branch with the given name, starting from the active rollback.
+    ///
+    /// If a branch with the same name already exists, it is moved to the new checkpoint.
+    pub fn checkpoint_branch(&mut self, name: impl Into<String>, rollback: Snapshot) {
+        let name = name.into();
+
+        self.branch = if let Some(i) = self.branches.iter().position(|b| b.name == name) {
+            i
+        } else {
+            self.branches.push(Branch {
+                name,
+                tip: self.active.unwrap_or(0),
+            });
+            self.branches.len() - 1
+        };
+
+        // The new checkpoint continues from the active one, which may be on another branch
+        self.path = self.path_to(self.active);
+
+        self.push(rollback);
+    }
 
+    fn push(&mut self, mut rollback: Snapshot) {
         // Force conversion into rollback snapshot
         rollback.rollbacks = None;
 
-        self.checkpoints.truncate(active + 1);
         self.checkpoints.push(rollback);
+        self.parents.push(self.active);
 
-        self.active = Some(self.checkpoints.len() - 1);
+        let index = self.checkpoints.len() - 1;
+
+        self.path.truncate(self.position().map_or(0, |p| p + 1));
+        self.path.push(index);
+
+        if let Some(branch) = self.branches.get_mut(self.branch) {
+            branch.tip = index;
+        } else {
+            self.branches.push(Branch {
+                name: DEFAULT_BRANCH.into(),
+                tip: index,
+            });
+            self.branch = self.branches.len() - 1;
+        }
+
+        self.active = Some(index);
+    }
+
+    /// Switch to the branch with the given name, returning its newest [`Snapshot`].
+    ///
+    /// Returns `None` if the branch does not exist.
+    pub fn switch_branch(&mut self, name: &str) -> Option<&Snapshot> {
+        let branch = self.branches.iter().position(|b| b.name == name)?;
+        let tip = self.branches[branch].tip;
+
+        self.branch = branch;
+        self.active = Some(tip);
+        self.refresh_path();
+
+        Some(&self.checkpoints[tip])
+    }
+
+    /// Removes all but the newest `keep` checkpoints of each branch.
+    ///
+    /// If the active checkpoint is removed, the newest checkpoint of the current branch becomes active. Branches
+    /// without checkpoints left are removed.
+    pub fn prune(&mut self, keep: usize) {
+        let mut kept = vec![false; self.checkpoints.len()];
+
+        for branch in &self.branches {
+            let mut next = Some(branch.tip);
+
+            for _ in 0..keep {
+                let Some(index) = next else { break };
+
+                kept[index] = true;
+                next = self.parents[index];
+            }
+        }
+
+        self.retain(&kept);
     }
 
-    /// Rolls back the given number of checkpoints.
+    /// Removes the branch with the given name, along with the checkpoints no other branch leads to.
+    ///
+    /// Returns `false` if the branch does not exist or is the current branch.
+    pub fn delete_branch(&mut self, name: &str) -> bool {
+        let Some(branch) = self.branches.iter().position(|b| b.name == name) else {
+            return false;
+        };
+
+        if branch == self.branch {
+            return false;
+        }
+
+        self.branches.remove(branch);
+
+        if branch < self.branch {
+            self.branch -= 1;
+        }
+
+        let mut kept = vec![false; self.checkpoints.len()];
+
+        for branch in &self.branches {
+            let mut next = Some(branch.tip);
+
+            while let Some(index) = next.filter(|i| !kept[*i]) {
+                kept[index] = true;
+                next = self.parents[index];
+            }
+        }
+
+        self.retain(&kept);
+
+        true
+    }
+
+    /// Removes the checkpoints that are not kept, remapping the remaining indices.
+    fn retain(&mut self, kept: &[bool]) {
+        let mut remap = vec![None; kept.len()];
+
+        for (new, (index, _)) in kept.iter().enumerate().filter(|(_, k)| **k).enumerate() {
+            remap[index] = Some(new);
+        }
+
+        let mut index = 0;
+
+        self.checkpoints.retain(|_| {
+            index += 1;
+            kept[index - 1]
+        });
+
+        self.parents = self
+            .parents
+            .iter()
+            .zip(kept)
+            .filter(|(_, k)| **k)
+            .map(|(parent, _)| parent.and_then(|p| remap[p]))
+            .collect();
+
+        let current = self.branches.get(self.branch).map(|b| b.name.clone());
+
+        self.branches.retain_mut(|b| match remap[b.tip] {
+            Some(tip) => {
+                b.tip = tip;
+                true
+            }
+            None => false,
+        });
+
+        self.branch = current
+            .and_then(|name| self.branches.iter().position(|b| b.name == name))
+            .unwrap_or_default();
+
+        self.active = self
+            .active
+            .and_then(|a| remap[a])
+            .or_else(|| self.branches.get(self.branch).map(|b| b.tip));
+
+        self.refresh_path();
+    }
+
+    /// Rolls back the given number of checkpoints along the current branch.
     ///
     /// If checkpoints is negative, it rolls forward.
     ///
@@ -39,15 +339,13 @@ impl Rollbacks {
     #[allow(clippy::cast_possible_wrap)]
     #[allow(clippy::cast_sign_loss)]
     pub fn rollback(&mut self, checkpoints: isize) -> Option<&Snapshot> {
-        if let Some(active) = self.active {
-            let raw = active as isize - checkpoints;
-            let new = raw.clamp(0, self.checkpoints.len() as isize - 1) as usize;
+        let position = self.position()?;
 
-            self.active = Some(new);
-            Some(&self.checkpoints[new])
-        } else {
-            None
-        }
+        let raw = position as isize - checkpoints;
+        let new = self.path[raw.clamp(0, self.path.len() as isize - 1) as usize];
+
+        self.active = Some(new);
+        Some(&self.checkpoints[new])
     }
 }
 
@@ -55,7 +353,11 @@ impl CloneReflect for Rollbacks {
     fn clone_value(&self) -> Self {
         Self {
             checkpoints: self.checkpoints.iter().map(|r| r.clone_value()).collect(),
+            parents: self.parents.clone(),
+            branches: self.branches.clone(),
+            branch: self.branch,
             active: self.active,
+            path: self.path.clone(),
         }
     }
 }
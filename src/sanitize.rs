@@ -0,0 +1,367 @@
+use std::{
+    any::TypeId,
+    collections::BTreeSet,
+};
+
+use bevy::{
+    prelude::*,
+    reflect::TypeRegistry,
+    utils::HashSet,
+};
+
+use crate::{
+    DefaultComponents,
+    DeserializeLimits,
+    Error,
+    Extras,
+    MarkerTypes,
+    Markers,
+    ReflectValidate,
+    RegistryRef,
+    RejectedValue,
+    Snapshot,
+};
+
+/// Rules for sanitizing untrusted snapshots, such as saves shared by other players.
+///
+/// Only types on the allow-list are kept, and every kept value is checked by its [`ReflectValidate`]. The bookkeeping
+/// types of `bevy_save` are always allowed, but the types they refer to must be allowed as well. [`Extras`] are kept
+/// only for allowed keys, and unknown data retained from unregistered types is always stripped.
+///
+/// Insert as a resource to sanitize every save loaded with a [`Pipeline`](crate::Pipeline), see [`Snapshot::sanitize`].
+#[derive(Resource, Debug, Clone)]
+pub struct SanitizePolicy {
+    limits: DeserializeLimits,
+    types: HashSet<String>,
+    extras: HashSet<String>,
+    validate: bool,
+}
+
+impl Default for SanitizePolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SanitizePolicy {
+    /// Create a policy allowing no types and no extras, with the default [`DeserializeLimits`].
+    pub fn new() -> Self {
+        Self {
+            limits: DeserializeLimits::DEFAULT,
+            types: HashSet::new(),
+            extras: HashSet::new(),
+            validate: true,
+        }
+    }
+
+    /// Reject snapshots exceeding the given limits.
+    pub fn limits(mut self, limits: DeserializeLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Allow components and resources of type `T`.
+    pub fn allow<T: TypePath>(self) -> Self {
+        self.allow_path(T::type_path())
+    }
+
+    /// Allow components and resources with the given type path.
+    pub fn allow_path(mut self, type_path: impl Into<String>) -> Self {
+        self.types.insert(type_path.into());
+        self
+    }
+
+    /// Keep the [`Extras`] value with the given key.
+    pub fn allow_extra(mut self, key: impl Into<String>) -> Self {
+        self.extras.insert(key.into());
+        self
+    }
+
+    /// Whether values are checked by their [`ReflectValidate`]. Defaults to `true`.
+    pub fn validate(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Returns `true` if the type with the given type path is allowed.
+    pub fn is_allowed(&self, type_path: &str) -> bool {
+        self.types.contains(type_path)
+    }
+}
+
+/// Everything removed from a [`Snapshot`] by [`Snapshot::sanitize`].
+///
+/// When loading with a [`SanitizePolicy`] resource, the report of the last load is inserted as a resource.
+#[derive(Resource, Debug, Default, Clone, PartialEq, Eq)]
+pub struct SanitizeReport {
+    /// The type paths of the removed values of types outside the allow-list.
+    pub disallowed: BTreeSet<String>,
+    /// The values removed because they were rejected by their [`ReflectValidate`].
+    pub rejected: Vec<RejectedValue>,
+    /// The keys of the removed [`Extras`] values.
+    pub stripped_extras: BTreeSet<String>,
+    /// The number of removed values of unregistered types.
+    pub stripped_unknown: usize,
+}
+
+impl SanitizeReport {
+    /// Returns `true` if nothing was removed.
+    pub fn is_clean(&self) -> bool {
+        self.disallowed.is_empty()
+            && self.rejected.is_empty()
+            && self.stripped_extras.is_empty()
+            && self.stripped_unknown == 0
+    }
+}
+
+fn type_path(value: &dyn Reflect) -> &str {
+    value
+        .get_represented_type_info()
+        .map_or_else(|| value.reflect_type_path(), |i| i.type_path())
+}
+
+fn type_id(value: &dyn Reflect) -> Option<TypeId> {
+    value.get_represented_type_info().map(|i| i.type_id())
+}
+
+struct Sanitizer<'a> {
+    policy: &'a SanitizePolicy,
+    registry: &'a TypeRegistry,
+    report: SanitizeReport,
+}
+
+impl Sanitizer<'_> {
+    fn check_limits(&self, snapshot: &Snapshot) -> Result<(), Error> {
+        let limits = self.policy.limits;
+
+        if snapshot.resources.len() > limits.max_components
+            || snapshot
+                .entities
+                .iter()
+                .any(|e| e.components.len() > limits.max_components)
+        {
+            return Err(Error::custom(format_args!(
+                "snapshot exceeds the limit of {} components",
+                limits.max_components
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the sanitized value, or `None` if it is removed.
+    fn value(
+        &mut self,
+        entity: Option<Entity>,
+        value: Box<dyn Reflect>,
+    ) -> Option<Box<dyn Reflect>> {
+        let id = type_id(&*value);
+
+        if id == Some(TypeId::of::<Extras>()) {
+            let mut extras = Extras::from_reflect(&*value)?;
+
+            extras.values.retain(|key, _| {
+                let keep = self.policy.extras.contains(key);
+                if !keep {
+                    self.report.stripped_extras.insert(key.clone());
+                }
+                keep
+            });
+
+            return Some(Box::new(extras));
+        }
+
+        if id == Some(TypeId::of::<DefaultComponents>()) {
+            let mut defaults = DefaultComponents::from_reflect(&*value)?;
+
+            defaults.types.retain(|path| {
+                let keep = self.policy.is_allowed(path);
+                if !keep {
+                    self.report.disallowed.insert(path.clone());
+                }
+                keep
+            });
+
+            return Some(Box::new(defaults));
+        }
+
+        // Listed marker types are checked separately, see `markers`
+        if id == Some(TypeId::of::<Markers>()) || id == Some(TypeId::of::<MarkerTypes>()) {
+            return Some(value);
+        }
+
+        let path = type_path(&*value);
+
+        if !self.policy.is_allowed(path) {
+            self.report.disallowed.insert(path.to_owned());
+            return None;
+        }
+
+        if !self.policy.validate {
+            return Some(value);
+        }
+
+        let Some(validate) = id
+            .and_then(|id| self.registry.get(id))
+            .and_then(|r| r.data::<ReflectValidate>())
+        else {
+            return Some(value);
+        };
+
+        match validate.validate(&*value) {
+            Ok(value) => Some(value),
+            Err(reason) => {
+                self.report.rejected.push(RejectedValue {
+                    entity,
+                    type_path: path.to_owned(),
+                    reason,
+                });
+                None
+            }
+        }
+    }
+
+    /// Removes the indices of disallowed marker types from the [`Markers`] of each entity.
+    fn markers(&mut self, snapshot: &mut Snapshot) {
+        let Some(types) = snapshot.get_resource::<MarkerTypes>() else {
+            return;
+        };
+
+        let mut disallowed = Vec::new();
+
+        for (index, path) in (0u32..).zip(&types.types) {
+            if !self.policy.is_allowed(path) {
+                self.report.disallowed.insert(path.clone());
+                disallowed.push(index);
+            }
+        }
+
+        if disallowed.is_empty() {
+            return;
+        }
+
+        for entity in &mut snapshot.entities {
+            for component in &mut entity.components {
+                if type_id(&**component) != Some(TypeId::of::<Markers>()) {
+                    continue;
+                }
+
+                if let Some(mut markers) = Markers::from_reflect(&**component) {
+                    markers.indices.retain(|i| !disallowed.contains(i));
+                    *component = Box::new(markers);
+                }
+            }
+        }
+    }
+
+    fn snapshot(&mut self, snapshot: &mut Snapshot) {
+        let unknown = std::mem::take(&mut snapshot.unknown);
+
+        self.report.stripped_unknown +=
+            unknown.resources.len() + unknown.components.values().map(Vec::len).sum::<usize>();
+
+        self.markers(snapshot);
+
+        snapshot.resources = std::mem::take(&mut snapshot.resources)
+            .into_iter()
+            .filter_map(|r| self.value(None, r))
+            .collect();
+
+        for entity in &mut snapshot.entities {
+            entity.components = std::mem::take(&mut entity.components)
+                .into_iter()
+                .filter_map(|c| self.value(Some(entity.entity), c))
+                .collect();
+        }
+
+        if let Some(rollbacks) = &mut snapshot.rollbacks {
+            for checkpoint in &mut rollbacks.checkpoints {
+                self.snapshot(checkpoint);
+            }
+        }
+    }
+}
+
+impl Snapshot {
+    /// Removes everything not permitted by the [`SanitizePolicy`], so untrusted saves can be applied with reduced risk.
+    ///
+    /// Values of types outside the allow-list, values rejected by their [`ReflectValidate`], [`Extras`] with keys that
+    /// are not allowed and unknown data are removed, including from the checkpoints of the [`Rollbacks`](crate::Rollbacks).
+    ///
+    /// # Errors
+    /// If the snapshot exceeds the limits of the policy. Nothing is removed in that case.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Brick;
+    ///
+    /// #[derive(Component, Reflect, Default)]
+    /// #[reflect(Component)]
+    /// struct Admin;
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins((MinimalPlugins, SavePlugins));
+    /// # app.register_type::<Brick>();
+    /// # app.register_type::<Admin>();
+    /// # let world = &mut app.world;
+    /// world.spawn((Brick, Admin));
+    ///
+    /// let mut snapshot = Snapshot::builder(world).extract_all_entities().build();
+    ///
+    /// let policy = SanitizePolicy::new().allow::<Brick>();
+    /// let report = snapshot
+    ///     .sanitize(&policy, world.resource::<AppTypeRegistry>())
+    ///     .unwrap();
+    ///
+    /// assert!(report.disallowed.contains(Admin::type_path()));
+    /// assert_eq!(snapshot.entities[0].components.len(), 1);
+    /// ```
+    pub fn sanitize<'a>(
+        &mut self,
+        policy: &SanitizePolicy,
+        registry: impl Into<RegistryRef<'a>>,
+    ) -> Result<SanitizeReport, Error> {
+        let registry = registry.into();
+        let registry = registry.read();
+
+        let mut sanitizer = Sanitizer {
+            policy,
+            registry: &registry,
+            report: SanitizeReport::default(),
+        };
+
+        // Limits are checked up front so a rejected snapshot is left untouched
+        if let Some(exceeded) = policy.limits.exceeded_by(self) {
+            return Err(Error::custom(exceeded));
+        }
+
+        sanitizer.check_limits(self)?;
+        if let Some(rollbacks) = &self.rollbacks {
+            for checkpoint in &rollbacks.checkpoints {
+                sanitizer.check_limits(checkpoint)?;
+            }
+        }
+
+        sanitizer.snapshot(self);
+
+        Ok(sanitizer.report)
+    }
+
+    /// Sanitizes the snapshot with the [`SanitizePolicy`] of the [`World`], if any, inserting the [`SanitizeReport`].
+    pub(crate) fn sanitize_for(
+        &mut self,
+        world: &mut World,
+        registry: &TypeRegistry,
+    ) -> Result<(), Error> {
+        if let Some(policy) = world.get_resource::<SanitizePolicy>().cloned() {
+            let report = self.sanitize(&policy, registry)?;
+            world.insert_resource(report);
+        }
+
+        Ok(())
+    }
+}
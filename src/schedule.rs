@@ -0,0 +1,329 @@
+use std::marker::PhantomData;
+
+use bevy::{
+    app::AppExit,
+    prelude::*,
+};
+
+use crate::{
+    Error,
+    Pipeline,
+    WorldRollbackExt,
+    WorldSaveableExt,
+};
+
+/// The [`SystemSet`] in which deferred save and load operations are performed.
+///
+/// Runs in [`PostUpdate`] after transform propagation, so captured snapshots always contain a consistent
+/// frame where [`GlobalTransform`] matches [`Transform`].
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SaveSet;
+
+/// A deferred save or load operation.
+pub type DeferredOperation = Box<dyn FnOnce(&mut World) -> Result<(), Error> + Send + Sync>;
+
+struct QueuedOperation {
+    group: Option<&'static str>,
+    priority: i32,
+    operation: DeferredOperation,
+}
+
+/// Queue of save and load operations that will be performed at the [`SaveSet`] barrier.
+///
+/// Operations belonging to the same exclusion group (see [`Pipeline::group`]) are always performed one after another
+/// in the order they were enqueued, never interleaved with operations of other groups.
+/// Groups with a higher priority (see [`Pipeline::priority`]) are performed first.
+#[derive(Resource, Default)]
+pub struct SaveQueue {
+    operations: Vec<QueuedOperation>,
+}
+
+impl SaveQueue {
+    /// Returns true if there are no pending operations.
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Returns the number of pending operations.
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Returns the number of pending operations in the given exclusion group.
+    pub fn pending_in(&self, group: &str) -> usize {
+        self.operations
+            .iter()
+            .filter(|o| o.group == Some(group))
+            .count()
+    }
+
+    /// Enqueue a save with the given [`Pipeline`].
+    pub fn save<P: Pipeline + Send + Sync + 'static>(&mut self, pipeline: P) {
+        self.push_grouped(P::group(), P::priority(), move |world| world.save(pipeline));
+    }
+
+    /// Enqueue a load with the given [`Pipeline`].
+    pub fn load<P: Pipeline + Send + Sync + 'static>(&mut self, pipeline: P) {
+        self.push_grouped(P::group(), P::priority(), move |world| world.load(pipeline));
+    }
+
+    /// Enqueue a custom operation.
+    pub fn push<F>(&mut self, operation: F)
+    where
+        F: FnOnce(&mut World) -> Result<(), Error> + Send + Sync + 'static,
+    {
+        self.push_grouped(None, 0, operation);
+    }
+
+    /// Enqueue a custom operation in the given exclusion group, with the given priority.
+    pub fn push_grouped<F>(&mut self, group: Option<&'static str>, priority: i32, operation: F)
+    where
+        F: FnOnce(&mut World) -> Result<(), Error> + Send + Sync + 'static,
+    {
+        self.operations.push(QueuedOperation {
+            group,
+            priority,
+            operation: Box::new(operation),
+        });
+    }
+
+    /// Performs all pending operations.
+    ///
+    /// Groups are performed in order of priority, then in the order they were first enqueued.
+    ///
+    /// Errors are logged and do not prevent the remaining operations from running.
+    pub fn apply(world: &mut World) {
+        let operations = std::mem::take(&mut world.resource_mut::<SaveQueue>().operations);
+
+        let mut batches: Vec<(i32, Vec<DeferredOperation>)> = Vec::new();
+        let mut groups: Vec<(&'static str, usize)> = Vec::new();
+
+        for queued in operations {
+            let index = queued
+                .group
+                .and_then(|g| groups.iter().find(|(n, _)| *n == g).map(|(_, i)| *i));
+
+            if let Some(index) = index {
+                let batch = &mut batches[index];
+                batch.0 = batch.0.max(queued.priority);
+                batch.1.push(queued.operation);
+            } else {
+                if let Some(group) = queued.group {
+                    groups.push((group, batches.len()));
+                }
+
+                batches.push((queued.priority, vec![queued.operation]));
+            }
+        }
+
+        // Stable, so batches with equal priority keep their enqueue order
+        batches.sort_by_key(|(priority, _)| std::cmp::Reverse(*priority));
+
+        for operation in batches.into_iter().flat_map(|(_, b)| b) {
+            if let Err(err) = operation(world) {
+                error!("Deferred save operation failed: {err}");
+            }
+        }
+    }
+}
+
+/// Final save operations performed when the app exits.
+///
+/// Added to by [`AppSaveableExt::on_exit_save`](crate::AppSaveableExt::on_exit_save).
+/// When [`AppExit`] is sent, pending [`SaveQueue`] operations are performed first, followed by the exit saves,
+/// and then any [`Batched`](crate::Batched) writes are flushed.
+#[derive(Resource, Default)]
+pub struct ExitSaves {
+    operations: Vec<DeferredOperation>,
+}
+
+impl ExitSaves {
+    /// Returns the number of exit saves.
+    pub fn len(&self) -> usize {
+        self.operations.len()
+    }
+
+    /// Returns true if there are no exit saves.
+    pub fn is_empty(&self) -> bool {
+        self.operations.is_empty()
+    }
+
+    /// Perform a save with the given [`Pipeline`] when the app exits.
+    pub fn save<P: Pipeline + Send + Sync + 'static>(&mut self, pipeline: P) {
+        self.push(move |world| world.save(pipeline));
+    }
+
+    /// Perform a custom operation when the app exits.
+    pub fn push<F>(&mut self, operation: F)
+    where
+        F: FnOnce(&mut World) -> Result<(), Error> + Send + Sync + 'static,
+    {
+        self.operations.push(Box::new(operation));
+    }
+
+    /// Performs pending [`SaveQueue`] operations and all exit saves if [`AppExit`] was sent.
+    ///
+    /// Errors are logged and do not prevent the remaining operations from running.
+    // `Option::is_none_or` is newer than the Rust version supported by Bevy 0.13
+    #[allow(clippy::unnecessary_map_or)]
+    pub fn apply(world: &mut World) {
+        if world
+            .get_resource::<Events<AppExit>>()
+            .map_or(true, Events::is_empty)
+        {
+            return;
+        }
+
+        if world.contains_resource::<SaveQueue>() {
+            SaveQueue::apply(world);
+        }
+
+        let operations = std::mem::take(&mut world.resource_mut::<Self>().operations);
+
+        for operation in operations {
+            if let Err(err) = operation(world) {
+                error!("Exit save failed: {err}");
+            }
+        }
+    }
+}
+
+/// Throttles how often rollback checkpoints are captured with the [`Pipeline`] `P`.
+///
+/// Added by [`AppSaveableExt::checkpoint_every`](crate::AppSaveableExt::checkpoint_every), which captures a
+/// checkpoint in the [`SaveSet`] at most every `interval` ticks, or on the next tick after [`request`](Self::request).
+#[derive(Resource)]
+pub struct CaptureThrottle<P> {
+    interval: u32,
+    elapsed: u32,
+    requested: bool,
+    _marker: PhantomData<fn() -> P>,
+}
+
+impl<P> CaptureThrottle<P> {
+    /// Create a new [`CaptureThrottle`] that is ready every `interval` ticks.
+    pub fn new(interval: u32) -> Self {
+        Self {
+            interval: interval.max(1),
+            elapsed: 0,
+            requested: false,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of ticks between captures.
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    /// Change the number of ticks between captures.
+    pub fn set_interval(&mut self, interval: u32) {
+        self.interval = interval.max(1);
+    }
+
+    /// Capture on the next tick, regardless of the interval.
+    pub fn request(&mut self) {
+        self.requested = true;
+    }
+
+    /// Advance by one tick, returning true if a capture should be performed.
+    pub fn tick(&mut self) -> bool {
+        self.elapsed += 1;
+
+        if self.requested || self.elapsed >= self.interval {
+            self.elapsed = 0;
+            self.requested = false;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<P: Pipeline + 'static> CaptureThrottle<P> {
+    /// System capturing a checkpoint with the [`Pipeline`] whenever the throttle is ready.
+    pub fn checkpoint(world: &mut World) {
+        if world.resource_mut::<Self>().tick() {
+            world.checkpoint::<P>();
+        }
+    }
+}
+
+/// Exclusive system saving with the [`Pipeline`] every time it runs.
+///
+/// Can be added to any schedule, such as [`FixedUpdate`], and combined with run conditions. Unlike
+/// [`save_deferred`](WorldSaveableExt::save_deferred), the save is performed immediately. Errors are logged.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// #[derive(Resource)]
+/// struct AutosaveTimer(Timer);
+///
+/// fn autosave_ready(timer: Res<AutosaveTimer>) -> bool {
+///     timer.0.just_finished()
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins((MinimalPlugins, SavePlugins));
+/// app.add_systems(FixedUpdate, save_system("autosave").run_if(autosave_ready));
+/// ```
+pub fn save_system<P: Pipeline + Clone + Send + Sync + 'static>(
+    pipeline: P,
+) -> impl FnMut(&mut World) + Send + Sync + 'static {
+    move |world| {
+        if let Err(err) = world.save(pipeline.clone()) {
+            error!("Save failed: {err}");
+        }
+    }
+}
+
+/// Exclusive system loading with the [`Pipeline`] every time it runs.
+///
+/// Can be added to any schedule and combined with run conditions, see [`save_system`]. Errors are logged.
+pub fn load_system<P: Pipeline + Clone + Send + Sync + 'static>(
+    pipeline: P,
+) -> impl FnMut(&mut World) + Send + Sync + 'static {
+    move |world| {
+        if let Err(err) = world.load(pipeline.clone()) {
+            error!("Load failed: {err}");
+        }
+    }
+}
+
+/// Exclusive system capturing a rollback checkpoint with the [`Pipeline`] every time it runs.
+///
+/// See [`WorldRollbackExt::checkpoint`].
+pub fn checkpoint_system<P: Pipeline + 'static>() -> impl FnMut(&mut World) + Send + Sync + 'static
+{
+    |world| world.checkpoint::<P>()
+}
+
+/// Exclusive system rolling back the given number of checkpoints with the [`Pipeline`] every time it runs.
+///
+/// If `checkpoints` is negative, it rolls forward. See [`WorldRollbackExt::rollback`]. Errors are logged.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// #[derive(Resource)]
+/// struct Desync;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins((MinimalPlugins, SavePlugins));
+/// app.add_systems(FixedUpdate, (
+///     rollback_system::<&str>(1).run_if(resource_exists::<Desync>),
+///     checkpoint_system::<&str>(),
+/// ).chain());
+/// ```
+pub fn rollback_system<P: Pipeline + 'static>(
+    checkpoints: isize,
+) -> impl FnMut(&mut World) + Send + Sync + 'static {
+    move |world| {
+        if let Err(err) = world.rollback::<P>(checkpoints) {
+            error!("Rollback failed: {err}");
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use bevy::{
+    prelude::*,
+    reflect::{
+        serde::{
+            TypedReflectDeserializer,
+            TypedReflectSerializer,
+        },
+        GetPath,
+        TypeRegistry,
+    },
+};
+use serde::de::DeserializeSeed;
+use serde_json::Value;
+
+use crate::{
+    Error,
+    PatchEntry,
+    PatchTarget,
+    Pipeline,
+    ReflectPatch,
+    SnapshotSerializer,
+    WorldSaveableExt,
+};
+
+/// Extension trait exposing snapshots and saveable values to scripting languages as JSON.
+///
+/// The methods only take and return [`PatchTarget`]s, strings, and [`serde_json::Value`]s, so they can be wrapped as
+/// script functions by any scripting integration, such as `bevy_mod_scripting`. Saves can be triggered from scripts
+/// with [`WorldSaveableExt::save_deferred`].
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component)]
+/// struct Player {
+///     health: u32,
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # app.register_type::<Player>();
+/// # let world = &mut app.world;
+/// let player = world.spawn(Player { health: 10 }).id();
+///
+/// let target = PatchTarget::Component {
+///     entity: player,
+///     type_path: Player::type_path().to_owned(),
+/// };
+///
+/// world.set_json(target.clone(), "health", 50.into()).unwrap();
+///
+/// assert_eq!(world.get_json(&target, "health").unwrap(), 50);
+/// ```
+pub trait WorldScriptExt {
+    /// Captures a [`Snapshot`](crate::Snapshot) with the [`Pipeline`] and returns it as JSON.
+    ///
+    /// # Errors
+    /// If the snapshot could not be serialized.
+    fn snapshot_json<P: Pipeline>(&self) -> Result<Value, Error>;
+
+    /// Returns the field at `path` of the target as JSON, or the entire value if `path` is empty.
+    ///
+    /// # Errors
+    /// - If the target type is not registered, or the target entity or resource does not exist
+    /// - If the path is invalid
+    fn get_json(&self, target: &PatchTarget, path: &str) -> Result<Value, Error>;
+
+    /// Sets the field at `path` of the target from JSON, or the entire value if `path` is empty.
+    ///
+    /// # Errors
+    /// - See [`ReflectPatch::apply`]
+    /// - If the value does not match the type of the field
+    fn set_json(&mut self, target: PatchTarget, path: &str, value: Value) -> Result<(), Error>;
+}
+
+impl WorldScriptExt for World {
+    fn snapshot_json<P: Pipeline>(&self) -> Result<Value, Error> {
+        let snapshot = self.snapshot::<P>();
+        let registry = self.resource::<AppTypeRegistry>();
+
+        serde_json::to_value(SnapshotSerializer::new(&snapshot, registry)).map_err(Error::saving)
+    }
+
+    fn get_json(&self, target: &PatchTarget, path: &str) -> Result<Value, Error> {
+        let registry = self.resource::<AppTypeRegistry>().read();
+        let field = reflect_field(self, target, path, &registry)?;
+
+        serde_json::to_value(TypedReflectSerializer::new(field, &registry)).map_err(Error::saving)
+    }
+
+    fn set_json(&mut self, target: PatchTarget, path: &str, value: Value) -> Result<(), Error> {
+        let value = {
+            let registry = self.resource::<AppTypeRegistry>().read();
+            let field = reflect_field(self, &target, path, &registry)?;
+
+            let registration = field
+                .get_represented_type_info()
+                .and_then(|i| registry.get(i.type_id()))
+                .ok_or_else(|| {
+                    Error::custom(format!("unregistered type `{}`", field.reflect_type_path()))
+                })?;
+
+            TypedReflectDeserializer::new(registration, &registry)
+                .deserialize(value)
+                .map_err(Error::loading)?
+        };
+
+        ReflectPatch {
+            entries: vec![PatchEntry {
+                target,
+                path: path.to_owned(),
+                value,
+            }],
+        }
+        .apply(self)
+    }
+}
+
+fn reflect_field<'w>(
+    world: &'w World,
+    target: &PatchTarget,
+    path: &str,
+    registry: &TypeRegistry,
+) -> Result<&'w dyn Reflect, Error> {
+    let type_path = match target {
+        PatchTarget::Component { type_path, .. } | PatchTarget::Resource { type_path } => type_path,
+    };
+
+    let registration = registry
+        .get_with_type_path(type_path)
+        .ok_or_else(|| Error::custom(format!("unregistered type `{type_path}`")))?;
+
+    let value = match target {
+        PatchTarget::Component { entity, .. } => {
+            let reflect = registration.data::<ReflectComponent>().ok_or_else(|| {
+                Error::custom(format!("`{type_path}` does not reflect `Component`"))
+            })?;
+
+            let entity = world
+                .get_entity(*entity)
+                .ok_or_else(|| Error::custom(format!("missing entity {entity:?}")))?;
+
+            reflect
+                .reflect(entity)
+                .ok_or_else(|| Error::custom(format!("missing component `{type_path}`")))?
+        }
+        PatchTarget::Resource { .. } => {
+            let reflect = registration.data::<ReflectResource>().ok_or_else(|| {
+                Error::custom(format!("`{type_path}` does not reflect `Resource`"))
+            })?;
+
+            reflect
+                .reflect(world)
+                .ok_or_else(|| Error::custom(format!("missing resource `{type_path}`")))?
+        }
+    };
+
+    if path.is_empty() {
+        Ok(value)
+    } else {
+        value
+            .reflect_path(path)
+            .map_err(|e| Error::custom(format!("invalid path `{path}`: {e}")))
+    }
+}
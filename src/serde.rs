@@ -1,7 +1,20 @@
-use std::fmt::Formatter;
+use std::{
+    fmt::Formatter,
+    ops::Deref,
+    sync::{
+        Mutex,
+        PoisonError,
+        RwLockReadGuard,
+    },
+};
 
 use bevy::{
     ecs::entity::Entity,
+    prelude::{
+        AppTypeRegistry,
+        Resource,
+        World,
+    },
     reflect::{
         serde::{
             TypeRegistrationDeserializer,
@@ -14,12 +27,17 @@ use bevy::{
         TypeRegistryArc,
     },
     scene::DynamicEntity,
+    tasks::{
+        ComputeTaskPool,
+        TaskPool,
+    },
     utils::HashSet,
 };
 use serde::{
     de::{
         DeserializeSeed,
         Error,
+        IgnoredAny,
         MapAccess,
         SeqAccess,
         Visitor,
@@ -36,34 +54,124 @@ use serde::{
 };
 
 use crate::{
+    content::{
+        Content,
+        ContentDeserializer,
+    },
+    profile::profile_span,
+    ModManifest,
     Rollbacks,
     Snapshot,
+    UnknownBlob,
+    UnknownData,
 };
 
-const SNAPSHOT_STRUCT: &str = "Snapshot";
-const SNAPSHOT_ENTITIES: &str = "entities";
-const SNAPSHOT_RESOURCES: &str = "resources";
-const SNAPSHOT_ROLLBACKS: &str = "rollbacks";
+pub(crate) const SNAPSHOT_STRUCT: &str = "Snapshot";
+pub(crate) const SNAPSHOT_ENTITIES: &str = "entities";
+pub(crate) const SNAPSHOT_RESOURCES: &str = "resources";
+pub(crate) const SNAPSHOT_ROLLBACKS: &str = "rollbacks";
 
 const ROLLBACKS_STRUCT: &str = "Rollbacks";
 const ROLLBACKS_CHECKPOINTS: &str = "checkpoints";
 const ROLLBACKS_ACTIVE: &str = "active";
 
 const ENTITY_STRUCT: &str = "Entity";
-const ENTITY_COMPONENTS: &str = "components";
+pub(crate) const ENTITY_COMPONENTS: &str = "components";
+
+/// A type registry used for serialization, borrowed either directly or through its shared [`TypeRegistryArc`].
+///
+/// Serializers accept any of [`&TypeRegistry`](TypeRegistry), [`&TypeRegistryArc`](TypeRegistryArc) and
+/// [`&AppTypeRegistry`](AppTypeRegistry), so a registry that is already locked for reading can be used directly.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # let world = &mut app.world;
+/// let snapshot = Snapshot::builder(world).build();
+/// let registry = world.resource::<AppTypeRegistry>();
+///
+/// let mut shared = Vec::new();
+/// DefaultFormat::serialize(&mut shared, &SnapshotSerializer::new(&snapshot, registry)).unwrap();
+///
+/// let mut borrowed = Vec::new();
+/// DefaultFormat::serialize(&mut borrowed, &SnapshotSerializer::new(&snapshot, &*registry.read())).unwrap();
+///
+/// assert_eq!(shared, borrowed);
+/// ```
+#[derive(Clone, Copy)]
+pub enum RegistryRef<'a> {
+    /// A borrowed [`TypeRegistry`].
+    Borrowed(&'a TypeRegistry),
+    /// A shared [`TypeRegistryArc`], locked for reading while serializing.
+    Shared(&'a TypeRegistryArc),
+}
+
+impl<'a> RegistryRef<'a> {
+    /// Returns the borrowed registry, locking a shared registry for reading.
+    pub fn read(&self) -> RegistryGuard<'a> {
+        match *self {
+            Self::Borrowed(registry) => RegistryGuard::Borrowed(registry),
+            Self::Shared(registry) => RegistryGuard::Locked(registry.read()),
+        }
+    }
+}
+
+impl<'a> From<&'a TypeRegistry> for RegistryRef<'a> {
+    fn from(registry: &'a TypeRegistry) -> Self {
+        Self::Borrowed(registry)
+    }
+}
+
+impl<'a> From<&'a TypeRegistryArc> for RegistryRef<'a> {
+    fn from(registry: &'a TypeRegistryArc) -> Self {
+        Self::Shared(registry)
+    }
+}
+
+impl<'a> From<&'a AppTypeRegistry> for RegistryRef<'a> {
+    fn from(registry: &'a AppTypeRegistry) -> Self {
+        Self::Shared(registry)
+    }
+}
+
+/// A [`TypeRegistry`] read through a [`RegistryRef`].
+pub enum RegistryGuard<'a> {
+    /// A borrowed [`TypeRegistry`].
+    Borrowed(&'a TypeRegistry),
+    /// A [`TypeRegistryArc`] locked for reading.
+    Locked(RwLockReadGuard<'a, TypeRegistry>),
+}
+
+impl Deref for RegistryGuard<'_> {
+    type Target = TypeRegistry;
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Borrowed(registry) => registry,
+            Self::Locked(guard) => guard,
+        }
+    }
+}
 
 /// Handles serialization of a snapshot as a struct containing its entities and resources.
 pub struct SnapshotSerializer<'a> {
     /// The snapshot to serialize.
     pub snapshot: &'a Snapshot,
     /// Type registry in which the components and resources types used in the snapshot are registered.
-    pub registry: &'a TypeRegistryArc,
+    pub registry: RegistryRef<'a>,
 }
 
 impl<'a> SnapshotSerializer<'a> {
     /// Creates a snapshot serializer.
-    pub fn new(snapshot: &'a Snapshot, registry: &'a TypeRegistryArc) -> Self {
-        SnapshotSerializer { snapshot, registry }
+    pub fn new(snapshot: &'a Snapshot, registry: impl Into<RegistryRef<'a>>) -> Self {
+        SnapshotSerializer {
+            snapshot,
+            registry: registry.into(),
+        }
     }
 }
 
@@ -83,10 +191,12 @@ impl<'a> Serialize for SnapshotSerializer<'a> {
         state.serialize_field(SNAPSHOT_ENTITIES, &EntityMapSerializer {
             entities: &self.snapshot.entities,
             registry: self.registry,
+            unknown: &self.snapshot.unknown,
         })?;
         state.serialize_field(SNAPSHOT_RESOURCES, &ReflectMapSerializer {
             entries: &self.snapshot.resources,
             registry: self.registry,
+            unknown: self.snapshot.unknown.resources(),
         })?;
 
         if let Some(rollbacks) = &self.snapshot.rollbacks {
@@ -101,8 +211,8 @@ impl<'a> Serialize for SnapshotSerializer<'a> {
 }
 
 struct SnapshotListSerializer<'a> {
-    snapshots: &'a [Snapshot],
-    registry: &'a TypeRegistryArc,
+    snapshots: Vec<&'a Snapshot>,
+    registry: RegistryRef<'a>,
 }
 
 impl<'a> Serialize for SnapshotListSerializer<'a> {
@@ -112,7 +222,7 @@ impl<'a> Serialize for SnapshotListSerializer<'a> {
     {
         let mut seq = serializer.serialize_seq(Some(self.snapshots.len()))?;
 
-        for snapshot in self.snapshots {
+        for &snapshot in &self.snapshots {
             seq.serialize_element(&SnapshotSerializer {
                 snapshot,
                 registry: self.registry,
@@ -128,7 +238,7 @@ pub struct RollbacksSerializer<'a> {
     /// The rollbacks to serialize.
     pub rollbacks: &'a Rollbacks,
     /// Type registry in which the components and resources types used in the rollbacks are registered.
-    pub registry: &'a TypeRegistryArc,
+    pub registry: RegistryRef<'a>,
 }
 
 impl<'a> Serialize for RollbacksSerializer<'a> {
@@ -138,19 +248,22 @@ impl<'a> Serialize for RollbacksSerializer<'a> {
     {
         let mut state = serializer.serialize_struct(ROLLBACKS_STRUCT, 2)?;
 
+        let (snapshots, active) = self.rollbacks.current_path();
+
         state.serialize_field(ROLLBACKS_CHECKPOINTS, &SnapshotListSerializer {
-            snapshots: &self.rollbacks.checkpoints,
+            snapshots,
             registry: self.registry,
         })?;
-        state.serialize_field(ROLLBACKS_ACTIVE, &self.rollbacks.active)?;
+        state.serialize_field(ROLLBACKS_ACTIVE, &active)?;
 
         state.end()
     }
 }
 
-struct EntityMapSerializer<'a> {
-    entities: &'a [DynamicEntity],
-    registry: &'a TypeRegistryArc,
+pub(crate) struct EntityMapSerializer<'a> {
+    pub(crate) entities: &'a [DynamicEntity],
+    pub(crate) registry: RegistryRef<'a>,
+    pub(crate) unknown: &'a UnknownData,
 }
 
 impl<'a> Serialize for EntityMapSerializer<'a> {
@@ -163,6 +276,7 @@ impl<'a> Serialize for EntityMapSerializer<'a> {
             state.serialize_entry(&entity.entity, &EntitySerializer {
                 entity,
                 registry: self.registry,
+                unknown: self.unknown.components(entity.entity),
             })?;
         }
         state.end()
@@ -171,7 +285,8 @@ impl<'a> Serialize for EntityMapSerializer<'a> {
 
 struct EntitySerializer<'a> {
     entity: &'a DynamicEntity,
-    registry: &'a TypeRegistryArc,
+    registry: RegistryRef<'a>,
+    unknown: &'a [UnknownBlob],
 }
 
 impl<'a> Serialize for EntitySerializer<'a> {
@@ -183,14 +298,16 @@ impl<'a> Serialize for EntitySerializer<'a> {
         state.serialize_field(ENTITY_COMPONENTS, &ReflectMapSerializer {
             entries: &self.entity.components,
             registry: self.registry,
+            unknown: self.unknown,
         })?;
         state.end()
     }
 }
 
-struct ReflectMapSerializer<'a> {
-    entries: &'a [Box<dyn Reflect>],
-    registry: &'a TypeRegistryArc,
+pub(crate) struct ReflectMapSerializer<'a> {
+    pub(crate) entries: &'a [Box<dyn Reflect>],
+    pub(crate) registry: RegistryRef<'a>,
+    pub(crate) unknown: &'a [UnknownBlob],
 }
 
 impl<'a> Serialize for ReflectMapSerializer<'a> {
@@ -198,20 +315,29 @@ impl<'a> Serialize for ReflectMapSerializer<'a> {
     where
         S: serde::Serializer,
     {
-        let mut state = serializer.serialize_map(Some(self.entries.len()))?;
+        let registry = self.registry.read();
+
+        let mut state = serializer.serialize_map(Some(self.entries.len() + self.unknown.len()))?;
         for reflect in self.entries {
+            let type_path = reflect.get_represented_type_info().unwrap().type_path();
+
+            profile_span!("serialize", type_path);
+
             state.serialize_entry(
-                reflect.get_represented_type_info().unwrap().type_path(),
-                &TypedReflectSerializer::new(&**reflect, &self.registry.read()),
+                type_path,
+                &TypedReflectSerializer::new(&**reflect, &registry),
             )?;
         }
+        for blob in self.unknown {
+            state.serialize_entry(&blob.type_path, &blob.value)?;
+        }
         state.end()
     }
 }
 
 #[derive(Deserialize)]
 #[serde(field_identifier, rename_all = "lowercase")]
-enum SnapshotField {
+pub(crate) enum SnapshotField {
     Entities,
     Resources,
     Rollbacks,
@@ -230,10 +356,142 @@ enum EntityField {
     Components,
 }
 
+/// Limits enforced while deserializing a [`Snapshot`], protecting against corrupted or malicious saves.
+///
+/// Insert as a resource to override the limits used when loading with a [`Pipeline`](crate::Pipeline).
+/// To limit the total size of a save, use the [`Limited`](crate::Limited) middleware.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DeserializeLimits {
+    /// The maximum number of entities in a snapshot, counted across all of its rollback checkpoints.
+    pub max_entities: usize,
+    /// The maximum number of components per entity, also applied to the resources of a snapshot.
+    pub max_components: usize,
+    /// The maximum number of rollback checkpoints in a snapshot.
+    pub max_checkpoints: usize,
+}
+
+impl DeserializeLimits {
+    /// The default limits, large enough for any reasonable save.
+    pub const DEFAULT: Self = Self {
+        max_entities: 1 << 20,
+        max_components: 1 << 10,
+        max_checkpoints: 1 << 12,
+    };
+
+    /// No limits.
+    pub const UNLIMITED: Self = Self {
+        max_entities: usize::MAX,
+        max_components: usize::MAX,
+        max_checkpoints: usize::MAX,
+    };
+
+    /// Returns the limits configured in the [`World`], or the default limits.
+    pub(crate) fn from_world(world: &World) -> Self {
+        world.get_resource::<Self>().copied().unwrap_or_default()
+    }
+
+    /// Returns the limits left for the rest of a snapshot after the given number of entities and checkpoints.
+    pub(crate) fn remaining(self, entities: usize, checkpoints: usize) -> Self {
+        Self {
+            max_entities: self.max_entities.saturating_sub(entities),
+            max_checkpoints: self.max_checkpoints.saturating_sub(checkpoints),
+            ..self
+        }
+    }
+
+    /// Returns a description of the limit the snapshot and its rollback checkpoints exceed, if any.
+    pub(crate) fn exceeded_by(&self, snapshot: &Snapshot) -> Option<String> {
+        let (entities, checkpoints) = snapshot_size(snapshot);
+
+        if entities > self.max_entities {
+            Some(format!(
+                "snapshot exceeds the limit of {} entities",
+                self.max_entities
+            ))
+        } else if checkpoints > self.max_checkpoints {
+            Some(format!(
+                "snapshot exceeds the limit of {} checkpoints",
+                self.max_checkpoints
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the number of entities and rollback checkpoints in the snapshot, including nested checkpoints.
+fn snapshot_size(snapshot: &Snapshot) -> (usize, usize) {
+    snapshot
+        .rollbacks
+        .iter()
+        .flat_map(|r| &r.checkpoints)
+        .map(snapshot_size)
+        .fold(
+            (snapshot.entities.len(), 0),
+            |(e, c), (entities, checkpoints)| (e + entities, c + checkpoints + 1),
+        )
+}
+
+impl Default for DeserializeLimits {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 /// Handles snapshot deserialization.
 pub struct SnapshotDeserializer<'a> {
     /// Type registry in which the components and resources types used in the snapshot to deserialize are registered.
     pub registry: &'a TypeRegistry,
+    /// Limits enforced while deserializing.
+    pub limits: DeserializeLimits,
+    /// Whether entities are deserialized in parallel, see [`SnapshotDeserializer::parallel`].
+    pub parallel: bool,
+    /// Whether values of unregistered types are retained, see [`SnapshotDeserializer::lenient`].
+    pub lenient: bool,
+}
+
+impl<'a> SnapshotDeserializer<'a> {
+    /// Creates a snapshot deserializer with the default [`DeserializeLimits`].
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            limits: DeserializeLimits::default(),
+            parallel: false,
+            lenient: false,
+        }
+    }
+
+    /// Creates a snapshot deserializer with the [`DeserializeLimits`] of the [`World`], retaining values of
+    /// unregistered types if the [`World`] has a [`ModManifest`].
+    pub(crate) fn for_world(registry: &'a TypeRegistry, world: &World) -> Self {
+        Self::new(registry)
+            .limits(DeserializeLimits::from_world(world))
+            .lenient(world.contains_resource::<ModManifest>())
+    }
+
+    /// Set the [`DeserializeLimits`] enforced while deserializing.
+    pub fn limits(mut self, limits: DeserializeLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Deserialize entities in parallel on the [`ComputeTaskPool`].
+    ///
+    /// Entities are first parsed into owned buffers, which requires a self-describing format
+    /// (see [`Format::self_describing`](crate::Format::self_describing)).
+    pub fn parallel(mut self, parallel: bool) -> Self {
+        self.parallel = parallel;
+        self
+    }
+
+    /// Retain components and resources of unregistered types instead of failing.
+    ///
+    /// Their values are kept in the [`Snapshot::unknown`] data and written again when the snapshot is serialized.
+    /// Requires a self-describing format (see [`Format::self_describing`](crate::Format::self_describing)).
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
 }
 
 impl<'a, 'de> DeserializeSeed<'de> for SnapshotDeserializer<'a> {
@@ -248,13 +506,19 @@ impl<'a, 'de> DeserializeSeed<'de> for SnapshotDeserializer<'a> {
             &[SNAPSHOT_ENTITIES, SNAPSHOT_RESOURCES, SNAPSHOT_ROLLBACKS],
             SnapshotVisitor {
                 registry: self.registry,
+                limits: self.limits,
+                parallel: self.parallel,
+                lenient: self.lenient,
             },
         )
     }
 }
 
 struct SnapshotVisitor<'a> {
-    pub registry: &'a TypeRegistry,
+    registry: &'a TypeRegistry,
+    limits: DeserializeLimits,
+    parallel: bool,
+    lenient: bool,
 }
 
 impl<'a, 'de> Visitor<'de> for SnapshotVisitor<'a> {
@@ -268,6 +532,9 @@ impl<'a, 'de> Visitor<'de> for SnapshotVisitor<'a> {
     where
         A: MapAccess<'de>,
     {
+        let unknown = UnknownStore::default();
+        let unknown_ref = self.lenient.then_some(&unknown);
+
         let mut entities = None;
         let mut resources = None;
         let mut rollbacks = None;
@@ -280,6 +547,9 @@ impl<'a, 'de> Visitor<'de> for SnapshotVisitor<'a> {
                     }
                     entities = Some(map.next_value_seed(EntityMapDeserializer {
                         registry: self.registry,
+                        limits: self.limits,
+                        parallel: self.parallel,
+                        unknown: unknown_ref,
                     })?);
                 }
                 SnapshotField::Resources => {
@@ -288,15 +558,24 @@ impl<'a, 'de> Visitor<'de> for SnapshotVisitor<'a> {
                     }
                     resources = Some(map.next_value_seed(ReflectMapDeserializer {
                         registry: self.registry,
+                        limits: self.limits,
+                        unknown: unknown_ref,
+                        owner: None,
                     })?);
                 }
                 SnapshotField::Rollbacks => {
                     if rollbacks.is_some() {
                         return Err(Error::duplicate_field(SNAPSHOT_ROLLBACKS));
                     }
-                    rollbacks = Some(map.next_value_seed(RollbacksDeserializer {
-                        registry: self.registry,
-                    })?);
+                    rollbacks = Some(
+                        map.next_value_seed(RollbacksDeserializer {
+                            registry: self.registry,
+                            limits: self
+                                .limits
+                                .remaining(entities.as_ref().map_or(0, Vec::len), 0),
+                            lenient: self.lenient,
+                        })?,
+                    );
                 }
             }
         }
@@ -304,37 +583,57 @@ impl<'a, 'de> Visitor<'de> for SnapshotVisitor<'a> {
         let entities = entities.ok_or_else(|| Error::missing_field(SNAPSHOT_ENTITIES))?;
         let resources = resources.ok_or_else(|| Error::missing_field(SNAPSHOT_RESOURCES))?;
 
-        Ok(Snapshot {
+        let snapshot = Snapshot {
             entities,
             resources,
             rollbacks,
-        })
+            unknown: unknown.into_inner().unwrap_or_else(PoisonError::into_inner),
+        };
+
+        // Rollbacks may come before the entities they share the limits with
+        if let Some(exceeded) = self.limits.exceeded_by(&snapshot) {
+            return Err(Error::custom(exceeded));
+        }
+
+        Ok(snapshot)
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
     {
+        let unknown = UnknownStore::default();
+        let unknown_ref = self.lenient.then_some(&unknown);
+
         let entities = seq
             .next_element_seed(EntityMapDeserializer {
                 registry: self.registry,
+                limits: self.limits,
+                parallel: self.parallel,
+                unknown: unknown_ref,
             })?
             .ok_or_else(|| Error::missing_field(SNAPSHOT_ENTITIES))?;
 
         let resources = seq
             .next_element_seed(ReflectMapDeserializer {
                 registry: self.registry,
+                limits: self.limits,
+                unknown: unknown_ref,
+                owner: None,
             })?
             .ok_or_else(|| Error::missing_field(SNAPSHOT_RESOURCES))?;
 
         let rollbacks = seq.next_element_seed(RollbacksDeserializer {
             registry: self.registry,
+            limits: self.limits.remaining(entities.len(), 0),
+            lenient: self.lenient,
         })?;
 
         Ok(Snapshot {
             entities,
             resources,
             rollbacks,
+            unknown: unknown.into_inner().unwrap_or_else(PoisonError::into_inner),
         })
     }
 }
@@ -343,6 +642,21 @@ impl<'a, 'de> Visitor<'de> for SnapshotVisitor<'a> {
 pub struct RollbacksDeserializer<'a> {
     /// Type registry in which the components and resources types used to deserialize the rollbacks are registered.
     pub registry: &'a TypeRegistry,
+    /// Limits enforced while deserializing each snapshot.
+    pub limits: DeserializeLimits,
+    /// Whether values of unregistered types are retained, see [`SnapshotDeserializer::lenient`].
+    pub lenient: bool,
+}
+
+impl<'a> RollbacksDeserializer<'a> {
+    /// Creates a rollbacks deserializer with the default [`DeserializeLimits`].
+    pub fn new(registry: &'a TypeRegistry) -> Self {
+        Self {
+            registry,
+            limits: DeserializeLimits::default(),
+            lenient: false,
+        }
+    }
 }
 
 impl<'a, 'de> DeserializeSeed<'de> for RollbacksDeserializer<'a> {
@@ -357,6 +671,8 @@ impl<'a, 'de> DeserializeSeed<'de> for RollbacksDeserializer<'a> {
             &[ROLLBACKS_CHECKPOINTS, ROLLBACKS_ACTIVE],
             RollbacksVisitor {
                 registry: self.registry,
+                limits: self.limits,
+                lenient: self.lenient,
             },
         )
     }
@@ -364,6 +680,8 @@ impl<'a, 'de> DeserializeSeed<'de> for RollbacksDeserializer<'a> {
 
 struct RollbacksVisitor<'a> {
     registry: &'a TypeRegistry,
+    limits: DeserializeLimits,
+    lenient: bool,
 }
 
 impl<'a, 'de> Visitor<'de> for RollbacksVisitor<'a> {
@@ -388,6 +706,8 @@ impl<'a, 'de> Visitor<'de> for RollbacksVisitor<'a> {
                     }
                     checkpoints = Some(map.next_value_seed(SnapshotListDeserializer {
                         registry: self.registry,
+                        limits: self.limits,
+                        lenient: self.lenient,
                     })?);
                 }
                 RollbacksField::Active => {
@@ -402,10 +722,8 @@ impl<'a, 'de> Visitor<'de> for RollbacksVisitor<'a> {
         let checkpoints = checkpoints.ok_or_else(|| Error::missing_field(ROLLBACKS_CHECKPOINTS))?;
         let active = active.ok_or_else(|| Error::missing_field(ROLLBACKS_ACTIVE))?;
 
-        Ok(Rollbacks {
-            checkpoints,
-            active,
-        })
+        Rollbacks::linear(checkpoints, active)
+            .ok_or_else(|| Error::custom("active rollback checkpoint is out of bounds"))
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
@@ -415,6 +733,8 @@ impl<'a, 'de> Visitor<'de> for RollbacksVisitor<'a> {
         let checkpoints = seq
             .next_element_seed(SnapshotListDeserializer {
                 registry: self.registry,
+                limits: self.limits,
+                lenient: self.lenient,
             })?
             .ok_or_else(|| Error::missing_field(ROLLBACKS_CHECKPOINTS))?;
 
@@ -422,15 +742,15 @@ impl<'a, 'de> Visitor<'de> for RollbacksVisitor<'a> {
             .next_element()?
             .ok_or_else(|| Error::missing_field(ROLLBACKS_ACTIVE))?;
 
-        Ok(Rollbacks {
-            checkpoints,
-            active,
-        })
+        Rollbacks::linear(checkpoints, active)
+            .ok_or_else(|| Error::custom("active rollback checkpoint is out of bounds"))
     }
 }
 
 struct SnapshotListDeserializer<'a> {
     registry: &'a TypeRegistry,
+    limits: DeserializeLimits,
+    lenient: bool,
 }
 
 impl<'a, 'de> DeserializeSeed<'de> for SnapshotListDeserializer<'a> {
@@ -442,12 +762,16 @@ impl<'a, 'de> DeserializeSeed<'de> for SnapshotListDeserializer<'a> {
     {
         deserializer.deserialize_seq(SnapshotListVisitor {
             registry: self.registry,
+            limits: self.limits,
+            lenient: self.lenient,
         })
     }
 }
 
 struct SnapshotListVisitor<'a> {
     registry: &'a TypeRegistry,
+    limits: DeserializeLimits,
+    lenient: bool,
 }
 
 impl<'a, 'de> Visitor<'de> for SnapshotListVisitor<'a> {
@@ -462,10 +786,27 @@ impl<'a, 'de> Visitor<'de> for SnapshotListVisitor<'a> {
         A: SeqAccess<'de>,
     {
         let mut result = Vec::new();
+        let mut entities = 0;
+        let mut checkpoints = 0;
+
+        // Checkpoints share the limits, so a save cannot exceed them by splitting its entities across checkpoints
+        while let Some(next) = seq.next_element_seed(
+            SnapshotDeserializer::new(self.registry)
+                .limits(self.limits.remaining(entities, checkpoints))
+                .lenient(self.lenient),
+        )? {
+            let (e, c) = snapshot_size(&next);
+
+            entities += e;
+            checkpoints += c + 1;
+
+            if checkpoints > self.limits.max_checkpoints {
+                return Err(Error::custom(format_args!(
+                    "snapshot exceeds the limit of {} checkpoints",
+                    self.limits.max_checkpoints
+                )));
+            }
 
-        while let Some(next) = seq.next_element_seed(SnapshotDeserializer {
-            registry: self.registry,
-        })? {
             result.push(next);
         }
 
@@ -473,8 +814,11 @@ impl<'a, 'de> Visitor<'de> for SnapshotListVisitor<'a> {
     }
 }
 
-struct EntityMapDeserializer<'a> {
-    registry: &'a TypeRegistry,
+pub(crate) struct EntityMapDeserializer<'a> {
+    pub(crate) registry: &'a TypeRegistry,
+    pub(crate) limits: DeserializeLimits,
+    pub(crate) parallel: bool,
+    pub(crate) unknown: Option<&'a UnknownStore>,
 }
 
 impl<'a, 'de> DeserializeSeed<'de> for EntityMapDeserializer<'a> {
@@ -486,12 +830,18 @@ impl<'a, 'de> DeserializeSeed<'de> for EntityMapDeserializer<'a> {
     {
         deserializer.deserialize_map(EntityMapVisitor {
             registry: self.registry,
+            limits: self.limits,
+            parallel: self.parallel,
+            unknown: self.unknown,
         })
     }
 }
 
 struct EntityMapVisitor<'a> {
     registry: &'a TypeRegistry,
+    limits: DeserializeLimits,
+    parallel: bool,
+    unknown: Option<&'a UnknownStore>,
 }
 
 impl<'a, 'de> Visitor<'de> for EntityMapVisitor<'a> {
@@ -506,21 +856,88 @@ impl<'a, 'de> Visitor<'de> for EntityMapVisitor<'a> {
         A: MapAccess<'de>,
     {
         let mut entities = Vec::new();
+        let mut buffered = Vec::new();
+
         while let Some(entity) = map.next_key::<Entity>()? {
-            let entity = map.next_value_seed(EntityDeserializer {
-                entity,
-                registry: self.registry,
-            })?;
-            entities.push(entity);
+            if entities.len() + buffered.len() >= self.limits.max_entities {
+                return Err(Error::custom(format_args!(
+                    "snapshot exceeds the limit of {} entities",
+                    self.limits.max_entities,
+                )));
+            }
+
+            if self.parallel {
+                buffered.push((entity, map.next_value::<Content>()?));
+            } else {
+                entities.push(map.next_value_seed(EntityDeserializer {
+                    entity,
+                    registry: self.registry,
+                    limits: self.limits,
+                    unknown: self.unknown,
+                })?);
+            }
+        }
+
+        if !buffered.is_empty() {
+            entities = self.deserialize_buffered(buffered).map_err(Error::custom)?;
         }
 
         Ok(entities)
     }
 }
 
-struct EntityDeserializer<'a> {
-    entity: Entity,
-    registry: &'a TypeRegistry,
+impl EntityMapVisitor<'_> {
+    /// Deserializes the buffered entities in parallel, preserving their order.
+    fn deserialize_buffered(
+        &self,
+        buffered: Vec<(Entity, Content)>,
+    ) -> Result<Vec<DynamicEntity>, serde::de::value::Error> {
+        let pool = ComputeTaskPool::get_or_init(TaskPool::default);
+        let size = buffered.len().div_ceil(pool.thread_num().max(1));
+
+        let mut buffered = buffered.into_iter();
+        let registry = self.registry;
+        let limits = self.limits;
+        let unknown = self.unknown;
+
+        let chunks = pool.scope(|scope| loop {
+            let chunk = buffered.by_ref().take(size).collect::<Vec<_>>();
+
+            if chunk.is_empty() {
+                break;
+            }
+
+            scope.spawn(async move {
+                chunk
+                    .into_iter()
+                    .map(|(entity, content)| {
+                        EntityDeserializer {
+                            entity,
+                            registry,
+                            limits,
+                            unknown,
+                        }
+                        .deserialize(ContentDeserializer::new(content))
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            });
+        });
+
+        let mut entities = Vec::new();
+
+        for chunk in chunks {
+            entities.extend(chunk?);
+        }
+
+        Ok(entities)
+    }
+}
+
+pub(crate) struct EntityDeserializer<'a> {
+    pub(crate) entity: Entity,
+    pub(crate) registry: &'a TypeRegistry,
+    pub(crate) limits: DeserializeLimits,
+    pub(crate) unknown: Option<&'a UnknownStore>,
 }
 
 impl<'a, 'de> DeserializeSeed<'de> for EntityDeserializer<'a> {
@@ -533,6 +950,8 @@ impl<'a, 'de> DeserializeSeed<'de> for EntityDeserializer<'a> {
         deserializer.deserialize_struct(ENTITY_STRUCT, &[ENTITY_COMPONENTS], EntityVisitor {
             entity: self.entity,
             registry: self.registry,
+            limits: self.limits,
+            unknown: self.unknown,
         })
     }
 }
@@ -540,6 +959,8 @@ impl<'a, 'de> DeserializeSeed<'de> for EntityDeserializer<'a> {
 struct EntityVisitor<'a> {
     entity: Entity,
     registry: &'a TypeRegistry,
+    limits: DeserializeLimits,
+    unknown: Option<&'a UnknownStore>,
 }
 
 impl<'a, 'de> Visitor<'de> for EntityVisitor<'a> {
@@ -556,6 +977,9 @@ impl<'a, 'de> Visitor<'de> for EntityVisitor<'a> {
         let components = seq
             .next_element_seed(ReflectMapDeserializer {
                 registry: self.registry,
+                limits: self.limits,
+                unknown: self.unknown,
+                owner: Some(self.entity),
             })?
             .ok_or_else(|| Error::missing_field(ENTITY_COMPONENTS))?;
 
@@ -579,6 +1003,9 @@ impl<'a, 'de> Visitor<'de> for EntityVisitor<'a> {
 
                     components = Some(map.next_value_seed(ReflectMapDeserializer {
                         registry: self.registry,
+                        limits: self.limits,
+                        unknown: self.unknown,
+                        owner: Some(self.entity),
                     })?);
                 }
             }
@@ -594,8 +1021,12 @@ impl<'a, 'de> Visitor<'de> for EntityVisitor<'a> {
     }
 }
 
-struct ReflectMapDeserializer<'a> {
-    registry: &'a TypeRegistry,
+pub(crate) struct ReflectMapDeserializer<'a> {
+    pub(crate) registry: &'a TypeRegistry,
+    pub(crate) limits: DeserializeLimits,
+    pub(crate) unknown: Option<&'a UnknownStore>,
+    /// The entity owning the components, or `None` for resources.
+    pub(crate) owner: Option<Entity>,
 }
 
 impl<'a, 'de> DeserializeSeed<'de> for ReflectMapDeserializer<'a> {
@@ -607,12 +1038,18 @@ impl<'a, 'de> DeserializeSeed<'de> for ReflectMapDeserializer<'a> {
     {
         deserializer.deserialize_map(ReflectMapVisitor {
             registry: self.registry,
+            limits: self.limits,
+            unknown: self.unknown,
+            owner: self.owner,
         })
     }
 }
 
 struct ReflectMapVisitor<'a> {
-    pub registry: &'a TypeRegistry,
+    registry: &'a TypeRegistry,
+    limits: DeserializeLimits,
+    unknown: Option<&'a UnknownStore>,
+    owner: Option<Entity>,
 }
 
 impl<'a, 'de> Visitor<'de> for ReflectMapVisitor<'a> {
@@ -628,9 +1065,37 @@ impl<'a, 'de> Visitor<'de> for ReflectMapVisitor<'a> {
     {
         let mut added = HashSet::new();
         let mut entries = Vec::new();
-        while let Some(registration) =
-            map.next_key_seed(TypeRegistrationDeserializer::new(self.registry))?
-        {
+
+        loop {
+            let registration = match self.unknown {
+                Some(unknown) => {
+                    let Some(type_path) = map.next_key::<String>()? else {
+                        break;
+                    };
+
+                    let Some(registration) = self.registry.get_with_type_path(&type_path) else {
+                        let value = map.next_value::<Content>()?;
+                        store_unknown(unknown, self.owner, type_path, value);
+                        continue;
+                    };
+
+                    registration
+                }
+                None => {
+                    match map.next_key_seed(TypeRegistrationDeserializer::new(self.registry))? {
+                        Some(registration) => registration,
+                        None => break,
+                    }
+                }
+            };
+
+            if entries.len() >= self.limits.max_components {
+                return Err(Error::custom(format_args!(
+                    "entity exceeds the limit of {} components",
+                    self.limits.max_components,
+                )));
+            }
+
             if !added.insert(registration.type_id()) {
                 return Err(Error::custom(format_args!(
                     "duplicate reflect type: `{}`",
@@ -651,12 +1116,95 @@ impl<'a, 'de> Visitor<'de> for ReflectMapVisitor<'a> {
         A: SeqAccess<'de>,
     {
         let mut dynamic_properties = Vec::new();
-        while let Some(entity) =
-            seq.next_element_seed(UntypedReflectDeserializer::new(self.registry))?
-        {
+
+        loop {
+            let entity = match self.unknown {
+                Some(unknown) => match seq.next_element_seed(LenientReflectDeserializer {
+                    registry: self.registry,
+                    unknown,
+                    owner: self.owner,
+                })? {
+                    Some(Some(entity)) => entity,
+                    Some(None) => continue,
+                    None => break,
+                },
+                None => {
+                    match seq.next_element_seed(UntypedReflectDeserializer::new(self.registry))? {
+                        Some(entity) => entity,
+                        None => break,
+                    }
+                }
+            };
+
+            if dynamic_properties.len() >= self.limits.max_components {
+                return Err(Error::custom(format_args!(
+                    "entity exceeds the limit of {} components",
+                    self.limits.max_components,
+                )));
+            }
+
             dynamic_properties.push(entity);
         }
 
         Ok(dynamic_properties)
     }
 }
+
+/// Values of unregistered types collected while deserializing leniently.
+pub(crate) type UnknownStore = Mutex<UnknownData>;
+
+fn store_unknown(unknown: &UnknownStore, owner: Option<Entity>, type_path: String, value: Content) {
+    unknown
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(owner, UnknownBlob { type_path, value });
+}
+
+/// Deserializes a single `{ type_path: value }` entry, retaining it as unknown data if the type is not registered.
+struct LenientReflectDeserializer<'a> {
+    registry: &'a TypeRegistry,
+    unknown: &'a UnknownStore,
+    owner: Option<Entity>,
+}
+
+impl<'de> DeserializeSeed<'de> for LenientReflectDeserializer<'_> {
+    type Value = Option<Box<dyn Reflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for LenientReflectDeserializer<'_> {
+    type Value = Option<Box<dyn Reflect>>;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("map containing `type` and `value` entries for the reflected value")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let type_path = map
+            .next_key::<String>()?
+            .ok_or_else(|| Error::invalid_length(0, &"a single entry"))?;
+
+        let value = if let Some(registration) = self.registry.get_with_type_path(&type_path) {
+            Some(map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?)
+        } else {
+            let value = map.next_value::<Content>()?;
+            store_unknown(self.unknown, self.owner, type_path, value);
+            None
+        };
+
+        if map.next_key::<IgnoredAny>()?.is_some() {
+            return Err(Error::invalid_length(2, &"a single entry"));
+        }
+
+        Ok(value)
+    }
+}
@@ -0,0 +1,290 @@
+use std::{
+    any::TypeId,
+    sync::Arc,
+};
+
+use bevy::{
+    ecs::{
+        component::Tick,
+        entity::EntityHashMap,
+    },
+    prelude::*,
+    scene::DynamicEntity,
+};
+
+use crate::{
+    Error,
+    Pipeline,
+    Snapshot,
+    SnapshotBuilder,
+    UnknownData,
+};
+
+/// A captured component or resource value, shared between [`SharedSnapshot`]s while it is unchanged.
+pub type SharedValue = Arc<dyn Reflect>;
+
+fn type_id(value: &dyn Reflect) -> Option<TypeId> {
+    value.get_represented_type_info().map(|i| i.type_id())
+}
+
+/// An entity captured in a [`SharedSnapshot`].
+#[derive(Clone)]
+pub struct SharedEntity {
+    /// The entity.
+    pub entity: Entity,
+    /// The captured components of the entity.
+    pub components: Vec<SharedValue>,
+}
+
+/// An immutable [`Snapshot`] whose values are shared with the checkpoints captured before and after it.
+///
+/// Cloning a [`SharedSnapshot`] only clones the [`Arc`]s of its values.
+#[derive(Clone, Default)]
+pub struct SharedSnapshot {
+    /// The captured entities.
+    pub entities: Vec<SharedEntity>,
+    /// The captured resources.
+    pub resources: Vec<SharedValue>,
+}
+
+impl SharedSnapshot {
+    /// Returns the number of values stored by both this and the other [`SharedSnapshot`], rather than copied.
+    pub fn shared_with(&self, other: &SharedSnapshot) -> usize {
+        let values = |s: &SharedSnapshot| {
+            s.entities
+                .iter()
+                .flat_map(|e| e.components.iter())
+                .chain(s.resources.iter())
+                .map(|v| Arc::as_ptr(v).cast::<()>())
+                .collect::<Vec<_>>()
+        };
+
+        let theirs = values(other);
+
+        values(self)
+            .into_iter()
+            .filter(|v| theirs.contains(v))
+            .count()
+    }
+
+    /// Copies the values into a [`Snapshot`], so it can be applied or serialized.
+    pub fn to_snapshot(&self) -> Snapshot {
+        Snapshot {
+            entities: self
+                .entities
+                .iter()
+                .map(|e| DynamicEntity {
+                    entity: e.entity,
+                    components: e.components.iter().map(|c| c.clone_value()).collect(),
+                })
+                .collect(),
+            resources: self.resources.iter().map(|r| r.clone_value()).collect(),
+            rollbacks: None,
+            unknown: UnknownData::default(),
+        }
+    }
+}
+
+/// Checkpoints for rollback which share unchanged values instead of cloning them.
+///
+/// Each checkpoint only clones the components and resources changed since the previous checkpoint through
+/// reflection. Unchanged values are shared with the previous checkpoint, making per-tick checkpoints cheap when most
+/// of the [`World`] is idle. Values are immutable once captured, and are copied into a [`Snapshot`] when rolling back.
+///
+/// Unlike [`DeltaCheckpoints`](crate::DeltaCheckpoints), every checkpoint is complete, so rolling back never replays
+/// deltas.
+///
+/// Types are filtered by the [`RollbackRegistry`](crate::RollbackRegistry) and the provided [`SceneFilter`].
+#[derive(Resource, Default)]
+pub struct SharedCheckpoints {
+    filter: SceneFilter,
+    checkpoints: Vec<SharedSnapshot>,
+    active: Option<usize>,
+    last_tick: Option<Tick>,
+}
+
+impl SharedCheckpoints {
+    /// Only capture types allowed by the given [`SceneFilter`].
+    pub fn with_filter(mut self, filter: SceneFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Returns true if no checkpoints have been created.
+    pub fn is_empty(&self) -> bool {
+        self.checkpoints.is_empty()
+    }
+
+    /// Returns the number of checkpoints.
+    pub fn len(&self) -> usize {
+        self.checkpoints.len()
+    }
+
+    /// Returns the checkpoint at the given index.
+    pub fn get(&self, index: usize) -> Option<&SharedSnapshot> {
+        self.checkpoints.get(index)
+    }
+
+    /// Capture a new checkpoint from the [`World`] and set it as the active checkpoint.
+    ///
+    /// If you rollback and then create a checkpoint, it will erase all rollforward checkpoints.
+    pub fn checkpoint(&mut self, world: &World) {
+        let index = self.active.map_or(0, |a| a + 1);
+
+        self.checkpoints.truncate(index);
+
+        let builder = SnapshotBuilder::rollback(world).filter(self.filter.clone());
+        let empty = SharedSnapshot::default();
+
+        let (changed, previous) = match (self.last_tick, self.checkpoints.last()) {
+            (Some(last_tick), Some(previous)) => {
+                (builder.extract_changed_since(last_tick).build(), previous)
+            }
+            _ => (
+                builder
+                    .extract_all_entities()
+                    .extract_all_resources()
+                    .build(),
+                &empty,
+            ),
+        };
+
+        let mut changed_entities = changed
+            .entities
+            .into_iter()
+            .map(|e| (e.entity, e.components))
+            .collect::<EntityHashMap<_>>();
+
+        let previous_entities = previous
+            .entities
+            .iter()
+            .map(|e| (e.entity, &e.components))
+            .collect::<EntityHashMap<_>>();
+
+        let entities = world
+            .iter_entities()
+            .map(|entity| {
+                let mut components = changed_entities
+                    .remove(&entity.id())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(SharedValue::from)
+                    .collect::<Vec<_>>();
+
+                let changed_types = components
+                    .iter()
+                    .filter_map(|c| type_id(&**c))
+                    .collect::<Vec<_>>();
+
+                // Unchanged components still on the entity are shared
+                components.extend(
+                    previous_entities
+                        .get(&entity.id())
+                        .into_iter()
+                        .flat_map(|c| c.iter())
+                        .filter(|c| {
+                            type_id(&***c).is_some_and(|id| {
+                                !changed_types.contains(&id) && entity.contains_type_id(id)
+                            })
+                        })
+                        .cloned(),
+                );
+
+                SharedEntity {
+                    entity: entity.id(),
+                    components,
+                }
+            })
+            .collect();
+
+        drop(previous_entities);
+
+        let mut resources = changed
+            .resources
+            .into_iter()
+            .map(SharedValue::from)
+            .collect::<Vec<_>>();
+
+        let changed_types = resources
+            .iter()
+            .filter_map(|r| type_id(&**r))
+            .collect::<Vec<_>>();
+
+        // Unchanged resources still in the world are shared
+        resources.extend(
+            previous
+                .resources
+                .iter()
+                .filter(|r| {
+                    type_id(&***r).is_some_and(|id| {
+                        !changed_types.contains(&id)
+                            && world.components().get_resource_id(id).is_some_and(|id| {
+                                world.get_resource_change_ticks_by_id(id).is_some()
+                            })
+                    })
+                })
+                .cloned(),
+        );
+
+        self.checkpoints.push(SharedSnapshot {
+            entities,
+            resources,
+        });
+        self.active = Some(index);
+        self.last_tick = Some(world.increment_change_tick());
+    }
+
+    /// Rolls back the given number of checkpoints, returning the active [`SharedSnapshot`].
+    ///
+    /// If checkpoints is negative, it rolls forward.
+    ///
+    /// This function will always clamp itself to valid checkpoints.
+    /// The next checkpoint created after rolling back is captured in full.
+    #[allow(clippy::cast_possible_wrap)]
+    #[allow(clippy::cast_sign_loss)]
+    pub fn rollback(&mut self, checkpoints: isize) -> Option<&SharedSnapshot> {
+        let active = self.active?;
+        let raw = active as isize - checkpoints;
+        let new = raw.clamp(0, self.checkpoints.len() as isize - 1) as usize;
+
+        self.active = Some(new);
+        self.last_tick = None;
+
+        self.checkpoints.get(new)
+    }
+}
+
+/// Extension trait that adds shared checkpoint methods to Bevy's [`World`].
+pub trait WorldSharedExt {
+    /// Creates a checkpoint sharing unchanged values with the previous checkpoint.
+    ///
+    /// Inserts a default [`SharedCheckpoints`] resource if it does not exist.
+    fn checkpoint_shared(&mut self);
+
+    /// Rolls back / forward the [`World`] state using [`SharedCheckpoints`] and the given [`Pipeline`].
+    ///
+    /// # Errors
+    /// - See [`Error`]
+    fn rollback_shared<P: Pipeline>(&mut self, checkpoints: isize) -> Result<(), Error>;
+}
+
+impl WorldSharedExt for World {
+    fn checkpoint_shared(&mut self) {
+        self.init_resource::<SharedCheckpoints>();
+        self.resource_scope(|world, mut checkpoints: Mut<SharedCheckpoints>| {
+            checkpoints.checkpoint(world);
+        });
+    }
+
+    fn rollback_shared<P: Pipeline>(&mut self, checkpoints: isize) -> Result<(), Error> {
+        let snapshot = self
+            .get_resource_mut::<SharedCheckpoints>()
+            .and_then(|mut c| c.rollback(checkpoints).map(SharedSnapshot::to_snapshot));
+
+        if let Some(snapshot) = snapshot {
+            P::apply(self, &snapshot)
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -1,14 +1,17 @@
 use bevy::{
     prelude::*,
+    reflect::TypeRegistry,
     scene::DynamicEntity,
 };
 
 use crate::{
     CloneReflect,
     Error,
+    FloatPrecision,
     Rollbacks,
     SnapshotApplier,
     SnapshotBuilder,
+    UnknownData,
 };
 
 /// A collection of serializable entities and resources.
@@ -22,6 +25,8 @@ pub struct Snapshot {
     pub resources: Vec<Box<dyn Reflect>>,
 
     pub(crate) rollbacks: Option<Rollbacks>,
+
+    pub(crate) unknown: UnknownData,
 }
 
 impl Snapshot {
@@ -104,6 +109,182 @@ impl Snapshot {
     pub fn applier<'a>(&'a self, world: &'a mut World) -> SnapshotApplier<'_> {
         SnapshotApplier::new(self, world)
     }
+
+    /// Returns a copy of the component `T` stored for the given entity, if any.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect, Default, PartialEq, Debug)]
+    /// #[reflect(Component)]
+    /// struct Health(u32);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # app.register_type::<Health>();
+    /// # let world = &mut app.world;
+    /// let entity = world.spawn(Health(10)).id();
+    ///
+    /// let snapshot = Snapshot::builder(world).extract_all_entities().build();
+    ///
+    /// assert_eq!(snapshot.get_component::<Health>(entity), Some(Health(10)));
+    /// assert_eq!(snapshot.entities_with::<Health>().collect::<Vec<_>>(), vec![entity]);
+    /// ```
+    pub fn get_component<T: FromReflect + TypePath>(&self, entity: Entity) -> Option<T> {
+        self.entities
+            .iter()
+            .find(|e| e.entity == entity)?
+            .components
+            .iter()
+            .find(|c| c.represents::<T>())
+            .and_then(|c| T::from_reflect(&**c))
+    }
+
+    /// Returns a copy of the resource `T` stored in the snapshot, if any.
+    pub fn get_resource<T: FromReflect + TypePath>(&self) -> Option<T> {
+        self.resources
+            .iter()
+            .find(|r| r.represents::<T>())
+            .and_then(|r| T::from_reflect(&**r))
+    }
+
+    /// Returns the [`Rollbacks`] stored in the snapshot, if any.
+    pub fn rollbacks(&self) -> Option<&Rollbacks> {
+        self.rollbacks.as_ref()
+    }
+
+    /// Returns an iterator over the entities that have the component `T` stored in the snapshot.
+    pub fn entities_with<T: Reflect + TypePath>(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.entities
+            .iter()
+            .filter(|e| e.components.iter().any(|c| c.represents::<T>()))
+            .map(|e| e.entity)
+    }
+
+    /// Returns the type paths of unregistered types skipped while deserializing the snapshot.
+    ///
+    /// See [`SnapshotDeserializer::lenient`](crate::SnapshotDeserializer::lenient).
+    pub fn skipped_types(&self) -> impl Iterator<Item = &str> {
+        self.unknown.type_paths().into_iter()
+    }
+
+    /// Insert the resource into the snapshot, replacing any existing value of the same type.
+    ///
+    /// The resource is inserted at its position by type path, keeping the order of [`SnapshotBuilder::build`].
+    pub fn insert_resource<T: Reflect + TypePath>(&mut self, value: T) {
+        self.insert_boxed_resource(Box::new(value));
+    }
+
+    /// Insert the reflected resource at its position by type path, replacing any existing value of the same type.
+    pub(crate) fn insert_boxed_resource(&mut self, value: Box<dyn Reflect>) {
+        let type_path = resource_type_path(&*value).to_owned();
+
+        self.resources
+            .retain(|r| resource_type_path(&**r) != type_path);
+
+        let index = self
+            .resources
+            .partition_point(|r| resource_type_path(&**r) < type_path.as_str());
+
+        self.resources.insert(index, value);
+    }
+
+    /// Remove the resource `T` from the snapshot, returning true if it was present.
+    pub fn remove_resource<T: Reflect + TypePath>(&mut self) -> bool {
+        let len = self.resources.len();
+        self.resources.retain(|r| !r.represents::<T>());
+        self.resources.len() != len
+    }
+
+    /// Insert the component for the given entity, replacing any existing value of the same type.
+    ///
+    /// Adds the entity to the snapshot if it is not already present.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_save::prelude::*;
+    /// #[derive(Component, Reflect, Default, PartialEq, Debug)]
+    /// #[reflect(Component)]
+    /// struct Health(u32);
+    ///
+    /// # let mut app = App::new();
+    /// # app.add_plugins(MinimalPlugins);
+    /// # app.add_plugins(SavePlugins);
+    /// # app.register_type::<Health>();
+    /// # let world = &mut app.world;
+    /// let entity = world.spawn(Health(1)).id();
+    ///
+    /// let mut snapshot = Snapshot::builder(world).extract_all_entities().build();
+    ///
+    /// // Load, but reset health
+    /// snapshot.insert_component(entity, Health(100));
+    ///
+    /// assert_eq!(snapshot.get_component::<Health>(entity), Some(Health(100)));
+    /// ```
+    pub fn insert_component<T: Reflect + TypePath>(&mut self, entity: Entity, value: T) {
+        let index = self
+            .entities
+            .iter()
+            .position(|e| e.entity == entity)
+            .unwrap_or_else(|| {
+                self.entities.push(DynamicEntity {
+                    entity,
+                    components: Vec::new(),
+                });
+                self.entities.len() - 1
+            });
+
+        let components = &mut self.entities[index].components;
+
+        components.retain(|c| !c.represents::<T>());
+        components.push(Box::new(value));
+    }
+
+    /// Remove the component `T` from the given entity, returning true if it was present.
+    pub fn remove_component<T: Reflect + TypePath>(&mut self, entity: Entity) -> bool {
+        let Some(entity) = self.entities.iter_mut().find(|e| e.entity == entity) else {
+            return false;
+        };
+
+        let len = entity.components.len();
+        entity.components.retain(|c| !c.represents::<T>());
+        entity.components.len() != len
+    }
+
+    /// Remove the given entity and all of its components from the snapshot, returning true if it was present.
+    pub fn remove_entity(&mut self, entity: Entity) -> bool {
+        let len = self.entities.len();
+        self.entities.retain(|e| e.entity != entity);
+        self.entities.len() != len
+    }
+
+    /// Retain only the entities matching the predicate.
+    pub fn retain_entities<F: FnMut(&DynamicEntity) -> bool>(&mut self, predicate: F) {
+        self.entities.retain(predicate);
+    }
+
+    /// Quantize all floats contained in the [`Snapshot`] with the given [`FloatPrecision`].
+    ///
+    /// Types registered with [`ReflectExactFloats`](crate::ReflectExactFloats) are left untouched.
+    pub fn quantize(&mut self, precision: FloatPrecision, registry: &TypeRegistry) {
+        let components = self
+            .entities
+            .iter_mut()
+            .flat_map(|e| e.components.iter_mut());
+
+        for reflect in components.chain(self.resources.iter_mut()) {
+            precision.quantize(&mut **reflect, registry);
+        }
+
+        if let Some(rollbacks) = &mut self.rollbacks {
+            for checkpoint in &mut rollbacks.checkpoints {
+                checkpoint.quantize(precision, registry);
+            }
+        }
+    }
 }
 
 impl CloneReflect for Snapshot {
@@ -112,6 +293,14 @@ impl CloneReflect for Snapshot {
             entities: self.entities.iter().map(|e| e.clone_value()).collect(),
             resources: self.resources.clone_value(),
             rollbacks: self.rollbacks.clone_value(),
+            unknown: self.unknown.clone(),
         }
     }
 }
+
+/// Returns the type path of the resource, as used to order the resources of a [`Snapshot`].
+pub(crate) fn resource_type_path(value: &dyn Reflect) -> &str {
+    value
+        .get_represented_type_info()
+        .map_or_else(|| value.reflect_type_path(), |i| i.type_path())
+}
@@ -0,0 +1,128 @@
+use bevy::{
+    prelude::*,
+    reflect::{
+        DynamicStruct,
+        DynamicTupleStruct,
+        TypeInfo,
+        TypeRegistry,
+    },
+    scene::DynamicEntity,
+};
+
+/// Marker stored in place of components that were equal to their [`Default`] value when captured.
+///
+/// Created by [`SnapshotBuilder::sparse`](crate::SnapshotBuilder::sparse).
+/// The [`SnapshotApplier`](crate::SnapshotApplier) inserts the default value of each listed type instead of this marker.
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct DefaultComponents {
+    /// The type paths of the omitted components.
+    pub types: Vec<String>,
+}
+
+/// Replaces components equal to their [`Default`] value with a single [`DefaultComponents`] marker.
+pub(crate) fn sparsify(entity: &mut DynamicEntity, registry: &TypeRegistry) {
+    let mut types = Vec::new();
+
+    entity.components.retain(|component| {
+        let Some(info) = component.get_represented_type_info() else {
+            return true;
+        };
+
+        let is_default = registry
+            .get_type_data::<ReflectDefault>(info.type_id())
+            .and_then(|d| component.reflect_partial_eq(&*d.default()))
+            .unwrap_or(false);
+
+        if is_default {
+            types.push(info.type_path().to_owned());
+        }
+
+        !is_default
+    });
+
+    if !types.is_empty() {
+        entity
+            .components
+            .push(Box::new(DefaultComponents { types }));
+    }
+}
+
+/// The type paths of the zero-sized components of a [`Snapshot`](crate::Snapshot), indexed by [`Markers`].
+///
+/// Created by [`SnapshotBuilder::compact_markers`](crate::SnapshotBuilder::compact_markers) and stored as a resource of
+/// the snapshot. The [`SnapshotApplier`](crate::SnapshotApplier) never inserts it into the world.
+#[derive(Resource, Reflect, Default, Debug, Clone, PartialEq, Eq)]
+#[reflect(Resource)]
+pub struct MarkerTypes {
+    /// The type paths of the marker components.
+    pub types: Vec<String>,
+}
+
+/// Marker stored in place of the zero-sized components of an entity.
+///
+/// Created by [`SnapshotBuilder::compact_markers`](crate::SnapshotBuilder::compact_markers).
+/// The [`SnapshotApplier`](crate::SnapshotApplier) inserts each listed type instead of this marker.
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq, Eq)]
+#[reflect(Component)]
+pub struct Markers {
+    /// Indices into [`MarkerTypes::types`].
+    pub indices: Vec<u32>,
+}
+
+/// Returns true if the type has no fields, such as a unit struct.
+fn is_marker(info: &TypeInfo) -> bool {
+    match info {
+        TypeInfo::Struct(info) => info.field_len() == 0,
+        TypeInfo::TupleStruct(info) => info.field_len() == 0,
+        _ => false,
+    }
+}
+
+/// Replaces zero-sized components with a single [`Markers`] component indexing into `table`.
+pub(crate) fn compact_markers(entity: &mut DynamicEntity, table: &mut MarkerTypes) {
+    let mut indices = Vec::new();
+
+    entity.components.retain(|component| {
+        let Some(info) = component
+            .get_represented_type_info()
+            .filter(|i| is_marker(i))
+        else {
+            return true;
+        };
+
+        let index = table
+            .types
+            .iter()
+            .position(|t| t == info.type_path())
+            .unwrap_or_else(|| {
+                table.types.push(info.type_path().to_owned());
+                table.types.len() - 1
+            });
+
+        indices.extend(u32::try_from(index).ok());
+
+        false
+    });
+
+    if !indices.is_empty() {
+        entity.components.push(Box::new(Markers { indices }));
+    }
+}
+
+/// Creates a value of the marker type, to be inserted with `ReflectComponent`.
+pub(crate) fn marker_value(info: &'static TypeInfo) -> Option<Box<dyn Reflect>> {
+    match info {
+        TypeInfo::Struct(_) => {
+            let mut value = DynamicStruct::default();
+            value.set_represented_type(Some(info));
+            Some(Box::new(value))
+        }
+        TypeInfo::TupleStruct(_) => {
+            let mut value = DynamicTupleStruct::default();
+            value.set_represented_type(Some(info));
+            Some(Box::new(value))
+        }
+        _ => None,
+    }
+}
@@ -0,0 +1,340 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    marker::PhantomData,
+    sync::PoisonError,
+};
+
+use bevy::{
+    prelude::*,
+    utils::HashMap,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::{
+    intercept::intercept_save,
+    serde::{
+        EntityMapDeserializer,
+        EntityMapSerializer,
+        ReflectMapDeserializer,
+        ReflectMapSerializer,
+        UnknownStore,
+    },
+    Backend,
+    Error,
+    Format,
+    Pipeline,
+    RegistryRef,
+    RollbacksDeserializer,
+    RollbacksSerializer,
+    Snapshot,
+    SnapshotDeserializer,
+};
+
+/// The section containing the [`SaveIndex`].
+pub const SECTION_META: &str = "meta";
+/// The section containing the snapshot entities.
+pub const SECTION_ENTITIES: &str = "entities";
+/// The section containing the snapshot resources.
+pub const SECTION_RESOURCES: &str = "resources";
+/// The section containing the snapshot [`Rollbacks`](crate::Rollbacks).
+pub const SECTION_ROLLBACKS: &str = "rollbacks";
+
+/// Index of a multi-part save, stored in the [`SECTION_META`] section.
+///
+/// Maps each section name to the hash of its serialized contents.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct SaveIndex {
+    /// Hashes of the sections contained in the save.
+    pub sections: BTreeMap<String, u64>,
+}
+
+/// Tracks the hashes of the sections most recently written to or read from the backend.
+///
+/// Used to skip rewriting sections that have not changed.
+#[derive(Resource, Default)]
+pub struct SectionHashes {
+    indices: HashMap<String, SaveIndex>,
+}
+
+impl SectionHashes {
+    /// Forget all tracked hashes, forcing every section to be rewritten on the next save.
+    pub fn clear(&mut self) {
+        self.indices.clear();
+    }
+
+    /// Returns the last known [`SaveIndex`] for the given key.
+    pub fn get(&self, key: &str) -> Option<&SaveIndex> {
+        self.indices.get(key)
+    }
+
+    pub(crate) fn insert(&mut self, key: String, index: SaveIndex) {
+        self.indices.insert(key, index);
+    }
+}
+
+/// FNV-1a hasher that can be serialized into directly, producing hashes that are stable between runs.
+struct SectionHasher(u64);
+
+impl Default for SectionHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl std::io::Write for SectionHasher {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        for byte in buf {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn section_key(key: &str, section: &str) -> String {
+    format!("{key}/{section}")
+}
+
+fn hash_section<F: Format, T: Serialize>(value: &T) -> Result<u64, Error> {
+    let mut hasher = SectionHasher::default();
+    F::serialize(&mut hasher, value)?;
+    Ok(hasher.0)
+}
+
+/// Writes the sections of the [`Snapshot`] to the backend as a multi-part save.
+///
+/// Sections whose hash matches the `previous` index are not rewritten.
+///
+/// Returns the new [`SaveIndex`].
+///
+/// # Errors
+/// - See [`Error`]
+pub fn write_sections<'a, F: Format, B: Backend<String>>(
+    backend: &B,
+    key: &str,
+    snapshot: &Snapshot,
+    registry: impl Into<RegistryRef<'a>>,
+    previous: &SaveIndex,
+) -> Result<SaveIndex, Error> {
+    let registry = registry.into();
+    let mut index = SaveIndex::default();
+
+    let mut write = |section: &str, hash: u64| -> bool {
+        index.sections.insert(section.to_owned(), hash);
+        previous.sections.get(section) != Some(&hash)
+    };
+
+    let entities = EntityMapSerializer {
+        entities: &snapshot.entities,
+        registry,
+        unknown: snapshot.unknown(),
+    };
+
+    if write(SECTION_ENTITIES, hash_section::<F, _>(&entities)?) {
+        backend.save::<F, _>(section_key(key, SECTION_ENTITIES), &entities)?;
+    }
+
+    let resources = ReflectMapSerializer {
+        entries: &snapshot.resources,
+        registry,
+        unknown: snapshot.unknown().resources(),
+    };
+
+    if write(SECTION_RESOURCES, hash_section::<F, _>(&resources)?) {
+        backend.save::<F, _>(section_key(key, SECTION_RESOURCES), &resources)?;
+    }
+
+    if let Some(rollbacks) = &snapshot.rollbacks {
+        let rollbacks = RollbacksSerializer {
+            rollbacks,
+            registry,
+        };
+
+        if write(SECTION_ROLLBACKS, hash_section::<F, _>(&rollbacks)?) {
+            backend.save::<F, _>(section_key(key, SECTION_ROLLBACKS), &rollbacks)?;
+        }
+    }
+
+    if &index != previous {
+        backend.save::<F, _>(section_key(key, SECTION_META), &index)?;
+    }
+
+    Ok(index)
+}
+
+/// Reads the [`SaveIndex`] of a multi-part save from the backend.
+///
+/// # Errors
+/// - See [`Error`]
+pub fn read_index<F: Format, B: Backend<String>>(
+    backend: &B,
+    key: &str,
+) -> Result<SaveIndex, Error> {
+    backend.load::<F, _, _>(section_key(key, SECTION_META), PhantomData)
+}
+
+/// Reads the sections of a multi-part save from the backend, reassembling them into a single [`Snapshot`].
+///
+/// The sections are read with the limits and options of the given [`SnapshotDeserializer`], with the limits shared
+/// between all sections.
+///
+/// # Errors
+/// - See [`Error`]
+pub fn read_sections<F: Format, B: Backend<String>>(
+    backend: &B,
+    key: &str,
+    de: &SnapshotDeserializer,
+) -> Result<(Snapshot, SaveIndex), Error> {
+    let index = read_index::<F, _>(backend, key)?;
+
+    for section in [SECTION_ENTITIES, SECTION_RESOURCES] {
+        if !index.sections.contains_key(section) {
+            return Err(Error::custom(format!("missing section: {section}")));
+        }
+    }
+
+    let unknown = UnknownStore::default();
+    let unknown_ref = de.lenient.then_some(&unknown);
+
+    let entities =
+        backend.load::<F, _, _>(section_key(key, SECTION_ENTITIES), EntityMapDeserializer {
+            registry: de.registry,
+            limits: de.limits,
+            parallel: de.parallel,
+            unknown: unknown_ref,
+        })?;
+
+    let resources = backend.load::<F, _, _>(
+        section_key(key, SECTION_RESOURCES),
+        ReflectMapDeserializer {
+            registry: de.registry,
+            limits: de.limits,
+            unknown: unknown_ref,
+            owner: None,
+        },
+    )?;
+
+    let rollbacks = if index.sections.contains_key(SECTION_ROLLBACKS) {
+        Some(backend.load::<F, _, _>(
+            section_key(key, SECTION_ROLLBACKS),
+            RollbacksDeserializer {
+                registry: de.registry,
+                limits: de.limits.remaining(entities.len(), 0),
+                lenient: de.lenient,
+            },
+        )?)
+    } else {
+        None
+    };
+
+    let snapshot = Snapshot {
+        entities,
+        resources,
+        rollbacks,
+        unknown: unknown.into_inner().unwrap_or_else(PoisonError::into_inner),
+    };
+
+    if let Some(exceeded) = de.limits.exceeded_by(&snapshot) {
+        return Err(Error::custom(exceeded));
+    }
+
+    Ok((snapshot, index))
+}
+
+/// Extension trait that adds multi-part save methods to Bevy's [`World`].
+///
+/// Multi-part saves store each section of a [`Snapshot`] separately, along with a [`SaveIndex`]:
+///
+/// - `KEY/meta`
+/// - `KEY/entities`
+/// - `KEY/resources`
+/// - `KEY/rollbacks`
+///
+/// Only sections that have changed since they were last written are rewritten.
+pub trait WorldSplitExt {
+    /// Saves the game state with the given [`Pipeline`] as a multi-part save.
+    ///
+    /// # Errors
+    /// - See [`Error`]
+    fn save_split<P>(&mut self, pipeline: P) -> Result<(), Error>
+    where
+        P: Pipeline,
+        P::Backend: Backend<String>,
+        for<'a> P::Key<'a>: Display;
+
+    /// Loads a multi-part save with the given [`Pipeline`], reassembling the sections into a single [`Snapshot`].
+    ///
+    /// The save is checked and sanitized like saves loaded with [`WorldSaveableExt::load`](crate::WorldSaveableExt::load).
+    ///
+    /// # Errors
+    /// - See [`Error`]
+    fn load_split<P>(&mut self, pipeline: P) -> Result<(), Error>
+    where
+        P: Pipeline,
+        P::Backend: Backend<String>,
+        for<'a> P::Key<'a>: Display;
+}
+
+impl WorldSplitExt for World {
+    fn save_split<P>(&mut self, pipeline: P) -> Result<(), Error>
+    where
+        P: Pipeline,
+        P::Backend: Backend<String>,
+        for<'a> P::Key<'a>: Display,
+    {
+        let pipeline = pipeline.with_context(self);
+        let intercepted = intercept_save(self, pipeline)?;
+        let key = intercepted.key();
+
+        let registry = self.resource::<AppTypeRegistry>().clone();
+        let backend = self.resource::<P::Backend>();
+
+        let snapshot = Snapshot::capture_save(self, &intercepted.pipeline, intercepted.metadata);
+
+        let previous = self
+            .get_resource::<SectionHashes>()
+            .and_then(|h| h.get(&key).cloned())
+            .or_else(|| read_index::<P::Format, _>(backend, &key).ok())
+            .unwrap_or_default();
+
+        let index = write_sections::<P::Format, _>(backend, &key, &snapshot, &registry, &previous)?;
+
+        self.get_resource_or_insert_with(SectionHashes::default)
+            .insert(key, index);
+
+        Ok(())
+    }
+
+    fn load_split<P>(&mut self, pipeline: P) -> Result<(), Error>
+    where
+        P: Pipeline,
+        P::Backend: Backend<String>,
+        for<'a> P::Key<'a>: Display,
+    {
+        let pipeline = pipeline.with_context(self);
+        let key = pipeline.key().to_string();
+
+        let registry = self.resource::<AppTypeRegistry>().clone();
+        let reg = registry.read();
+        let backend = self.resource::<P::Backend>();
+
+        let de = SnapshotDeserializer::for_world(&reg, self);
+        let (mut snapshot, index) = read_sections::<P::Format, _>(backend, &key, &de)?;
+
+        snapshot.prepare_load(self, &reg)?;
+
+        self.get_resource_or_insert_with(SectionHashes::default)
+            .insert(key, index);
+
+        pipeline.apply_seed(self, &snapshot)
+    }
+}
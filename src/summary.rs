@@ -0,0 +1,214 @@
+use std::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+    },
+    io::{
+        Read,
+        Write,
+    },
+    marker::PhantomData,
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
+
+use bevy::{
+    prelude::*,
+    reflect::TypePath,
+};
+use serde::{
+    de::DeserializeSeed,
+    Deserialize,
+    Serialize,
+};
+
+use crate::{
+    Backend,
+    Error,
+    Format,
+    Pipeline,
+    PlayTime,
+    RONFormat,
+    Snapshot,
+};
+
+/// Lists the resources and component types contained in a save, without any of their values.
+///
+/// Pipelines with [`Pipeline::summary`] enabled write the summary next to each save, so load menus can inspect saves
+/// with [`SaveSummary::load`] without reading the full snapshot.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// #[derive(Resource, Reflect, Default)]
+/// #[reflect(Resource)]
+/// struct HardcoreMode;
+///
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component)]
+/// struct Enemy;
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # app.register_type::<HardcoreMode>();
+/// # app.register_type::<Enemy>();
+/// # let world = &mut app.world;
+/// world.insert_resource(HardcoreMode);
+/// world.spawn(Enemy);
+/// world.spawn(Enemy);
+///
+/// let snapshot = Snapshot::builder(world)
+///     .extract_all_entities()
+///     .extract_resource::<HardcoreMode>()
+///     .build();
+///
+/// let summary = snapshot.summary();
+///
+/// assert!(summary.contains_resource::<HardcoreMode>());
+/// assert_eq!(summary.count::<Enemy>(), 2);
+/// ```
+#[derive(Serialize, Deserialize, Default, Debug, Clone, PartialEq, Eq)]
+pub struct SaveSummary {
+    /// The type paths of the resources contained in the save.
+    pub resources: BTreeSet<String>,
+
+    /// The number of entities contained in the save.
+    pub entities: usize,
+
+    /// The number of entities with each component, by type path.
+    pub components: BTreeMap<String, usize>,
+
+    /// When the summary was created, usually when the save was written.
+    #[serde(default)]
+    pub saved_at: Option<SystemTime>,
+
+    /// The [`PlayTime`] stored in the save.
+    #[serde(default)]
+    pub play_time: Option<Duration>,
+}
+
+impl SaveSummary {
+    /// Summarize the contents of the [`Snapshot`].
+    pub fn from_snapshot(snapshot: &Snapshot) -> Self {
+        let mut summary = Self {
+            resources: snapshot
+                .resources
+                .iter()
+                .map(|r| type_path(r.as_ref()).to_owned())
+                .collect(),
+            entities: snapshot.entities.len(),
+            components: BTreeMap::new(),
+            saved_at: Some(SystemTime::now()),
+            play_time: snapshot.get_resource::<PlayTime>().map(|p| p.total),
+        };
+
+        for entity in &snapshot.entities {
+            for component in &entity.components {
+                *summary
+                    .components
+                    .entry(type_path(component.as_ref()).to_owned())
+                    .or_default() += 1;
+            }
+        }
+
+        summary
+    }
+
+    /// Returns `true` if the save contains the resource `T`.
+    pub fn contains_resource<T: TypePath>(&self) -> bool {
+        self.contains_resource_path(T::type_path())
+    }
+
+    /// Returns `true` if the save contains the resource with the given type path.
+    pub fn contains_resource_path(&self, type_path: &str) -> bool {
+        self.resources.contains(type_path)
+    }
+
+    /// Returns the number of entities in the save with the component `T`.
+    pub fn count<T: TypePath>(&self) -> usize {
+        self.count_path(T::type_path())
+    }
+
+    /// Returns the number of entities in the save with the component with the given type path.
+    pub fn count_path(&self, type_path: &str) -> usize {
+        self.components.get(type_path).copied().unwrap_or_default()
+    }
+
+    /// Read a summary written by a [`Pipeline`] with [`Pipeline::summary`] enabled.
+    ///
+    /// # Errors
+    /// If the summary could not be read.
+    pub fn read(reader: impl Read) -> Result<Self, Error> {
+        SummaryFormat::deserialize(reader, PhantomData)
+    }
+
+    /// Write the summary in the format used by [`Pipeline::summary`].
+    ///
+    /// # Errors
+    /// If the summary could not be written.
+    pub fn write(&self, writer: impl Write) -> Result<(), Error> {
+        SummaryFormat::serialize(writer, self)
+    }
+
+    /// Load the summary of the save of the [`Pipeline`], without loading the save itself.
+    ///
+    /// # Errors
+    /// - If the pipeline did not write a summary for the save
+    /// - If the summary could not be loaded
+    pub fn load<P: Pipeline>(world: &World, pipeline: P) -> Result<Self, Error> {
+        let pipeline = pipeline.with_context(world);
+
+        world
+            .resource::<P::Backend>()
+            .load::<SummaryFormat, _, _>(pipeline.key(), PhantomData)
+    }
+}
+
+impl Snapshot {
+    /// Returns a [`SaveSummary`] of the contents of the snapshot.
+    pub fn summary(&self) -> SaveSummary {
+        SaveSummary::from_snapshot(self)
+    }
+}
+
+fn type_path(value: &dyn Reflect) -> &str {
+    value
+        .get_represented_type_info()
+        .map_or_else(|| value.reflect_type_path(), |i| i.type_path())
+}
+
+/// Stores a [`SaveSummary`] as RON next to the save it describes, regardless of the [`Pipeline::Format`].
+pub(crate) struct SummaryFormat;
+
+impl Format for SummaryFormat {
+    fn name() -> &'static str {
+        "summary"
+    }
+
+    fn extension() -> &'static str {
+        ".summary.ron"
+    }
+
+    fn key_suffix() -> &'static str {
+        ".summary"
+    }
+
+    fn mime_type() -> &'static str {
+        RONFormat::mime_type()
+    }
+
+    fn serialize<W: Write, T: Serialize>(writer: W, value: &T) -> Result<(), Error> {
+        RONFormat::serialize(writer, value)
+    }
+
+    fn deserialize<R: Read, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        reader: R,
+        seed: S,
+    ) -> Result<T, Error> {
+        RONFormat::deserialize(reader, seed)
+    }
+}
@@ -0,0 +1,185 @@
+use std::fmt::Display;
+
+use bevy::{
+    prelude::*,
+    utils::HashMap,
+};
+
+use crate::{
+    intercept::intercept_save,
+    Backend,
+    Error,
+    Pipeline,
+    Snapshot,
+    SnapshotDeserializer,
+    SnapshotSerializer,
+    Stamped,
+};
+
+/// Identifies the device saving the game, used to build the [`SaveRevision`] of each save.
+#[derive(Resource, Debug, Clone, PartialEq, Eq)]
+pub struct DeviceId(pub String);
+
+/// How two [`SaveRevision`]s relate to each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Causality {
+    /// Both revisions contain the same history.
+    Equal,
+    /// The first revision is an ancestor of the second.
+    Before,
+    /// The first revision descends from the second.
+    After,
+    /// The revisions were saved independently and have diverged.
+    Concurrent,
+}
+
+/// A vector timestamp stored alongside every synced save.
+///
+/// Each device increments its own counter when saving, allowing saves made on different devices to be ordered,
+/// and independent saves of the same slot to be detected.
+#[derive(Resource, Reflect, Default, Debug, Clone, PartialEq, Eq)]
+#[reflect(Resource)]
+pub struct SaveRevision {
+    /// The number of saves made by each device.
+    pub clock: HashMap<String, u64>,
+}
+
+impl SaveRevision {
+    /// Returns the total number of saves in the history of this revision.
+    pub fn counter(&self) -> u64 {
+        self.clock.values().sum()
+    }
+
+    /// Record a new save made by the given device.
+    pub fn increment(&mut self, device: &str) {
+        *self.clock.entry(device.to_owned()).or_default() += 1;
+    }
+
+    /// Merge the history of another revision into this one, e.g. after resolving a conflict.
+    pub fn merge(&mut self, other: &Self) {
+        for (device, count) in &other.clock {
+            let entry = self.clock.entry(device.clone()).or_default();
+            *entry = (*entry).max(*count);
+        }
+    }
+
+    /// Compare the history of this revision to another.
+    pub fn compare(&self, other: &Self) -> Causality {
+        let mut before = false;
+        let mut after = false;
+
+        for device in self.clock.keys().chain(other.clock.keys()) {
+            let a = self.clock.get(device).copied().unwrap_or_default();
+            let b = other.clock.get(device).copied().unwrap_or_default();
+
+            before |= a < b;
+            after |= a > b;
+        }
+
+        match (before, after) {
+            (false, false) => Causality::Equal,
+            (true, false) => Causality::Before,
+            (false, true) => Causality::After,
+            (true, true) => Causality::Concurrent,
+        }
+    }
+}
+
+/// Sent when a synced save could not be written because the stored save has diverged from the [`World`].
+#[derive(Event, Debug, Clone)]
+pub struct SaveConflict {
+    /// The key of the conflicting save.
+    pub key: String,
+    /// The revision of the [`World`].
+    pub local: SaveRevision,
+    /// The revision of the stored save.
+    pub remote: SaveRevision,
+}
+
+/// Extension trait that adds conflict-aware save methods to Bevy's [`World`].
+///
+/// Synced saves store a [`SaveRevision`], incremented for the [`DeviceId`] on every save.
+/// Loading a synced save with [`WorldSaveableExt::load`](crate::WorldSaveableExt::load) restores its [`SaveRevision`].
+pub trait WorldSyncExt {
+    /// Saves the game state with the given [`Pipeline`], unless the stored save contains history the [`World`] has not seen.
+    ///
+    /// On conflict, a [`SaveConflict`] event is sent so the game can prompt the user.
+    /// To overwrite the stored save anyway, [`merge`](SaveRevision::merge) the remote revision into the local one and save again.
+    ///
+    /// # Errors
+    /// - [`Error::Conflict`] if the stored save has diverged or was modified while saving
+    /// - [`Error::Loading`] if the stored save cannot be loaded to compare its revision, in which case it is left untouched
+    /// - See [`Error`]
+    fn save_synced<P>(&mut self, pipeline: P) -> Result<(), Error>
+    where
+        P: Pipeline,
+        for<'a> P::Key<'a>: Display;
+}
+
+impl WorldSyncExt for World {
+    fn save_synced<P>(&mut self, pipeline: P) -> Result<(), Error>
+    where
+        P: Pipeline,
+        for<'a> P::Key<'a>: Display,
+    {
+        let pipeline = pipeline.with_context(self);
+        let intercepted = intercept_save(self, pipeline)?;
+        let key = intercepted.key();
+
+        let registry = self.resource::<AppTypeRegistry>().clone();
+        let backend = self.resource::<P::Backend>();
+
+        let etag = backend.etag::<Stamped<P::Format>>(key.clone())?;
+
+        let remote = if etag.is_some() {
+            let reg = registry.read();
+            let de = SnapshotDeserializer::for_world(&reg, self);
+
+            let snapshot: Snapshot = backend.load::<Stamped<P::Format>, _, _>(key.clone(), de)?;
+
+            snapshot.get_resource::<SaveRevision>().unwrap_or_default()
+        } else {
+            SaveRevision::default()
+        };
+
+        let mut local = self
+            .get_resource::<SaveRevision>()
+            .cloned()
+            .unwrap_or_default();
+
+        if matches!(
+            remote.compare(&local),
+            Causality::After | Causality::Concurrent
+        ) {
+            self.send_event(SaveConflict {
+                key: key.clone(),
+                local,
+                remote,
+            });
+
+            return Err(Error::Conflict);
+        }
+
+        let device = self
+            .get_resource::<DeviceId>()
+            .map_or_else(String::new, |d| d.0.clone());
+
+        local.increment(&device);
+
+        let mut snapshot =
+            Snapshot::capture_save(self, &intercepted.pipeline, intercepted.metadata);
+        snapshot.insert_resource(local.clone());
+
+        let backend = self.resource::<P::Backend>();
+
+        backend.save_if_match::<Stamped<P::Format>, _>(
+            key,
+            &SnapshotSerializer::new(&snapshot, &registry),
+            etag.as_deref(),
+        )?;
+
+        self.insert_resource(local);
+
+        Ok(())
+    }
+}
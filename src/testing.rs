@@ -0,0 +1,316 @@
+//! Utilities for testing saving and loading.
+//!
+//! Includes test backends and [`world_fingerprint`], for asserting that a world is restored to an equivalent state.
+
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{
+        Hash,
+        Hasher,
+    },
+    io::ErrorKind,
+    sync::{
+        atomic::{
+            AtomicUsize,
+            Ordering,
+        },
+        Arc,
+    },
+};
+
+use bevy::prelude::*;
+use serde::{
+    de::DeserializeSeed,
+    Serialize,
+};
+
+use crate::{
+    diff::render,
+    Backend,
+    Error,
+    Format,
+    Snapshot,
+};
+
+/// Test [`Backend`] that discards every write and never finds a save.
+///
+/// Values are still serialized, so serialization errors are reported. Loads fail with an [`Error::IO`] of kind
+/// [`NotFound`](ErrorKind::NotFound), as if the save was never written.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::{prelude::*, testing::NoopBackend, ErrorCode};
+/// struct TestPipeline;
+///
+/// impl Pipeline for TestPipeline {
+///     type Backend = NoopBackend;
+///     type Format = DefaultFormat;
+///
+///     type Key<'a> = &'a str;
+///
+///     fn key(&self) -> Self::Key<'_> {
+///         "test"
+///     }
+/// }
+///
+/// let mut app = App::new();
+///
+/// app.add_plugins((MinimalPlugins, SavePlugins))
+///     .init_pipeline::<TestPipeline>();
+///
+/// let world = &mut app.world;
+///
+/// world.save(TestPipeline).unwrap();
+///
+/// let err = world.load(TestPipeline).unwrap_err();
+///
+/// assert_eq!(err.code(), ErrorCode::NotFound);
+/// ```
+#[derive(Resource, Default, Debug, Clone, Copy)]
+pub struct NoopBackend;
+
+impl<K> Backend<K> for NoopBackend {
+    fn save<F: Format, T: Serialize>(&self, key: K, value: &T) -> Result<(), Error> {
+        let _ = key;
+        F::serialize(std::io::sink(), value)
+    }
+
+    fn load<F: Format, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        &self,
+        key: K,
+        seed: S,
+    ) -> Result<T, Error> {
+        let _ = (key, seed);
+        Err(Error::IO(ErrorKind::NotFound.into()))
+    }
+}
+
+type ErrorFn = Arc<dyn Fn() -> Error + Send + Sync>;
+
+/// Test [`Backend`] middleware that fails every Nth operation, for exercising error handling and retry logic.
+///
+/// Saves and loads are counted as operations. Failing operations return the configured error without reaching the
+/// inner backend, while all other operations are passed through. By default no operation fails, so the failure rate
+/// can be changed at runtime through the backend resource.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::{prelude::*, testing::{FlakyBackend, NoopBackend}, Error, ErrorCode};
+/// struct TestPipeline;
+///
+/// impl Pipeline for TestPipeline {
+///     type Backend = FlakyBackend<NoopBackend>;
+///     type Format = DefaultFormat;
+///
+///     type Key<'a> = &'a str;
+///
+///     fn key(&self) -> Self::Key<'_> {
+///         "test"
+///     }
+/// }
+///
+/// let mut app = App::new();
+///
+/// app.add_plugins((MinimalPlugins, SavePlugins))
+///     .init_pipeline::<TestPipeline>();
+///
+/// let world = &mut app.world;
+///
+/// world
+///     .resource_mut::<FlakyBackend<NoopBackend>>()
+///     .set_fail_every(2)
+///     .set_error(|| Error::Conflict);
+///
+/// assert!(world.save(TestPipeline).is_ok());
+/// assert_eq!(world.save(TestPipeline).unwrap_err().code(), ErrorCode::Conflict);
+/// assert!(world.save(TestPipeline).is_ok());
+///
+/// assert_eq!(world.resource::<FlakyBackend<NoopBackend>>().failures(), 1);
+/// ```
+#[derive(Resource)]
+pub struct FlakyBackend<B> {
+    inner: B,
+    fail_every: usize,
+    error: ErrorFn,
+    operations: AtomicUsize,
+    failures: AtomicUsize,
+}
+
+impl<B: Default> Default for FlakyBackend<B> {
+    fn default() -> Self {
+        Self::new(B::default(), 0)
+    }
+}
+
+impl<B> FlakyBackend<B> {
+    /// Create a new [`FlakyBackend`] wrapping the given backend, failing every `fail_every`th operation.
+    ///
+    /// Operations fail with an [`Error::IO`] by default. No operation fails if `fail_every` is zero.
+    pub fn new(inner: B, fail_every: usize) -> Self {
+        Self {
+            inner,
+            fail_every,
+            error: Arc::new(|| Error::IO(ErrorKind::Other.into())),
+            operations: AtomicUsize::new(0),
+            failures: AtomicUsize::new(0),
+        }
+    }
+
+    /// Fail with the error returned by the closure, instead of an [`Error::IO`].
+    pub fn with_error(mut self, error: impl Fn() -> Error + Send + Sync + 'static) -> Self {
+        self.error = Arc::new(error);
+        self
+    }
+
+    /// Fail every `fail_every`th operation from now on, or no operations if it is zero.
+    pub fn set_fail_every(&mut self, fail_every: usize) -> &mut Self {
+        self.fail_every = fail_every;
+        self.operations = AtomicUsize::new(0);
+        self
+    }
+
+    /// Fail with the error returned by the closure from now on.
+    pub fn set_error(&mut self, error: impl Fn() -> Error + Send + Sync + 'static) -> &mut Self {
+        self.error = Arc::new(error);
+        self
+    }
+
+    /// Returns the wrapped backend.
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+
+    /// Returns the number of operations since the failure rate was last set.
+    pub fn operations(&self) -> usize {
+        self.operations.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of operations that failed.
+    pub fn failures(&self) -> usize {
+        self.failures.load(Ordering::Relaxed)
+    }
+
+    fn check(&self) -> Result<(), Error> {
+        let operation = self.operations.fetch_add(1, Ordering::Relaxed) + 1;
+
+        if operation.checked_rem(self.fail_every) == Some(0) {
+            self.failures.fetch_add(1, Ordering::Relaxed);
+            return Err((self.error)());
+        }
+
+        Ok(())
+    }
+}
+
+impl<K, B: Backend<K>> Backend<K> for FlakyBackend<B> {
+    fn save<F: Format, T: Serialize>(&self, key: K, value: &T) -> Result<(), Error> {
+        self.check()?;
+        self.inner.save::<F, T>(key, value)
+    }
+
+    fn load<F: Format, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        &self,
+        key: K,
+        seed: S,
+    ) -> Result<T, Error> {
+        self.check()?;
+        self.inner.load::<F, S, T>(key, seed)
+    }
+
+    fn etag<F: Format>(&self, key: K) -> Result<Option<String>, Error> {
+        self.inner.etag::<F>(key)
+    }
+
+    fn save_if_match<F: Format, T: Serialize>(
+        &self,
+        key: K,
+        value: &T,
+        etag: Option<&str>,
+    ) -> Result<(), Error> {
+        self.check()?;
+        self.inner.save_if_match::<F, T>(key, value, etag)
+    }
+}
+
+fn hash_values(values: &[Box<dyn Reflect>], registry: &bevy::reflect::TypeRegistry) -> u64 {
+    let mut values = values
+        .iter()
+        .map(|v| {
+            let type_path = v
+                .get_represented_type_info()
+                .map_or_else(|| v.reflect_type_path(), |i| i.type_path());
+
+            (type_path, render(&**v, registry))
+        })
+        .collect::<Vec<_>>();
+
+    values.sort_unstable();
+
+    let mut hasher = DefaultHasher::new();
+    values.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Hashes the reflect-visible state of the entities and resources of the [`World`] allowed by the filter.
+///
+/// Entities without any allowed components are ignored. Entity ids and the order of entities, components and resources do not affect the fingerprint, so the state of a
+/// world can be compared with the state it was restored to, even if the entities were respawned. Values are compared
+/// by their serialized representation, so components referring to other entities, such as [`Parent`] and
+/// [`Children`], should be denied when entities are respawned.
+///
+/// The fingerprint is only stable within a single build of the program.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::{prelude::*, testing::world_fingerprint};
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component)]
+/// struct Health(u32);
+///
+/// # let mut app = App::new();
+/// # app.add_plugins((MinimalPlugins, SavePlugins));
+/// # app.register_type::<Health>();
+/// # let world = &mut app.world;
+/// let filter = SceneFilter::deny_all().allow::<Health>();
+///
+/// world.spawn(Health(5));
+/// world.spawn(Health(10));
+///
+/// let before = world_fingerprint(world, &filter);
+/// let snapshot = Snapshot::from_world(world);
+///
+/// world.clear_entities();
+/// snapshot.apply(world).unwrap();
+///
+/// assert_eq!(world_fingerprint(world, &filter), before);
+///
+/// world.spawn(Health(1));
+///
+/// assert_ne!(world_fingerprint(world, &filter), before);
+/// ```
+pub fn world_fingerprint(world: &World, filter: &SceneFilter) -> u64 {
+    let snapshot = Snapshot::builder(world)
+        .filter(filter.clone())
+        .extract_all_entities()
+        .extract_all_resources()
+        .build();
+
+    let registry = world.resource::<AppTypeRegistry>().read();
+
+    let mut entities = snapshot
+        .entities
+        .iter()
+        .filter(|e| !e.components.is_empty())
+        .map(|e| hash_values(&e.components, &registry))
+        .collect::<Vec<_>>();
+
+    entities.sort_unstable();
+
+    let mut hasher = DefaultHasher::new();
+    entities.hash(&mut hasher);
+    hash_values(&snapshot.resources, &registry).hash(&mut hasher);
+    hasher.finish()
+}
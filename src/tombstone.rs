@@ -0,0 +1,119 @@
+use bevy::{
+    ecs::entity::EntityHashMap,
+    prelude::*,
+    utils::HashSet,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+/// A stable identifier for a saveable entity, persisting across saves and loads.
+///
+/// Entities with a [`SaveId`] are tracked by [`Tombstones`] when despawned.
+#[derive(
+    Component, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize,
+)]
+#[reflect(Component, Serialize, Deserialize)]
+pub struct SaveId(pub u64);
+
+/// The [`SaveId`]s of entities that have been despawned, allowing incremental saves to delete them when applied.
+///
+/// When saving a subset of the world, entities despawned since an older save would reappear when it is loaded.
+/// Extract this resource into each snapshot and the [`SnapshotApplier`](crate::SnapshotApplier) will:
+/// - Despawn existing entities with a [`SaveId`] tombstoned in the snapshot
+/// - Skip entities in the snapshot with a tombstoned [`SaveId`]
+/// - Merge the tombstones of the snapshot into the [`World`], so they are carried into the next save
+///
+/// Entities despawned by the [`SnapshotApplier`](crate::SnapshotApplier) itself are not recorded, and a tombstone is
+/// removed once an entity with its [`SaveId`] exists again, such as after a rollback.
+///
+/// Clear the tombstones when starting a new game.
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # let world = &mut app.world;
+/// let snapshot = Snapshot::builder(world)
+///     .extract_entities_matching(|e| e.contains::<SaveId>())
+///     .extract_resource::<Tombstones>()
+///     .build();
+/// ```
+#[derive(Resource, Reflect, Default, Debug, Clone)]
+#[reflect(Resource)]
+pub struct Tombstones {
+    /// The [`SaveId`]s of despawned entities.
+    pub ids: HashSet<SaveId>,
+    /// The [`SaveId`]s of live entities, recorded when despawned.
+    #[reflect(ignore)]
+    known: EntityHashMap<SaveId>,
+}
+
+impl PartialEq for Tombstones {
+    fn eq(&self, other: &Self) -> bool {
+        self.ids == other.ids
+    }
+}
+
+impl Eq for Tombstones {}
+
+impl Tombstones {
+    /// Returns true if the given [`SaveId`] has been despawned.
+    pub fn contains(&self, id: SaveId) -> bool {
+        self.ids.contains(&id)
+    }
+
+    /// Record the given [`SaveId`] as despawned.
+    pub fn insert(&mut self, id: SaveId) {
+        self.ids.insert(id);
+    }
+
+    /// Remove the tombstone of the given [`SaveId`], returning `true` if it was despawned.
+    pub fn remove(&mut self, id: SaveId) -> bool {
+        self.ids.remove(&id)
+    }
+
+    /// Merge the tombstones of another [`Tombstones`] into this one.
+    pub fn merge(&mut self, other: &Self) {
+        self.ids.extend(other.ids.iter().copied());
+    }
+
+    /// Remove all tombstones.
+    pub fn clear(&mut self) {
+        self.ids.clear();
+    }
+
+    /// Stop tracking the entity, so despawning it does not record a tombstone.
+    pub(crate) fn forget(&mut self, entity: Entity) {
+        self.known.remove(&entity);
+    }
+
+    /// System recording the [`SaveId`] of despawned entities, or entities that had their [`SaveId`] removed.
+    ///
+    /// Tombstones of [`SaveId`]s added to an entity again are removed.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn track(
+        mut tombstones: ResMut<Self>,
+        changed: Query<(Entity, &SaveId), Changed<SaveId>>,
+        mut removed: RemovedComponents<SaveId>,
+    ) {
+        for entity in removed.read() {
+            if changed.contains(entity) {
+                continue;
+            }
+
+            if let Some(id) = tombstones.known.remove(&entity) {
+                tombstones.insert(id);
+            }
+        }
+
+        for (entity, id) in &changed {
+            tombstones.known.insert(entity, *id);
+            tombstones.remove(*id);
+        }
+    }
+}
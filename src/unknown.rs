@@ -0,0 +1,109 @@
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+};
+
+use bevy::prelude::*;
+
+use crate::{
+    content::Content,
+    Snapshot,
+};
+
+/// The value of a component or resource of an unregistered type, retained as-is.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnknownBlob {
+    pub(crate) type_path: String,
+    pub(crate) value: Content,
+}
+
+impl UnknownBlob {
+    /// Returns the type path of the unregistered type.
+    pub fn type_path(&self) -> &str {
+        &self.type_path
+    }
+}
+
+/// Components and resources of unregistered types retained by a [`Snapshot`].
+///
+/// Collected while deserializing leniently (see [`SnapshotDeserializer::lenient`](crate::SnapshotDeserializer::lenient))
+/// and written again when the snapshot is serialized.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct UnknownData {
+    pub(crate) components: BTreeMap<Entity, Vec<UnknownBlob>>,
+    pub(crate) resources: Vec<UnknownBlob>,
+}
+
+impl UnknownData {
+    /// Returns `true` if there is no unknown data.
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty() && self.resources.is_empty()
+    }
+
+    /// Returns the unknown components of the given entity.
+    pub fn components(&self, entity: Entity) -> &[UnknownBlob] {
+        self.components.get(&entity).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the unknown resources.
+    pub fn resources(&self) -> &[UnknownBlob] {
+        &self.resources
+    }
+
+    /// Returns the type paths of all unknown types, without duplicates.
+    pub fn type_paths(&self) -> BTreeSet<&str> {
+        self.components
+            .values()
+            .flatten()
+            .chain(&self.resources)
+            .map(UnknownBlob::type_path)
+            .collect()
+    }
+
+    pub(crate) fn insert(&mut self, owner: Option<Entity>, blob: UnknownBlob) {
+        match owner {
+            Some(entity) => self.components.entry(entity).or_default().push(blob),
+            None => self.resources.push(blob),
+        }
+    }
+}
+
+/// Components of unregistered types loaded for an entity, written again when the entity is saved.
+///
+/// Inserted when applying a [`Snapshot`] with [`UnknownData`].
+#[derive(Component, Default, Debug, Clone, PartialEq)]
+pub struct UnknownComponents(pub Vec<UnknownBlob>);
+
+/// Resources of unregistered types loaded from the last save, written again with every save.
+///
+/// Inserted when applying a [`Snapshot`] with [`UnknownData`], and removed when applying one without.
+#[derive(Resource, Default, Debug, Clone, PartialEq)]
+pub struct UnknownResources(pub Vec<UnknownBlob>);
+
+impl Snapshot {
+    /// Returns the components and resources of unregistered types retained by the snapshot.
+    pub fn unknown(&self) -> &UnknownData {
+        &self.unknown
+    }
+
+    /// Stores the [`UnknownComponents`] of the extracted entities and the [`UnknownResources`] of the [`World`]
+    /// in the snapshot, so they are written again.
+    pub(crate) fn stamp_unknown(&mut self, world: &World) {
+        self.unknown = UnknownData::default();
+
+        for entity in &self.entities {
+            if let Some(unknown) = world
+                .get_entity(entity.entity)
+                .and_then(|e| e.get::<UnknownComponents>())
+            {
+                self.unknown
+                    .components
+                    .insert(entity.entity, unknown.0.clone());
+            }
+        }
+
+        if let Some(unknown) = world.get_resource::<UnknownResources>() {
+            self.unknown.resources.clone_from(&unknown.0);
+        }
+    }
+}
@@ -0,0 +1,361 @@
+use std::{
+    any::TypeId,
+    fmt::{
+        Display,
+        Formatter,
+    },
+    sync::Arc,
+};
+
+use bevy::{
+    ecs::reflect::ReflectMapEntities,
+    prelude::*,
+    reflect::{
+        FromType,
+        ReflectFromReflect,
+        TypeInfo,
+        TypeRegistry,
+        VariantInfo,
+    },
+    utils::HashSet,
+};
+
+/// A misconfiguration that prevents a type from being saved or restored correctly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaveableIssue {
+    /// The component is registered but does not reflect [`Component`], so it will not be saved.
+    MissingReflectComponent {
+        /// The type path of the component.
+        type_path: &'static str,
+    },
+
+    /// The resource is registered but does not reflect [`Resource`], so it will not be saved.
+    MissingReflectResource {
+        /// The type path of the resource.
+        type_path: &'static str,
+    },
+
+    /// The type does not reflect [`FromReflect`] or [`Default`], so it cannot be restored.
+    MissingFromReflect {
+        /// The type path of the type.
+        type_path: &'static str,
+    },
+
+    /// The type contains an [`Entity`] but does not reflect `MapEntities`, so the reference will dangle after applying.
+    UnmappedEntity {
+        /// The type path of the type.
+        type_path: &'static str,
+        /// The path of the field containing the [`Entity`].
+        field: String,
+    },
+}
+
+impl Display for SaveableIssue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingReflectComponent { type_path } => write!(
+                f,
+                "`{type_path}` is registered but missing `#[reflect(Component)]`, it will not be saved"
+            ),
+            Self::MissingReflectResource { type_path } => write!(
+                f,
+                "`{type_path}` is registered but missing `#[reflect(Resource)]`, it will not be saved"
+            ),
+            Self::MissingFromReflect { type_path } => write!(
+                f,
+                "`{type_path}` is missing `ReflectFromReflect` and `ReflectDefault`, it cannot be restored"
+            ),
+            Self::UnmappedEntity { type_path, field } => write!(
+                f,
+                "`{type_path}` stores an `Entity` in `{field}` but is missing `#[reflect(MapEntities)]`, the reference will dangle after applying"
+            ),
+        }
+    }
+}
+
+/// Returns the paths of all fields of the given type that contain an [`Entity`].
+pub fn entity_fields(type_id: TypeId, registry: &TypeRegistry) -> Vec<String> {
+    fn visit(
+        type_id: TypeId,
+        path: String,
+        registry: &TypeRegistry,
+        visited: &mut HashSet<TypeId>,
+        out: &mut Vec<String>,
+    ) {
+        if type_id == TypeId::of::<Entity>() {
+            out.push(path);
+            return;
+        }
+
+        if !visited.insert(type_id) {
+            return;
+        }
+
+        let join = |field: &str| {
+            if path.is_empty() {
+                field.to_owned()
+            } else {
+                format!("{path}.{field}")
+            }
+        };
+
+        match registry.get_type_info(type_id) {
+            Some(TypeInfo::Struct(info)) => {
+                for field in info.iter() {
+                    visit(field.type_id(), join(field.name()), registry, visited, out);
+                }
+            }
+            Some(TypeInfo::TupleStruct(info)) => {
+                for field in info.iter() {
+                    let name = field.index().to_string();
+                    visit(field.type_id(), join(&name), registry, visited, out);
+                }
+            }
+            Some(TypeInfo::Tuple(info)) => {
+                for field in info.iter() {
+                    let name = field.index().to_string();
+                    visit(field.type_id(), join(&name), registry, visited, out);
+                }
+            }
+            Some(TypeInfo::List(info)) => {
+                visit(
+                    info.item_type_id(),
+                    format!("{path}[]"),
+                    registry,
+                    visited,
+                    out,
+                );
+            }
+            Some(TypeInfo::Array(info)) => {
+                visit(
+                    info.item_type_id(),
+                    format!("{path}[]"),
+                    registry,
+                    visited,
+                    out,
+                );
+            }
+            Some(TypeInfo::Map(info)) => {
+                visit(
+                    info.key_type_id(),
+                    format!("{path}{{key}}"),
+                    registry,
+                    visited,
+                    out,
+                );
+                visit(
+                    info.value_type_id(),
+                    format!("{path}{{value}}"),
+                    registry,
+                    visited,
+                    out,
+                );
+            }
+            Some(TypeInfo::Enum(info)) => {
+                for variant in info.iter() {
+                    match variant {
+                        VariantInfo::Struct(v) => {
+                            for field in v.iter() {
+                                let name = format!("{}::{}", v.name(), field.name());
+                                visit(field.type_id(), join(&name), registry, visited, out);
+                            }
+                        }
+                        VariantInfo::Tuple(v) => {
+                            for field in v.iter() {
+                                let name = format!("{}::{}", v.name(), field.index());
+                                visit(field.type_id(), join(&name), registry, visited, out);
+                            }
+                        }
+                        VariantInfo::Unit(_) => {}
+                    }
+                }
+            }
+            Some(TypeInfo::Value(_)) | None => {}
+        }
+
+        visited.remove(&type_id);
+    }
+
+    let mut out = Vec::new();
+    visit(
+        type_id,
+        String::new(),
+        registry,
+        &mut HashSet::new(),
+        &mut out,
+    );
+    out
+}
+
+/// Walks the components and resources known to the [`World`] and reports any that are registered with the
+/// [`AppTypeRegistry`] but cannot be saved or restored correctly.
+pub fn validate_saveables(world: &World) -> Vec<SaveableIssue> {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let mut issues = Vec::new();
+
+    for info in world.components().iter() {
+        let Some(type_id) = info.type_id() else {
+            continue;
+        };
+
+        let Some(registration) = registry.get(type_id) else {
+            continue;
+        };
+
+        let type_path = registration.type_info().type_path();
+
+        let is_resource = world.components().get_resource_id(type_id) == Some(info.id());
+
+        if is_resource {
+            if registration.data::<ReflectResource>().is_none() {
+                issues.push(SaveableIssue::MissingReflectResource { type_path });
+                continue;
+            }
+        } else if registration.data::<ReflectComponent>().is_none() {
+            issues.push(SaveableIssue::MissingReflectComponent { type_path });
+            continue;
+        }
+
+        if registration.data::<ReflectFromReflect>().is_none()
+            && registration.data::<ReflectDefault>().is_none()
+        {
+            issues.push(SaveableIssue::MissingFromReflect { type_path });
+        }
+
+        if registration.data::<ReflectMapEntities>().is_none() {
+            for field in entity_fields(type_id, &registry) {
+                issues.push(SaveableIssue::UnmappedEntity { type_path, field });
+            }
+        }
+    }
+
+    issues
+}
+
+/// Logs a warning for each registered component that stores an [`Entity`] without reflecting `MapEntities`.
+///
+/// Added by [`SavePlugin`](crate::SavePlugin) in debug builds, running once at startup after types are registered.
+#[allow(clippy::needless_pass_by_value)]
+pub fn warn_unmapped_entities(registry: Res<AppTypeRegistry>) {
+    let registry = registry.read();
+
+    for registration in registry.iter() {
+        if registration.data::<ReflectComponent>().is_none()
+            || registration.data::<ReflectMapEntities>().is_some()
+        {
+            continue;
+        }
+
+        let type_path = registration.type_info().type_path();
+
+        for field in entity_fields(registration.type_id(), &registry) {
+            warn!("{}", SaveableIssue::UnmappedEntity { type_path, field });
+        }
+    }
+}
+
+/// Logs a warning for each issue reported by [`validate_saveables`].
+pub fn log_saveable_issues(world: &mut World) {
+    for issue in validate_saveables(world) {
+        warn!("{issue}");
+    }
+}
+
+/// Checks the invariants of a saved value before it is applied, fixing it in place or rejecting it.
+///
+/// Reflect it with `#[reflect(Validate)]` to validate components and resources when applying a snapshot, so values
+/// from corrupted or hand-edited saves are not inserted blindly. Rejected values are not applied and are listed in
+/// the [`ApplyReport`](crate::ApplyReport).
+///
+/// # Example
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_save::prelude::*;
+/// #[derive(Component, Reflect, Default)]
+/// #[reflect(Component, Validate)]
+/// struct Health {
+///     current: f32,
+///     max: f32,
+/// }
+///
+/// impl Validate for Health {
+///     fn validate(&mut self) -> Result<(), String> {
+///         if self.current.is_nan() || self.max.is_nan() {
+///             return Err("health is NaN".into());
+///         }
+///
+///         self.current = self.current.min(self.max);
+///         Ok(())
+///     }
+/// }
+///
+/// # let mut app = App::new();
+/// # app.add_plugins(MinimalPlugins);
+/// # app.add_plugins(SavePlugins);
+/// # app.register_type::<Health>();
+/// # let world = &mut app.world;
+/// let healthy = world.spawn(Health { current: 150.0, max: 100.0 }).id();
+/// let corrupted = world.spawn(Health { current: f32::NAN, max: 100.0 }).id();
+///
+/// let snapshot = Snapshot::builder(world).extract_all_entities().build();
+///
+/// world.clear_entities();
+///
+/// let mut entity_map = Default::default();
+///
+/// snapshot.applier(world).entity_map(&mut entity_map).apply().unwrap();
+///
+/// assert_eq!(world.get::<Health>(entity_map[&healthy]).unwrap().current, 100.0);
+/// assert!(world.get::<Health>(entity_map[&corrupted]).is_none());
+/// assert_eq!(world.resource::<ApplyReport>().rejected.len(), 1);
+/// ```
+pub trait Validate {
+    /// Fix the value in place, or return the reason it is rejected.
+    ///
+    /// # Errors
+    /// If the value is invalid and cannot be fixed.
+    fn validate(&mut self) -> Result<(), String>;
+}
+
+type ValidateFn = Arc<dyn Fn(&dyn Reflect) -> Result<Box<dyn Reflect>, String> + Send + Sync>;
+
+/// Type data validating saved values of a type before they are applied.
+///
+/// Created from [`Validate`] with `#[reflect(Validate)]`, or from a closure with
+/// [`AppSaveableExt::add_validator`](crate::AppSaveableExt::add_validator) for types of other crates.
+#[derive(Clone)]
+pub struct ReflectValidate {
+    func: ValidateFn,
+}
+
+impl ReflectValidate {
+    /// Create type data validating values of the type `T` with the closure.
+    pub fn from_fn<T: FromReflect + TypePath>(
+        validate: impl Fn(&mut T) -> Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            func: Arc::new(move |value| {
+                let mut value = T::from_reflect(value)
+                    .ok_or_else(|| format!("value is not a `{}`", T::type_path()))?;
+
+                validate(&mut value)?;
+
+                Ok(Box::new(value))
+            }),
+        }
+    }
+
+    /// Returns the validated and possibly fixed value, or the reason it is rejected.
+    ///
+    /// # Errors
+    /// If the value is rejected or is not of the validated type.
+    pub fn validate(&self, value: &dyn Reflect) -> Result<Box<dyn Reflect>, String> {
+        (self.func)(value)
+    }
+}
+
+impl<T: Validate + FromReflect + TypePath> FromType<T> for ReflectValidate {
+    fn from_type() -> Self {
+        Self::from_fn(T::validate)
+    }
+}
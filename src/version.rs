@@ -0,0 +1,176 @@
+use std::cmp::Ordering;
+
+use bevy::prelude::*;
+
+use crate::{
+    Error,
+    Snapshot,
+};
+
+/// The version of the game, stored alongside every save.
+///
+/// Set with [`AppSaveableExt::set_game_version`](crate::AppSaveableExt::set_game_version).
+#[derive(Resource, Reflect, Default, Debug, Clone, PartialEq, Eq)]
+#[reflect(Resource)]
+pub struct GameVersion(pub String);
+
+/// Determines which saves can be loaded by the current [`GameVersion`].
+///
+/// Versions are compared component-wise as dot-separated numbers, e.g. `1.10.0` > `1.9.2`, with missing components
+/// treated as zero. Pre-releases sort before their release, e.g. `2.0.0-rc.1` < `2.0.0`.
+#[derive(Resource, Default, Debug, Clone, PartialEq, Eq)]
+pub struct SaveCompatibility {
+    /// The oldest version that can be loaded.
+    ///
+    /// If `None`, saves from any older version can be loaded, including saves without a version.
+    pub min: Option<String>,
+
+    /// The newest version that can be loaded.
+    ///
+    /// If `None`, defaults to the current [`GameVersion`].
+    pub max: Option<String>,
+}
+
+/// A pre-release identifier, where numeric identifiers sort before alphanumeric ones.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Identifier {
+    Numeric(u64),
+    Text(String),
+}
+
+/// A parsed version, ordered like semantic versions.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Version {
+    release: Vec<u64>,
+    pre: Vec<Identifier>,
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.release.cmp(&other.release).then_with(|| {
+            // A pre-release sorts before the release itself
+            match (self.pre.is_empty(), other.pre.is_empty()) {
+                (true, true) => Ordering::Equal,
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => self.pre.cmp(&other.pre),
+            }
+        })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Parses a version such as `1.2.0-rc.1`.
+///
+/// Components without a number count as zero, trailing zeros are ignored so `1.0 == 1.0.0`, and build metadata
+/// after `+` is ignored.
+pub(crate) fn parse_version(version: &str) -> Version {
+    let version = version.trim().trim_start_matches('v');
+    let version = version.split_once('+').map_or(version, |(v, _)| v);
+    let (release, pre) = version.split_once('-').unwrap_or((version, ""));
+
+    let mut release = release
+        .split('.')
+        .map(|p| {
+            let digits = p.find(|c: char| !c.is_ascii_digit()).unwrap_or(p.len());
+            p[..digits].parse().unwrap_or(0)
+        })
+        .collect::<Vec<u64>>();
+
+    while release.last() == Some(&0) {
+        release.pop();
+    }
+
+    let pre = pre
+        .split('.')
+        .filter(|p| !p.is_empty())
+        .map(|p| {
+            p.parse()
+                .map_or_else(|_| Identifier::Text(p.to_owned()), Identifier::Numeric)
+        })
+        .collect();
+
+    Version { release, pre }
+}
+
+impl SaveCompatibility {
+    /// Checks that a save created by the given version can be loaded by the `current` version.
+    ///
+    /// # Errors
+    /// - [`Error::IncompatibleSave`] if the save is outside of the supported range
+    // `Option::is_none_or` is newer than the Rust version supported by Bevy 0.13
+    #[allow(clippy::unnecessary_map_or)]
+    pub fn check(&self, current: &str, save: Option<&str>) -> Result<(), Error> {
+        let max = self.max.as_deref().unwrap_or(current);
+
+        let compatible = match save {
+            Some(save) => {
+                let save = parse_version(save);
+
+                self.min
+                    .as_deref()
+                    .map_or(true, |min| save >= parse_version(min))
+                    && save <= parse_version(max)
+            }
+            None => self.min.is_none(),
+        };
+
+        if compatible {
+            Ok(())
+        } else {
+            Err(Error::IncompatibleSave {
+                save: save.unwrap_or("unknown").to_owned(),
+                supported: format!("{}..={max}", self.min.as_deref().unwrap_or("")),
+            })
+        }
+    }
+}
+
+impl Snapshot {
+    /// Returns the [`GameVersion`] the snapshot was saved with, if any.
+    pub fn game_version(&self) -> Option<GameVersion> {
+        self.get_resource::<GameVersion>()
+    }
+
+    /// Stores the [`GameVersion`] of the [`World`] in the snapshot, replacing any existing one.
+    pub(crate) fn stamp_version(&mut self, world: &World) {
+        self.remove_resource::<GameVersion>();
+
+        if let Some(version) = world.get_resource::<GameVersion>() {
+            self.insert_resource(version.clone());
+        }
+    }
+
+    /// Checks the [`GameVersion`] of the snapshot against the [`SaveCompatibility`] of the [`World`], then removes it.
+    ///
+    /// Does nothing if the [`World`] does not have a [`GameVersion`].
+    pub(crate) fn check_version(&mut self, world: &World) -> Result<(), Error> {
+        let result = self.verify_version(world);
+
+        self.remove_resource::<GameVersion>();
+
+        result
+    }
+
+    /// Checks the [`GameVersion`] of the snapshot against the [`SaveCompatibility`] of the [`World`], keeping it.
+    ///
+    /// Does nothing if the [`World`] does not have a [`GameVersion`].
+    pub(crate) fn verify_version(&self, world: &World) -> Result<(), Error> {
+        let Some(current) = world.get_resource::<GameVersion>() else {
+            return Ok(());
+        };
+
+        let save = self.game_version();
+
+        world
+            .get_resource::<SaveCompatibility>()
+            .cloned()
+            .unwrap_or_default()
+            .check(&current.0, save.as_ref().map(|v| v.0.as_str()))
+    }
+}
@@ -1,15 +1,26 @@
-use bevy::prelude::*;
+use bevy::{
+    prelude::*,
+    reflect::TypeRegistry,
+};
 
 use crate::{
+    domain::rollbacks_mut,
+    intercept::intercept_save,
+    profile::profile_span,
+    retry::retry,
     Backend,
+    BackendOperation,
     CloneReflect,
     Error,
+    Format,
     Pipeline,
-    Rollbacks,
+    SaveQueue,
     Snapshot,
     SnapshotBuilder,
     SnapshotDeserializer,
     SnapshotSerializer,
+    Stamped,
+    SummaryFormat,
 };
 
 /// Extension trait that adds save-related methods to Bevy's [`World`].
@@ -17,6 +28,17 @@ pub trait WorldSaveableExt: Sized {
     /// Captures a [`Snapshot`] from the current [`World`] state.
     fn snapshot<P: Pipeline>(&self) -> Snapshot;
 
+    /// Captures a [`Snapshot`] with the given [`Pipeline`], without saving it.
+    ///
+    /// Useful for networking, undo, or tests that never touch storage.
+    fn capture<P: Pipeline>(&self, pipeline: P) -> Snapshot;
+
+    /// Applies a [`Snapshot`] with the given [`Pipeline`], without loading it.
+    ///
+    /// # Errors
+    /// - See [`Error`]
+    fn apply<P: Pipeline>(&mut self, pipeline: P, snapshot: &Snapshot) -> Result<(), Error>;
+
     /// Saves the game state with the given [`Pipeline`].
     ///
     /// # Errors
@@ -28,35 +50,141 @@ pub trait WorldSaveableExt: Sized {
     /// # Errors
     /// - See [`Error`]
     fn load<P: Pipeline>(&mut self, pipeline: P) -> Result<(), Error>;
+
+    /// Enqueues a save with the given [`Pipeline`] to be performed at the [`SaveSet`](crate::SaveSet) barrier.
+    ///
+    /// This guarantees the captured [`Snapshot`] reflects a fully updated frame.
+    fn save_deferred<P: Pipeline + Send + Sync + 'static>(&mut self, pipeline: P);
+
+    /// Enqueues a load with the given [`Pipeline`] to be performed at the [`SaveSet`](crate::SaveSet) barrier.
+    fn load_deferred<P: Pipeline + Send + Sync + 'static>(&mut self, pipeline: P);
 }
 
 impl WorldSaveableExt for World {
     fn snapshot<P: Pipeline>(&self) -> Snapshot {
-        P::capture(Snapshot::builder(self))
+        P::capture(
+            Snapshot::builder(self)
+                .domain(P::domain())
+                .checkpoint_persistence(P::checkpoint_persistence()),
+        )
+    }
+
+    fn capture<P: Pipeline>(&self, pipeline: P) -> Snapshot {
+        let pipeline = pipeline.with_context(self);
+        pipeline.capture_seed(
+            Snapshot::builder(self)
+                .domain(P::domain())
+                .checkpoint_persistence(P::checkpoint_persistence()),
+        )
+    }
+
+    fn apply<P: Pipeline>(&mut self, pipeline: P, snapshot: &Snapshot) -> Result<(), Error> {
+        let pipeline = pipeline.with_context(self);
+        pipeline.apply_seed(self, snapshot)
     }
 
     fn save<P: Pipeline>(&self, pipeline: P) -> Result<(), Error> {
+        let pipeline = pipeline.with_context(self);
+        let mut intercepted = intercept_save(self, pipeline)?;
+
         let registry = self.resource::<AppTypeRegistry>();
         let backend = self.resource::<P::Backend>();
 
-        let snapshot = pipeline.capture_seed(Snapshot::builder(self));
+        let metadata = std::mem::take(&mut intercepted.metadata);
+        let snapshot = Snapshot::capture_save(self, &intercepted.pipeline, metadata);
 
         let ser = SnapshotSerializer::new(&snapshot, registry);
 
-        backend.save::<P::Format, _>(pipeline.key(), &ser)
+        profile_span!("write");
+
+        retry::<P, _>(self, BackendOperation::Save, || {
+            intercepted.save::<Stamped<P::Format>, _>(backend, &ser)
+        })?;
+
+        if P::summary() {
+            let summary = snapshot.summary();
+
+            retry::<P, _>(self, BackendOperation::Save, || {
+                intercepted.save::<SummaryFormat, _>(backend, &summary)
+            })?;
+        }
+
+        Ok(())
     }
 
     fn load<P: Pipeline>(&mut self, pipeline: P) -> Result<(), Error> {
+        let pipeline = pipeline.with_context(self);
+
         let registry = self.resource::<AppTypeRegistry>().clone();
         let reg = registry.read();
         let backend = self.resource::<P::Backend>();
 
-        let de = SnapshotDeserializer { registry: &reg };
+        let mut snapshot = {
+            profile_span!("read");
+
+            retry::<P, _>(self, BackendOperation::Load, || {
+                let de = SnapshotDeserializer::for_world(&reg, self)
+                    .parallel(P::Format::self_describing());
 
-        let snapshot = backend.load::<P::Format, _, _>(pipeline.key(), de)?;
+                backend.load::<Stamped<P::Format>, _, _>(pipeline.key(), de)
+            })?
+        };
+
+        snapshot.prepare_load(self, &reg)?;
 
         pipeline.apply_seed(self, &snapshot)
     }
+
+    fn save_deferred<P: Pipeline + Send + Sync + 'static>(&mut self, pipeline: P) {
+        self.resource_mut::<SaveQueue>().save(pipeline);
+    }
+
+    fn load_deferred<P: Pipeline + Send + Sync + 'static>(&mut self, pipeline: P) {
+        self.resource_mut::<SaveQueue>().load(pipeline);
+    }
+}
+
+impl Snapshot {
+    /// Captures the snapshot saved by the [`Pipeline`], quantized for its [`Format`] and stamped with the version, mods,
+    /// unknown data and interceptor metadata of the [`World`].
+    pub(crate) fn capture_save<P: Pipeline>(
+        world: &World,
+        pipeline: &P,
+        metadata: Vec<Box<dyn Reflect>>,
+    ) -> Self {
+        let mut snapshot = {
+            profile_span!("capture");
+
+            pipeline.capture_seed(
+                Snapshot::builder(world)
+                    .domain(P::domain())
+                    .checkpoint_persistence(P::checkpoint_persistence()),
+            )
+        };
+
+        snapshot.quantize(
+            P::Format::float_precision(),
+            &world.resource::<AppTypeRegistry>().read(),
+        );
+        snapshot.stamp_version(world);
+        snapshot.stamp_mods(world);
+        snapshot.stamp_unknown(world);
+        snapshot.stamp_metadata(metadata);
+
+        snapshot
+    }
+
+    /// Checks a snapshot read from storage against the [`SaveCompatibility`](crate::SaveCompatibility) and
+    /// [`ModManifest`](crate::ModManifest) of the [`World`], then sanitizes it with its [`SanitizePolicy`](crate::SanitizePolicy).
+    pub(crate) fn prepare_load(
+        &mut self,
+        world: &mut World,
+        registry: &TypeRegistry,
+    ) -> Result<(), Error> {
+        self.check_version(world)?;
+        self.check_mods(world)?;
+        self.sanitize_for(world, registry)
+    }
 }
 
 /// Extension trait that adds rollback-related methods to Bevy's [`World`].
@@ -69,17 +197,30 @@ pub trait WorldRollbackExt {
     /// # Errors
     /// - See [`Error`]
     fn rollback<P: Pipeline>(&mut self, checkpoints: isize) -> Result<(), Error>;
+
+    /// Creates a checkpoint for rollback on a new branch with the given name.
+    fn checkpoint_branch<P: Pipeline>(&mut self, name: &str);
+
+    /// Switches to the branch with the given name, applying its newest checkpoint.
+    ///
+    /// # Errors
+    /// - [`Error::Custom`] if the branch does not exist
+    /// - See [`Error`]
+    fn switch_branch<P: Pipeline>(&mut self, name: &str) -> Result<(), Error>;
 }
 
 impl WorldRollbackExt for World {
     fn checkpoint<P: Pipeline>(&mut self) {
-        let rollback = P::capture(SnapshotBuilder::rollback(self));
-        self.resource_mut::<Rollbacks>().checkpoint(rollback);
+        let rollback = P::capture(
+            SnapshotBuilder::rollback(self)
+                .domain(P::domain())
+                .filter(P::checkpoint_filter(self)),
+        );
+        rollbacks_mut(self, P::domain()).checkpoint(rollback);
     }
 
     fn rollback<P: Pipeline>(&mut self, checkpoints: isize) -> Result<(), Error> {
-        if let Some(rollback) = self
-            .resource_mut::<Rollbacks>()
+        if let Some(rollback) = rollbacks_mut(self, P::domain())
             .rollback(checkpoints)
             .map(|r| r.clone_value())
         {
@@ -88,4 +229,22 @@ impl WorldRollbackExt for World {
             Ok(())
         }
     }
+
+    fn checkpoint_branch<P: Pipeline>(&mut self, name: &str) {
+        let rollback = P::capture(
+            SnapshotBuilder::rollback(self)
+                .domain(P::domain())
+                .filter(P::checkpoint_filter(self)),
+        );
+        rollbacks_mut(self, P::domain()).checkpoint_branch(name, rollback);
+    }
+
+    fn switch_branch<P: Pipeline>(&mut self, name: &str) -> Result<(), Error> {
+        let rollback = rollbacks_mut(self, P::domain())
+            .switch_branch(name)
+            .map(|r| r.clone_value())
+            .ok_or_else(|| Error::custom(format!("no rollback branch named `{name}`")))?;
+
+        P::apply(self, &rollback)
+    }
 }
@@ -0,0 +1,243 @@
+use bevy::{
+    ecs::{
+        entity::{
+            EntityMapper,
+            MapEntities,
+        },
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+};
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Bullet(u32);
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Marker;
+
+#[derive(Component, Reflect)]
+#[reflect(Component, MapEntities)]
+struct Target(Entity);
+
+impl FromWorld for Target {
+    fn from_world(_: &mut World) -> Self {
+        Self(Entity::PLACEHOLDER)
+    }
+}
+
+impl MapEntities for Target {
+    fn map_entities<M: EntityMapper>(&mut self, mapper: &mut M) {
+        self.0 = mapper.map_entity(self.0);
+    }
+}
+
+#[derive(Default)]
+struct Pool {
+    free: Vec<Entity>,
+    allocated: usize,
+    released: Vec<Entity>,
+}
+
+impl EntityAllocator for Pool {
+    fn allocate(&mut self, world: &mut World) -> Entity {
+        self.allocated += 1;
+        self.free.pop().unwrap_or_else(|| world.spawn_empty().id())
+    }
+
+    fn release(&mut self, world: &mut World, entity: Entity) {
+        world.entity_mut(entity).retain::<()>();
+        self.released.push(entity);
+        self.free.push(entity);
+    }
+}
+
+fn setup() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Bullet>()
+        .register_type::<Marker>();
+
+    app
+}
+
+fn bullets(world: &mut World) -> Vec<u32> {
+    let mut bullets = world
+        .query::<&Bullet>()
+        .iter(world)
+        .map(|b| b.0)
+        .collect::<Vec<_>>();
+
+    bullets.sort_unstable();
+    bullets
+}
+
+#[test]
+fn test_allocator_reuses_despawned() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    world.spawn(Bullet(0));
+    world.spawn(Bullet(1));
+
+    let snapshot = Snapshot::builder(world).extract_all_entities().build();
+
+    world.clear_entities();
+
+    let a = world.spawn(Bullet(2)).id();
+    let b = world.spawn(Bullet(3)).id();
+
+    let mut pool = Pool::default();
+
+    snapshot
+        .applier(world)
+        .despawn::<With<Bullet>>()
+        .allocator(&mut pool)
+        .apply()
+        .unwrap();
+
+    assert_eq!(pool.released.len(), 2);
+    assert_eq!(pool.allocated, 2);
+    assert!(pool.free.is_empty());
+
+    // Both released entities were reused for the snapshot entities
+    assert_eq!(world.entities().len(), 2);
+    assert!(world.get::<Bullet>(a).is_some());
+    assert!(world.get::<Bullet>(b).is_some());
+    assert_eq!(bullets(world), [0, 1]);
+}
+
+#[test]
+fn test_allocator_spawns_when_empty() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    world.spawn(Bullet(0));
+    world.spawn(Bullet(1));
+
+    let snapshot = Snapshot::builder(world).extract_all_entities().build();
+
+    world.clear_entities();
+
+    let mut pool = Pool::default();
+
+    snapshot
+        .applier(world)
+        .allocator(&mut pool)
+        .apply()
+        .unwrap();
+
+    assert!(pool.released.is_empty());
+    assert_eq!(pool.allocated, 2);
+    assert_eq!(bullets(world), [0, 1]);
+}
+
+#[test]
+fn test_allocator_despawn_matching() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    world.spawn(Bullet(0));
+
+    let snapshot = Snapshot::builder(world).extract_all_entities().build();
+
+    world.clear_entities();
+
+    let marked = world.spawn(Marker).id();
+    let kept = world.spawn(Bullet(1)).id();
+
+    let mut pool = Pool::default();
+
+    snapshot
+        .applier(world)
+        .despawn_matching(|e| e.contains::<Marker>())
+        .allocator(&mut pool)
+        .apply()
+        .unwrap();
+
+    assert_eq!(pool.released, [marked]);
+
+    // The marked entity was stripped and reused, the other was left alone
+    assert!(world.get::<Marker>(marked).is_none());
+    assert_eq!(world.get::<Bullet>(marked), Some(&Bullet(0)));
+    assert_eq!(world.get::<Bullet>(kept), Some(&Bullet(1)));
+}
+
+#[test]
+fn test_allocator_sandbox_keeps_existing() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    world.spawn(Bullet(0));
+
+    let snapshot = Snapshot::builder(world).extract_all_entities().build();
+
+    let mut pool = Pool::default();
+
+    snapshot
+        .applier(world)
+        .despawn::<With<Bullet>>()
+        .sandbox(FromSnapshot(1))
+        .allocator(&mut pool)
+        .apply()
+        .unwrap();
+
+    assert!(pool.released.is_empty());
+    assert_eq!(pool.allocated, 1);
+    assert_eq!(bullets(world), [0, 0]);
+}
+
+#[test]
+fn test_allocator_spawn_empty_policies() {
+    let mut app = setup();
+
+    app.register_type::<Parent>()
+        .register_type::<Children>()
+        .register_type::<Target>();
+
+    let world = &mut app.world;
+
+    let mut child = Entity::PLACEHOLDER;
+
+    let enemy = world.spawn_empty().id();
+
+    world.spawn_empty().with_children(|p| {
+        child = p.spawn((Bullet(0), Target(enemy))).id();
+    });
+
+    let snapshot = Snapshot::builder(world).extract_entity(child).build();
+
+    world.clear_entities();
+
+    let free = [
+        world.spawn_empty().id(),
+        world.spawn_empty().id(),
+        world.spawn_empty().id(),
+    ];
+
+    let mut pool = Pool {
+        free: free.to_vec(),
+        ..default()
+    };
+
+    snapshot
+        .applier(world)
+        .missing_parent(MissingParentPolicy::SpawnEmpty)
+        .dangling_entity(DanglingEntityPolicy::SpawnEmpty)
+        .allocator(&mut pool)
+        .apply()
+        .unwrap();
+
+    // The bullet, its new empty parent and its new empty target all come from the pool
+    assert_eq!(pool.allocated, 3);
+    assert!(pool.free.is_empty());
+    assert_eq!(world.entities().len(), 3);
+
+    let (parent, target) = world.query::<(&Parent, &Target)>().single(world);
+
+    assert!(free.contains(&parent.get()));
+    assert!(free.contains(&target.0));
+}
@@ -0,0 +1,171 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Resource, Reflect, Default, Debug, PartialEq)]
+#[reflect(Resource)]
+struct Score(u32);
+
+#[derive(Resource, Reflect, Default, Debug, PartialEq)]
+#[reflect(Resource)]
+struct Gem(u32);
+
+struct ScorePipeline(String);
+
+impl Pipeline for ScorePipeline {
+    type Backend = DefaultDebugBackend;
+    type Format = DefaultDebugFormat;
+
+    type Key<'k> = &'k str;
+
+    fn key(&self) -> Self::Key<'_> {
+        &self.0
+    }
+
+    fn capture(builder: SnapshotBuilder) -> Snapshot {
+        builder.extract_resource::<Score>().build()
+    }
+}
+
+struct GemPipeline(String);
+
+impl Pipeline for GemPipeline {
+    type Backend = DefaultDebugBackend;
+    type Format = DefaultDebugFormat;
+
+    type Key<'k> = &'k str;
+
+    fn key(&self) -> Self::Key<'_> {
+        &self.0
+    }
+
+    fn capture(builder: SnapshotBuilder) -> Snapshot {
+        builder
+            .extract_resource::<Score>()
+            .extract_resource::<Gem>()
+            .build()
+    }
+}
+
+fn init_app() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .init_pipeline::<ScorePipeline>()
+        .register_type::<Score>()
+        .insert_resource(Score(10));
+
+    app
+}
+
+#[test]
+fn test_archive() {
+    let dir = std::env::temp_dir().join("bevy_save_archive");
+    let key = |name: &str| dir.join(name).to_string_lossy().into_owned();
+    let archive = dir.join("shared.bsa");
+
+    let mut app = init_app();
+    let world = &mut app.world;
+
+    world.save(ScorePipeline(key("slot"))).unwrap();
+    world.save_split(ScorePipeline(key("split"))).unwrap();
+
+    let meta = world
+        .export_save(ScorePipeline(key("slot")), &archive)
+        .unwrap();
+
+    assert!(!meta.split);
+
+    world.resource_mut::<Score>().0 = 0;
+
+    world
+        .import_save(ScorePipeline(key("imported")), &archive)
+        .unwrap();
+    world.load(ScorePipeline(key("imported"))).unwrap();
+
+    assert_eq!(world.resource::<Score>(), &Score(10));
+
+    let meta = world
+        .export_save(ScorePipeline(key("split")), &archive)
+        .unwrap();
+
+    assert!(meta.split);
+
+    world.resource_mut::<Score>().0 = 0;
+
+    world
+        .import_save(ScorePipeline(key("imported_split")), &archive)
+        .unwrap();
+    world
+        .load_split(ScorePipeline(key("imported_split")))
+        .unwrap();
+
+    assert_eq!(world.resource::<Score>(), &Score(10));
+
+    std::fs::write(&archive, b"garbage").unwrap();
+
+    assert!(world
+        .import_save(ScorePipeline(key("invalid")), &archive)
+        .is_err());
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+#[test]
+fn test_archive_checks() {
+    let dir = std::env::temp_dir().join("bevy_save_archive_checks");
+    let key = |name: &str| dir.join(name).to_string_lossy().into_owned();
+    let archive = dir.join("shared.bsa");
+
+    let mut app = init_app();
+
+    app.init_pipeline::<GemPipeline>()
+        .set_game_version("2.0.0", SaveCompatibility::default())
+        .register_mod_type::<Gem>("gems")
+        .insert_resource(Gem(3));
+    app.world.save(GemPipeline(key("slot"))).unwrap();
+
+    // Saves holding data of missing mods can be exported
+    let mut app = init_app();
+
+    app.init_pipeline::<GemPipeline>()
+        .set_game_version("2.0.0", SaveCompatibility::default())
+        .register_mod_type::<Score>("base");
+    app.world
+        .export_save(GemPipeline(key("slot")), &archive)
+        .unwrap();
+
+    // Saves from newer versions are rejected
+    let mut app = init_app();
+
+    app.init_pipeline::<GemPipeline>()
+        .set_game_version("1.0.0", SaveCompatibility::default())
+        .register_mod_type::<Gem>("gems");
+
+    assert!(app
+        .world
+        .import_save(GemPipeline(key("imported")), &archive)
+        .is_err());
+
+    // The sanitize policy is applied and the stamps are kept
+    let mut app = init_app();
+
+    app.init_pipeline::<GemPipeline>()
+        .set_game_version("2.0.0", SaveCompatibility::default())
+        .register_mod_type::<Gem>("gems")
+        .insert_resource(SanitizePolicy::new().allow::<Gem>())
+        .insert_resource(Gem(0));
+    app.world
+        .import_save(GemPipeline(key("imported")), &archive)
+        .unwrap();
+
+    assert!(app.world.contains_resource::<SanitizeReport>());
+
+    app.world.resource_mut::<Score>().0 = 0;
+    app.world.remove_resource::<SanitizePolicy>();
+    app.world.load(GemPipeline(key("imported"))).unwrap();
+
+    assert_eq!(app.world.resource::<Gem>(), &Gem(3));
+    assert_eq!(app.world.resource::<Score>(), &Score(0));
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,203 @@
+use std::{
+    collections::HashMap,
+    marker::PhantomData,
+    sync::{
+        Arc,
+        Mutex,
+        MutexGuard,
+        Weak,
+    },
+    time::Duration,
+};
+
+use bevy::{
+    app::AppExit,
+    prelude::*,
+};
+use bevy_save::{
+    prelude::*,
+    testing::FlakyBackend,
+    Error,
+};
+use serde::{
+    de::DeserializeSeed,
+    Serialize,
+};
+
+#[derive(Resource, Default)]
+struct MemoryBackend {
+    files: Mutex<HashMap<String, Vec<u8>>>,
+    writes: Mutex<usize>,
+}
+
+impl MemoryBackend {
+    fn files(&self) -> MutexGuard<'_, HashMap<String, Vec<u8>>> {
+        self.files.lock().unwrap()
+    }
+
+    fn writes(&self) -> usize {
+        *self.writes.lock().unwrap()
+    }
+}
+
+impl Backend<String> for MemoryBackend {
+    fn save<F: Format, T: Serialize>(&self, key: String, value: &T) -> Result<(), Error> {
+        let mut data = Vec::new();
+        F::serialize(&mut data, value)?;
+
+        self.files()
+            .insert(format!("{key}{}", F::extension()), data);
+        *self.writes.lock().unwrap() += 1;
+
+        Ok(())
+    }
+
+    fn load<F: Format, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        &self,
+        key: String,
+        seed: S,
+    ) -> Result<T, Error> {
+        let files = self.files();
+        let data = files
+            .get(&format!("{key}{}", F::extension()))
+            .ok_or(Error::custom("missing"))?;
+
+        F::deserialize(data.as_slice(), seed)
+    }
+}
+
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct Score(u32);
+
+struct Autosave;
+
+impl Pipeline for Autosave {
+    type Backend = Batched<MemoryBackend>;
+    type Format = JSONFormat;
+
+    type Key<'a> = &'a str;
+
+    fn key(&self) -> Self::Key<'_> {
+        "autosave"
+    }
+
+    fn capture(builder: SnapshotBuilder) -> Snapshot {
+        builder.extract_resource::<Score>().build()
+    }
+}
+
+#[test]
+fn test_batched_writes() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Score>()
+        .init_resource::<Score>()
+        .init_pipeline::<Autosave>()
+        .batch_writes::<MemoryBackend>(Duration::from_secs(3600));
+
+    for score in 1..=3 {
+        app.world.resource_mut::<Score>().0 = score;
+        app.world.save(Autosave).unwrap();
+    }
+
+    let batched = app.world.resource::<Batched<MemoryBackend>>();
+
+    assert_eq!(batched.window(), Duration::from_secs(3600));
+    assert_eq!(batched.pending(), 1);
+    assert_eq!(batched.inner().writes(), 0);
+
+    // Loads see the pending write
+    app.world.resource_mut::<Score>().0 = 0;
+    app.world.load(Autosave).unwrap();
+    assert_eq!(app.world.resource::<Score>().0, 3);
+
+    app.world.send_event(AppExit);
+    app.update();
+
+    let batched = app.world.resource::<Batched<MemoryBackend>>();
+
+    assert_eq!(batched.pending(), 0);
+    assert_eq!(batched.inner().writes(), 1);
+
+    // The latest payload was written as-is
+    app.world.resource_mut::<Score>().0 = 0;
+    app.world.load(Autosave).unwrap();
+    assert_eq!(app.world.resource::<Score>().0, 3);
+}
+
+fn saved(backend: &MemoryBackend) -> u32 {
+    backend
+        .load::<JSONFormat, _, _>("score".to_owned(), PhantomData)
+        .unwrap()
+}
+
+#[test]
+fn test_batched_failed_writes() {
+    let batched = Batched::new(
+        FlakyBackend::new(MemoryBackend::default(), 2),
+        Duration::from_secs(3600),
+    );
+
+    batched
+        .save::<JSONFormat, _>("score".to_owned(), &0u32)
+        .unwrap();
+    batched.flush().unwrap();
+
+    batched
+        .save::<JSONFormat, _>("score".to_owned(), &1u32)
+        .unwrap();
+
+    // The failed write is kept for the next flush
+    assert!(batched.flush().is_err());
+    assert_eq!(batched.pending(), 1);
+    assert_eq!(saved(batched.inner().inner()), 0);
+
+    batched.flush().unwrap();
+
+    assert_eq!(batched.pending(), 0);
+    assert_eq!(batched.inner().failures(), 1);
+    assert_eq!(saved(batched.inner().inner()), 1);
+}
+
+type Flaky = Batched<FlakyBackend<MemoryBackend>>;
+
+#[test]
+fn test_batched_failed_writes_keep_newer() {
+    let batched: Arc<Mutex<Option<Weak<Flaky>>>> = Arc::default();
+    let resave = batched.clone();
+
+    // Save a newer payload while the failing write is in flight
+    let backend = FlakyBackend::new(MemoryBackend::default(), 2).with_error(move || {
+        let batched = resave.lock().unwrap().take().and_then(|b| b.upgrade());
+
+        if let Some(batched) = batched {
+            batched
+                .save::<JSONFormat, _>("score".to_owned(), &2u32)
+                .unwrap();
+        }
+
+        Error::custom("flaky")
+    });
+
+    let flaky = Arc::new(Batched::new(backend, Duration::from_secs(3600)));
+    flaky
+        .save::<JSONFormat, _>("score".to_owned(), &0u32)
+        .unwrap();
+    flaky.flush().unwrap();
+
+    *batched.lock().unwrap() = Some(Arc::downgrade(&flaky));
+
+    flaky
+        .save::<JSONFormat, _>("score".to_owned(), &1u32)
+        .unwrap();
+
+    assert!(flaky.flush().is_err());
+    assert_eq!(flaky.pending(), 1);
+
+    flaky.flush().unwrap();
+
+    assert_eq!(flaky.pending(), 0);
+    assert_eq!(saved(flaky.inner().inner()), 2);
+}
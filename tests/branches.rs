@@ -0,0 +1,188 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+use serde::de::DeserializeSeed;
+
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct Counter(u32);
+
+fn capture(value: u32) -> Snapshot {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Counter>()
+        .insert_resource(Counter(value));
+
+    Snapshot::builder(&app.world)
+        .extract_resource::<Counter>()
+        .build()
+}
+
+fn value(snapshot: Option<&Snapshot>) -> u32 {
+    snapshot.unwrap().get_resource::<Counter>().unwrap().0
+}
+
+#[test]
+fn test_rollback_branches() {
+    let mut rollbacks = Rollbacks::default();
+
+    rollbacks.checkpoint(capture(0));
+    rollbacks.checkpoint(capture(1));
+    rollbacks.checkpoint(capture(2));
+
+    assert_eq!(rollbacks.branch(), Some(DEFAULT_BRANCH));
+    assert_eq!(value(rollbacks.rollback(2)), 0);
+
+    // Checkpoint after rollback creates a new branch
+    rollbacks.checkpoint(capture(10));
+
+    assert_eq!(rollbacks.branches().count(), 2);
+    assert_ne!(rollbacks.branch(), Some(DEFAULT_BRANCH));
+
+    assert_eq!(value(rollbacks.rollback(1)), 0);
+    assert_eq!(value(rollbacks.rollback(-5)), 10);
+
+    // The original future is preserved
+    assert_eq!(value(rollbacks.switch_branch(DEFAULT_BRANCH)), 2);
+    assert_eq!(value(rollbacks.rollback(1)), 1);
+
+    rollbacks.checkpoint_branch("experiment", capture(20));
+
+    assert_eq!(rollbacks.branch(), Some("experiment"));
+    assert_eq!(value(rollbacks.rollback(1)), 1);
+    assert_eq!(value(rollbacks.rollback(1)), 0);
+    assert!(rollbacks.switch_branch("missing").is_none());
+}
+
+#[test]
+fn test_checkpoint_persistence() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins));
+
+    let mut rollbacks = app.world.resource_mut::<Rollbacks>();
+
+    for i in 0..5 {
+        rollbacks.checkpoint(capture(i));
+    }
+
+    rollbacks.rollback(1);
+
+    let persisted = |persistence| {
+        Snapshot::builder(&app.world)
+            .checkpoint_persistence(persistence)
+            .extract_rollbacks()
+            .build()
+    };
+
+    assert!(persisted(CheckpointPersistence::Never)
+        .rollbacks()
+        .is_none());
+    assert_eq!(
+        persisted(CheckpointPersistence::All)
+            .rollbacks()
+            .map(|r| r.len()),
+        Some(5)
+    );
+
+    let snapshot = persisted(CheckpointPersistence::LastN(2));
+    let mut rollbacks = snapshot.rollbacks().unwrap().clone_value();
+
+    assert_eq!(rollbacks.len(), 2);
+
+    // The active checkpoint is kept
+    assert_eq!(value(rollbacks.rollback(0)), 3);
+    assert_eq!(value(rollbacks.rollback(-1)), 4);
+    assert_eq!(value(rollbacks.rollback(5)), 3);
+}
+
+#[test]
+fn test_rollbacks_active_out_of_bounds() {
+    let registry = AppTypeRegistry::default();
+    let registry = registry.read();
+
+    let deserialize = |json: &str| {
+        let mut de = serde_json::Deserializer::from_str(json);
+        RollbacksDeserializer::new(&registry).deserialize(&mut de)
+    };
+
+    assert!(deserialize(r#"{"checkpoints":[],"active":null}"#).is_ok());
+    assert!(deserialize(r#"{"checkpoints":[],"active":3}"#).is_err());
+    assert!(deserialize("[[],0]").is_err());
+}
+
+#[test]
+fn test_branch_names_unique() {
+    let mut rollbacks = Rollbacks::default();
+
+    rollbacks.checkpoint(capture(0));
+    rollbacks.checkpoint(capture(1));
+    rollbacks.checkpoint_branch("branch-1", capture(2));
+
+    rollbacks.rollback(1);
+
+    // The automatic branch does not take over the user's branch
+    rollbacks.checkpoint(capture(3));
+
+    assert_eq!(rollbacks.branches().count(), 3);
+    assert_eq!(value(rollbacks.switch_branch("branch-1")), 2);
+}
+
+#[test]
+fn test_rollbacks_prune() {
+    let mut rollbacks = Rollbacks::default();
+
+    for i in 0..5 {
+        rollbacks.checkpoint(capture(i));
+    }
+
+    rollbacks.rollback(3);
+    rollbacks.checkpoint_branch("experiment", capture(10));
+    rollbacks.checkpoint(capture(11));
+
+    assert_eq!(rollbacks.len(), 7);
+
+    rollbacks.prune(2);
+
+    // The newest two checkpoints of each branch are kept
+    assert_eq!(rollbacks.len(), 4);
+    assert_eq!(rollbacks.branch(), Some("experiment"));
+    assert_eq!(value(rollbacks.rollback(0)), 11);
+    assert_eq!(value(rollbacks.rollback(5)), 10);
+
+    assert_eq!(value(rollbacks.switch_branch(DEFAULT_BRANCH)), 4);
+    assert_eq!(value(rollbacks.rollback(5)), 3);
+
+    rollbacks.prune(0);
+
+    assert!(rollbacks.is_empty());
+    assert_eq!(rollbacks.branch(), None);
+    assert!(rollbacks.rollback(1).is_none());
+}
+
+#[test]
+fn test_rollbacks_delete_branch() {
+    let mut rollbacks = Rollbacks::default();
+
+    for i in 0..3 {
+        rollbacks.checkpoint(capture(i));
+    }
+
+    rollbacks.rollback(1);
+    rollbacks.checkpoint_branch("experiment", capture(10));
+    rollbacks.checkpoint(capture(11));
+
+    // The current branch cannot be deleted
+    assert!(!rollbacks.delete_branch("experiment"));
+    assert!(!rollbacks.delete_branch("missing"));
+
+    rollbacks.switch_branch(DEFAULT_BRANCH);
+
+    assert!(rollbacks.delete_branch("experiment"));
+
+    // Only the checkpoints unique to the deleted branch are removed
+    assert_eq!(rollbacks.len(), 3);
+    assert_eq!(rollbacks.branches().collect::<Vec<_>>(), [DEFAULT_BRANCH]);
+    assert_eq!(value(rollbacks.rollback(0)), 2);
+    assert_eq!(value(rollbacks.rollback(5)), 0);
+}
@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Clone, Default, Debug, PartialEq)]
+#[reflect(Component, CloneValue)]
+struct Position(f32);
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Health(u32);
+
+#[derive(Resource, Reflect, Clone, Default, Debug, PartialEq)]
+#[reflect(Resource, CloneValue)]
+struct Score(u32);
+
+fn setup() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Position>()
+        .register_type::<Health>()
+        .register_type::<Score>()
+        .insert_resource(Score(7));
+
+    app.world.spawn((Position(1.0), Health(10)));
+    app.world.spawn((Position(2.0), Health(20)));
+
+    app
+}
+
+fn assert_cloned(snapshot: &Snapshot) {
+    assert_eq!(snapshot.entities.len(), 2);
+
+    for entity in &snapshot.entities {
+        for component in &entity.components {
+            // Only types registered with `CloneValue` are stored as their concrete type
+            if component.represents::<Position>() {
+                assert!(component.as_any().is::<Position>());
+            } else {
+                assert!(component.represents::<Health>());
+                assert!(!component.as_any().is::<Health>());
+            }
+        }
+    }
+
+    let score = snapshot
+        .resources
+        .iter()
+        .find(|r| r.represents::<Score>())
+        .unwrap();
+
+    assert!(score.as_any().is::<Score>());
+}
+
+#[test]
+fn test_clone_extract() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    let snapshot = Snapshot::builder(world)
+        .extract_all_entities()
+        .extract_resource::<Score>()
+        .build();
+
+    assert_cloned(&snapshot);
+
+    let mut plan = Snapshot::builder(world).plan();
+    let snapshot = Snapshot::builder(world)
+        .extract_with_plan(&mut plan)
+        .build();
+
+    assert_cloned(&snapshot);
+}
+
+#[test]
+fn test_clone_reflect_values() {
+    let app = setup();
+    let registry = app.world.resource::<AppTypeRegistry>().read();
+
+    let dynamic = Position(3.0).clone_value();
+    let values: [&dyn Reflect; 4] = [&Position(1.0), &Position(2.0), &Health(5), &*dynamic];
+
+    let cloned = clone_reflect_values(values, &registry);
+
+    assert_eq!(cloned.len(), 4);
+
+    // Values of the registered type are cloned directly
+    assert!(cloned[0].as_any().is::<Position>());
+    assert!(cloned[1].as_any().is::<Position>());
+    assert_eq!(Position::from_reflect(&*cloned[1]), Some(Position(2.0)));
+
+    // Other values fall back to a dynamic clone
+    assert!(!cloned[2].as_any().is::<Health>());
+    assert_eq!(Health::from_reflect(&*cloned[2]), Some(Health(5)));
+
+    assert!(!cloned[3].as_any().is::<Position>());
+    assert_eq!(Position::from_reflect(&*cloned[3]), Some(Position(3.0)));
+}
@@ -0,0 +1,60 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Health(u32);
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Mana(u32);
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Cooldown(f32);
+
+#[test]
+fn test_extract_components() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Health>()
+        .register_type::<Mana>()
+        .register_type::<Cooldown>();
+
+    let world = &mut app.world;
+
+    let hero = world.spawn((Health(10), Mana(5), Cooldown(0.5))).id();
+    let slime = world.spawn((Health(3), Cooldown(1.0))).id();
+    world.spawn(Cooldown(2.0));
+
+    let snapshot = Snapshot::builder(world)
+        .extract_components::<(Health, Mana)>()
+        .build();
+
+    assert_eq!(snapshot.entities.len(), 2);
+    assert_eq!(snapshot.entities_with::<Health>().count(), 2);
+    assert_eq!(snapshot.entities_with::<Mana>().collect::<Vec<_>>(), [hero]);
+    assert_eq!(snapshot.entities_with::<Cooldown>().count(), 0);
+
+    // Later calls add to the components extracted earlier
+    let snapshot = Snapshot::builder(world)
+        .extract_components::<(Health,)>()
+        .extract_components::<(Cooldown,)>()
+        .build();
+
+    assert_eq!(snapshot.entities.len(), 3);
+    assert_eq!(snapshot.entities_with::<Health>().count(), 2);
+    assert_eq!(snapshot.entities_with::<Cooldown>().count(), 3);
+
+    // The filter still applies
+    let snapshot = Snapshot::builder(world)
+        .deny::<Mana>()
+        .extract_components::<(Health, Mana)>()
+        .build();
+
+    assert_eq!(snapshot.entities_with::<Mana>().count(), 0);
+    assert_eq!(snapshot.entities_with::<Health>().collect::<Vec<_>>(), [
+        hero, slime
+    ]);
+}
@@ -0,0 +1,207 @@
+use bevy::{
+    ecs::{
+        entity::{
+            EntityMapper,
+            MapEntities,
+        },
+        reflect::ReflectMapEntities,
+    },
+    prelude::*,
+};
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect)]
+#[reflect(Component, MapEntities)]
+struct Target(Entity);
+
+impl FromWorld for Target {
+    fn from_world(_: &mut World) -> Self {
+        Self(Entity::PLACEHOLDER)
+    }
+}
+
+impl MapEntities for Target {
+    fn map_entities<M: EntityMapper>(&mut self, mapper: &mut M) {
+        self.0 = mapper.map_entity(self.0);
+    }
+}
+
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct Score(u32);
+
+fn setup() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Target>()
+        .register_type::<Score>();
+
+    app
+}
+
+/// Captures two turrets targeting an enemy that is not included in the snapshot.
+fn snapshot() -> Snapshot {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    world.insert_resource(Score(5));
+
+    let enemy = world.spawn_empty().id();
+    world.spawn(Target(enemy));
+    world.spawn(Target(enemy));
+
+    Snapshot::builder(world)
+        .extract_entities_matching(|e| e.contains::<Target>())
+        .extract_resource::<Score>()
+        .build()
+}
+
+fn targets(world: &mut World) -> Vec<Entity> {
+    world.query::<&Target>().iter(world).map(|t| t.0).collect()
+}
+
+#[test]
+fn test_dangling_spawn_empty() {
+    let snapshot = snapshot();
+
+    let mut app = setup();
+    let world = &mut app.world;
+
+    snapshot
+        .applier(world)
+        .dangling_entity(DanglingEntityPolicy::SpawnEmpty)
+        .apply()
+        .unwrap();
+
+    let targets = targets(world);
+
+    // Both turrets share the same new entity
+    assert_eq!(targets.len(), 2);
+    assert_eq!(targets[0], targets[1]);
+    assert!(world.get_entity(targets[0]).is_some());
+    assert_eq!(world.entity(targets[0]).archetype().components().count(), 0);
+}
+
+#[test]
+fn test_dangling_map_to_placeholder() {
+    let snapshot = snapshot();
+
+    let mut app = setup();
+    let world = &mut app.world;
+
+    let placeholder = world.spawn_empty().id();
+
+    snapshot
+        .applier(world)
+        .dangling_entity(DanglingEntityPolicy::MapToPlaceholder(placeholder))
+        .apply()
+        .unwrap();
+
+    assert_eq!(targets(world), [placeholder, placeholder]);
+}
+
+#[test]
+fn test_dangling_null() {
+    let snapshot = snapshot();
+
+    let mut app = setup();
+    let world = &mut app.world;
+
+    snapshot
+        .applier(world)
+        .dangling_entity(DanglingEntityPolicy::Null)
+        .apply()
+        .unwrap();
+
+    assert_eq!(targets(world), [Entity::PLACEHOLDER, Entity::PLACEHOLDER]);
+}
+
+#[test]
+fn test_dangling_error() {
+    let snapshot = snapshot();
+
+    let mut app = setup();
+    let world = &mut app.world;
+
+    let entities = world.entities().len();
+
+    let err = snapshot
+        .applier(world)
+        .dangling_entity(DanglingEntityPolicy::Error)
+        .apply()
+        .unwrap_err();
+
+    assert!(matches!(err, bevy_save::Error::DanglingEntity { .. }));
+
+    // Nothing was applied
+    assert_eq!(world.entities().len(), entities);
+    assert!(world.get_resource::<Score>().is_none());
+}
+
+#[test]
+fn test_dangling_null_reference() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    world.spawn(Target(Entity::PLACEHOLDER));
+
+    let snapshot = Snapshot::builder(world).extract_all_entities().build();
+
+    for policy in [
+        DanglingEntityPolicy::SpawnEmpty,
+        DanglingEntityPolicy::Error,
+    ] {
+        let mut app = setup();
+        let world = &mut app.world;
+
+        let entities = world.entities().len();
+
+        snapshot
+            .applier(world)
+            .dangling_entity(policy)
+            .apply()
+            .unwrap();
+
+        // Only the turret was spawned
+        assert_eq!(world.entities().len(), entities + 1);
+        assert_eq!(targets(world), [Entity::PLACEHOLDER]);
+    }
+}
+
+#[test]
+fn test_dangling_tombstoned() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    world.insert_resource(Score(5));
+
+    let enemy = world.spawn(SaveId(1)).id();
+    world.spawn(Target(enemy));
+
+    let mut tombstones = Tombstones::default();
+    tombstones.insert(SaveId(1));
+
+    let mut snapshot = Snapshot::builder(world)
+        .extract_all_entities()
+        .extract_resource::<Score>()
+        .build();
+
+    snapshot.insert_resource(tombstones);
+
+    let mut app = setup();
+    let world = &mut app.world;
+
+    let entities = world.entities().len();
+
+    let err = snapshot
+        .applier(world)
+        .dangling_entity(DanglingEntityPolicy::Error)
+        .apply()
+        .unwrap_err();
+
+    // The tombstoned enemy is not applied, so the reference to it is dangling
+    assert!(matches!(err, bevy_save::Error::DanglingEntity { .. }));
+    assert_eq!(world.entities().len(), entities);
+    assert!(world.get_resource::<Score>().is_none());
+}
@@ -0,0 +1,126 @@
+#![cfg(feature = "dedup")]
+
+use std::marker::PhantomData;
+
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct Terrain(Vec<u32>);
+
+struct Slot(&'static str);
+
+impl Pipeline for Slot {
+    type Backend = Deduplicated<DirBackend>;
+    type Format = DefaultFormat;
+
+    type Key<'k> = &'k str;
+
+    fn key(&self) -> Self::Key<'_> {
+        self.0
+    }
+
+    fn capture(builder: SnapshotBuilder) -> Snapshot {
+        builder.extract_resource::<Terrain>().build()
+    }
+}
+
+fn chunk_files(root: &std::path::Path) -> usize {
+    std::fs::read_dir(root.join(Deduplicated::<DirBackend>::DEFAULT_CHUNK_PREFIX))
+        .unwrap()
+        .filter(|e| e.as_ref().unwrap().path().extension() == Some("chunk".as_ref()))
+        .count()
+}
+
+#[test]
+fn test_dedup() {
+    let root = std::env::temp_dir().join("bevy_save_dedup");
+    let _ = std::fs::remove_dir_all(&root);
+
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .init_pipeline::<Slot>()
+        .insert_resource(Deduplicated::new(
+            DirBackend::new(&root),
+            Chunking::default(),
+        ))
+        .register_type::<Terrain>();
+
+    let world = &mut app.world;
+
+    let terrain = (0..100_000u32)
+        .map(|i| i.wrapping_mul(2_654_435_761))
+        .collect::<Vec<_>>();
+
+    world.insert_resource(Terrain(terrain.clone()));
+    world.save(Slot("autosave/world")).unwrap();
+
+    let chunks = chunk_files(&root);
+    assert!(chunks > 1);
+
+    // A nearly identical save only stores the changed chunks
+    world.resource_mut::<Terrain>().0.insert(500, 7);
+    world.save(Slot("quicksave/world")).unwrap();
+
+    let added = chunk_files(&root) - chunks;
+    assert!(added > 0 && added < chunks / 2, "{added} of {chunks}");
+
+    // Identical saves are free
+    world.save(Slot("manual/world")).unwrap();
+    assert_eq!(chunk_files(&root) - chunks, added);
+
+    let backend = world.resource::<Deduplicated<DirBackend>>();
+    let manifest = backend
+        .manifest::<Stamped<DefaultFormat>>("autosave/world")
+        .unwrap();
+    assert_eq!(manifest.chunks.len(), chunks);
+
+    world.remove_resource::<Terrain>();
+    world.load(Slot("autosave/world")).unwrap();
+    assert_eq!(world.resource::<Terrain>().0, terrain);
+
+    world.remove_resource::<Terrain>();
+    world.load(Slot("quicksave/world")).unwrap();
+    assert_eq!(world.resource::<Terrain>().0[500], 7);
+
+    // Corrupted chunks are detected
+    let backend = world.resource::<Deduplicated<DirBackend>>();
+    let chunk = root
+        .join(backend.chunk_key(&manifest.chunks[0]))
+        .with_extension("chunk");
+    std::fs::write(chunk, b"corrupted").unwrap();
+
+    assert!(world.load(Slot("autosave/world")).is_err());
+
+    std::fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn test_fixed_chunks() {
+    let root = std::env::temp_dir().join("bevy_save_dedup_fixed");
+    let _ = std::fs::remove_dir_all(&root);
+
+    let backend =
+        Deduplicated::new(DirBackend::new(&root), Chunking::Fixed(16)).chunk_prefix("blobs");
+    let value = vec![1u8; 256];
+
+    backend.save::<JSONFormat, _>("a/save", &value).unwrap();
+
+    let manifest = backend.manifest::<JSONFormat>("a/save").unwrap();
+
+    // Repeated data is stored once
+    let unique = manifest
+        .chunks
+        .iter()
+        .collect::<std::collections::HashSet<_>>();
+    assert!(unique.len() < manifest.chunks.len());
+
+    let loaded: Vec<u8> = backend
+        .load::<JSONFormat, _, _>("a/save", PhantomData)
+        .unwrap();
+    assert_eq!(loaded, value);
+
+    std::fs::remove_dir_all(root).unwrap();
+}
@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Health(u32);
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Mana(u32);
+
+fn health(snapshot: &Snapshot, entity: Entity) -> Option<u32> {
+    snapshot
+        .entities
+        .iter()
+        .find(|e| e.entity == entity)?
+        .components
+        .iter()
+        .find_map(|c| Health::from_reflect(&**c))
+        .map(|h| h.0)
+}
+
+#[test]
+fn test_delta_checkpoints() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Health>()
+        .register_type::<Mana>()
+        .insert_resource(
+            DeltaCheckpoints::new(3)
+                .with_filter(SceneFilter::deny_all().allow::<Health>().allow::<Mana>()),
+        );
+
+    let world = &mut app.world;
+
+    let a = world.spawn(Health(1)).id();
+    let b = world.spawn((Health(2), Mana(2))).id();
+
+    world.checkpoint_delta();
+
+    world.get_mut::<Health>(a).unwrap().0 = 10;
+    let c = world.spawn(Health(3)).id();
+
+    world.checkpoint_delta();
+
+    world.despawn(b);
+    world.entity_mut(a).insert(Mana(1));
+
+    world.checkpoint_delta();
+
+    world.checkpoint_delta();
+
+    let checkpoints = world.resource::<DeltaCheckpoints>();
+
+    assert_eq!(checkpoints.len(), 4);
+    assert!(checkpoints.is_keyframe(0));
+    assert!(!checkpoints.is_keyframe(1));
+    assert!(!checkpoints.is_keyframe(2));
+    assert!(checkpoints.is_keyframe(3));
+
+    let first = checkpoints.snapshot_at(0).unwrap();
+
+    assert_eq!(first.entities.len(), 2);
+    assert_eq!(health(&first, a), Some(1));
+    assert_eq!(health(&first, b), Some(2));
+
+    let second = checkpoints.snapshot_at(1).unwrap();
+
+    assert_eq!(second.entities.len(), 3);
+    assert_eq!(health(&second, a), Some(10));
+    assert_eq!(health(&second, c), Some(3));
+
+    let third = checkpoints.snapshot_at(2).unwrap();
+
+    assert_eq!(third.entities.len(), 2);
+    assert_eq!(health(&third, b), None);
+
+    let entity = third.entities.iter().find(|e| e.entity == a).unwrap();
+
+    assert_eq!(entity.components.len(), 2);
+    assert_eq!(health(&third, a), Some(10));
+
+    let keyframe = checkpoints.snapshot_at(3).unwrap();
+
+    assert_eq!(keyframe.entities.len(), third.entities.len());
+}
@@ -0,0 +1,114 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Enemy;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Boss;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Pickup;
+
+fn app() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Enemy>()
+        .register_type::<Boss>()
+        .register_type::<Pickup>();
+
+    app.world.spawn(Enemy);
+    app.world.spawn((Enemy, Boss));
+    app.world.spawn(Pickup);
+
+    app
+}
+
+fn count<C: Component>(world: &mut World) -> usize {
+    world.query::<&C>().iter(world).count()
+}
+
+#[test]
+fn test_despawn_filter() {
+    let mut app = app();
+    let world = &mut app.world;
+
+    let snapshot = Snapshot::builder(world).build();
+
+    // Chosen from config at runtime
+    let filter = EntityFilter::parse(
+        "With(Enemy) & Without(Boss)",
+        &world.resource::<AppTypeRegistry>().read(),
+    )
+    .unwrap();
+
+    snapshot
+        .applier(world)
+        .despawn_filter(filter)
+        .apply()
+        .unwrap();
+
+    assert_eq!(count::<Enemy>(world), 1);
+    assert_eq!(count::<Boss>(world), 1);
+    assert_eq!(count::<Pickup>(world), 1);
+}
+
+#[test]
+fn test_despawn_with_components() {
+    let mut app = app();
+    let world = &mut app.world;
+
+    let ids = [
+        world.init_component::<Enemy>(),
+        world.init_component::<Boss>(),
+    ];
+
+    let snapshot = Snapshot::builder(world).build();
+
+    snapshot
+        .applier(world)
+        .despawn_with_components(ids)
+        .apply()
+        .unwrap();
+
+    assert_eq!(count::<Enemy>(world), 1);
+    assert_eq!(count::<Boss>(world), 0);
+}
+
+#[test]
+fn test_despawn_matching_combined() {
+    let mut combined = app();
+    let world = &mut combined.world;
+
+    let snapshot = Snapshot::builder(world).build();
+
+    snapshot
+        .applier(world)
+        .despawn::<With<Pickup>>()
+        .despawn_matching(|e| e.contains::<Boss>())
+        .apply()
+        .unwrap();
+
+    assert_eq!(count::<Enemy>(world), 1);
+    assert_eq!(count::<Boss>(world), 0);
+    assert_eq!(count::<Pickup>(world), 0);
+
+    // Sandboxed snapshots never despawn
+    let mut sandboxed = app();
+    let world = &mut sandboxed.world;
+
+    let snapshot = Snapshot::builder(world).build();
+
+    snapshot
+        .applier(world)
+        .sandbox(FromSnapshot(1))
+        .despawn_matching(|_| true)
+        .apply()
+        .unwrap();
+
+    assert_eq!(world.entities().len(), 3);
+}
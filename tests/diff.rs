@@ -0,0 +1,107 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Velocity(f32);
+
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct Frame(u32);
+
+struct DiffPipeline;
+
+impl Pipeline for DiffPipeline {
+    type Backend = DefaultBackend;
+    type Format = DefaultFormat;
+
+    type Key<'a> = &'a str;
+
+    fn key(&self) -> Self::Key<'_> {
+        "diff"
+    }
+
+    fn capture(builder: SnapshotBuilder) -> Snapshot {
+        builder
+            .extract_all_entities()
+            .extract_all_resources()
+            .build()
+    }
+}
+
+#[test]
+fn test_checkpoint_diff() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .init_pipeline::<DiffPipeline>()
+        .register_type::<Position>()
+        .register_type::<Velocity>()
+        .register_type::<Frame>()
+        .allow_rollback::<Position>()
+        .allow_rollback::<Velocity>()
+        .allow_rollback::<Frame>()
+        .insert_resource(Frame(0));
+
+    let world = &mut app.world;
+
+    let player = world
+        .spawn((Position { x: 0.0, y: 0.0 }, Velocity(1.0)))
+        .id();
+    let enemy = world.spawn(Position { x: 5.0, y: 5.0 }).id();
+
+    world.checkpoint::<DiffPipeline>();
+    world.checkpoint::<DiffPipeline>();
+
+    world.resource_mut::<Frame>().0 = 1;
+    world.get_mut::<Position>(player).unwrap().x = 1.0;
+    world.despawn(enemy);
+    let bullet = world.spawn(Velocity(10.0)).id();
+
+    world.checkpoint::<DiffPipeline>();
+
+    let rollbacks = world.resource::<Rollbacks>();
+    let registry = world.resource::<AppTypeRegistry>();
+
+    // Identical checkpoints have no differences
+    assert!(rollbacks.diff(0, 1, registry).unwrap().is_empty());
+    assert!(rollbacks.diff(1, 3, registry).is_none());
+
+    let diff = rollbacks.diff(1, 2, registry).unwrap();
+
+    let frame = diff.resource::<Frame>().unwrap();
+    assert_eq!(frame.change(), ChangeKind::Changed);
+    assert_eq!(frame.before.as_deref(), Some("(0)"));
+    assert_eq!(frame.after.as_deref(), Some("(1)"));
+
+    // Only the changed component of the player is reported
+    let position = diff.component::<Position>(player).unwrap();
+    assert_eq!(position.before.as_deref(), Some("(x:0.0,y:0.0)"));
+    assert_eq!(position.after.as_deref(), Some("(x:1.0,y:0.0)"));
+    assert!(diff.component::<Velocity>(player).is_none());
+
+    let changes = diff
+        .entities
+        .iter()
+        .map(|e| (e.entity, e.change))
+        .collect::<Vec<_>>();
+
+    assert_eq!(changes, [
+        (player, ChangeKind::Changed),
+        (enemy, ChangeKind::Removed),
+        (bullet, ChangeKind::Added),
+    ]);
+
+    let text = diff.to_string();
+
+    assert!(text.contains("~ diff::Frame: (0) -> (1)"));
+    assert!(text.contains("- diff::Position: (x:5.0,y:5.0)"));
+    assert!(text.contains("+ diff::Velocity: (10.0)"));
+}
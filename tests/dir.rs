@@ -0,0 +1,92 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Health(u32);
+
+struct DirPipeline;
+
+impl Pipeline for DirPipeline {
+    type Backend = DirBackend;
+    type Format = DefaultDebugFormat;
+
+    type Key<'k> = &'k str;
+
+    fn key(&self) -> Self::Key<'_> {
+        "slot"
+    }
+
+    fn capture(builder: SnapshotBuilder) -> Snapshot {
+        builder.extract_all_entities().build()
+    }
+
+    fn summary() -> bool {
+        true
+    }
+}
+
+#[test]
+fn test_dir_backend() {
+    let root = std::env::temp_dir().join("bevy_save_dir");
+    let _ = std::fs::remove_dir_all(&root);
+
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .init_pipeline::<DirPipeline>()
+        .insert_resource(DirBackend::new(&root))
+        .register_type::<Health>();
+
+    let world = &mut app.world;
+
+    world.spawn(Health(5));
+
+    world.save(DirPipeline).unwrap();
+    world.save_split(DirPipeline).unwrap();
+
+    let manifest = world.resource::<DirBackend>().manifest("slot").unwrap();
+
+    for section in [
+        DirBackend::DEFAULT_SECTION,
+        SECTION_META,
+        SECTION_ENTITIES,
+        SECTION_RESOURCES,
+    ] {
+        let entry = &manifest.sections[section];
+
+        assert!(root.join("slot").join(&entry.file).exists());
+        assert!(entry.size > 0);
+    }
+
+    // The summary does not replace the entry of the save
+    let summary = format!("{}.summary", DirBackend::DEFAULT_SECTION);
+
+    assert_eq!(
+        manifest.sections[DirBackend::DEFAULT_SECTION].file,
+        "snapshot.json"
+    );
+    assert_eq!(manifest.sections[&summary].file, "snapshot.summary.ron");
+
+    let slot = world.resource::<DirBackend>().slots().unwrap();
+    let size = |file: &str| {
+        std::fs::metadata(root.join("slot").join(file))
+            .unwrap()
+            .len()
+    };
+
+    assert_eq!(
+        slot[0].bytes,
+        manifest
+            .sections
+            .values()
+            .map(|e| size(&e.file))
+            .sum::<u64>()
+    );
+
+    world.clear_entities();
+
+    world.load(DirPipeline).unwrap();
+
+    assert_eq!(world.query::<&Health>().single(world), &Health(5));
+}
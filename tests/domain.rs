@@ -0,0 +1,91 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct Score(u32);
+
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct Tokens(u32);
+
+struct MainGame;
+
+impl Pipeline for MainGame {
+    type Backend = DefaultBackend;
+    type Format = DefaultFormat;
+
+    type Key<'a> = &'a str;
+
+    fn key(&self) -> Self::Key<'_> {
+        "main"
+    }
+
+    fn capture(builder: SnapshotBuilder) -> Snapshot {
+        builder.extract_all_resources().build()
+    }
+}
+
+struct Minigame;
+
+impl Pipeline for Minigame {
+    type Backend = DefaultBackend;
+    type Format = DefaultFormat;
+
+    type Key<'a> = &'a str;
+
+    fn domain() -> Option<&'static str> {
+        Some("minigame")
+    }
+
+    fn key(&self) -> Self::Key<'_> {
+        "minigame"
+    }
+
+    fn capture(builder: SnapshotBuilder) -> Snapshot {
+        builder.extract_all_resources().build()
+    }
+}
+
+#[test]
+fn test_domains() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .init_pipeline::<MainGame>()
+        .init_pipeline::<Minigame>()
+        .register_type::<Score>()
+        .register_type::<Tokens>()
+        .allow_rollback::<Score>()
+        .allow_rollback_in::<Tokens>("minigame")
+        .init_resource::<Score>()
+        .init_resource::<Tokens>();
+
+    let world = &mut app.world;
+
+    world.checkpoint::<MainGame>();
+    world.checkpoint::<Minigame>();
+
+    world.resource_mut::<Score>().0 = 1;
+    world.resource_mut::<Tokens>().0 = 1;
+
+    world.checkpoint::<Minigame>();
+
+    let domains = world.resource::<SaveDomains>();
+
+    assert_eq!(world.resource::<Rollbacks>().len(), 1);
+    assert_eq!(domains.rollbacks("minigame").map(|r| r.len()), Some(2));
+    assert_eq!(domains.names().collect::<Vec<_>>(), vec!["minigame"]);
+
+    // Each domain only rolls back the types allowed in it
+    world.rollback::<Minigame>(1).unwrap();
+
+    assert_eq!(world.resource::<Score>().0, 1);
+    assert_eq!(world.resource::<Tokens>().0, 0);
+
+    world.resource_mut::<Tokens>().0 = 5;
+    world.rollback::<MainGame>(1).unwrap();
+
+    assert_eq!(world.resource::<Score>().0, 0);
+    assert_eq!(world.resource::<Tokens>().0, 5);
+}
@@ -0,0 +1,76 @@
+use bevy::{
+    prelude::*,
+    utils::HashMap,
+};
+use bevy_save::prelude::*;
+
+#[derive(Reflect, Default)]
+struct Inventory {
+    slots: HashMap<Entity, u32>,
+}
+
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct Inventories {
+    by_player: HashMap<Entity, Inventory>,
+}
+
+#[test]
+fn test_remap_entity_keys() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Inventory>()
+        .register_type::<HashMap<Entity, u32>>()
+        .register_type::<HashMap<Entity, Inventory>>()
+        .remap_entity_keys::<Inventories>();
+
+    let world = &mut app.world;
+
+    let player = world.spawn_empty().id();
+    let item = world.spawn_empty().id();
+    let outside = Entity::from_raw(1000);
+
+    world.insert_resource(Inventories {
+        by_player: HashMap::from([(player, Inventory {
+            slots: HashMap::from([(item, 3), (outside, 1)]),
+        })]),
+    });
+
+    let snapshot = Snapshot::builder(world)
+        .extract_all_entities()
+        .extract_resource::<Inventories>()
+        .build();
+
+    let registry = world.resource::<AppTypeRegistry>();
+
+    let mut data = Vec::new();
+    DefaultFormat::serialize(&mut data, &SnapshotSerializer::new(&snapshot, registry)).unwrap();
+
+    let snapshot =
+        DefaultFormat::deserialize(&*data, SnapshotDeserializer::new(&registry.read())).unwrap();
+
+    world.clear_entities();
+    world.remove_resource::<Inventories>();
+
+    // Shift entity ids so the saved keys no longer match
+    world.spawn_batch([(), (), ()]);
+
+    let mut entity_map = Default::default();
+
+    snapshot
+        .applier(world)
+        .entity_map(&mut entity_map)
+        .apply()
+        .unwrap();
+
+    let inventories = world.resource::<Inventories>();
+
+    assert_eq!(inventories.by_player.len(), 1);
+
+    let slots = &inventories.by_player[&entity_map[&player]].slots;
+
+    // Entities that were not saved are dropped
+    assert_eq!(slots.len(), 1);
+    assert_eq!(slots[&entity_map[&item]], 3);
+}
@@ -0,0 +1,98 @@
+use std::{
+    collections::HashSet,
+    io::ErrorKind,
+};
+
+use bevy::{
+    ecs::entity::Entity,
+    scene::SceneSpawnError,
+};
+use bevy_save::{
+    Error,
+    ErrorCode,
+};
+
+#[test]
+fn test_error_codes() {
+    let io = |kind: ErrorKind| Error::IO(kind.into());
+
+    let errors = [
+        (Error::Saving, ErrorCode::Saving, 1, "saving"),
+        (Error::Loading, ErrorCode::Loading, 2, "loading"),
+        (
+            Error::SceneSpawnError(SceneSpawnError::UnregisteredType {
+                std_type_name: "Foo".into(),
+            }),
+            ErrorCode::SceneSpawn,
+            3,
+            "scene_spawn",
+        ),
+        (io(ErrorKind::Other), ErrorCode::Io, 4, "io"),
+        (io(ErrorKind::NotFound), ErrorCode::NotFound, 5, "not_found"),
+        (
+            Error::IncompatibleSave {
+                save: "0.1.0".into(),
+                supported: ">=1.0".into(),
+            },
+            ErrorCode::IncompatibleSave,
+            6,
+            "incompatible_save",
+        ),
+        (
+            Error::InvalidSignature,
+            ErrorCode::InvalidSignature,
+            7,
+            "invalid_signature",
+        ),
+        (Error::Conflict, ErrorCode::Conflict, 8, "conflict"),
+        (
+            Error::other(std::io::Error::from(ErrorKind::Other)),
+            ErrorCode::Other,
+            9,
+            "other",
+        ),
+        (Error::custom("custom"), ErrorCode::Custom, 10, "custom"),
+        (
+            Error::DanglingEntity {
+                type_path: "Target".into(),
+                entity: Entity::PLACEHOLDER,
+            },
+            ErrorCode::DanglingEntity,
+            11,
+            "dangling_entity",
+        ),
+        (
+            Error::Vetoed("busy".into()),
+            ErrorCode::Vetoed,
+            12,
+            "vetoed",
+        ),
+        (
+            Error::QuotaExceeded,
+            ErrorCode::QuotaExceeded,
+            13,
+            "quota_exceeded",
+        ),
+        (
+            Error::NoSuchEntity(Entity::PLACEHOLDER),
+            ErrorCode::NoSuchEntity,
+            14,
+            "no_such_entity",
+        ),
+    ];
+
+    let mut keys = HashSet::new();
+
+    for (err, code, discriminant, key) in errors {
+        let key = format!("bevy_save.error.{key}");
+
+        assert_eq!(err.code(), code, "{err}");
+
+        // Discriminants and keys are stable
+        assert_eq!(code as u16, discriminant, "{err}");
+        assert_eq!(code.message_key(), key);
+        assert_eq!(err.message_key(), key);
+
+        assert!(keys.insert(key), "duplicate message key for {err}");
+    }
+}
@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct DespawnTimer(f32);
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Particle(u32);
+
+fn setup() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<DespawnTimer>()
+        .register_type::<Particle>();
+
+    app
+}
+
+#[test]
+fn test_exclude_if() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    let kept = world.spawn(DespawnTimer(5.0)).id();
+    let expiring = world.spawn(DespawnTimer(0.05)).id();
+    let particle = world.spawn(Particle(1)).id();
+    let both = world.spawn((DespawnTimer(5.0), Particle(2))).id();
+    let plain = world.spawn_empty().id();
+
+    let builder = || {
+        Snapshot::builder(world)
+            .exclude_if::<DespawnTimer>(|timer| timer.0 < 0.1)
+            .exclude_if::<Particle>(|_| true)
+    };
+
+    let mut plan = builder().plan();
+
+    let snapshots = [
+        builder().extract_all_entities().build(),
+        builder()
+            .extract_entities([kept, expiring, particle, both, plain].into_iter())
+            .build(),
+        builder().extract_with_plan(&mut plan).build(),
+    ];
+
+    for snapshot in snapshots {
+        let mut entities = snapshot
+            .entities
+            .iter()
+            .map(|e| e.entity)
+            .collect::<Vec<_>>();
+
+        entities.sort_unstable();
+
+        // Entities matching any predicate are skipped, entities without the components are kept
+        assert_eq!(entities, [kept, plain]);
+    }
+}
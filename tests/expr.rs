@@ -0,0 +1,136 @@
+use std::any::TypeId;
+
+use bevy::prelude::*;
+use bevy_save::{
+    prelude::*,
+    Error,
+};
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct A;
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct B;
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct C;
+
+fn setup() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<A>()
+        .register_type::<B>()
+        .register_type::<C>();
+
+    app
+}
+
+fn parse(app: &App, expr: &str) -> Result<EntityFilter, Error> {
+    EntityFilter::parse(expr, &app.world.resource::<AppTypeRegistry>().read())
+}
+
+fn with<T: 'static>() -> Box<EntityFilter> {
+    Box::new(EntityFilter::With(TypeId::of::<T>()))
+}
+
+#[test]
+fn test_expr_precedence() {
+    let app = setup();
+
+    // `&` binds tighter than `|`
+    assert_eq!(
+        parse(&app, "With(A) | With(B) & With(C)").unwrap(),
+        EntityFilter::Or(
+            with::<A>(),
+            Box::new(EntityFilter::And(with::<B>(), with::<C>()))
+        )
+    );
+
+    assert_eq!(
+        parse(&app, "With(A) & With(B) | With(C)").unwrap(),
+        EntityFilter::Or(
+            Box::new(EntityFilter::And(with::<A>(), with::<B>())),
+            with::<C>()
+        )
+    );
+
+    // Parentheses group expressions
+    assert_eq!(
+        parse(&app, "(With(A) | With(B)) & With(C)").unwrap(),
+        EntityFilter::And(
+            Box::new(EntityFilter::Or(with::<A>(), with::<B>())),
+            with::<C>()
+        )
+    );
+
+    // `!` binds tighter than `&`
+    assert_eq!(
+        parse(&app, "!With(A) & With(B)").unwrap(),
+        EntityFilter::And(Box::new(EntityFilter::Not(with::<A>())), with::<B>())
+    );
+
+    // Operators are left-associative
+    assert_eq!(
+        parse(&app, "With(A) | With(B) | With(C)").unwrap(),
+        EntityFilter::Or(
+            Box::new(EntityFilter::Or(with::<A>(), with::<B>())),
+            with::<C>()
+        )
+    );
+
+    // Whitespace is ignored and full type paths are resolved
+    assert_eq!(
+        parse(&app, "  Without ( expr::A )&!!With(B)  ").unwrap(),
+        EntityFilter::And(
+            Box::new(EntityFilter::Without(TypeId::of::<A>())),
+            Box::new(EntityFilter::Not(Box::new(EntityFilter::Not(with::<B>()))))
+        )
+    );
+}
+
+#[test]
+fn test_expr_errors() {
+    let app = setup();
+
+    for (expr, message) in [
+        ("", "expected `(`"),
+        ("With A", "expected `(`"),
+        ("With(A) &", "expected `(`"),
+        ("With(A", "expected `)`"),
+        ("(With(A) | With(B)", "expected `)`"),
+        ("Has(A)", "unknown filter `Has`"),
+        ("With(Missing)", "unregistered type `Missing`"),
+        ("With(A) With(B)", "unexpected input at 8"),
+        ("With(A))", "unexpected input at 7"),
+    ] {
+        let err = parse(&app, expr).unwrap_err();
+
+        assert!(
+            matches!(&err, Error::Custom(m) if m.contains(message)),
+            "`{expr}`: {err}"
+        );
+    }
+}
+
+#[test]
+fn test_expr_matches() {
+    let mut app = setup();
+
+    let filter = parse(&app, "With(A) & !(With(B) | Without(C))").unwrap();
+
+    let world = &mut app.world;
+
+    let matching = world.spawn((A, C)).id();
+    let with_b = world.spawn((A, B, C)).id();
+    let without_c = world.spawn(A).id();
+    let without_a = world.spawn(C).id();
+
+    assert!(filter.matches(&world.entity(matching)));
+    assert!(!filter.matches(&world.entity(with_b)));
+    assert!(!filter.matches(&world.entity(without_c)));
+    assert!(!filter.matches(&world.entity(without_a)));
+}
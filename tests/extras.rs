@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[test]
+fn test_extras() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins));
+
+    let world = &mut app.world;
+
+    let mut extras = Extras::default();
+    extras.insert("deaths", &3_u32).unwrap();
+    extras.insert("checkpoint", &"cave").unwrap();
+    world.insert_resource(extras);
+
+    // Extras captured from the world are merged with the extracted values
+    let snapshot = Snapshot::builder(world)
+        .extract_resource::<Extras>()
+        .extract_extra("deaths", 4_u32)
+        .extract_extra("play_time", (1_u32, 30.5_f32))
+        .build();
+
+    assert_eq!(snapshot.extra::<u32>("deaths"), Some(4));
+    assert_eq!(snapshot.extra::<(u32, f32)>("play_time"), Some((1, 30.5)));
+    assert_eq!(snapshot.extra::<String>("checkpoint"), Some("cave".into()));
+    assert_eq!(snapshot.extra::<String>("deaths"), None);
+    assert_eq!(snapshot.extra::<u32>("missing"), None);
+
+    let registry = world.resource::<AppTypeRegistry>();
+
+    let mut data = Vec::new();
+    DefaultFormat::serialize(&mut data, &SnapshotSerializer::new(&snapshot, registry)).unwrap();
+
+    let snapshot =
+        DefaultFormat::deserialize(&*data, SnapshotDeserializer::new(&registry.read())).unwrap();
+
+    assert_eq!(snapshot.extras().unwrap().len(), 3);
+
+    world.remove_resource::<Extras>();
+
+    snapshot.apply(world).unwrap();
+
+    let extras = world.resource::<Extras>();
+
+    assert_eq!(extras.get::<u32>("deaths"), Some(4));
+    assert_eq!(extras.get::<(u32, f32)>("play_time"), Some((1, 30.5)));
+}
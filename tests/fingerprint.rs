@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+use bevy_save::{
+    prelude::*,
+    testing::world_fingerprint,
+};
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Position(f32, f32);
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Name(String);
+
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct Level(u32);
+
+fn filter() -> SceneFilter {
+    SceneFilter::deny_all()
+        .allow::<Position>()
+        .allow::<Name>()
+        .allow::<Level>()
+}
+
+#[test]
+fn test_world_fingerprint() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Position>()
+        .register_type::<Name>()
+        .register_type::<Level>();
+
+    let world = &mut app.world;
+
+    world.insert_resource(Level(3));
+    world.spawn((Position(1.0, 2.0), Name("player".into())));
+    world.spawn(Position(5.0, 5.0));
+
+    let before = world_fingerprint(world, &filter());
+
+    // Unrelated entities and components do not matter
+    world.spawn(Transform::default());
+
+    assert_eq!(world_fingerprint(world, &filter()), before);
+
+    // A capture applied to a cleared world produces an equivalent world
+    let snapshot = Snapshot::builder(world)
+        .filter(filter())
+        .extract_all_entities()
+        .extract_all_resources()
+        .build();
+
+    world.clear_entities();
+    world.remove_resource::<Level>();
+
+    assert_ne!(world_fingerprint(world, &filter()), before);
+
+    snapshot.apply(world).unwrap();
+
+    assert_eq!(world_fingerprint(world, &filter()), before);
+
+    // Any change to a value changes the fingerprint
+    let mut query = world.query::<&mut Position>();
+    query.iter_mut(world).next().unwrap().0 += 1.0;
+
+    assert_ne!(world_fingerprint(world, &filter()), before);
+}
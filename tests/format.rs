@@ -87,7 +87,7 @@ fn extract(world: &World) -> Snapshot {
 #[test]
 fn test_json() {
     fn serialize(snapshot: &Snapshot, registry: &AppTypeRegistry) -> String {
-        let serializer = SnapshotSerializer { snapshot, registry };
+        let serializer = SnapshotSerializer::new(snapshot, registry);
 
         let mut buf = Vec::new();
         let format = serde_json::ser::PrettyFormatter::with_indent(b"    ");
@@ -161,9 +161,8 @@ fn test_json() {
 
     assert_eq!(output, expected);
 
-    let deserializer = SnapshotDeserializer {
-        registry: &registry.read(),
-    };
+    let reg = registry.read();
+    let deserializer = SnapshotDeserializer::new(&reg);
 
     let mut de = serde_json::Deserializer::from_str(&output);
 
@@ -177,7 +176,7 @@ fn test_json() {
 #[test]
 fn test_mp() {
     fn serialize(snapshot: &Snapshot, registry: &AppTypeRegistry) -> Vec<u8> {
-        let serializer = SnapshotSerializer { snapshot, registry };
+        let serializer = SnapshotSerializer::new(snapshot, registry);
 
         let mut buf = Vec::new();
         let mut ser = rmp_serde::Serializer::new(&mut buf);
@@ -210,9 +209,8 @@ fn test_mp() {
 
     assert_eq!(output, expected);
 
-    let deserializer = SnapshotDeserializer {
-        registry: &registry.read(),
-    };
+    let reg = registry.read();
+    let deserializer = SnapshotDeserializer::new(&reg);
 
     let mut de = rmp_serde::Deserializer::new(&*output);
 
@@ -222,3 +220,230 @@ fn test_mp() {
 
     assert_eq!(output, expected);
 }
+
+#[test]
+fn test_limits() {
+    let mut app = init_app();
+    let world = &mut app.world;
+
+    let registry = world.resource::<AppTypeRegistry>();
+    let snapshot = extract(world);
+
+    let mut data = Vec::new();
+    JSONFormat::serialize(&mut data, &SnapshotSerializer::new(&snapshot, registry)).unwrap();
+
+    let registry = registry.read();
+
+    let limits = DeserializeLimits {
+        max_entities: snapshot.entities.len() - 1,
+        ..default()
+    };
+
+    assert!(
+        JSONFormat::deserialize(&*data, SnapshotDeserializer::new(&registry).limits(limits))
+            .is_err()
+    );
+
+    let limits = DeserializeLimits {
+        max_components: 1,
+        ..default()
+    };
+
+    assert!(
+        JSONFormat::deserialize(&*data, SnapshotDeserializer::new(&registry).limits(limits))
+            .is_err()
+    );
+
+    assert!(matches!(
+        Limited::<JSONFormat, 16>::deserialize(&*data, SnapshotDeserializer::new(&registry)),
+        Err(bevy_save::Error::Custom(_))
+    ));
+
+    let value =
+        Limited::<JSONFormat>::deserialize(&*data, SnapshotDeserializer::new(&registry)).unwrap();
+
+    assert_eq!(value.entities.len(), snapshot.entities.len());
+}
+
+#[test]
+fn test_limits_checkpoints() {
+    let mut app = init_app();
+    let world = &mut app.world;
+
+    let checkpoint = extract(world);
+    let len = checkpoint.entities.len();
+
+    world.resource_mut::<Rollbacks>().checkpoint(checkpoint);
+
+    for _ in 0..2 {
+        let checkpoint = extract(world);
+        world.resource_mut::<Rollbacks>().checkpoint(checkpoint);
+    }
+
+    let snapshot = Snapshot::builder(world)
+        .extract_all_entities()
+        .extract_rollbacks()
+        .build();
+
+    let registry = world.resource::<AppTypeRegistry>();
+
+    let mut data = Vec::new();
+    JSONFormat::serialize(&mut data, &SnapshotSerializer::new(&snapshot, registry)).unwrap();
+
+    let registry = registry.read();
+
+    let load = |limits| {
+        JSONFormat::deserialize(&*data, SnapshotDeserializer::new(&registry).limits(limits))
+    };
+
+    // Each checkpoint is within the limit, but all of them together are not
+    assert!(load(DeserializeLimits {
+        max_entities: len * 4 - 1,
+        ..default()
+    })
+    .is_err());
+    assert!(load(DeserializeLimits {
+        max_entities: len * 4,
+        ..default()
+    })
+    .is_ok());
+
+    assert!(load(DeserializeLimits {
+        max_checkpoints: 2,
+        ..default()
+    })
+    .is_err());
+    assert!(load(DeserializeLimits {
+        max_checkpoints: 3,
+        ..default()
+    })
+    .is_ok());
+}
+
+#[test]
+fn test_detect() {
+    type Transition = DetectFormat<RMPFormat, (JSONFormat, RONFormat)>;
+
+    let mut app = init_app();
+    let world = &mut app.world;
+
+    let registry = world.resource::<AppTypeRegistry>();
+    let snapshot = extract(world);
+    let serializer = SnapshotSerializer::new(&snapshot, registry);
+
+    let registry = registry.read();
+
+    let load = |data: &[u8]| {
+        Transition::deserialize(data, SnapshotDeserializer::new(&registry))
+            .unwrap()
+            .entities
+            .len()
+    };
+
+    // Legacy saves without a header
+    let mut json = Vec::new();
+    JSONFormat::serialize(&mut json, &serializer).unwrap();
+    assert_eq!(load(&json), snapshot.entities.len());
+
+    let mut ron = Vec::new();
+    RONFormat::serialize(&mut ron, &serializer).unwrap();
+    assert_eq!(load(&ron), snapshot.entities.len());
+
+    // Legacy saves with a header
+    let mut stamped = Vec::new();
+    Stamped::<RONFormat>::serialize(&mut stamped, &serializer).unwrap();
+    assert_eq!(load(&stamped), snapshot.entities.len());
+
+    // New saves are written with the primary format
+    let mut data = Vec::new();
+    Stamped::<Transition>::serialize(&mut data, &serializer).unwrap();
+
+    let (header, _) = SaveHeader::read(&*data).unwrap();
+    assert_eq!(header.unwrap().format, "msgpack");
+    assert_eq!(load(&data), snapshot.entities.len());
+}
+
+#[test]
+fn test_scene() {
+    type Migration = Stamped<DetectFormat<RMPFormat, (SceneFormat,)>>;
+
+    let mut app = init_app();
+    let world = &mut app.world;
+
+    let registry = world.resource::<AppTypeRegistry>().clone();
+
+    // A plain scene file, as written by `bevy_scene`
+    let scene = DynamicSceneBuilder::from_world(world)
+        .extract_entities(world.iter_entities().map(|e| e.id()))
+        .build()
+        .serialize_ron(&registry)
+        .unwrap();
+
+    assert!(SceneFormat::matches(scene.as_bytes()));
+    assert!(!SceneFormat::matches(b"{\"entities\": {}}"));
+
+    let snapshot = Migration::deserialize(
+        scene.as_bytes(),
+        SnapshotDeserializer::new(&registry.read()),
+    )
+    .unwrap();
+
+    assert_eq!(snapshot.entities.len(), world.entities().len() as usize);
+
+    let mut other = init_app();
+    let world = &mut other.world;
+
+    world.clear_entities();
+
+    snapshot.apply(world).unwrap();
+
+    let mut data = world
+        .query::<&Basic>()
+        .iter(world)
+        .map(|b| b.data)
+        .collect::<Vec<_>>();
+    data.sort_unstable();
+
+    assert_eq!(data, vec![42]);
+    assert_eq!(world.query::<&Unit>().iter(world).count(), 3);
+}
+
+#[test]
+fn test_file_metadata() {
+    assert_eq!(RONFormat::extension(), ".ron");
+    assert_eq!(RONFormat::mime_type(), "application/ron");
+    assert_eq!(JSONFormat::mime_type(), "application/json");
+    assert_eq!(Quantized::<JSONFormat, 2>::mime_type(), "application/json");
+
+    assert_eq!(Stamped::<JSONFormat>::mime_type(), "application/json");
+
+    let file = FileIO::new().path::<RONFormat>("player");
+
+    assert_eq!(file.file_name().unwrap(), "player.ron");
+
+    let file = FileIO::default()
+        .append_extension(false)
+        .path::<RONFormat>("player.save");
+
+    assert_eq!(file.file_name().unwrap(), "player.save");
+}
+
+#[test]
+fn test_deserialize_slice() {
+    use std::marker::PhantomData;
+
+    let value = vec![1u32, 2, 3];
+
+    let mut data = Vec::new();
+    Stamped::<RONFormat>::serialize(&mut data, &value).unwrap();
+
+    let loaded: Vec<u32> = Stamped::<RONFormat>::deserialize_slice(&data, PhantomData).unwrap();
+    assert_eq!(loaded, value);
+
+    // Saves without a header are read as-is
+    let mut data = Vec::new();
+    RONFormat::serialize(&mut data, &value).unwrap();
+
+    let loaded: Vec<u32> = Stamped::<RONFormat>::deserialize_slice(&data, PhantomData).unwrap();
+    assert_eq!(loaded, value);
+}
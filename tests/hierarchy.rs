@@ -0,0 +1,187 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Tag(u32);
+
+fn setup() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Tag>()
+        .register_type::<Parent>()
+        .register_type::<Children>();
+
+    app
+}
+
+fn find(world: &mut World, name: u32) -> Entity {
+    world
+        .query::<(Entity, &Tag)>()
+        .iter(world)
+        .find(|(_, n)| n.0 == name)
+        .map(|(e, _)| e)
+        .unwrap()
+}
+
+#[test]
+fn test_hierarchy_both_sides() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    world.spawn(Tag(0)).with_children(|p| {
+        p.spawn(Tag(1));
+        p.spawn(Tag(2));
+    });
+
+    let snapshot = Snapshot::builder(world).extract_all_entities().build();
+
+    assert!(snapshot
+        .entities
+        .iter()
+        .flat_map(|e| &e.components)
+        .all(|c| !c.represents::<Children>()));
+
+    let mut other = setup();
+    let world = &mut other.world;
+
+    snapshot.apply(world).unwrap();
+
+    let parent = find(world, 0);
+    let children = world.get::<Children>(parent).unwrap().to_vec();
+
+    assert_eq!(children.len(), 2);
+
+    for child in children {
+        assert_eq!(world.get::<Parent>(child).unwrap().get(), parent);
+    }
+}
+
+#[test]
+fn test_hierarchy_missing_parent() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    let mut child = Entity::PLACEHOLDER;
+
+    world.spawn(Tag(0)).with_children(|p| {
+        child = p.spawn(Tag(1)).id();
+    });
+
+    let snapshot = Snapshot::builder(world).extract_entity(child).build();
+
+    let mut other = setup();
+    let world = &mut other.world;
+
+    snapshot.apply(world).unwrap();
+
+    let child = find(world, 1);
+    let parent = world.get::<Parent>(child).unwrap().get();
+
+    assert_eq!(world.get::<Children>(parent).unwrap().to_vec(), vec![child]);
+}
+
+#[test]
+fn test_hierarchy_missing_parent_policy() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    let mut child = Entity::PLACEHOLDER;
+
+    world.spawn(Tag(0)).with_children(|p| {
+        child = p.spawn(Tag(1)).id();
+    });
+
+    let snapshot = Snapshot::builder(world).extract_entity(child).build();
+
+    let mut other = setup();
+    let world = &mut other.world;
+
+    let root = world.spawn(Tag(2)).id();
+
+    snapshot
+        .applier(world)
+        .missing_parent(MissingParentPolicy::ReparentTo(root))
+        .apply()
+        .unwrap();
+
+    let child = find(world, 1);
+
+    assert_eq!(world.get::<Parent>(child).unwrap().get(), root);
+
+    let mut other = setup();
+    let world = &mut other.world;
+
+    snapshot
+        .applier(world)
+        .missing_parent(MissingParentPolicy::Strip)
+        .apply()
+        .unwrap();
+
+    let child = find(world, 1);
+
+    assert!(world.get::<Parent>(child).is_none());
+    assert_eq!(world.entities().len(), 1);
+}
+
+#[test]
+fn test_hierarchy_sibling_order() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    let children = (1..=4)
+        .map(|n| world.spawn(Tag(n)).id())
+        .collect::<Vec<_>>();
+
+    // Siblings in a different order than their entities
+    world
+        .spawn(Tag(0))
+        .push_children(&[children[2], children[0], children[3], children[1]]);
+
+    let snapshot = Snapshot::builder(world).extract_all_entities().build();
+
+    let mut other = setup();
+    let world = &mut other.world;
+
+    snapshot.apply(world).unwrap();
+
+    let parent = find(world, 0);
+    let tags = world
+        .get::<Children>(parent)
+        .unwrap()
+        .iter()
+        .map(|c| world.get::<Tag>(*c).unwrap().0)
+        .collect::<Vec<_>>();
+
+    assert_eq!(tags, [3, 1, 4, 2]);
+}
+
+#[test]
+fn test_hierarchy_reparent_to_missing() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    let mut child = Entity::PLACEHOLDER;
+
+    world.spawn(Tag(0)).with_children(|p| {
+        child = p.spawn(Tag(1)).id();
+    });
+
+    let snapshot = Snapshot::builder(world).extract_entity(child).build();
+
+    let mut other = setup();
+    let world = &mut other.world;
+
+    let missing = world.spawn_empty().id();
+    world.despawn(missing);
+
+    let err = snapshot
+        .applier(world)
+        .missing_parent(MissingParentPolicy::ReparentTo(missing))
+        .apply()
+        .unwrap_err();
+
+    assert!(matches!(err, bevy_save::Error::NoSuchEntity(e) if e == missing));
+    assert_eq!(world.query::<&Tag>().iter(world).count(), 0);
+}
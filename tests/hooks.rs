@@ -0,0 +1,182 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Player;
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Health(u32);
+
+#[derive(Component)]
+struct Hooked;
+
+#[derive(Component)]
+struct Spawned(Entity);
+
+#[derive(Resource, Reflect, Clone, Default, Debug, PartialEq)]
+#[reflect(Resource)]
+struct Score(u32);
+
+fn setup() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Player>()
+        .register_type::<Health>()
+        .register_type::<Score>();
+
+    app
+}
+
+#[test]
+fn test_hook_filtered() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    world.spawn(Player);
+    world.spawn_empty();
+
+    let snapshot = Snapshot::builder(world).extract_all_entities().build();
+
+    let mut other = setup();
+    let world = &mut other.world;
+
+    snapshot
+        .applier(world)
+        .hook_filtered::<With<Player>>(|_, cmds| {
+            cmds.insert(Hooked);
+        })
+        .apply()
+        .unwrap();
+
+    let hooked = world
+        .query_filtered::<(), (With<Player>, With<Hooked>)>()
+        .iter(world)
+        .count();
+
+    assert_eq!(hooked, 1);
+    assert_eq!(world.query::<&Hooked>().iter(world).count(), 1);
+}
+
+#[test]
+fn test_resource_hook() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    world.insert_resource(Score(5_000_000));
+
+    let snapshot = Snapshot::builder(world).extract_resource::<Score>().build();
+
+    let mut other = setup();
+    let world = &mut other.world;
+
+    world.insert_resource(Score(10));
+
+    snapshot
+        .applier(world)
+        .resource_hook(|old: Option<&Score>, new: &Score, cmds| {
+            if new.0 > 1_000_000 {
+                cmds.insert_resource(old.cloned().unwrap_or_default());
+            }
+        })
+        .apply()
+        .unwrap();
+
+    assert_eq!(world.resource::<Score>(), &Score(10));
+}
+
+#[test]
+fn test_hook_with_snapshot() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    let weak = world.spawn(Health(10)).id();
+    let strong = world.spawn(Health(50)).id();
+
+    let snapshot = Snapshot::builder(world).extract_all_entities().build();
+
+    let mut other = setup();
+    let world = &mut other.world;
+
+    let mut entity_map = Default::default();
+
+    snapshot
+        .applier(world)
+        .entity_map(&mut entity_map)
+        // Heal every entity, then fix up based on the captured data
+        .hook(|_, cmds| {
+            cmds.insert(Health(100));
+        })
+        .hook_with_snapshot(|source, entity, cmds| {
+            let captured = source
+                .components
+                .iter()
+                .find(|c| c.represents::<Health>())
+                .and_then(|c| Health::from_reflect(&**c))
+                .unwrap();
+
+            if captured.0 < 20 {
+                let spawned = cmds.spawn(Spawned(source.entity)).id();
+                cmds.entity(entity.id()).insert(Hooked).add_child(spawned);
+            }
+        })
+        .apply()
+        .unwrap();
+
+    let weak = entity_map[&weak];
+    let strong = entity_map[&strong];
+
+    // The hook sees the applied entity
+    assert_eq!(world.get::<Health>(weak), Some(&Health(100)));
+    assert!(world.get::<Hooked>(weak).is_some());
+    assert!(world.get::<Hooked>(strong).is_none());
+
+    let (spawned, parent) = world.query::<(&Spawned, &Parent)>().single(world);
+
+    assert_eq!(parent.get(), weak);
+    assert_eq!(entity_map[&spawned.0], weak);
+}
+
+#[test]
+fn test_hook_filtered_after_apply() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    world.spawn((Player, Health(10)));
+    world.spawn(Health(20));
+
+    let snapshot = Snapshot::builder(world).extract_all_entities().build();
+
+    let mut other = setup();
+    let world = &mut other.world;
+
+    // Existing entities are not hooked, even if they match
+    let existing = world.spawn(Player).id();
+
+    snapshot
+        .applier(world)
+        // Players only gain `Health` when the snapshot is applied
+        .hook_filtered::<(With<Player>, With<Health>)>(|_, cmds| {
+            cmds.insert(Hooked);
+        })
+        .hook_filtered::<Without<Player>>(|entity, cmds| {
+            let health = entity.get::<Health>().unwrap().0;
+            cmds.insert(Health(health * 2));
+        })
+        .apply()
+        .unwrap();
+
+    assert!(world.get::<Hooked>(existing).is_none());
+
+    let mut hooked = world
+        .query::<(&Health, Option<&Hooked>)>()
+        .iter(world)
+        .map(|(h, hooked)| (h.0, hooked.is_some()))
+        .collect::<Vec<_>>();
+
+    hooked.sort_unstable();
+
+    assert_eq!(hooked, [(10, true), (40, false)]);
+}
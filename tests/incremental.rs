@@ -0,0 +1,61 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Brick(u32);
+
+#[test]
+fn test_capture_incremental() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Brick>();
+
+    app.world.spawn_batch((0..25).map(Brick));
+
+    let capture = IncrementalCapture::new(10).extract_resources(false);
+    let handle = app.world.capture_incremental(capture.clone());
+
+    app.update();
+    app.update();
+
+    assert!(!handle.is_finished());
+    assert!(handle.take().is_none());
+    assert_eq!(handle.progress(), 0.8);
+
+    // Cancelled mid-way, a new capture starts immediately
+    handle.cancel();
+
+    let restarted = app.world.capture_incremental(capture);
+
+    // Despawned entities are skipped
+    let first = app.world.iter_entities().next().unwrap().id();
+    app.world.despawn(first);
+
+    for _ in 0..3 {
+        app.update();
+    }
+
+    assert!(handle.is_cancelled());
+    assert!(!handle.is_finished());
+    assert!(handle.take().is_none());
+
+    assert!(restarted.is_finished());
+    assert_eq!(restarted.progress(), 1.0);
+
+    let snapshot = restarted.take().unwrap();
+
+    assert_eq!(snapshot.entities.len(), 24);
+    assert_eq!(
+        snapshot
+            .get_component::<Brick>(snapshot.entities[0].entity)
+            .unwrap()
+            .0,
+        1
+    );
+    assert!(snapshot.resources.is_empty());
+
+    // Taken only once
+    assert!(restarted.take().is_none());
+}
@@ -0,0 +1,50 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Brick;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Collider(f32);
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Paddle;
+
+#[test]
+fn test_debug_archetypes() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Brick>()
+        .register_type::<Collider>()
+        .register_type::<Paddle>();
+
+    let world = &mut app.world;
+
+    world.spawn_batch((0..120).map(|_| (Collider(1.0), Brick)));
+    world.spawn((Paddle, Collider(2.0)));
+
+    let snapshot = Snapshot::builder(world).extract_all_entities().build();
+
+    let archetypes = snapshot.archetypes();
+
+    assert_eq!(archetypes.len(), 2);
+    assert_eq!(archetypes[0].count, 120);
+    assert_eq!(archetypes[0].components, [
+        "inspect::Brick",
+        "inspect::Collider"
+    ]);
+
+    let grouped = format!("{:?}", snapshot.debug().group_archetypes(true));
+
+    assert!(grouped.contains("120 entities: [Brick, Collider]"));
+    assert!(grouped.contains("1 entity: [Collider, Paddle]"));
+
+    // Every entity is listed by default
+    let full = format!("{snapshot:?}");
+
+    assert_eq!(full.matches("Collider").count(), 121);
+}
@@ -0,0 +1,126 @@
+use bevy::{
+    ecs::entity::EntityHashMap,
+    prelude::*,
+    scene::{
+        DynamicEntity,
+        ScenePlugin,
+    },
+};
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Health(u32);
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Armor(u32);
+
+fn app() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        ScenePlugin,
+        SavePlugins,
+        SceneInstancePlugin,
+    ))
+    .register_type::<Health>()
+    .register_type::<Armor>();
+
+    app
+}
+
+fn instances(world: &mut World, root: Entity) -> Vec<(Entity, u32)> {
+    let mut instances = world
+        .query::<(Entity, &SceneOrigin)>()
+        .iter(world)
+        .filter(|(_, o)| o.root == root)
+        .map(|(e, o)| (e, o.index))
+        .collect::<Vec<_>>();
+
+    instances.sort_by_key(|(_, i)| *i);
+    instances
+}
+
+#[test]
+fn test_scene_overrides() {
+    let mut app = app();
+
+    let scene = app
+        .world
+        .resource_mut::<Assets<DynamicScene>>()
+        .add(DynamicScene {
+            resources: Vec::new(),
+            entities: vec![
+                DynamicEntity {
+                    entity: Entity::from_raw(0),
+                    components: vec![Box::new(Health(10)), Box::new(Armor(5))],
+                },
+                DynamicEntity {
+                    entity: Entity::from_raw(1),
+                    components: vec![Box::new(Health(20))],
+                },
+            ],
+        });
+
+    let root = app
+        .world
+        .spawn(DynamicSceneBundle {
+            scene: scene.clone(),
+            ..default()
+        })
+        .id();
+
+    app.update();
+
+    let spawned = instances(&mut app.world, root);
+
+    assert_eq!(spawned.len(), 2);
+
+    app.world.get_mut::<Health>(spawned[0].0).unwrap().0 = 1;
+
+    let snapshot = Snapshot::builder(&app.world)
+        .extract_entities(spawned.iter().map(|(e, _)| *e))
+        .scene_overrides()
+        .build();
+
+    // Only the overridden component is captured
+    assert_eq!(
+        snapshot.get_component::<Health>(spawned[0].0),
+        Some(Health(1))
+    );
+    assert_eq!(snapshot.get_component::<Armor>(spawned[0].0), None);
+    assert_eq!(snapshot.get_component::<Health>(spawned[1].0), None);
+
+    app.world.entity_mut(root).despawn_recursive();
+
+    let root = app.world.spawn_empty().id();
+    let saved = snapshot
+        .get_component::<SceneOrigin>(spawned[0].0)
+        .unwrap()
+        .root;
+
+    let mut entity_map = EntityHashMap::default();
+    entity_map.insert(saved, root);
+
+    snapshot
+        .applier(&mut app.world)
+        .entity_map(&mut entity_map)
+        .apply()
+        .unwrap();
+
+    app.world
+        .entity_mut(root)
+        .insert(DynamicSceneBundle { scene, ..default() });
+
+    app.update();
+
+    let loaded = instances(&mut app.world, root);
+
+    assert_eq!(loaded.len(), 2);
+    assert_eq!(app.world.get::<Health>(loaded[0].0), Some(&Health(1)));
+    assert_eq!(app.world.get::<Armor>(loaded[0].0), Some(&Armor(5)));
+    assert_eq!(app.world.get::<Health>(loaded[1].0), Some(&Health(20)));
+}
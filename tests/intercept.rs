@@ -0,0 +1,158 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    sync::Mutex,
+};
+
+use bevy::prelude::*;
+use bevy_save::{
+    prelude::*,
+    Error,
+    ErrorCode,
+};
+use serde::{
+    de::DeserializeSeed,
+    Serialize,
+};
+
+#[derive(Resource, Default)]
+struct MemoryBackend {
+    files: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+impl<K: Display> Backend<K> for MemoryBackend {
+    fn save<F: Format, T: Serialize>(&self, key: K, value: &T) -> Result<(), Error> {
+        let mut data = Vec::new();
+        F::serialize(&mut data, value)?;
+
+        self.files.lock().unwrap().insert(key.to_string(), data);
+
+        Ok(())
+    }
+
+    fn load<F: Format, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        &self,
+        key: K,
+        seed: S,
+    ) -> Result<T, Error> {
+        let files = self.files.lock().unwrap();
+        let data = files
+            .get(&key.to_string())
+            .ok_or(Error::custom("missing"))?;
+
+        F::deserialize(data.as_slice(), seed)
+    }
+}
+
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct Score(u32);
+
+#[derive(Resource, Reflect, Default, Debug, PartialEq)]
+#[reflect(Resource)]
+struct SavedBy(String);
+
+#[derive(Resource)]
+struct Cutscene;
+
+struct SlotPipeline(String);
+
+impl Pipeline for SlotPipeline {
+    type Backend = MemoryBackend;
+    type Format = JSONFormat;
+
+    type Key<'a> = &'a str;
+
+    fn key(&self) -> Self::Key<'_> {
+        &self.0
+    }
+
+    fn capture(builder: SnapshotBuilder) -> Snapshot {
+        builder.extract_resource::<Score>().build()
+    }
+}
+
+#[test]
+fn test_save_interceptors() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .init_pipeline::<SlotPipeline>()
+        .register_type::<Score>()
+        .register_type::<SavedBy>()
+        .init_resource::<Score>()
+        .add_save_interceptor(|ctx: &mut SaveContext| {
+            if ctx.world().contains_resource::<Cutscene>() {
+                ctx.veto("cutscene");
+            }
+        })
+        .add_save_interceptor(|ctx: &mut SaveContext| {
+            ctx.set_key("redirected");
+            ctx.insert_metadata(SavedBy("interceptor".into()));
+        });
+
+    let world = &mut app.world;
+
+    world.insert_resource(Cutscene);
+
+    let err = world.save(SlotPipeline("slot".into())).unwrap_err();
+
+    assert!(matches!(err, Error::Vetoed(ref reason) if reason == "cutscene"));
+    assert_eq!(err.code(), ErrorCode::Vetoed);
+    assert!(world
+        .resource::<MemoryBackend>()
+        .files
+        .lock()
+        .unwrap()
+        .is_empty());
+
+    world.remove_resource::<Cutscene>();
+    world.save(SlotPipeline("slot".into())).unwrap();
+
+    {
+        let files = world.resource::<MemoryBackend>().files.lock().unwrap();
+
+        assert!(files.contains_key("redirected"));
+        assert!(!files.contains_key("slot"));
+
+        // Metadata is stored in type path order with the captured resources
+        let data = String::from_utf8(files["redirected"].clone()).unwrap();
+
+        assert!(data.find("SavedBy").unwrap() < data.find("Score").unwrap());
+    }
+
+    world.load(SlotPipeline("redirected".into())).unwrap();
+
+    assert_eq!(
+        world.get_resource::<SavedBy>(),
+        Some(&SavedBy("interceptor".into()))
+    );
+}
+
+#[test]
+fn test_insert_resource_sorted() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Score>()
+        .init_resource::<Score>();
+
+    let mut snapshot = Snapshot::builder(&app.world)
+        .extract_resource::<Score>()
+        .build();
+
+    snapshot.insert_resource(SavedBy("test".into()));
+    snapshot.insert_resource(SavedBy("replaced".into()));
+
+    let paths = snapshot
+        .resources
+        .iter()
+        .map(|r| r.get_represented_type_info().unwrap().type_path())
+        .collect::<Vec<_>>();
+
+    assert_eq!(paths, [SavedBy::type_path(), Score::type_path()]);
+    assert_eq!(
+        snapshot.get_resource::<SavedBy>(),
+        Some(SavedBy("replaced".into()))
+    );
+}
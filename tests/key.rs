@@ -0,0 +1,87 @@
+use std::path::PathBuf;
+
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct Score(u32);
+
+struct SlotPipeline {
+    profile: String,
+    slot: u32,
+}
+
+impl Pipeline for SlotPipeline {
+    type Backend = DirBackend;
+    type Format = DefaultFormat;
+
+    type Key<'a> = EncodedKey<(&'a str, u32)>;
+
+    fn key(&self) -> Self::Key<'_> {
+        EncodedKey((&self.profile, self.slot))
+    }
+
+    fn capture(builder: SnapshotBuilder) -> Snapshot {
+        builder.extract_resource::<Score>().build()
+    }
+}
+
+#[test]
+fn test_key_segments() {
+    assert_eq!(EncodedKey("slot").to_string(), "slot");
+    assert_eq!(EncodedKey(("alice", 3)).segments(), ["alice", "3"]);
+    assert_eq!(EncodedKey(("alice", 3u8, true)).to_string(), "alice/3/true");
+    assert_eq!(
+        EncodedKey((String::from("bob"), -1, 'x', 7usize)).to_string(),
+        "bob/-1/x/7"
+    );
+
+    // Only normal path components are kept, so keys cannot escape the save directory
+    assert_eq!(
+        EncodedKey(PathBuf::from("/saves/../alice/./slot")).segments(),
+        ["saves", "alice", "slot"]
+    );
+    assert_eq!(
+        EncodedKey(("profiles", PathBuf::from("alice/1"))).to_string(),
+        "profiles/alice/1"
+    );
+}
+
+#[test]
+fn test_key_backend() {
+    let dir = std::env::temp_dir().join("bevy_save_test_key");
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Score>()
+        .insert_resource(DirBackend::new(&dir))
+        .init_resource::<Score>();
+
+    let world = &mut app.world;
+
+    let slot = |slot| SlotPipeline {
+        profile: "alice".into(),
+        slot,
+    };
+
+    for n in [1, 2] {
+        world.resource_mut::<Score>().0 = n * 10;
+        world.save(slot(n)).unwrap();
+    }
+
+    assert!(dir
+        .join("alice")
+        .join(format!("1{}", DefaultFormat::extension()))
+        .exists());
+
+    // Each slot is stored separately
+    for n in [1, 2] {
+        world.load(slot(n)).unwrap();
+        assert_eq!(world.resource::<Score>().0, n * 10);
+    }
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
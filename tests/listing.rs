@@ -0,0 +1,84 @@
+use std::time::{
+    Duration,
+    SystemTime,
+};
+
+use bevy::{
+    prelude::*,
+    time::TimeUpdateStrategy,
+};
+use bevy_save::prelude::*;
+
+#[test]
+fn test_play_time() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+
+    for _ in 0..4 {
+        app.update();
+    }
+
+    let play_time = *app.world.resource::<PlayTime>();
+
+    assert!(play_time.total >= Duration::from_millis(300));
+
+    let before = SystemTime::now();
+    let snapshot = Snapshot::builder(&app.world)
+        .extract_resource::<PlayTime>()
+        .build();
+    let summary = snapshot.summary();
+
+    assert_eq!(summary.play_time, Some(play_time.total));
+    assert!(summary.saved_at.unwrap() >= before);
+
+    // Restored play time keeps accumulating
+    app.world.resource_mut::<PlayTime>().total = Duration::ZERO;
+    snapshot.apply(&mut app.world).unwrap();
+    app.update();
+
+    assert!(app.world.resource::<PlayTime>().total > play_time.total);
+}
+
+fn names<'a>(saves: &[(&'a str, SaveSummary)]) -> Vec<&'a str> {
+    saves.iter().map(|s| s.0).collect()
+}
+
+#[test]
+fn test_save_order() {
+    let now = SystemTime::now();
+    let summary = |secs: u64, minutes_ago: Option<u64>| SaveSummary {
+        play_time: Some(Duration::from_secs(secs)),
+        saved_at: minutes_ago.map(|m| now - Duration::from_secs(m * 60)),
+        ..Default::default()
+    };
+
+    let mut saves = vec![
+        ("b", summary(100, Some(5))),
+        ("c", summary(300, None)),
+        ("a", summary(200, Some(1))),
+    ];
+
+    SaveOrder::LastPlayed.sort(&mut saves);
+    assert_eq!(names(&saves), ["a", "b", "c"]);
+
+    SaveOrder::PlayTime.sort(&mut saves);
+    assert_eq!(names(&saves), ["c", "a", "b"]);
+
+    SaveOrder::Name.sort(&mut saves);
+    assert_eq!(names(&saves), ["a", "b", "c"]);
+
+    let slot = |name: &str, minutes_ago: u64| SlotInfo {
+        name: name.into(),
+        bytes: 0,
+        modified: now - Duration::from_secs(minutes_ago * 60),
+    };
+
+    let mut slots = vec![slot("old", 60), slot("new", 1)];
+
+    SaveOrder::LastPlayed.sort(&mut slots);
+    assert_eq!(slots[0].name, "new");
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "mmap")]
+
+use std::marker::PhantomData;
+
+use bevy_save::prelude::*;
+
+fn roundtrip<F: Format>(key: &str) {
+    let io = FileIO::default().memory_map(true);
+    let value = (0..1000u32).collect::<Vec<_>>();
+
+    io.save::<F, _>(key, &value).unwrap();
+
+    let loaded: Vec<u32> = io.load::<F, _, _>(key, PhantomData).unwrap();
+
+    assert_eq!(loaded, value);
+
+    std::fs::remove_file(io.path::<F>(key)).unwrap();
+}
+
+#[test]
+fn test_mmap() {
+    roundtrip::<Stamped<DefaultFormat>>("bevy_save_mmap_rmp");
+    roundtrip::<Stamped<RONFormat>>("bevy_save_mmap_ron");
+    roundtrip::<JSONFormat>("bevy_save_mmap_json");
+
+    // Missing saves still fail with an IO error
+    let io = FileIO::default().memory_map(true);
+
+    assert!(io
+        .load::<RONFormat, _, Vec<u32>>("bevy_save_mmap_missing", PhantomData)
+        .is_err());
+}
@@ -0,0 +1,150 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Health(u32);
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Gem(u32);
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Spell(u32);
+
+struct ModPipeline(String);
+
+impl Pipeline for ModPipeline {
+    type Backend = DefaultDebugBackend;
+    type Format = DefaultDebugFormat;
+
+    type Key<'k> = &'k str;
+
+    fn key(&self) -> Self::Key<'_> {
+        &self.0
+    }
+
+    fn capture(builder: SnapshotBuilder) -> Snapshot {
+        builder.extract_all_entities().build()
+    }
+
+    fn apply(world: &mut World, snapshot: &Snapshot) -> Result<(), bevy_save::Error> {
+        snapshot.applier(world).despawn::<With<Health>>().apply()
+    }
+}
+
+fn app() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .init_pipeline::<ModPipeline>()
+        .register_type::<Health>();
+
+    app
+}
+
+#[test]
+fn test_missing_mods() {
+    let path = std::env::temp_dir().join("bevy_save_mods");
+    let key = path.to_string_lossy().into_owned();
+
+    let mut app = app();
+
+    app.register_mod_type::<Gem>("gems")
+        .register_mod_type::<Spell>("spells");
+
+    app.world.spawn((Health(10), Gem(3), Spell(7)));
+    app.world.save(ModPipeline(key.clone())).unwrap();
+
+    // The `gems` mod is no longer loaded
+    let mut app = self::app();
+
+    app.register_mod_type::<Spell>("spells");
+    app.world.load(ModPipeline(key.clone())).unwrap();
+
+    let missing = app.world.resource::<MissingMods>();
+
+    assert_eq!(missing.0.len(), 1);
+    assert_eq!(missing.0[0].name, "gems");
+    assert_eq!(missing.0[0].types, [Gem::type_path()]);
+
+    let mut query = app.world.query::<(&Health, &Spell)>();
+    assert_eq!(query.single(&app.world), (&Health(10), &Spell(7)));
+
+    // Unregistered types outside of missing mods are still an error
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .init_pipeline::<ModPipeline>()
+        .register_mod_type::<Gem>("gems")
+        .register_mod_type::<Spell>("spells");
+
+    assert!(app.world.load(ModPipeline(key.clone())).is_err());
+}
+
+#[test]
+fn test_unknown_round_trip() {
+    let path = std::env::temp_dir().join("bevy_save_unknown");
+    let key = path.to_string_lossy().into_owned();
+
+    let mut app = app();
+
+    app.register_mod_type::<Gem>("gems")
+        .register_mod_type::<Spell>("spells");
+
+    app.world.spawn((Health(10), Gem(3), Spell(7)));
+    app.world.save(ModPipeline(key.clone())).unwrap();
+
+    // Load and save again while the `gems` mod is disabled
+    let mut app = self::app();
+
+    app.register_mod_type::<Spell>("spells");
+    app.world.load(ModPipeline(key.clone())).unwrap();
+    app.world.save(ModPipeline(key.clone())).unwrap();
+
+    // Data of disabled mods can be loaded and saved repeatedly
+    let mut app = self::app();
+
+    app.register_mod_type::<Spell>("spells");
+    app.world.load(ModPipeline(key.clone())).unwrap();
+    app.world.save(ModPipeline(key.clone())).unwrap();
+
+    assert_eq!(app.world.resource::<MissingMods>().0[0].name, "gems");
+
+    // Data of the `gems` mod survives
+    let mut app = self::app();
+
+    app.register_mod_type::<Gem>("gems")
+        .register_mod_type::<Spell>("spells");
+    app.world.load(ModPipeline(key.clone())).unwrap();
+
+    assert!(app.world.resource::<MissingMods>().is_empty());
+
+    let mut query = app.world.query::<(&Health, &Gem, &Spell)>();
+    assert_eq!(query.single(&app.world), (&Health(10), &Gem(3), &Spell(7)));
+}
+
+#[test]
+fn test_unknown_resources_cleared() {
+    let mut app = app();
+
+    app.world.spawn(Health(10));
+
+    let snapshot = Snapshot::builder(&app.world).extract_all_entities().build();
+
+    // Left over from an earlier load
+    app.world.insert_resource(UnknownResources::default());
+
+    snapshot
+        .applier(&mut app.world)
+        .sandbox(FromSnapshot(1))
+        .apply()
+        .unwrap();
+
+    assert!(app.world.contains_resource::<UnknownResources>());
+
+    snapshot.applier(&mut app.world).apply().unwrap();
+
+    assert!(!app.world.contains_resource::<UnknownResources>());
+}
@@ -0,0 +1,73 @@
+use std::{
+    cell::Cell,
+    rc::Rc,
+};
+
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+/// Not `Send`, like a handle to a platform API.
+struct Gamepad {
+    sensitivity: Rc<Cell<f32>>,
+}
+
+#[derive(Reflect, Debug, PartialEq)]
+struct GamepadSettings {
+    sensitivity: f32,
+}
+
+#[test]
+fn test_nonsend_saveable() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .insert_non_send_resource(Gamepad {
+            sensitivity: Rc::new(Cell::new(0.5)),
+        })
+        .register_nonsend_saveable(
+            |gamepad: &Gamepad| GamepadSettings {
+                sensitivity: gamepad.sensitivity.get(),
+            },
+            |gamepad: &mut Gamepad, settings: GamepadSettings| {
+                gamepad.sensitivity.set(settings.sensitivity);
+            },
+        );
+
+    let world = &mut app.world;
+
+    let snapshot = Snapshot::builder(world).extract_all_resources().build();
+
+    assert_eq!(
+        snapshot.get_resource::<GamepadSettings>(),
+        Some(GamepadSettings { sensitivity: 0.5 })
+    );
+
+    // Filtered like any other resource
+    let filtered = Snapshot::builder(world)
+        .deny::<GamepadSettings>()
+        .extract_all_resources()
+        .build();
+
+    assert_eq!(filtered.get_resource::<GamepadSettings>(), None);
+
+    let snapshot = Snapshot::builder(world)
+        .extract_resource_by_path(GamepadSettings::type_path())
+        .build();
+
+    let registry = world.resource::<AppTypeRegistry>();
+
+    let mut data = Vec::new();
+    DefaultFormat::serialize(&mut data, &SnapshotSerializer::new(&snapshot, registry)).unwrap();
+
+    let snapshot =
+        DefaultFormat::deserialize(&*data, SnapshotDeserializer::new(&registry.read())).unwrap();
+
+    world
+        .non_send_resource_mut::<Gamepad>()
+        .sensitivity
+        .set(2.0);
+
+    snapshot.applier(world).apply().unwrap();
+
+    assert_eq!(world.non_send_resource::<Gamepad>().sensitivity.get(), 0.5);
+}
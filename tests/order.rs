@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct RigidBody;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Collider;
+
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct Gravity(f32);
+
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct Wind(f32);
+
+fn app() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<RigidBody>()
+        .register_type::<Collider>()
+        .register_type::<Gravity>()
+        .register_type::<Wind>();
+
+    app
+}
+
+/// Returns true if an entity ever had the component `A` without the component `B`.
+fn had_without<A: Component, B: Component>(world: &World) -> bool {
+    let a = world.components().component_id::<A>();
+    let b = world.components().component_id::<B>();
+
+    world.archetypes().iter().any(|archetype| {
+        a.is_some_and(|a| archetype.contains(a)) && !b.is_some_and(|b| archetype.contains(b))
+    })
+}
+
+#[test]
+fn test_component_order() {
+    let mut source = app();
+
+    source.world.spawn((Collider, RigidBody));
+
+    let snapshot = Snapshot::builder(&source.world)
+        .extract_all_entities()
+        .build();
+
+    // Without ordering, components are inserted in their captured order
+    let mut unordered = app();
+
+    snapshot.apply(&mut unordered.world).unwrap();
+
+    assert!(had_without::<Collider, RigidBody>(&unordered.world));
+
+    let mut ordered = app();
+
+    ordered.apply_component_after::<Collider, RigidBody>();
+
+    snapshot.apply(&mut ordered.world).unwrap();
+
+    assert!(!had_without::<Collider, RigidBody>(&ordered.world));
+    assert!(had_without::<RigidBody, Collider>(&ordered.world));
+}
+
+#[test]
+fn test_resource_capture_order() {
+    let save = |app: &App| {
+        let snapshot = Snapshot::builder(&app.world)
+            .extract_resource::<Wind>()
+            .extract_resource::<Gravity>()
+            .build();
+
+        let registry = app.world.resource::<AppTypeRegistry>();
+
+        let mut data = Vec::new();
+        JSONFormat::serialize(&mut data, &SnapshotSerializer::new(&snapshot, registry)).unwrap();
+
+        (snapshot, data)
+    };
+
+    let mut first = app();
+    first
+        .insert_resource(Wind(2.0))
+        .insert_resource(Gravity(9.8));
+
+    let mut second = app();
+    second
+        .insert_resource(Gravity(9.8))
+        .insert_resource(Wind(2.0));
+
+    let (snapshot, data) = save(&first);
+
+    // Resources are sorted by type path, regardless of the order they were added to the world
+    assert!(snapshot.resources[0].represents::<Gravity>());
+    assert!(snapshot.resources[1].represents::<Wind>());
+    assert_eq!(data, save(&second).1);
+}
@@ -0,0 +1,108 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+enum Shape {
+    #[default]
+    Empty,
+    Circle(f32),
+    Rect {
+        w: f32,
+        h: f32,
+    },
+}
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Label {
+    text: String,
+    tag: Option<u32>,
+    target: Option<Entity>,
+}
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Marker;
+
+fn init_app() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Shape>()
+        .register_type::<Label>()
+        .register_type::<Marker>()
+        .register_type::<Option<u32>>()
+        .register_type::<Option<Entity>>();
+
+    let world = &mut app.world;
+
+    let mut previous = None;
+
+    for i in 0..500u32 {
+        let shape = match i % 3 {
+            0 => Shape::Empty,
+            1 => Shape::Circle(i as f32),
+            _ => Shape::Rect { w: 1.5, h: 2.5 },
+        };
+
+        let mut entity = world.spawn((
+            shape,
+            Label {
+                text: format!("entity {i}"),
+                tag: (i % 2 == 0).then_some(i),
+                target: previous,
+            },
+            Transform::from_xyz(i as f32, 0.0, 0.0),
+        ));
+
+        if i % 5 == 0 {
+            entity.insert(Marker);
+        }
+
+        previous = Some(entity.id());
+    }
+
+    app
+}
+
+fn roundtrip<F: Format>(world: &World, parallel: bool) -> Vec<u8> {
+    let registry = world.resource::<AppTypeRegistry>();
+    let snapshot = Snapshot::builder(world).extract_all_entities().build();
+
+    let mut data = Vec::new();
+    F::serialize(&mut data, &SnapshotSerializer::new(&snapshot, registry)).unwrap();
+
+    let loaded = F::deserialize(
+        &*data,
+        SnapshotDeserializer::new(&registry.read()).parallel(parallel),
+    )
+    .unwrap();
+
+    assert_eq!(loaded.entities.len(), snapshot.entities.len());
+
+    let mut output = Vec::new();
+    F::serialize(&mut output, &SnapshotSerializer::new(&loaded, registry)).unwrap();
+
+    output
+}
+
+#[test]
+fn test_parallel_json() {
+    let app = init_app();
+
+    assert_eq!(
+        roundtrip::<JSONFormat>(&app.world, false),
+        roundtrip::<JSONFormat>(&app.world, true)
+    );
+}
+
+#[test]
+fn test_parallel_mp() {
+    let app = init_app();
+
+    assert_eq!(
+        roundtrip::<RMPFormat>(&app.world, false),
+        roundtrip::<RMPFormat>(&app.world, true)
+    );
+}
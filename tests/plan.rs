@@ -0,0 +1,88 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Health(u32);
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Mana(u32);
+
+#[test]
+fn test_plan_new_archetype() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Health>()
+        .register_type::<Mana>();
+
+    let world = &mut app.world;
+
+    world.spawn(Health(1));
+
+    let mut plan = Snapshot::builder(world).plan();
+
+    // Spawned in an archetype that did not exist when the plan was built
+    world.spawn((Health(2), Mana(3)));
+
+    assert!(!plan.is_valid(world));
+
+    let snapshot = Snapshot::builder(world)
+        .extract_with_plan(&mut plan)
+        .build();
+
+    assert!(plan.is_valid(world));
+    assert_eq!(snapshot.entities.len(), 2);
+
+    let mana = snapshot
+        .entities
+        .iter()
+        .flat_map(|e| &e.components)
+        .filter(|c| c.represents::<Mana>())
+        .filter_map(|c| Mana::from_reflect(&**c))
+        .map(|m| m.0)
+        .collect::<Vec<_>>();
+
+    assert_eq!(mana, [3]);
+}
+
+#[test]
+fn test_plan_rollback_registry_changed() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Health>()
+        .register_type::<Mana>()
+        .allow_rollback::<Health>()
+        .allow_rollback::<Mana>();
+
+    let world = &mut app.world;
+
+    world.spawn((Health(1), Mana(2)));
+
+    let mut plan = SnapshotBuilder::rollback(world).plan();
+
+    assert!(plan.is_valid(world));
+
+    world.resource_mut::<RollbackRegistry>().deny::<Mana>();
+
+    assert!(!plan.is_valid(world));
+
+    let snapshot = SnapshotBuilder::rollback(world)
+        .extract_with_plan(&mut plan)
+        .build();
+
+    assert!(plan.is_valid(world));
+    assert!(snapshot.entities[0]
+        .components
+        .iter()
+        .all(|c| !c.represents::<Mana>()));
+
+    // Plans that do not filter by the rollback registry are unaffected
+    let plan = Snapshot::builder(world).plan();
+
+    world.resource_mut::<RollbackRegistry>().allow::<Mana>();
+
+    assert!(plan.is_valid(world));
+}
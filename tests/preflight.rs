@@ -0,0 +1,98 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Health(u32);
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Mana(u32);
+
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct Score(u32);
+
+struct Slot(&'static str);
+
+impl Pipeline for Slot {
+    type Backend = DirBackend;
+    type Format = DefaultDebugFormat;
+
+    type Key<'k> = &'k str;
+
+    fn key(&self) -> Self::Key<'_> {
+        self.0
+    }
+
+    fn capture(builder: SnapshotBuilder) -> Snapshot {
+        builder
+            .extract_all_entities()
+            .extract_resource::<Score>()
+            .build()
+    }
+}
+
+fn app(root: &std::path::Path) -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .init_pipeline::<Slot>()
+        .insert_resource(DirBackend::new(root))
+        .register_type::<Health>()
+        .register_type::<Score>();
+
+    app
+}
+
+#[test]
+fn test_preflight_load() {
+    let root = std::env::temp_dir().join("bevy_save_preflight");
+    let _ = std::fs::remove_dir_all(&root);
+
+    let mut old = app(&root);
+    old.register_type::<Mana>();
+
+    old.world.insert_resource(Score(10));
+    old.world.spawn((Health(5), Mana(3)));
+    old.world.spawn(Mana(1));
+    old.world.save(Slot("slot")).unwrap();
+
+    let mut new = app(&root);
+    let world = &mut new.world;
+
+    world.insert_resource(Score(0));
+
+    let report = world.preflight_load(Slot("slot"));
+
+    // Each unregistered type is reported once
+    assert_eq!(report.issues.len(), 1);
+    assert!(matches!(
+        &report.issues[0],
+        PreflightIssue::Unregistered { type_path } if type_path == "preflight::Mana"
+    ));
+
+    // Nothing was applied
+    assert_eq!(world.resource::<Score>().0, 0);
+    assert_eq!(world.entities().len(), 0);
+
+    // The report agrees with the load
+    assert!(world.load(Slot("slot")).is_err());
+
+    let report = world.preflight_load(Slot("missing"));
+
+    assert!(matches!(&report.issues[..], [PreflightIssue::Unreadable(
+        _
+    )]));
+
+    // Saves without issues load
+    world.spawn(Health(7));
+    world.save(Slot("other")).unwrap();
+
+    let report = world.preflight_load(Slot("other"));
+
+    assert!(report.is_ok(), "{report}");
+    world.load(Slot("other")).unwrap();
+
+    std::fs::remove_dir_all(root).unwrap();
+}
@@ -0,0 +1,100 @@
+use bevy::{
+    ecs::entity::EntityHashMap,
+    prelude::*,
+};
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Health(u32);
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Poisoned;
+
+fn setup() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Health>()
+        .register_type::<Poisoned>();
+
+    app
+}
+
+#[test]
+fn test_preserve_entities() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    let player = world.spawn(Health(100)).id();
+    let enemy = world.spawn(Health(50)).id();
+
+    let snapshot = Snapshot::builder(world).extract_all_entities().build();
+
+    world.entity_mut(player).insert((Health(10), Poisoned));
+    world.entity_mut(enemy).insert(Health(0));
+
+    snapshot.applier(world).preserve_entities().apply().unwrap();
+
+    // Updated in place, without spawning new entities
+    assert_eq!(world.entities().len(), 2);
+    assert_eq!(world.get::<Health>(player), Some(&Health(100)));
+    assert_eq!(world.get::<Health>(enemy), Some(&Health(50)));
+
+    // Components added since the capture are kept
+    assert!(world.get::<Poisoned>(player).is_some());
+}
+
+#[test]
+fn test_preserve_entities_respawn() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    let player = world.spawn(Health(100)).id();
+    let enemy = world.spawn(Health(50)).id();
+
+    let snapshot = Snapshot::builder(world).extract_all_entities().build();
+
+    world.despawn(enemy);
+
+    // Reuses the index of the enemy with a newer generation
+    let other = world.spawn(Poisoned).id();
+    assert_eq!(other.index(), enemy.index());
+
+    let mut entity_map = EntityHashMap::default();
+
+    snapshot
+        .applier(world)
+        .preserve_entities()
+        .entity_map(&mut entity_map)
+        .apply()
+        .unwrap();
+
+    assert_eq!(entity_map[&player], player);
+
+    // The despawned enemy was respawned instead of overwriting the newer entity
+    let respawned = entity_map[&enemy];
+
+    assert_ne!(respawned, other);
+    assert_eq!(world.get::<Health>(respawned), Some(&Health(50)));
+    assert_eq!(world.get::<Health>(other), None);
+    assert_eq!(world.entities().len(), 3);
+}
+
+#[test]
+fn test_preserve_entities_disabled() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    let player = world.spawn(Health(100)).id();
+
+    let snapshot = Snapshot::builder(world).extract_all_entities().build();
+
+    snapshot.apply(world).unwrap();
+
+    // Without preserving, a copy is spawned
+    assert_eq!(world.entities().len(), 2);
+    assert_eq!(world.query::<&Health>().iter(world).count(), 2);
+    assert_eq!(world.get::<Health>(player), Some(&Health(100)));
+}
@@ -0,0 +1,92 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Position {
+    x: f32,
+    y: f64,
+}
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component, ExactFloats)]
+struct Seed(f64);
+
+#[test]
+fn test_quantize() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Position>()
+        .register_type::<Seed>();
+
+    let world = &mut app.world;
+
+    let entity = world
+        .spawn((
+            Position {
+                x: 1.23456,
+                y: 9.87654,
+            },
+            Seed(0.123456789),
+        ))
+        .id();
+
+    let mut snapshot = Snapshot::builder(world).extract_entity(entity).build();
+
+    snapshot.quantize(
+        FloatPrecision::Decimals(2),
+        &world.resource::<AppTypeRegistry>().read(),
+    );
+
+    snapshot
+        .applier(world)
+        .entity_map(&mut [(entity, entity)].into_iter().collect())
+        .apply()
+        .unwrap();
+
+    assert_eq!(
+        world.entity(entity).get::<Position>(),
+        Some(&Position { x: 1.23, y: 9.88 })
+    );
+    assert_eq!(world.entity(entity).get::<Seed>(), Some(&Seed(0.123456789)));
+}
+
+#[test]
+fn test_significant_bits() {
+    let precision = FloatPrecision::SignificantBits(10);
+
+    assert_eq!(precision.quantize_f32(1.0), 1.0);
+    assert_eq!(precision.quantize_f32(1.0001), 1.0);
+    assert_eq!(precision.quantize_f64(-2.0004), -2.0);
+    assert!(precision.quantize_f32(f32::NAN).is_nan());
+}
+
+#[test]
+fn test_quantize_stays_finite() {
+    // Rounding the mantissa up would carry into the exponent
+    for bits in [0, 10, 22] {
+        let precision = FloatPrecision::SignificantBits(bits);
+
+        assert_eq!(precision.quantize_f32(f32::MAX), f32::MAX);
+        assert_eq!(precision.quantize_f32(f32::MIN), f32::MIN);
+        assert_eq!(precision.quantize_f64(f64::MAX), f64::MAX);
+        assert_eq!(precision.quantize_f64(f64::MIN), f64::MIN);
+    }
+
+    // The scale overflows
+    for places in [400, i32::MAX as u32 + 1, u32::MAX] {
+        let precision = FloatPrecision::Decimals(places);
+
+        assert_eq!(precision.quantize_f64(1.5), 1.5);
+        assert_eq!(precision.quantize_f64(0.0), 0.0);
+        assert_eq!(precision.quantize_f32(1.5), 1.5);
+    }
+
+    // The scaled value overflows
+    let precision = FloatPrecision::Decimals(2);
+
+    assert_eq!(precision.quantize_f64(f64::MAX), f64::MAX);
+    assert_eq!(precision.quantize_f32(f32::MAX), f32::MAX);
+    assert_eq!(precision.quantize_f64(1.234), 1.23);
+}
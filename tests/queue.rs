@@ -0,0 +1,93 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Resource, Default)]
+struct Log(Vec<&'static str>);
+
+fn record(name: &'static str) -> impl FnOnce(&mut World) -> Result<(), bevy_save::Error> {
+    move |world| {
+        world.resource_mut::<Log>().0.push(name);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_queue_groups() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .init_resource::<Log>();
+
+    let mut queue = app.world.resource_mut::<SaveQueue>();
+
+    queue.push(record("a"));
+    queue.push_grouped(Some("world"), 0, record("world load"));
+    queue.push_grouped(Some("settings"), 10, record("settings save"));
+    queue.push(record("b"));
+    queue.push_grouped(Some("world"), 0, record("world save"));
+
+    assert_eq!(queue.pending_in("world"), 2);
+
+    app.update();
+
+    assert!(app.world.resource::<SaveQueue>().is_empty());
+    assert_eq!(app.world.resource::<Log>().0, [
+        "settings save",
+        "a",
+        "world load",
+        "world save",
+        "b",
+    ]);
+}
+
+#[test]
+fn test_checkpoint_every() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .checkpoint_every::<DebugPipeline>(3);
+
+    for _ in 0..6 {
+        app.update();
+    }
+
+    assert_eq!(app.world.resource::<Rollbacks>().len(), 2);
+
+    app.world
+        .resource_mut::<CaptureThrottle<DebugPipeline>>()
+        .request();
+    app.update();
+
+    assert_eq!(app.world.resource::<Rollbacks>().len(), 3);
+}
+
+#[test]
+fn test_on_exit_save() {
+    let path = std::env::temp_dir().join("bevy_save_exit");
+    let key: &'static str = Box::leak(path.to_string_lossy().into_owned().into_boxed_str());
+
+    let _ = std::fs::remove_file(format!("{key}.json"));
+
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .init_resource::<Log>()
+        .on_exit_save(DebugPipeline(key));
+
+    app.world
+        .resource_mut::<SaveQueue>()
+        .push(record("pending"));
+
+    app.update();
+
+    assert_eq!(app.world.resource::<Log>().0, ["pending"]);
+    assert!(!std::path::Path::new(&format!("{key}.json")).exists());
+
+    app.world.resource_mut::<SaveQueue>().push(record("exit"));
+    app.world.send_event(bevy::app::AppExit);
+
+    app.update();
+
+    assert_eq!(app.world.resource::<Log>().0, ["pending", "exit"]);
+    assert!(std::path::Path::new(&format!("{key}.json")).exists());
+}
@@ -0,0 +1,94 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct Score(u32);
+
+#[derive(Clone)]
+struct QuickPipeline;
+
+impl Pipeline for QuickPipeline {
+    type Backend = DefaultBackend;
+    type Format = DefaultFormat;
+
+    type Key<'a> = &'a str;
+
+    fn key(&self) -> Self::Key<'_> {
+        "quick"
+    }
+
+    fn capture(builder: SnapshotBuilder) -> Snapshot {
+        builder.extract_resource::<Score>().build()
+    }
+}
+
+fn drain_events(app: &mut App) -> Vec<QuickSaveEvent> {
+    app.world
+        .resource_mut::<Events<QuickSaveEvent>>()
+        .drain()
+        .collect()
+}
+
+#[test]
+fn test_quick_save() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .add_plugins(QuickSavePlugin::new(QuickPipeline).bind(KeyCode::F5, QuickAction::Checkpoint))
+        .init_pipeline::<QuickPipeline>()
+        .register_type::<Score>()
+        .allow_rollback::<Score>()
+        .init_resource::<Score>()
+        .init_resource::<ButtonInput<KeyCode>>();
+
+    app.world
+        .resource_mut::<ButtonInput<KeyCode>>()
+        .press(KeyCode::F5);
+
+    app.update();
+
+    assert_eq!(drain_events(&mut app), [
+        QuickSaveEvent::Started(QuickAction::Checkpoint),
+        QuickSaveEvent::Finished(QuickAction::Checkpoint),
+    ]);
+    assert_eq!(app.world.resource::<Rollbacks>().len(), 1);
+
+    app.world.resource_mut::<ButtonInput<KeyCode>>().clear();
+
+    app.world.resource_mut::<Score>().0 = 5;
+    app.world
+        .send_event(QuickSaveRequest(QuickAction::Checkpoint));
+    app.update();
+
+    // Only one action can be in progress at a time
+    app.world
+        .send_event(QuickSaveRequest(QuickAction::Rollback));
+    app.world.send_event(QuickSaveRequest(QuickAction::Save));
+    app.update();
+
+    let events = drain_events(&mut app);
+
+    assert!(events.contains(&QuickSaveEvent::Finished(QuickAction::Rollback)));
+    assert!(events.contains(&QuickSaveEvent::Rejected(QuickAction::Save)));
+    assert_eq!(app.world.resource::<Score>().0, 0);
+    assert!(app
+        .world
+        .resource::<QuickSave<QuickPipeline>>()
+        .pending()
+        .is_none());
+
+    app.world
+        .resource_mut::<QuickSave<QuickPipeline>>()
+        .set_cooldown(Duration::from_secs(3600));
+
+    app.world
+        .send_event(QuickSaveRequest(QuickAction::Rollforward));
+    app.update();
+
+    assert_eq!(drain_events(&mut app), [QuickSaveEvent::Rejected(
+        QuickAction::Rollforward
+    )]);
+}
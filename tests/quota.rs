@@ -0,0 +1,147 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+use bevy_save::{
+    prelude::*,
+    Error,
+    ErrorCode,
+};
+
+struct SlotPipeline(&'static str);
+
+impl Pipeline for SlotPipeline {
+    type Backend = Quota<DirBackend>;
+    type Format = DefaultFormat;
+
+    type Key<'a> = &'a str;
+
+    fn key(&self) -> Self::Key<'_> {
+        self.0
+    }
+}
+
+fn save(app: &mut App, key: &'static str) -> Result<(), Error> {
+    // Slots are ordered by modification time
+    std::thread::sleep(Duration::from_millis(10));
+
+    app.world.save(SlotPipeline(key))
+}
+
+fn slots(app: &App) -> Vec<String> {
+    let mut slots = app
+        .world
+        .resource::<Quota<DirBackend>>()
+        .inner()
+        .slots()
+        .unwrap()
+        .into_iter()
+        .map(|s| s.name)
+        .collect::<Vec<_>>();
+
+    slots.sort();
+    slots
+}
+
+#[test]
+fn test_quota() {
+    let dir = std::env::temp_dir().join("bevy_save_test_quota");
+    let _ = std::fs::remove_dir_all(&dir);
+
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .init_pipeline::<SlotPipeline>()
+        .save_quota::<DirBackend>(SaveQuota::default());
+
+    // Store saves in a temporary directory
+    app.insert_resource(Quota::new(
+        DirBackend::new(&dir),
+        SaveQuota::default()
+            .max_slots(2)
+            .policy(QuotaPolicy::DeleteOldestAutosave),
+    ));
+
+    save(&mut app, "alice/autosave_1/snapshot").unwrap();
+    save(&mut app, "alice/autosave_2/snapshot").unwrap();
+
+    // Profiles have separate quotas
+    save(&mut app, "bob/manual/snapshot").unwrap();
+
+    // Overwriting a slot does not count as a new slot
+    save(&mut app, "alice/autosave_1/snapshot").unwrap();
+
+    assert_eq!(slots(&app), [
+        "alice/autosave_1",
+        "alice/autosave_2",
+        "bob/manual"
+    ]);
+
+    // The oldest autosave makes room for the new save
+    save(&mut app, "alice/manual/snapshot").unwrap();
+
+    assert_eq!(slots(&app), [
+        "alice/autosave_1",
+        "alice/manual",
+        "bob/manual"
+    ]);
+
+    app.update();
+
+    let cleanups = app
+        .world
+        .resource_mut::<Events<SlotCleanup>>()
+        .drain()
+        .collect::<Vec<_>>();
+
+    assert_eq!(cleanups.len(), 1);
+    assert_eq!(cleanups[0].slot.name, "alice/autosave_2");
+    assert_eq!(cleanups[0].saved, "alice/manual");
+
+    // Manual saves are never deleted
+    save(&mut app, "bob/other/snapshot").unwrap();
+
+    let err = save(&mut app, "bob/autosave_1/snapshot").unwrap_err();
+
+    assert_eq!(err.code(), ErrorCode::QuotaExceeded);
+    assert_eq!(slots(&app), [
+        "alice/autosave_1",
+        "alice/manual",
+        "bob/manual",
+        "bob/other"
+    ]);
+
+    // Saves larger than the byte quota are refused
+    app.world
+        .resource_mut::<Quota<DirBackend>>()
+        .set_quota(SaveQuota::default().max_bytes(1));
+
+    assert!(matches!(
+        save(&mut app, "carol/manual/snapshot"),
+        Err(Error::QuotaExceeded)
+    ));
+
+    // Saves that cannot fit even without autosaves delete nothing
+    app.world.resource_mut::<Quota<DirBackend>>().set_quota(
+        SaveQuota::default()
+            .max_bytes(1)
+            .policy(QuotaPolicy::DeleteOldestAutosave),
+    );
+
+    assert!(matches!(
+        save(&mut app, "alice/other/snapshot"),
+        Err(Error::QuotaExceeded)
+    ));
+    assert_eq!(slots(&app), [
+        "alice/autosave_1",
+        "alice/manual",
+        "bob/manual",
+        "bob/other"
+    ]);
+    assert!(app
+        .world
+        .resource::<Quota<DirBackend>>()
+        .take_cleanups()
+        .is_empty());
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
@@ -0,0 +1,110 @@
+use bevy::{
+    ecs::entity::EntityHashMap,
+    prelude::*,
+};
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Chunk(u32);
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Player;
+
+#[derive(Resource, Reflect, Default, Debug, PartialEq)]
+#[reflect(Resource)]
+struct Seed(u64);
+
+fn setup() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Chunk>()
+        .register_type::<Player>()
+        .register_type::<Seed>();
+
+    app
+}
+
+fn save<F: Format>(world: &mut World) -> Vec<u8> {
+    for i in 0..50 {
+        world.spawn(Chunk(i));
+    }
+
+    world.spawn((Chunk(50), Player));
+    world.insert_resource(Seed(7));
+
+    let snapshot = Snapshot::builder(world)
+        .extract_all_entities()
+        .extract_resource::<Seed>()
+        .build();
+
+    let mut data = Vec::new();
+    Stamped::<F>::serialize(
+        &mut data,
+        &SnapshotSerializer::new(&snapshot, world.resource::<AppTypeRegistry>()),
+    )
+    .unwrap();
+
+    data
+}
+
+fn check<F: Format>() {
+    let mut app = setup();
+    let data = save::<F>(&mut app.world);
+
+    let reader = SnapshotReader::from_reader::<F>(&*data).unwrap();
+
+    assert_eq!(reader.len(), 51);
+
+    let players = reader.entities_with::<Player>().collect::<Vec<_>>();
+
+    assert_eq!(players.len(), 1);
+    assert_eq!(reader.component_types(players[0]).unwrap().count(), 2);
+
+    let mut other = setup();
+    let world = &mut other.world;
+
+    let registry = world.resource::<AppTypeRegistry>().clone();
+
+    // Only the requested entities are applied
+    let mut entity_map = EntityHashMap::default();
+
+    reader
+        .apply_entities(world, players.iter().copied(), &mut entity_map)
+        .unwrap();
+
+    assert_eq!(world.entities().len(), 1);
+    assert_eq!(
+        world.get::<Chunk>(entity_map[&players[0]]),
+        Some(&Chunk(50))
+    );
+
+    let chunks = reader.entities().take(10).collect::<Vec<_>>();
+
+    reader
+        .apply_entities(world, chunks, &mut entity_map)
+        .unwrap();
+
+    assert_eq!(world.entities().len(), 11);
+
+    let snapshot = reader
+        .read_entities_with::<Player>(&registry.read())
+        .unwrap();
+
+    assert_eq!(snapshot.entities.len(), 1);
+
+    let snapshot = reader.read_resources(&registry.read()).unwrap();
+
+    assert_eq!(snapshot.get_resource::<Seed>(), Some(Seed(7)));
+}
+
+#[test]
+fn test_reader() {
+    check::<RMPFormat>();
+    check::<JSONFormat>();
+
+    // Formats that are not self-describing cannot be indexed
+    assert!(SnapshotReader::from_reader::<RONFormat>(&[][..]).is_err());
+}
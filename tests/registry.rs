@@ -0,0 +1,60 @@
+use std::any::TypeId;
+
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct Score(u32);
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Velocity(f32);
+
+#[test]
+fn test_rollback_config() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Score>()
+        .register_type::<Velocity>()
+        .allow_rollback::<Score>()
+        .init_resource::<Score>();
+
+    let world = &mut app.world;
+
+    let registry = world.resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+
+    let allowed = |world: &World| {
+        world
+            .resource::<RollbackRegistry>()
+            .allowed(&registry)
+            .map(|r| r.type_info().type_path())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(allowed(world), vec![Score::type_path()]);
+
+    // Toggle at runtime, e.g. from a debug menu
+    let mut rollbacks = world.resource_mut::<RollbackRegistry>();
+    rollbacks.set_allowed_by_id(TypeId::of::<Velocity>(), true);
+    rollbacks.set_allowed_by_id(TypeId::of::<Score>(), false);
+
+    assert_eq!(allowed(world), vec![Velocity::type_path()]);
+
+    let config = world.resource::<RollbackRegistry>().config(&registry);
+
+    assert!(config.denied.iter().any(|t| t == Score::type_path()));
+
+    let snapshot = Snapshot::builder(world)
+        .extract_all_resources()
+        .extract_rollback_config()
+        .build();
+
+    assert_eq!(snapshot.rollback_config(), Some(config));
+
+    snapshot.apply(world).unwrap();
+
+    assert!(world.get_resource::<RollbackConfig>().is_none());
+}
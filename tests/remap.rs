@@ -0,0 +1,81 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+/// Stored health before it moved into `Stats`.
+#[derive(Reflect, Default)]
+struct OldHealth(u32);
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Stats {
+    health: u32,
+    armor: u32,
+}
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Dead;
+
+#[test]
+fn test_remap_component() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Stats>()
+        .register_type::<Dead>()
+        .remap_component(|old: OldHealth, entity: &mut EntityWorldMut| {
+            if let Some(mut stats) = entity.get_mut::<Stats>() {
+                stats.health = old.0;
+            }
+
+            if old.0 == 0 {
+                entity.insert(Dead);
+            }
+        });
+
+    let world = &mut app.world;
+
+    let alive = world.spawn_empty().id();
+    let dead = world.spawn_empty().id();
+
+    // The remapped type is captured before the target component
+    let mut snapshot = Snapshot::builder(world).build();
+    snapshot.insert_component(alive, OldHealth(80));
+    snapshot.insert_component(alive, Stats {
+        health: 0,
+        armor: 5,
+    });
+    snapshot.insert_component(dead, OldHealth(0));
+    snapshot.insert_component(dead, Stats::default());
+
+    let registry = world.resource::<AppTypeRegistry>();
+
+    let mut data = Vec::new();
+    DefaultFormat::serialize(&mut data, &SnapshotSerializer::new(&snapshot, registry)).unwrap();
+
+    let snapshot =
+        DefaultFormat::deserialize(&*data, SnapshotDeserializer::new(&registry.read())).unwrap();
+
+    world.clear_entities();
+
+    let mut entity_map = Default::default();
+
+    snapshot
+        .applier(world)
+        .entity_map(&mut entity_map)
+        .apply()
+        .unwrap();
+
+    let alive = entity_map[&alive];
+    let dead = entity_map[&dead];
+
+    assert_eq!(
+        world.get::<Stats>(alive),
+        Some(&Stats {
+            health: 80,
+            armor: 5
+        })
+    );
+    assert!(world.get::<Dead>(alive).is_none());
+    assert!(world.get::<Dead>(dead).is_some());
+}
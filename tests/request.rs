@@ -0,0 +1,75 @@
+use bevy::prelude::*;
+use bevy_save::{
+    prelude::*,
+    testing::{
+        FlakyBackend,
+        NoopBackend,
+    },
+};
+
+#[derive(Resource)]
+struct InCombat;
+
+struct Slot;
+
+impl Pipeline for Slot {
+    type Backend = FlakyBackend<NoopBackend>;
+    type Format = DefaultFormat;
+
+    type Key<'a> = &'a str;
+
+    fn key(&self) -> Self::Key<'_> {
+        "slot"
+    }
+}
+
+fn no_saves_in_combat(mut requests: EventReader<SaveRequested>, combat: Option<Res<InCombat>>) {
+    for request in requests.read() {
+        if combat.is_some() {
+            request.cancel_token.cancel("in combat");
+        }
+    }
+}
+
+fn operations(app: &App) -> usize {
+    app.world
+        .resource::<FlakyBackend<NoopBackend>>()
+        .operations()
+}
+
+#[test]
+fn test_request_save() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .init_pipeline::<Slot>()
+        .add_systems(Update, no_saves_in_combat);
+
+    // Requests are not performed immediately
+    let token = app.world.request_save(Slot);
+
+    assert_eq!(operations(&app), 0);
+
+    app.update();
+
+    assert!(!token.is_cancelled());
+    assert_eq!(operations(&app), 1);
+
+    // Vetoed requests are never performed
+    app.world.insert_resource(InCombat);
+
+    let token = app.world.request_save(Slot);
+
+    app.update();
+
+    assert_eq!(token.reason().as_deref(), Some("in combat"));
+    assert_eq!(operations(&app), 1);
+
+    // Loads are not affected by save vetoes
+    let token = app.world.request_load(Slot);
+
+    app.update();
+
+    assert!(!token.is_cancelled());
+    assert_eq!(operations(&app), 2);
+}
@@ -0,0 +1,103 @@
+use std::io::ErrorKind;
+
+use bevy::prelude::*;
+use bevy_save::{
+    prelude::*,
+    testing::{
+        FlakyBackend,
+        NoopBackend,
+    },
+    Error,
+};
+
+struct Slot;
+
+impl Pipeline for Slot {
+    type Backend = FlakyBackend<NoopBackend>;
+    type Format = DefaultFormat;
+
+    type Key<'a> = &'a str;
+
+    fn key(&self) -> Self::Key<'_> {
+        "slot"
+    }
+
+    fn retry_policy() -> RetryPolicy {
+        RetryPolicy::new(3)
+    }
+}
+
+fn retries(app: &mut App) -> Vec<BackendRetry> {
+    app.world
+        .resource_mut::<Events<BackendRetry>>()
+        .drain()
+        .collect()
+}
+
+#[test]
+fn test_retry_policy() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .init_pipeline::<Slot>();
+
+    app.world
+        .resource_mut::<FlakyBackend<NoopBackend>>()
+        .set_fail_every(2)
+        .set_error(|| Error::IO(ErrorKind::TimedOut.into()));
+
+    // The second operation fails and is retried
+    app.world.save(Slot).unwrap();
+    app.world.save(Slot).unwrap();
+
+    app.update();
+
+    let events = retries(&mut app);
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].operation, BackendOperation::Save);
+    assert_eq!(events[0].attempt, 1);
+    assert!(events[0].pipeline.ends_with("Slot"));
+
+    let backend = app.world.resource::<FlakyBackend<NoopBackend>>();
+
+    assert_eq!(backend.operations(), 3);
+    assert_eq!(backend.failures(), 1);
+
+    // Gives up after the maximum number of attempts
+    app.world
+        .resource_mut::<FlakyBackend<NoopBackend>>()
+        .set_fail_every(1);
+
+    assert!(app.world.load(Slot).is_err());
+
+    app.update();
+
+    let events = retries(&mut app);
+
+    assert_eq!(events.len(), 2);
+    assert!(events.iter().all(|e| e.operation == BackendOperation::Load));
+    assert_eq!(
+        app.world
+            .resource::<FlakyBackend<NoopBackend>>()
+            .operations(),
+        3
+    );
+
+    // Errors that are not transient are returned immediately
+    app.world
+        .resource_mut::<FlakyBackend<NoopBackend>>()
+        .set_fail_every(0);
+
+    assert!(app.world.load(Slot).is_err());
+
+    app.update();
+
+    assert!(retries(&mut app).is_empty());
+    assert_eq!(
+        app.world
+            .resource::<FlakyBackend<NoopBackend>>()
+            .operations(),
+        1
+    );
+}
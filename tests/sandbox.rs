@@ -0,0 +1,136 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Position(f32);
+
+#[derive(Resource, Reflect, Default, Debug, PartialEq)]
+#[reflect(Resource)]
+struct Score(u32);
+
+#[test]
+fn test_sandbox() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Position>()
+        .register_type::<Score>()
+        .register_type::<Parent>()
+        .register_type::<Children>()
+        .insert_resource(Score(1));
+
+    let world = &mut app.world;
+
+    let player = world
+        .spawn(Position(0.0))
+        .with_children(|p| {
+            p.spawn(Position(1.0));
+        })
+        .id();
+
+    let snapshot = Snapshot::builder(world)
+        .extract_all_entities()
+        .extract_resource::<Score>()
+        .build();
+
+    world.entity_mut(player).insert(Position(10.0));
+    world.insert_resource(Score(5));
+
+    let report = ApplyReport {
+        rejected: vec![RejectedValue {
+            entity: Some(player),
+            type_path: "Position".into(),
+            reason: "out of bounds".into(),
+        }],
+    };
+
+    world.insert_resource(report.clone());
+
+    for id in [1, 2] {
+        snapshot
+            .applier(world)
+            .despawn::<Without<FromSnapshot>>()
+            .preserve_entities()
+            .sandbox(FromSnapshot(id))
+            .apply()
+            .unwrap();
+    }
+
+    // The live world is untouched
+    assert_eq!(world.get::<Position>(player), Some(&Position(10.0)));
+    assert_eq!(world.resource::<Score>(), &Score(5));
+    assert_eq!(world.resource::<ApplyReport>(), &report);
+
+    for id in [1, 2] {
+        let mut ghosts = world
+            .query::<(Entity, &Position, &FromSnapshot)>()
+            .iter(world)
+            .filter(|(_, _, s)| s.0 == id)
+            .map(|(e, p, _)| (e, p.0))
+            .collect::<Vec<_>>();
+
+        ghosts.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        assert_eq!(ghosts.len(), 2);
+        assert_eq!(ghosts[0].1, 0.0);
+
+        // The hierarchy is rebuilt between the sandboxed entities
+        assert_eq!(
+            world.get::<Parent>(ghosts[1].0).map(Parent::get),
+            Some(ghosts[0].0)
+        );
+    }
+
+    assert_eq!(world.entities().len(), 6);
+}
+
+#[test]
+fn test_sandbox_reparent() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Position>()
+        .register_type::<Parent>()
+        .register_type::<Children>();
+
+    let world = &mut app.world;
+
+    let mut child = Entity::PLACEHOLDER;
+
+    world.spawn(Position(0.0)).with_children(|p| {
+        child = p.spawn(Position(1.0)).id();
+    });
+
+    let snapshot = Snapshot::builder(world).extract_entity(child).build();
+
+    let live = world.spawn_empty().id();
+
+    snapshot
+        .applier(world)
+        .missing_parent(MissingParentPolicy::ReparentTo(live))
+        .sandbox(FromSnapshot(1))
+        .apply()
+        .unwrap();
+
+    // The orphan gets a sandboxed parent instead of the live entity
+    assert!(world.get::<Children>(live).is_none());
+
+    let (ghost, parent) = world
+        .query_filtered::<(Entity, &Parent), (With<Position>, With<FromSnapshot>)>()
+        .single(world);
+
+    assert_ne!(parent.get(), live);
+    assert!(world.get::<FromSnapshot>(parent.get()).is_some());
+    assert_eq!(world.get::<Position>(ghost), Some(&Position(1.0)));
+
+    // The parent does not have to exist in sandbox mode
+    world.despawn(live);
+
+    snapshot
+        .applier(world)
+        .missing_parent(MissingParentPolicy::ReparentTo(live))
+        .sandbox(FromSnapshot(2))
+        .apply()
+        .unwrap();
+}
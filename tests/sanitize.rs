@@ -0,0 +1,137 @@
+use bevy::prelude::*;
+use bevy_save::{
+    prelude::*,
+    Error,
+};
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component, Validate)]
+struct Health(f32);
+
+impl Validate for Health {
+    fn validate(&mut self) -> Result<(), String> {
+        if self.0 < 0.0 {
+            return Err("health is negative".into());
+        }
+
+        self.0 = self.0.min(100.0);
+        Ok(())
+    }
+}
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Admin;
+
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct Gold(u32);
+
+struct Shared;
+
+impl Pipeline for Shared {
+    type Backend = DirBackend;
+    type Format = DefaultDebugFormat;
+
+    type Key<'k> = &'k str;
+
+    fn key(&self) -> Self::Key<'_> {
+        "shared"
+    }
+
+    fn capture(builder: SnapshotBuilder) -> Snapshot {
+        builder
+            .extract_all_entities()
+            .extract_resource::<Gold>()
+            .extract_extra("author", "player")
+            .extract_extra("script", "rm -rf /")
+            .build()
+    }
+
+    fn apply(world: &mut World, snapshot: &Snapshot) -> Result<(), Error> {
+        snapshot.applier(world).despawn::<With<Health>>().apply()
+    }
+}
+
+fn policy() -> SanitizePolicy {
+    SanitizePolicy::new()
+        .allow::<Health>()
+        .allow::<Gold>()
+        .allow_extra("author")
+}
+
+fn app(root: &std::path::Path) -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .init_pipeline::<Shared>()
+        .insert_resource(DirBackend::new(root))
+        .register_type::<Health>()
+        .register_type::<Admin>()
+        .register_type::<Gold>();
+
+    app
+}
+
+#[test]
+fn test_sanitize() {
+    let root = std::env::temp_dir().join("bevy_save_sanitize");
+    let _ = std::fs::remove_dir_all(&root);
+
+    let mut app = app(&root);
+    let world = &mut app.world;
+
+    world.insert_resource(Gold(10));
+    let boosted = world.spawn((Health(500.0), Admin)).id();
+    let corrupted = world.spawn(Health(-1.0)).id();
+
+    let mut snapshot = world.capture(Shared);
+    let report = snapshot
+        .sanitize(&policy(), world.resource::<AppTypeRegistry>())
+        .unwrap();
+
+    assert!(!report.is_clean());
+    assert_eq!(report.disallowed.iter().collect::<Vec<_>>(), [
+        "sanitize::Admin"
+    ]);
+    assert_eq!(report.rejected.len(), 1);
+    assert_eq!(report.rejected[0].entity, Some(corrupted));
+    assert_eq!(report.stripped_extras.iter().collect::<Vec<_>>(), [
+        "script"
+    ]);
+
+    // Allowed values are validated and kept
+    assert_eq!(snapshot.get_component::<Health>(boosted).unwrap().0, 100.0);
+    assert!(snapshot.get_component::<Admin>(boosted).is_none());
+    assert_eq!(snapshot.get_resource::<Gold>().unwrap().0, 10);
+    assert_eq!(
+        snapshot.extra::<String>("author").as_deref(),
+        Some("player")
+    );
+    assert!(snapshot.extra::<String>("script").is_none());
+
+    // Snapshots exceeding the limits are rejected untouched
+    let mut snapshot = world.capture(Shared);
+    let strict = policy().limits(DeserializeLimits {
+        max_entities: 1,
+        ..DeserializeLimits::DEFAULT
+    });
+
+    assert!(snapshot
+        .sanitize(&strict, world.resource::<AppTypeRegistry>())
+        .is_err());
+    assert_eq!(snapshot.entities.len(), 2);
+
+    // Loading sanitizes with the policy resource
+    world.save(Shared).unwrap();
+    world.insert_resource(policy());
+    world.load(Shared).unwrap();
+
+    let mut query = world.query_filtered::<&Health, Without<Admin>>();
+
+    assert_eq!(query.iter(world).map(|h| h.0).collect::<Vec<_>>(), [100.0]);
+    assert_eq!(world.query::<&Admin>().iter(world).count(), 0);
+    assert_eq!(world.resource::<SanitizeReport>().rejected.len(), 1);
+
+    std::fs::remove_dir_all(root).unwrap();
+}
@@ -0,0 +1,112 @@
+use bevy::prelude::*;
+use bevy_save::{
+    prelude::*,
+    Error,
+};
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Position(f32);
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Tile(u32);
+
+#[derive(Resource, Reflect, Default, Debug, PartialEq)]
+#[reflect(Resource)]
+struct Frame(u32);
+
+struct RollbackPipeline;
+
+impl Pipeline for RollbackPipeline {
+    type Backend = DefaultBackend;
+    type Format = DefaultFormat;
+
+    type Key<'a> = &'a str;
+
+    fn key(&self) -> Self::Key<'_> {
+        "rollback"
+    }
+
+    fn apply(world: &mut World, snapshot: &Snapshot) -> Result<(), Error> {
+        snapshot.applier(world).preserve_entities().apply()
+    }
+}
+
+#[test]
+fn test_shared_checkpoints() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Position>()
+        .register_type::<Tile>()
+        .register_type::<Frame>()
+        .insert_resource(Frame(0))
+        .insert_resource(
+            SharedCheckpoints::default().with_filter(
+                SceneFilter::deny_all()
+                    .allow::<Position>()
+                    .allow::<Tile>()
+                    .allow::<Frame>(),
+            ),
+        );
+
+    let world = &mut app.world;
+
+    let player = world.spawn(Position(0.0)).id();
+    world.spawn_batch((0..10).map(Tile));
+
+    world.checkpoint_shared();
+
+    world.get_mut::<Position>(player).unwrap().0 = 1.0;
+
+    world.checkpoint_shared();
+
+    world.resource_mut::<Frame>().0 = 2;
+    world.entity_mut(player).insert(Tile(99));
+
+    world.checkpoint_shared();
+
+    let checkpoints = world.resource::<SharedCheckpoints>();
+
+    assert_eq!(checkpoints.len(), 3);
+
+    let first = checkpoints.get(0).unwrap();
+    let second = checkpoints.get(1).unwrap();
+    let third = checkpoints.get(2).unwrap();
+
+    // Only the moved player is copied, the tiles and the frame are shared
+    assert_eq!(second.shared_with(first), 11);
+
+    // The player keeps its position, and the new tile and frame are copied
+    assert_eq!(third.shared_with(second), 11);
+
+    let snapshot = third.to_snapshot();
+    let entity = snapshot
+        .entities
+        .iter()
+        .find(|e| e.entity == player)
+        .unwrap();
+
+    assert_eq!(entity.components.len(), 2);
+    assert_eq!(snapshot.get_resource::<Frame>(), Some(Frame(2)));
+
+    world.rollback_shared::<RollbackPipeline>(2).unwrap();
+
+    assert_eq!(world.get::<Position>(player), Some(&Position(0.0)));
+    assert_eq!(world.resource::<Frame>(), &Frame(0));
+
+    // Checkpoints after rolling back are captured in full
+    world.checkpoint_shared();
+
+    let checkpoints = world.resource::<SharedCheckpoints>();
+
+    assert_eq!(checkpoints.len(), 2);
+    assert_eq!(
+        checkpoints
+            .get(1)
+            .unwrap()
+            .shared_with(checkpoints.get(0).unwrap()),
+        0
+    );
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "signing")]
+
+use std::marker::PhantomData;
+
+use bevy_save::{
+    prelude::*,
+    Error,
+};
+
+struct Key;
+
+impl SigningKey for Key {
+    fn key() -> &'static [u8] {
+        b"secret"
+    }
+}
+
+struct LegacyKey;
+
+impl SigningKey for LegacyKey {
+    fn key() -> &'static [u8] {
+        b"secret"
+    }
+
+    fn accept_unsigned() -> bool {
+        true
+    }
+}
+
+fn load<F: Format>(data: &[u8]) -> Result<Vec<u32>, Error> {
+    F::deserialize(data, PhantomData)
+}
+
+#[test]
+fn test_signing() {
+    let value = vec![1u32, 2, 3];
+
+    let mut data = Vec::new();
+    Signed::<JSONFormat, Key>::serialize(&mut data, &value).unwrap();
+
+    assert_eq!(load::<Signed<JSONFormat, Key>>(&data).unwrap(), value);
+
+    // Tampered
+    let last = data.len() - 2;
+    data[last] = b'4';
+
+    assert!(matches!(
+        load::<Signed<JSONFormat, Key>>(&data),
+        Err(Error::InvalidSignature)
+    ));
+
+    // Unsigned
+    let mut data = Vec::new();
+    JSONFormat::serialize(&mut data, &value).unwrap();
+
+    assert!(matches!(
+        load::<Signed<JSONFormat, Key>>(&data),
+        Err(Error::InvalidSignature)
+    ));
+
+    assert_eq!(load::<Signed<JSONFormat, LegacyKey>>(&data).unwrap(), value);
+}
+
+#[test]
+fn test_signing_metadata() {
+    assert_eq!(Signed::<JSONFormat, Key>::name(), JSONFormat::name());
+    assert_eq!(Signed::<JSONFormat, Key>::mime_type(), "application/json");
+
+    let value = vec![1u32, 2, 3];
+
+    let mut signed = Vec::new();
+    Signed::<JSONFormat, Key>::serialize(&mut signed, &value).unwrap();
+
+    let mut unsigned = Vec::new();
+    JSONFormat::serialize(&mut unsigned, &value).unwrap();
+
+    assert!(Signed::<JSONFormat, Key>::matches(&signed));
+    assert!(!Signed::<RMPFormat, Key>::matches(&signed));
+
+    // Unsigned saves only match if they are accepted
+    assert!(!Signed::<JSONFormat, Key>::matches(&unsigned));
+    assert!(Signed::<JSONFormat, LegacyKey>::matches(&unsigned));
+}
@@ -0,0 +1,135 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Health(u32);
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Player;
+
+#[derive(Resource, Reflect, Default, Debug, PartialEq)]
+#[reflect(Resource)]
+struct Score(u32);
+
+fn setup() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Health>()
+        .register_type::<Player>()
+        .register_type::<Score>()
+        .insert_resource(Score(7));
+
+    app
+}
+
+#[test]
+fn test_snapshot_getters() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    let player = world.spawn((Player, Health(10))).id();
+    let enemy = world.spawn(Health(20)).id();
+    let missing = world.spawn_empty().id();
+    world.despawn(missing);
+
+    let snapshot = Snapshot::builder(world)
+        .extract_entities([player, enemy].into_iter())
+        .extract_resource::<Score>()
+        .build();
+
+    let registry = world.resource::<AppTypeRegistry>();
+
+    // Deserialized snapshots only contain dynamic values
+    let mut data = Vec::new();
+    DefaultFormat::serialize(&mut data, &SnapshotSerializer::new(&snapshot, registry)).unwrap();
+
+    let loaded =
+        DefaultFormat::deserialize(&*data, SnapshotDeserializer::new(&registry.read())).unwrap();
+
+    for snapshot in [&snapshot, &loaded] {
+        assert_eq!(snapshot.get_component::<Health>(player), Some(Health(10)));
+        assert_eq!(snapshot.get_component::<Health>(enemy), Some(Health(20)));
+        assert_eq!(snapshot.get_component::<Player>(enemy), None);
+        assert_eq!(snapshot.get_component::<Health>(missing), None);
+
+        assert_eq!(snapshot.get_resource::<Score>(), Some(Score(7)));
+
+        assert_eq!(snapshot.entities_with::<Player>().collect::<Vec<_>>(), [
+            player
+        ]);
+        assert_eq!(snapshot.entities_with::<Health>().collect::<Vec<_>>(), [
+            player, enemy
+        ]);
+    }
+
+    let empty = Snapshot::builder(world).build();
+
+    assert_eq!(empty.get_resource::<Score>(), None);
+    assert_eq!(empty.entities_with::<Health>().count(), 0);
+}
+
+#[test]
+fn test_snapshot_mutation() {
+    let mut app = setup();
+    let world = &mut app.world;
+
+    let player = world.spawn((Player, Health(10))).id();
+    let enemy = world.spawn(Health(20)).id();
+    let boss = world.spawn(Health(30)).id();
+
+    let mut snapshot = Snapshot::builder(world)
+        .extract_all_entities()
+        .extract_resource::<Score>()
+        .build();
+
+    // Load, but reset health and score
+    snapshot.insert_component(player, Health(100));
+    snapshot.insert_resource(Score(0));
+
+    assert_eq!(snapshot.get_component::<Health>(player), Some(Health(100)));
+    assert_eq!(snapshot.get_resource::<Score>(), Some(Score(0)));
+    assert_eq!(snapshot.resources.len(), 1);
+
+    assert!(snapshot.remove_component::<Health>(enemy));
+    assert!(!snapshot.remove_component::<Health>(enemy));
+    assert!(!snapshot.remove_component::<Player>(enemy));
+
+    assert!(snapshot.remove_entity(boss));
+    assert!(!snapshot.remove_entity(boss));
+
+    snapshot.retain_entities(|e| e.entity != enemy || !e.components.is_empty());
+
+    assert_eq!(snapshot.entities.len(), 1);
+
+    let pet = world.spawn_empty().id();
+    world.despawn(pet);
+
+    // Inserting a component of a new entity adds it
+    snapshot.insert_component(pet, Health(5));
+
+    assert!(snapshot.remove_resource::<Score>());
+    assert!(!snapshot.remove_resource::<Score>());
+
+    world.insert_resource(Score(50));
+
+    snapshot
+        .applier(world)
+        .despawn::<With<Health>>()
+        .apply()
+        .unwrap();
+
+    let mut health = world
+        .query::<&Health>()
+        .iter(world)
+        .map(|h| h.0)
+        .collect::<Vec<_>>();
+
+    health.sort_unstable();
+
+    assert_eq!(health, [5, 100]);
+    assert_eq!(world.query::<&Player>().iter(world).count(), 1);
+    assert_eq!(world.resource::<Score>(), &Score(50));
+}
@@ -0,0 +1,121 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component, Default)]
+struct Health(u32);
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component, Default)]
+struct Mana(u32);
+
+#[test]
+fn test_sparse() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Health>()
+        .register_type::<Mana>();
+
+    let world = &mut app.world;
+
+    let entity = world.spawn((Health(0), Mana(5))).id();
+
+    let snapshot = Snapshot::builder(world)
+        .sparse()
+        .extract_entity(entity)
+        .build();
+
+    let components = &snapshot.entities[0].components;
+
+    assert_eq!(components.len(), 2);
+    assert!(components.iter().all(|c| !c.represents::<Health>()));
+    assert!(components
+        .iter()
+        .any(|c| c.represents::<DefaultComponents>()));
+
+    world.entity_mut(entity).insert(Health(10));
+
+    snapshot
+        .applier(world)
+        .entity_map(&mut [(entity, entity)].into_iter().collect())
+        .apply()
+        .unwrap();
+
+    assert_eq!(world.get::<Health>(entity), Some(&Health(0)));
+    assert_eq!(world.get::<Mana>(entity), Some(&Mana(5)));
+    assert!(world.get::<DefaultComponents>(entity).is_none());
+}
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Enemy;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Flying();
+
+#[test]
+fn test_compact_markers() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Health>()
+        .register_type::<Enemy>()
+        .register_type::<Flying>();
+
+    let world = &mut app.world;
+
+    let a = world.spawn((Health(1), Enemy, Flying())).id();
+    let b = world.spawn((Health(2), Enemy)).id();
+
+    let snapshot = Snapshot::builder(world)
+        .compact_markers()
+        .extract_all_entities()
+        .build();
+
+    let markers = snapshot.get_resource::<MarkerTypes>().unwrap();
+
+    assert_eq!(markers.types.len(), 2);
+    assert_eq!(
+        snapshot.get_component::<Markers>(a),
+        Some(Markers {
+            indices: vec![0, 1]
+        })
+    );
+    assert_eq!(
+        snapshot.get_component::<Markers>(b),
+        Some(Markers { indices: vec![0] })
+    );
+    assert!(snapshot.entities_with::<Enemy>().next().is_none());
+
+    let registry = world.resource::<AppTypeRegistry>();
+
+    let mut data = Vec::new();
+    DefaultFormat::serialize(&mut data, &SnapshotSerializer::new(&snapshot, registry)).unwrap();
+
+    let snapshot =
+        DefaultFormat::deserialize(&*data, SnapshotDeserializer::new(&registry.read())).unwrap();
+
+    world.despawn(a);
+    world.despawn(b);
+
+    let mut entity_map = Default::default();
+
+    snapshot
+        .applier(world)
+        .entity_map(&mut entity_map)
+        .apply()
+        .unwrap();
+
+    let a = entity_map[&a];
+    let b = entity_map[&b];
+
+    assert!(world.get::<Enemy>(a).is_some());
+    assert!(world.get::<Flying>(a).is_some());
+    assert!(world.get::<Enemy>(b).is_some());
+    assert!(world.get::<Flying>(b).is_none());
+    assert_eq!(world.get::<Health>(b), Some(&Health(2)));
+    assert!(world.get::<Markers>(a).is_none());
+    assert!(world.get_resource::<MarkerTypes>().is_none());
+}
@@ -0,0 +1,164 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Health(u32);
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component)]
+struct Gem(u32);
+
+#[derive(Resource, Reflect, Default, Debug, PartialEq)]
+#[reflect(Resource)]
+struct Score(u32);
+
+struct SplitPipeline<'a>(&'a str);
+
+impl<'a> Pipeline for SplitPipeline<'a> {
+    type Backend = DefaultDebugBackend;
+    type Format = DefaultDebugFormat;
+
+    type Key<'k> = &'k str;
+
+    fn key(&self) -> Self::Key<'_> {
+        self.0
+    }
+
+    fn capture(builder: SnapshotBuilder) -> Snapshot {
+        builder
+            .extract_all_entities()
+            .extract_resource::<Score>()
+            .build()
+    }
+}
+
+#[test]
+fn test_split() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .init_pipeline::<SplitPipeline>()
+        .register_type::<Health>()
+        .register_type::<Score>()
+        .insert_resource(Score(10));
+
+    let world = &mut app.world;
+
+    world.spawn(Health(5));
+
+    let dir = std::env::temp_dir().join("bevy_save_split");
+    let key = dir.join("slot").to_string_lossy().into_owned();
+
+    world.save_split(SplitPipeline(&key)).unwrap();
+
+    let index = world
+        .resource::<SectionHashes>()
+        .get(&key)
+        .cloned()
+        .unwrap();
+
+    assert!(index.sections.contains_key(SECTION_ENTITIES));
+    assert!(index.sections.contains_key(SECTION_RESOURCES));
+    assert!(!index.sections.contains_key(SECTION_ROLLBACKS));
+
+    world.resource_mut::<Score>().0 = 20;
+    world.save_split(SplitPipeline(&key)).unwrap();
+
+    let changed = world
+        .resource::<SectionHashes>()
+        .get(&key)
+        .cloned()
+        .unwrap();
+
+    assert_eq!(
+        index.sections.get(SECTION_ENTITIES),
+        changed.sections.get(SECTION_ENTITIES)
+    );
+    assert_ne!(
+        index.sections.get(SECTION_RESOURCES),
+        changed.sections.get(SECTION_RESOURCES)
+    );
+
+    world.resource_mut::<Score>().0 = 30;
+    world.clear_entities();
+    world.load_split(SplitPipeline(&key)).unwrap();
+
+    assert_eq!(world.resource::<Score>(), &Score(20));
+    assert_eq!(
+        world.query::<&Health>().iter(world).collect::<Vec<_>>(),
+        vec![&Health(5)]
+    );
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
+
+fn split_app() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .init_pipeline::<SplitPipeline>()
+        .register_type::<Health>()
+        .register_type::<Score>()
+        .insert_resource(Score(10));
+
+    app
+}
+
+#[test]
+fn test_split_load_checks() {
+    let dir = std::env::temp_dir().join("bevy_save_split_checks");
+    let key = dir.join("slot").to_string_lossy().into_owned();
+
+    let mut app = split_app();
+
+    app.register_mod_type::<Gem>("gems");
+    app.world.spawn((Health(5), Gem(3)));
+    app.world.save_split(SplitPipeline(&key)).unwrap();
+
+    // The limits of the world are enforced
+    let mut app = split_app();
+
+    app.register_mod_type::<Gem>("gems")
+        .insert_resource(DeserializeLimits {
+            max_entities: 0,
+            ..default()
+        });
+
+    assert!(app.world.load_split(SplitPipeline(&key)).is_err());
+
+    // The data of missing mods is kept
+    let mut app = split_app();
+
+    app.register_mod_type::<Health>("base");
+    app.world.load_split(SplitPipeline(&key)).unwrap();
+
+    assert_eq!(app.world.resource::<MissingMods>().0[0].name, "gems");
+
+    app.world.save_split(SplitPipeline(&key)).unwrap();
+
+    let mut app = split_app();
+
+    app.register_mod_type::<Gem>("gems");
+    app.world.load_split(SplitPipeline(&key)).unwrap();
+
+    let mut query = app.world.query::<(&Health, &Gem)>();
+    assert_eq!(query.single(&app.world), (&Health(5), &Gem(3)));
+
+    // The sanitize policy of the world is applied
+    let mut app = split_app();
+
+    app.register_mod_type::<Gem>("gems")
+        .insert_resource(SanitizePolicy::new().allow::<Health>().allow::<Gem>());
+    app.world.resource_mut::<Score>().0 = 30;
+    app.world.load_split(SplitPipeline(&key)).unwrap();
+
+    assert_eq!(app.world.resource::<Score>(), &Score(30));
+    assert!(app
+        .world
+        .resource::<SanitizeReport>()
+        .disallowed
+        .contains(Score::type_path()));
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
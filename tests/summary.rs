@@ -0,0 +1,173 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    sync::Mutex,
+};
+
+use bevy::prelude::*;
+use bevy_save::{
+    prelude::*,
+    Error,
+};
+use serde::{
+    de::DeserializeSeed,
+    Serialize,
+};
+
+#[derive(Resource, Default)]
+struct MemoryBackend {
+    files: Mutex<HashMap<String, Vec<u8>>>,
+}
+
+impl<K: Display> Backend<K> for MemoryBackend {
+    fn save<F: Format, T: Serialize>(&self, key: K, value: &T) -> Result<(), Error> {
+        let mut data = Vec::new();
+        F::serialize(&mut data, value)?;
+
+        self.files
+            .lock()
+            .unwrap()
+            .insert(format!("{key}{}", F::extension()), data);
+
+        Ok(())
+    }
+
+    fn load<F: Format, S: for<'de> DeserializeSeed<'de, Value = T>, T>(
+        &self,
+        key: K,
+        seed: S,
+    ) -> Result<T, Error> {
+        let files = self.files.lock().unwrap();
+        let data = files
+            .get(&format!("{key}{}", F::extension()))
+            .ok_or(Error::custom("missing"))?;
+
+        F::deserialize(data.as_slice(), seed)
+    }
+}
+
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct HardcoreMode;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Enemy;
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Player;
+
+struct SlotPipeline(&'static str);
+
+impl Pipeline for SlotPipeline {
+    type Backend = MemoryBackend;
+    type Format = RMPFormat;
+
+    type Key<'a> = &'a str;
+
+    fn summary() -> bool {
+        true
+    }
+
+    fn key(&self) -> Self::Key<'_> {
+        self.0
+    }
+
+    fn capture(builder: SnapshotBuilder) -> Snapshot {
+        builder
+            .extract_all_entities()
+            .extract_resource::<HardcoreMode>()
+            .build()
+    }
+}
+
+#[test]
+fn test_summary() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .init_pipeline::<SlotPipeline>()
+        .register_type::<HardcoreMode>()
+        .register_type::<Enemy>()
+        .register_type::<Player>();
+
+    let world = &mut app.world;
+
+    world.insert_resource(HardcoreMode);
+    world.spawn(Player);
+    world.spawn_batch([Enemy, Enemy, Enemy]);
+
+    world.save(SlotPipeline("slot")).unwrap();
+
+    let summary = SaveSummary::load(world, SlotPipeline("slot")).unwrap();
+
+    assert!(summary.contains_resource::<HardcoreMode>());
+    assert_eq!(summary.entities, 4);
+    assert_eq!(summary.count::<Enemy>(), 3);
+    assert_eq!(summary.count::<Player>(), 1);
+    assert_eq!(summary.count::<Transform>(), 0);
+
+    // The summary of a save that was never written is missing
+    assert!(SaveSummary::load(world, SlotPipeline("other")).is_err());
+
+    // The summary is written as standalone text
+    let mut data = Vec::new();
+    summary.write(&mut data).unwrap();
+
+    assert_eq!(SaveSummary::read(&*data).unwrap(), summary);
+}
+
+struct NoExtensionPipeline;
+
+impl Pipeline for NoExtensionPipeline {
+    type Backend = FileIO;
+    type Format = RMPFormat;
+
+    type Key<'a> = &'a str;
+
+    fn summary() -> bool {
+        true
+    }
+
+    fn key(&self) -> Self::Key<'_> {
+        "bevy_save_summary_no_extension.sav"
+    }
+
+    fn capture(builder: SnapshotBuilder) -> Snapshot {
+        builder.extract_all_entities().build()
+    }
+}
+
+#[test]
+fn test_summary_without_extension() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .init_pipeline::<NoExtensionPipeline>()
+        .insert_resource(FileIO::default().append_extension(false))
+        .register_type::<Enemy>();
+
+    let world = &mut app.world;
+
+    world.spawn(Enemy);
+    world.save(NoExtensionPipeline).unwrap();
+
+    // The summary does not overwrite the save
+    world.clear_entities();
+    world.load(NoExtensionPipeline).unwrap();
+
+    assert_eq!(world.query::<&Enemy>().iter(world).count(), 1);
+    assert_eq!(
+        SaveSummary::load(world, NoExtensionPipeline)
+            .unwrap()
+            .count::<Enemy>(),
+        1
+    );
+
+    let io = world.resource::<FileIO>();
+    let key = NoExtensionPipeline.key();
+
+    std::fs::remove_file(io.path::<RMPFormat>(key)).unwrap();
+    std::fs::remove_file(io.path::<RONFormat>(format!("{key}.summary"))).unwrap();
+}
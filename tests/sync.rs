@@ -0,0 +1,83 @@
+use bevy::prelude::*;
+use bevy_save::{
+    prelude::*,
+    Error,
+};
+
+fn setup(device: &str) -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .insert_resource(DeviceId(device.into()));
+
+    app
+}
+
+#[test]
+fn test_sync_conflict() {
+    let path = std::env::temp_dir().join("bevy_save_sync");
+    let key = path.to_string_lossy().into_owned();
+
+    let _ = std::fs::remove_file(format!("{key}.json"));
+
+    let mut a = setup("a");
+    let mut b = setup("b");
+
+    a.world.save_synced(DebugPipeline(&key)).unwrap();
+
+    assert_eq!(a.world.resource::<SaveRevision>().counter(), 1);
+
+    b.world.load(DebugPipeline(&key)).unwrap();
+    b.world.save_synced(DebugPipeline(&key)).unwrap();
+
+    let revision = b.world.resource::<SaveRevision>();
+
+    assert_eq!(revision.counter(), 2);
+    assert_eq!(
+        revision.compare(a.world.resource::<SaveRevision>()),
+        Causality::After
+    );
+
+    assert!(matches!(
+        a.world.save_synced(DebugPipeline(&key)),
+        Err(Error::Conflict)
+    ));
+
+    let events = a.world.resource::<Events<SaveConflict>>();
+    let mut reader = events.get_reader();
+    let conflict = reader.read(events).next().unwrap();
+
+    assert_eq!(conflict.remote.counter(), 2);
+    assert_eq!(conflict.local.counter(), 1);
+
+    // Resolve by keeping the local state
+    let remote = conflict.remote.clone();
+
+    a.world.resource_mut::<SaveRevision>().merge(&remote);
+    a.world.save_synced(DebugPipeline(&key)).unwrap();
+
+    assert_eq!(a.world.resource::<SaveRevision>().counter(), 3);
+}
+
+#[test]
+fn test_sync_unreadable_remote() {
+    let path = std::env::temp_dir().join("bevy_save_sync_unreadable");
+    let key = path.to_string_lossy().into_owned();
+    let file = format!("{key}.json");
+
+    std::fs::write(&file, "not a save").unwrap();
+
+    let mut app = setup("a");
+
+    // The stored save is kept instead of being treated as an empty history
+    assert!(app.world.save_synced(DebugPipeline(&key)).is_err());
+    assert_eq!(std::fs::read_to_string(&file).unwrap(), "not a save");
+    assert!(!app.world.contains_resource::<SaveRevision>());
+
+    std::fs::remove_file(&file).unwrap();
+
+    app.world.save_synced(DebugPipeline(&key)).unwrap();
+
+    assert_eq!(app.world.resource::<SaveRevision>().counter(), 1);
+    assert!(!std::path::Path::new(&format!("{file}.tmp")).exists());
+}
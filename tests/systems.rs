@@ -0,0 +1,95 @@
+use bevy::{
+    ecs::schedule::ScheduleLabel,
+    prelude::*,
+};
+use bevy_save::{
+    prelude::*,
+    testing::{
+        FlakyBackend,
+        NoopBackend,
+    },
+    Error,
+};
+
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+struct Position(i32);
+
+#[derive(Resource)]
+struct Rewind;
+
+#[derive(Resource)]
+struct SaveNow;
+
+#[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+struct Simulation;
+
+#[derive(Clone)]
+struct Slot;
+
+impl Pipeline for Slot {
+    type Backend = FlakyBackend<NoopBackend>;
+    type Format = DefaultFormat;
+
+    type Key<'a> = &'a str;
+
+    fn key(&self) -> Self::Key<'_> {
+        "slot"
+    }
+
+    fn capture(builder: SnapshotBuilder) -> Snapshot {
+        builder.extract_all_entities().build()
+    }
+
+    fn apply(world: &mut World, snapshot: &Snapshot) -> Result<(), Error> {
+        snapshot.applier(world).preserve_entities().apply()
+    }
+}
+
+fn advance(mut query: Query<&mut Position>) {
+    for mut position in &mut query {
+        position.0 += 1;
+    }
+}
+
+fn operations(world: &World) -> usize {
+    world.resource::<FlakyBackend<NoopBackend>>().operations()
+}
+
+#[test]
+fn test_schedule_systems() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .init_pipeline::<Slot>()
+        .register_type::<Position>()
+        .allow_rollback::<Position>()
+        .add_systems(
+            Simulation,
+            (
+                rollback_system::<Slot>(2).run_if(resource_exists::<Rewind>),
+                advance,
+                checkpoint_system::<Slot>(),
+                save_system(Slot).run_if(resource_exists::<SaveNow>),
+            )
+                .chain(),
+        );
+
+    let world = &mut app.world;
+    let entity = world.spawn(Position(0)).id();
+
+    for _ in 0..3 {
+        world.run_schedule(Simulation);
+    }
+
+    assert_eq!(world.get::<Position>(entity).unwrap().0, 3);
+    assert_eq!(operations(world), 0);
+
+    // Rolls back to the checkpoint of the first tick before advancing
+    world.insert_resource(Rewind);
+    world.insert_resource(SaveNow);
+    world.run_schedule(Simulation);
+
+    assert_eq!(world.get::<Position>(entity).unwrap().0, 2);
+    assert_eq!(operations(world), 1);
+}
@@ -0,0 +1,64 @@
+use bevy::prelude::*;
+use bevy_save::{
+    prelude::*,
+    testing::FlakyBackend,
+    Error,
+    ErrorCode,
+};
+
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+struct Score(u32);
+
+struct TestPipeline;
+
+impl Pipeline for TestPipeline {
+    type Backend = FlakyBackend<DirBackend>;
+    type Format = DefaultFormat;
+
+    type Key<'a> = &'a str;
+
+    fn key(&self) -> Self::Key<'_> {
+        "flaky"
+    }
+
+    fn capture(builder: SnapshotBuilder) -> Snapshot {
+        builder.extract_resource::<Score>().build()
+    }
+}
+
+#[test]
+fn test_flaky_backend() {
+    let dir = std::env::temp_dir().join("bevy_save_test_flaky");
+
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Score>()
+        .insert_resource(Score(5))
+        .insert_resource(
+            FlakyBackend::new(DirBackend::new(&dir), 3).with_error(|| Error::custom("flaky")),
+        );
+
+    let world = &mut app.world;
+
+    // Operations are passed through until every third one fails
+    world.save(TestPipeline).unwrap();
+    world.load(TestPipeline).unwrap();
+
+    let err = world.load(TestPipeline).unwrap_err();
+
+    assert_eq!(err.code(), ErrorCode::Custom);
+
+    world.resource_mut::<Score>().0 = 0;
+    world.load(TestPipeline).unwrap();
+
+    assert_eq!(world.resource::<Score>().0, 5);
+
+    let backend = world.resource::<FlakyBackend<DirBackend>>();
+
+    assert_eq!(backend.operations(), 4);
+    assert_eq!(backend.failures(), 1);
+
+    std::fs::remove_dir_all(dir).unwrap();
+}
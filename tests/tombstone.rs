@@ -0,0 +1,129 @@
+use bevy::prelude::*;
+use bevy_save::{
+    prelude::*,
+    testing::NoopBackend,
+};
+
+struct Region;
+
+impl Pipeline for Region {
+    type Backend = NoopBackend;
+    type Format = DefaultFormat;
+
+    type Key<'k> = &'k str;
+
+    fn key(&self) -> Self::Key<'_> {
+        "region"
+    }
+
+    fn capture(builder: SnapshotBuilder) -> Snapshot {
+        builder
+            .extract_entities_matching(|e| e.contains::<SaveId>())
+            .extract_resource::<Tombstones>()
+            .build()
+    }
+
+    fn apply(world: &mut World, snapshot: &Snapshot) -> Result<(), bevy_save::Error> {
+        snapshot.applier(world).despawn::<With<SaveId>>().apply()
+    }
+}
+
+fn setup() -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .allow_rollback::<SaveId>();
+
+    app
+}
+
+fn ids(world: &mut World) -> Vec<u64> {
+    let mut ids = world
+        .query::<&SaveId>()
+        .iter(world)
+        .map(|id| id.0)
+        .collect::<Vec<_>>();
+
+    ids.sort_unstable();
+    ids
+}
+
+#[test]
+fn test_tombstones() {
+    let mut app = setup();
+
+    let a = app.world.spawn(SaveId(0)).id();
+    app.world.spawn(SaveId(1));
+
+    app.update();
+
+    let region_a = Snapshot::builder(&app.world)
+        .extract_entity(a)
+        .extract_resource::<Tombstones>()
+        .build();
+
+    app.world.despawn(a);
+    app.update();
+
+    assert!(app.world.resource::<Tombstones>().contains(SaveId(0)));
+
+    let region_b = Snapshot::builder(&app.world)
+        .extract_resource::<Tombstones>()
+        .build();
+
+    // Loading the older save, then the newer save despawns the entity
+    let mut other = setup();
+    other.world.spawn(SaveId(0));
+
+    region_a.apply(&mut other.world).unwrap();
+    region_b.apply(&mut other.world).unwrap();
+
+    assert_eq!(ids(&mut other.world), Vec::<u64>::new());
+
+    // The tombstones are carried into the next save
+    assert!(other.world.resource::<Tombstones>().contains(SaveId(0)));
+}
+
+#[test]
+fn test_tombstones_load_twice() {
+    let mut app = setup();
+
+    app.world.spawn(SaveId(5));
+    app.update();
+
+    let snapshot = app.world.snapshot::<Region>();
+
+    // Entities replaced while loading are not tombstoned
+    Region::apply(&mut app.world, &snapshot).unwrap();
+    app.update();
+
+    assert!(!app.world.resource::<Tombstones>().contains(SaveId(5)));
+
+    Region::apply(&mut app.world, &snapshot).unwrap();
+    app.update();
+
+    assert_eq!(ids(&mut app.world), [5]);
+    assert!(app.world.resource::<Tombstones>().ids.is_empty());
+}
+
+#[test]
+fn test_tombstones_rollback() {
+    let mut app = setup();
+
+    let entity = app.world.spawn(SaveId(7)).id();
+    app.update();
+
+    app.world.checkpoint::<Region>();
+
+    app.world.despawn(entity);
+    app.update();
+
+    assert!(app.world.resource::<Tombstones>().contains(SaveId(7)));
+
+    // Rolling back restores the entity and removes its tombstone
+    app.world.rollback::<Region>(0).unwrap();
+    app.update();
+
+    assert_eq!(ids(&mut app.world), [7]);
+    assert!(!app.world.resource::<Tombstones>().contains(SaveId(7)));
+}
@@ -0,0 +1,87 @@
+#![cfg(debug_assertions)]
+
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use bevy::{
+    log::tracing_subscriber::{
+        layer::{
+            Context,
+            SubscriberExt,
+        },
+        Layer,
+        Registry,
+    },
+    prelude::*,
+    utils::tracing::{
+        field::{
+            Field,
+            Visit,
+        },
+        subscriber,
+        Event,
+        Subscriber,
+    },
+};
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+struct Target(Entity);
+
+/// Collects the messages of all logged events.
+#[derive(Clone, Default)]
+struct Capture(Arc<Mutex<Vec<String>>>);
+
+impl Visit for Capture {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.0.lock().unwrap().push(format!("{value:?}"));
+        }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for Capture {
+    fn on_event(&self, event: &Event<'_>, _: Context<'_, S>) {
+        event.record(&mut self.clone());
+    }
+}
+
+#[test]
+fn test_warn_unmapped_entities() {
+    let capture = Capture::default();
+
+    // Systems may run on other threads
+    subscriber::set_global_default(Registry::default().with(capture.clone())).unwrap();
+
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Target>();
+
+    let target = app.world.spawn_empty().id();
+    app.world.spawn(Target(target));
+
+    let warnings = || {
+        capture
+            .0
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|m| m.contains("unmapped::Target"))
+            .count()
+    };
+
+    // Warned once at startup, before anything is captured
+    app.update();
+    assert_eq!(warnings(), 1);
+
+    for _ in 0..3 {
+        Snapshot::builder(&app.world).extract_all_entities().build();
+        app.update();
+    }
+
+    assert_eq!(warnings(), 1);
+}
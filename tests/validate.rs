@@ -0,0 +1,111 @@
+use bevy::prelude::*;
+use bevy_save::prelude::*;
+
+#[derive(Component, Reflect, Default, Debug, PartialEq)]
+#[reflect(Component, Validate)]
+struct Health(f32);
+
+impl Validate for Health {
+    fn validate(&mut self) -> Result<(), String> {
+        if self.0.is_nan() {
+            return Err("health is NaN".into());
+        }
+
+        self.0 = self.0.clamp(0.0, 100.0);
+        Ok(())
+    }
+}
+
+#[derive(Resource, Reflect, Default, Debug, PartialEq)]
+#[reflect(Resource, Validate)]
+struct Difficulty(u32);
+
+impl Validate for Difficulty {
+    fn validate(&mut self) -> Result<(), String> {
+        if self.0 > 3 {
+            return Err(format!("unknown difficulty {}", self.0));
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_validate() {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .register_type::<Health>()
+        .register_type::<Difficulty>()
+        .add_validator(|transform: &mut Transform| {
+            if transform.is_finite() {
+                Ok(())
+            } else {
+                Err("transform is not finite".into())
+            }
+        });
+
+    let world = &mut app.world;
+
+    let healthy = world.spawn((Health(250.0), Transform::default())).id();
+    let corrupted = world
+        .spawn((
+            Health(f32::NAN),
+            Transform::from_xyz(f32::INFINITY, 0.0, 0.0),
+        ))
+        .id();
+
+    world.insert_resource(Difficulty(7));
+
+    let snapshot = Snapshot::builder(world)
+        .extract_all_entities()
+        .extract_resource::<Difficulty>()
+        .build();
+
+    let registry = world.resource::<AppTypeRegistry>();
+
+    let mut data = Vec::new();
+    DefaultFormat::serialize(&mut data, &SnapshotSerializer::new(&snapshot, registry)).unwrap();
+
+    let snapshot =
+        DefaultFormat::deserialize(&*data, SnapshotDeserializer::new(&registry.read())).unwrap();
+
+    world.clear_entities();
+    world.insert_resource(Difficulty(1));
+
+    let mut entity_map = Default::default();
+
+    snapshot
+        .applier(world)
+        .entity_map(&mut entity_map)
+        .apply()
+        .unwrap();
+
+    // Fixed values are applied, rejected values are skipped
+    assert_eq!(
+        world.get::<Health>(entity_map[&healthy]),
+        Some(&Health(100.0))
+    );
+    assert!(world.get::<Transform>(entity_map[&healthy]).is_some());
+    assert!(world.get::<Health>(entity_map[&corrupted]).is_none());
+    assert!(world.get::<Transform>(entity_map[&corrupted]).is_none());
+    assert_eq!(world.resource::<Difficulty>(), &Difficulty(1));
+
+    let report = world.resource::<ApplyReport>();
+
+    assert_eq!(report.rejected.len(), 3);
+    assert!(report
+        .rejected
+        .iter()
+        .any(|r| r.entity.is_none() && r.reason == "unknown difficulty 7"));
+    assert!(report
+        .rejected
+        .iter()
+        .filter_map(|r| r.entity)
+        .all(|e| e == corrupted));
+
+    // The report is replaced by the next apply
+    Snapshot::builder(world).build().apply(world).unwrap();
+
+    assert!(world.resource::<ApplyReport>().is_clean());
+}
@@ -0,0 +1,137 @@
+use bevy::prelude::*;
+use bevy_save::{
+    prelude::*,
+    Error,
+};
+
+fn setup(version: &str, compatibility: SaveCompatibility) -> App {
+    let mut app = App::new();
+
+    app.add_plugins((MinimalPlugins, SavePlugins))
+        .set_game_version(version, compatibility);
+
+    app
+}
+
+#[test]
+fn test_version() {
+    let path = std::env::temp_dir().join("bevy_save_version");
+    let key = path.to_string_lossy().into_owned();
+
+    let app = setup("1.2.0", SaveCompatibility::default());
+
+    app.world.save(DebugPipeline(&key)).unwrap();
+
+    // Same version
+    let mut app = setup("1.2.0", SaveCompatibility::default());
+
+    app.world.load(DebugPipeline(&key)).unwrap();
+
+    assert_eq!(app.world.resource::<GameVersion>().0, "1.2.0");
+
+    // Newer game can load older saves
+    let mut app = setup("1.10.0", SaveCompatibility {
+        min: Some("1.0.0".into()),
+        max: None,
+    });
+
+    app.world.load(DebugPipeline(&key)).unwrap();
+
+    assert_eq!(app.world.resource::<GameVersion>().0, "1.10.0");
+
+    // Save is too old
+    let mut app = setup("2.0.0", SaveCompatibility {
+        min: Some("2.0.0".into()),
+        max: None,
+    });
+
+    assert!(matches!(
+        app.world.load(DebugPipeline(&key)),
+        Err(Error::IncompatibleSave { save, .. }) if save == "1.2.0"
+    ));
+
+    // Save is too new
+    let mut app = setup("1.1.0", SaveCompatibility::default());
+
+    assert!(matches!(
+        app.world.load(DebugPipeline(&key)),
+        Err(Error::IncompatibleSave { .. })
+    ));
+}
+
+#[test]
+fn test_header() {
+    let path = std::env::temp_dir().join("bevy_save_header");
+    let key = path.to_string_lossy().into_owned();
+    let file = format!("{key}.json");
+
+    let mut app = setup("1.0.0", SaveCompatibility::default());
+
+    app.world.save(DebugPipeline(&key)).unwrap();
+
+    let data = std::fs::read(&file).unwrap();
+    let (header, _) = SaveHeader::read(data.as_slice()).unwrap();
+
+    assert_eq!(header, Some(SaveHeader::current::<JSONFormat>()));
+    assert_eq!(header.unwrap().format, "json");
+
+    // Saves written without a header still load
+    let contents = data.splitn(2, |b| *b == b'\n').nth(1).unwrap();
+    std::fs::write(&file, contents).unwrap();
+
+    app.world.load(DebugPipeline(&key)).unwrap();
+
+    // Saves written with another format are rejected
+    let mut data = Vec::new();
+    SaveHeader::current::<RMPFormat>().write(&mut data).unwrap();
+    data.extend_from_slice(contents);
+    std::fs::write(&file, data).unwrap();
+
+    assert!(app.world.load(DebugPipeline(&key)).is_err());
+}
+
+#[test]
+fn test_format_names() {
+    assert_eq!(JSONFormat::name(), "json");
+    assert_eq!(RMPFormat::name(), "msgpack");
+    assert_eq!(Quantized::<JSONFormat, 2>::name(), "json");
+
+    #[cfg(feature = "brotli")]
+    {
+        assert_eq!(Brotli::<JSONFormat>::name(), "brotli(json)");
+        assert_eq!(Brotli::<RMPFormat>::name(), "brotli(msgpack)");
+        assert!(std::ptr::eq(
+            Brotli::<JSONFormat>::name(),
+            Brotli::<JSONFormat>::name()
+        ));
+    }
+}
+
+#[test]
+fn test_version_ordering() {
+    let compatibility = SaveCompatibility::default();
+
+    // Missing components are zero
+    assert!(compatibility.check("1.0", Some("1.0.0")).is_ok());
+    assert!(compatibility.check("1.0.0", Some("1")).is_ok());
+    assert!(compatibility.check("1.9.2", Some("1.10")).is_err());
+
+    // Pre-releases come before their release
+    assert!(compatibility.check("2.0.0", Some("2.0.0-rc.1")).is_ok());
+    assert!(compatibility.check("2.0.0-rc.1", Some("2.0.0")).is_err());
+    assert!(compatibility
+        .check("2.0.0-rc.2", Some("2.0.0-rc.10"))
+        .is_err());
+    assert!(compatibility
+        .check("2.0.0-rc.1", Some("2.0.0-beta"))
+        .is_ok());
+
+    let compatibility = SaveCompatibility {
+        min: Some("2.0.0".into()),
+        max: None,
+    };
+
+    assert!(compatibility.check("2.1.0", Some("2.0.0-rc.1")).is_err());
+    assert!(compatibility.check("2.1.0", Some("2.0")).is_ok());
+    assert!(compatibility.check("2.1.0", Some("2.0.0+build.5")).is_ok());
+}